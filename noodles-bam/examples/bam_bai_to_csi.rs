@@ -0,0 +1,21 @@
+//! Converts a BAM index (BAI) to a coordinate-sorted index (CSI).
+//!
+//! This writes the output to stdout rather than `<src>.csi`.
+
+use std::{env, io};
+
+use noodles_bam::bai;
+use noodles_csi as csi;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = bai::fs::read(src)?;
+    let index = csi::Index::from(index);
+
+    let stdout = io::stdout().lock();
+    let mut writer = csi::io::Writer::new(stdout);
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! Validates a BAM index against its BAM file.
+//!
+//! The index is read from `<src>.bai`. Each issue is printed to stderr. If there are any issues,
+//! the repaired index is written to stdout.
+
+use std::{env, io, process};
+
+use noodles_bam::{self as bam, bai};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = bai::fs::read(format!("{src}.bai"))?;
+    let report = bam::fs::validate_index(&src, &index)?;
+
+    for issue in report.issues() {
+        eprintln!("{issue}");
+    }
+
+    if let Some(repaired_index) = report.repaired_index() {
+        let stdout = io::stdout().lock();
+        let mut writer = bai::io::Writer::new(stdout);
+        writer.write_index(repaired_index)?;
+
+        process::exit(1);
+    }
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 //! BAM filesystem operations.
 
-mod index;
+pub(crate) mod index;
 
 use std::{fs::File, io, path::Path};
 
 use noodles_bgzf as bgzf;
 
-pub use self::index::index;
+pub use self::index::{index, index_with_worker_count, validate as validate_index};
 use super::io::Reader;
 
 fn open<P>(src: P) -> io::Result<Reader<bgzf::io::Reader<File>>>
@@ -1,3 +1,6 @@
+mod multithreaded;
+mod validate;
+
 use std::{io, path::Path};
 
 use noodles_bgzf as bgzf;
@@ -9,6 +12,7 @@ use noodles_sam::{
     header::record::value::map::header::{sort_order::COORDINATE, tag::SORT_ORDER},
 };
 
+pub use self::{multithreaded::index_with_worker_count, validate::validate};
 use crate::{Record, bai, io::Reader};
 
 /// Indexes a BAM file.
@@ -33,31 +37,58 @@ where
     index_inner(&mut reader)
 }
 
-fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<bai::Index>
+pub(crate) fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<bai::Index>
 where
     R: bgzf::io::Read,
 {
     let header = reader.read_header()?;
+    validate_sort_order(&header)?;
 
-    if !is_coordinate_sorted(&header) {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "invalid sort order: expected {:?}, got {:?}",
-                Some(COORDINATE),
-                header
-                    .header()
-                    .and_then(|hdr| hdr.other_fields().get(&SORT_ORDER))
-            ),
-        ));
-    }
+    let mut indexer = Indexer::default();
+    index_records(reader, &mut indexer, None)?;
 
-    let mut record = Record::default();
+    Ok(indexer.build(header.reference_sequences().len()))
+}
 
-    let mut builder = Indexer::default();
+// Indexes records until the end of the stream or until a record starts at or after `limit`. This
+// returns the position of the stream after the last indexed record.
+fn index_records<R>(
+    reader: &mut Reader<R>,
+    indexer: &mut Indexer<Vec<bgzf::VirtualPosition>>,
+    limit: Option<bgzf::VirtualPosition>,
+) -> io::Result<bgzf::VirtualPosition>
+where
+    R: bgzf::io::Read,
+{
+    read_records(reader, limit, |alignment_context, chunk| {
+        indexer.add_record(alignment_context, chunk)
+    })
+}
+
+// Reads records until the end of the stream or until a record starts at or after `limit`, calling
+// `f` with the alignment context and chunk of each record. This returns the position of the
+// stream after the last read record.
+fn read_records<R, F>(
+    reader: &mut Reader<R>,
+    limit: Option<bgzf::VirtualPosition>,
+    mut f: F,
+) -> io::Result<bgzf::VirtualPosition>
+where
+    R: bgzf::io::Read,
+    F: FnMut(Option<(usize, Position, Position, bool)>, Chunk) -> io::Result<()>,
+{
+    let mut record = Record::default();
     let mut start_position = reader.get_ref().virtual_position();
 
-    while reader.read_record(&mut record)? != 0 {
+    loop {
+        if limit.is_some_and(|limit| start_position >= limit) {
+            break;
+        }
+
+        if reader.read_record(&mut record)? == 0 {
+            break;
+        }
+
         let end_position = reader.get_ref().virtual_position();
         let chunk = Chunk::new(start_position, end_position);
 
@@ -69,12 +100,29 @@ where
             _ => None,
         };
 
-        builder.add_record(alignment_context, chunk)?;
+        f(alignment_context, chunk)?;
 
         start_position = end_position;
     }
 
-    Ok(builder.build(header.reference_sequences().len()))
+    Ok(start_position)
+}
+
+pub(crate) fn validate_sort_order(header: &sam::Header) -> io::Result<()> {
+    if is_coordinate_sorted(header) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid sort order: expected {:?}, got {:?}",
+                Some(COORDINATE),
+                header
+                    .header()
+                    .and_then(|hdr| hdr.other_fields().get(&SORT_ORDER))
+            ),
+        ))
+    }
 }
 
 fn is_coordinate_sorted(header: &sam::Header) -> bool {
@@ -0,0 +1,373 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, Read, Seek},
+    num::NonZeroUsize,
+    panic,
+    path::Path,
+    thread,
+};
+
+use noodles_bgzf::{self as bgzf, io::Seek as _};
+use noodles_csi::binning_index::Indexer;
+use noodles_sam as sam;
+
+use super::{index_records, validate_sort_order};
+use crate::{bai, io::Reader};
+
+// The number of bytes read at the start of a partition to find the first record.
+const SCAN_LEN: u64 = 1 << 20;
+
+// The number of consecutive records that must be valid for an offset to be a record start.
+const MIN_CHAIN_LEN: usize = 8;
+
+type PartialIndex = (Indexer<Vec<bgzf::VirtualPosition>>, bgzf::VirtualPosition);
+
+/// Indexes a BAM file using multiple threads.
+///
+/// The file is split into `worker_count` partitions at BGZF block boundaries, and each partition
+/// is indexed on its own thread. The partial indexes are then merged in order. The resulting
+/// index is the same as the one built by [`super::index`].
+///
+/// The start of the first record in a partition is found by validating the fixed-length fields of
+/// consecutive records. If the start of a partition does not match the end of the previous
+/// partition, the partition is reindexed from the end of the previous partition.
+///
+/// The input must be coordinate-sorted and marked as such in the SAM header, i.e.,
+/// `SO:coordinate`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{num::NonZeroUsize, thread};
+/// use noodles_bam as bam;
+///
+/// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+/// let _index = bam::fs::index_with_worker_count("sample.bam", worker_count)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_with_worker_count<P>(src: P, worker_count: NonZeroUsize) -> io::Result<bai::Index>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+    index_inner(|| File::open(src), worker_count)
+}
+
+pub(super) fn index_inner<F, R>(open: F, worker_count: NonZeroUsize) -> io::Result<bai::Index>
+where
+    F: Fn() -> io::Result<R> + Sync,
+    R: Read + Seek,
+{
+    let mut reader = open().map(Reader::new)?;
+    let header = reader.read_header()?;
+    validate_sort_order(&header)?;
+
+    let header_end = reader.get_ref().virtual_position();
+
+    let positions: Vec<_> = bgzf::io::partition(&mut open()?, worker_count)?
+        .into_iter()
+        .filter(|&position| position > header_end.compressed())
+        .collect();
+
+    let mut ends = Vec::with_capacity(positions.len() + 1);
+
+    for &position in &positions {
+        let end = bgzf::VirtualPosition::try_from((position, 0))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        ends.push(Some(end));
+    }
+
+    ends.push(None);
+
+    thread::scope(|scope| {
+        let open = &open;
+        let header = &header;
+
+        let handles: Vec<_> = positions
+            .iter()
+            .zip(&ends[1..])
+            .map(|(&position, &end)| {
+                scope.spawn(move || -> io::Result<Option<_>> {
+                    let mut reader = open().map(bgzf::io::Reader::new)?;
+
+                    let Some(start) = find_record_start(&mut reader, header, position)? else {
+                        return Ok(None);
+                    };
+
+                    let partial_index = index_partition(reader, start, end)?;
+
+                    Ok(Some((start, partial_index)))
+                })
+            })
+            .collect();
+
+        let (mut indexer, mut position) =
+            index_partition(reader.into_inner(), header_end, ends[0])?;
+
+        for (handle, &end) in handles.into_iter().zip(&ends[1..]) {
+            let result = handle
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+            // A partition that failed or started at an unexpected position is reindexed from
+            // the end of the previous partition.
+            let (partial_indexer, end_position) = match result {
+                Ok(Some((start, partial_index))) if start == position => partial_index,
+                _ => {
+                    let reader = open().map(bgzf::io::Reader::new)?;
+                    index_partition(reader, position, end)?
+                }
+            };
+
+            indexer.merge(partial_indexer)?;
+            position = end_position;
+        }
+
+        Ok(indexer.build(header.reference_sequences().len()))
+    })
+}
+
+fn index_partition<R>(
+    mut reader: bgzf::io::Reader<R>,
+    start: bgzf::VirtualPosition,
+    end: Option<bgzf::VirtualPosition>,
+) -> io::Result<PartialIndex>
+where
+    R: Read + Seek,
+{
+    reader.seek_to_virtual_position(start)?;
+
+    let mut reader = Reader::from(reader);
+    let mut indexer = Indexer::default();
+    let position = index_records(&mut reader, &mut indexer, end)?;
+
+    Ok((indexer, position))
+}
+
+fn find_record_start<R>(
+    reader: &mut bgzf::io::Reader<R>,
+    header: &sam::Header,
+    position: u64,
+) -> io::Result<Option<bgzf::VirtualPosition>>
+where
+    R: Read + Seek,
+{
+    let start = bgzf::VirtualPosition::try_from((position, 0))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    reader.seek_to_virtual_position(start)?;
+
+    let block_len = reader.fill_buf()?.len();
+
+    let mut buf = Vec::new();
+    reader.take(SCAN_LEN).read_to_end(&mut buf)?;
+
+    let reference_sequence_count = header.reference_sequences().len();
+    let is_truncated = buf.len() as u64 == SCAN_LEN;
+
+    (0..block_len)
+        .find(|&i| is_record_start(&buf[i..], reference_sequence_count, is_truncated))
+        .map(|i| {
+            let uncompressed_position =
+                u16::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            bgzf::VirtualPosition::try_from((position, uncompressed_position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .transpose()
+}
+
+// Validates the fixed-length fields of up to `MIN_CHAIN_LEN` consecutive records. The chain may
+// be shorter if it ends at the end of the stream or, when `src` is truncated, if the next record
+// header is cut off. A chain that skips past the end of `src` is rejected, which only rejects
+// records larger than the scan length.
+fn is_record_start(mut src: &[u8], reference_sequence_count: usize, is_truncated: bool) -> bool {
+    let mut record_count = 0;
+
+    while record_count < MIN_CHAIN_LEN {
+        if src.is_empty() && record_count > 0 {
+            return true;
+        }
+
+        let Some(block_size) = read_block_size(src) else {
+            return record_count > 0 && is_truncated;
+        };
+
+        let record_src = &src[4..];
+
+        if !is_valid_record(record_src, block_size, reference_sequence_count) {
+            return false;
+        }
+
+        record_count += 1;
+
+        match src.get(4 + block_size..) {
+            Some(rest) => src = rest,
+            None => return false,
+        }
+    }
+
+    true
+}
+
+fn read_block_size(src: &[u8]) -> Option<usize> {
+    let buf = src.first_chunk::<4>()?;
+    usize::try_from(u32::from_le_bytes(*buf)).ok()
+}
+
+fn is_valid_record(src: &[u8], block_size: usize, reference_sequence_count: usize) -> bool {
+    const FIXED_LEN: usize = 32;
+
+    let Some(fields) = src.first_chunk::<FIXED_LEN>() else {
+        return false;
+    };
+
+    let read_i32 =
+        |i: usize| i32::from_le_bytes([fields[i], fields[i + 1], fields[i + 2], fields[i + 3]]);
+    let read_u16 = |i: usize| u16::from_le_bytes([fields[i], fields[i + 1]]);
+
+    let is_valid_reference_sequence_id =
+        |n: i32| n == -1 || usize::try_from(n).is_ok_and(|id| id < reference_sequence_count);
+
+    let reference_sequence_id = read_i32(0);
+    let position = read_i32(4);
+    let read_name_len = usize::from(fields[8]);
+    let cigar_op_count = usize::from(read_u16(12));
+    let sequence_len =
+        u32::from_le_bytes([fields[16], fields[17], fields[18], fields[19]]) as usize;
+    let mate_reference_sequence_id = read_i32(20);
+    let mate_position = read_i32(24);
+
+    let min_block_size =
+        FIXED_LEN + read_name_len + 4 * cigar_op_count + sequence_len.div_ceil(2) + sequence_len;
+
+    if !is_valid_reference_sequence_id(reference_sequence_id)
+        || !is_valid_reference_sequence_id(mate_reference_sequence_id)
+        || position < -1
+        || mate_position < -1
+        || read_name_len == 0
+        || block_size < min_block_size
+    {
+        return false;
+    }
+
+    // The read name is NUL-terminated.
+    match src.get(FIXED_LEN + read_name_len - 1) {
+        Some(&b) => b == 0x00,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            RecordBuf,
+            io::Write,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+            record_buf::Sequence,
+        },
+        header::record::value::{
+            Map,
+            map::{
+                self, ReferenceSequence,
+                header::{sort_order::COORDINATE, tag::SORT_ORDER},
+            },
+        },
+    };
+
+    use super::*;
+
+    fn build_data() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE_LENGTH: NonZeroUsize = NonZeroUsize::new(1 << 20).unwrap();
+
+        let header = sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(SORT_ORDER, COORDINATE)
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_id, step) in [(0, 31), (1, 47)] {
+            for i in 0..8192 {
+                let len = 16 + (i % 97);
+
+                let record = RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(1 + i * step)?)
+                    .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+                    .set_sequence(Sequence::from(vec![b'A'; len]))
+                    .build();
+
+                writer.write_alignment_record(&header, &record)?;
+            }
+        }
+
+        for _ in 0..16 {
+            let record = RecordBuf::builder().set_name("unmapped").build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok(writer.into_inner().into_inner())
+    }
+
+    #[test]
+    fn test_index_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+
+        let expected = super::super::index_inner(&mut Reader::new(&data[..]))?;
+
+        for worker_count in [1, 3, 8, 64] {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+            let actual = index_inner(|| Ok(Cursor::new(&data)), worker_count)?;
+            assert_eq!(actual, expected);
+
+            let mut actual_buf = Vec::new();
+            bai::io::Writer::new(&mut actual_buf).write_index(&actual)?;
+
+            let mut expected_buf = Vec::new();
+            bai::io::Writer::new(&mut expected_buf).write_index(&expected)?;
+
+            assert_eq!(actual_buf, expected_buf);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_record_start() {
+        // A record with no read name.
+        let src = [
+            0x20, 0x00, 0x00, 0x00, // block_size = 32
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x00, // l_read_name = 0
+        ];
+
+        assert!(!is_record_start(&src, 0, false));
+        assert!(!is_record_start(&[], 0, false));
+    }
+}
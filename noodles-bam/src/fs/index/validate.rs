@@ -0,0 +1,138 @@
+use std::{io, path::Path};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::binning_index::{
+    Validator, index::reference_sequence::index::LinearIndex, validator::Report,
+};
+
+use super::read_records;
+use crate::{bai, io::Reader};
+
+/// Validates a BAM index against its BAM file.
+///
+/// The returned report lists the issues of the index, e.g., when the index is stale because the
+/// BAM file was regenerated. If there are any issues, the report includes a repaired index built
+/// from the BAM file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bam::{self as bam, bai};
+///
+/// let index = bai::fs::read("sample.bam.bai")?;
+/// let report = bam::fs::validate_index("sample.bam", &index)?;
+///
+/// for issue in report.issues() {
+///     eprintln!("{issue}");
+/// }
+///
+/// if let Some(repaired_index) = report.repaired_index() {
+///     bai::fs::write("sample.bam.bai", repaired_index)?;
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn validate<P>(src: P, index: &bai::Index) -> io::Result<Report<LinearIndex>>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = super::super::open(src)?;
+    validate_inner(&mut reader, index)
+}
+
+fn validate_inner<R>(reader: &mut Reader<R>, index: &bai::Index) -> io::Result<Report<LinearIndex>>
+where
+    R: bgzf::io::Read,
+{
+    reader.read_header()?;
+
+    let mut validator = Validator::new(index);
+
+    read_records(reader, None, |alignment_context, chunk| {
+        validator.add_record(alignment_context, chunk)
+    })?;
+
+    Ok(validator.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use bstr::BString;
+    use noodles_core::Position;
+    use noodles_csi::{BinningIndex, binning_index::validator::Issue};
+    use noodles_sam::{
+        self as sam,
+        alignment::{RecordBuf, io::Write, record::Flags},
+        header::record::value::{
+            Map,
+            map::{
+                self, ReferenceSequence,
+                header::{sort_order::COORDINATE, tag::SORT_ORDER},
+            },
+        },
+    };
+
+    use super::*;
+    use crate::fs::index::index_inner;
+
+    fn build_data(alignment_starts: &[usize]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(SORT_ORDER, COORDINATE)
+                    .build()?,
+            )
+            .set_reference_sequences(
+                [(
+                    BString::from("sq0"),
+                    Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 20)?),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for &alignment_start in alignment_starts {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok(writer.into_inner().into_inner())
+    }
+
+    #[test]
+    fn test_validate_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data(&[8, 13, 21])?;
+        let index = index_inner(&mut Reader::new(&data[..]))?;
+
+        let report = validate_inner(&mut Reader::new(&data[..]), &index)?;
+        assert!(report.is_ok());
+
+        let stale_data = build_data(&[8, 13, 21, 40000])?;
+        let report = validate_inner(&mut Reader::new(&stale_data[..]), &index)?;
+
+        assert!(report.issues().contains(&Issue::RecordCountMismatch {
+            reference_sequence_id: 0,
+            expected: (4, 0),
+            actual: (3, 0),
+        }));
+
+        let repaired_index = report.repaired_index().unwrap();
+        let expected = index_inner(&mut Reader::new(&stale_data[..]))?;
+        assert_eq!(repaired_index, &expected);
+        assert_eq!(repaired_index.min_shift(), 14);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 //! BAM I/O.
 
 pub mod indexed_reader;
+pub mod indexed_writer;
 pub mod reader;
 pub mod writer;
 
-pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, indexed_writer::IndexedWriter, reader::Reader, writer::Writer,
+};
 
 pub(crate) const MAGIC_NUMBER: [u8; 4] = *b"BAM\x01";
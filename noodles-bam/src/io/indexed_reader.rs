@@ -2,17 +2,21 @@
 
 mod builder;
 
-use std::io::{self, Read};
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
 pub use self::builder::Builder;
+pub(crate) use self::builder::build_index_src;
 use super::{
     Reader,
-    reader::{Query, RecordBufs, Records},
+    reader::{Query, QueryRegions, RecordBufs, Records},
 };
 use crate::Record;
 
@@ -20,6 +24,7 @@ use crate::Record;
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R> {
@@ -77,6 +82,16 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<bgzf::io::Reader<R>>
@@ -91,6 +106,7 @@ where
         Self {
             inner: Reader::new(inner),
             index: Box::new(index),
+            aliases: Aliases::default(),
         }
     }
 }
@@ -100,12 +116,31 @@ where
     R: bgzf::io::BufRead + bgzf::io::Seek,
 {
     /// Returns an iterator over records that intersect the given region.
+    ///
+    /// If the region name is not in the header, it is resolved using the reader's aliases.
     pub fn query<'r, 'h: 'r>(
         &'r mut self,
         header: &'h sam::Header,
         region: &Region,
     ) -> io::Result<Query<'r, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = resolve_region(&self.aliases, header, region);
+        self.inner.query(header, &self.index, &region)
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`crate::io::Reader::query_regions`].
+    pub fn query_regions<'r>(
+        &'r mut self,
+        header: &sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| resolve_region(&self.aliases, header, region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
     }
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
@@ -113,3 +148,227 @@ where
         self.inner.query_unmapped(&self.index)
     }
 }
+
+fn resolve_region<'r>(
+    aliases: &Aliases,
+    header: &sam::Header,
+    region: &'r Region,
+) -> Cow<'r, Region> {
+    let reference_sequences = header.reference_sequences();
+    aliases.resolve_region(region, |name| reference_sequences.contains_key(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io::Write, num::NonZero, ops::Range};
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            io::Write as _,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+        },
+        header::record::value::{
+            Map,
+            map::{self, ReferenceSequence, header::tag::SORT_ORDER},
+        },
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    struct CountingRangeRead {
+        data: Vec<u8>,
+        requests: RefCell<Vec<Range<u64>>>,
+    }
+
+    impl bgzf::io::RangeRead for CountingRangeRead {
+        fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.data.read_at(pos, buf)?;
+            self.requests.borrow_mut().push(pos..pos + len as u64);
+            Ok(len)
+        }
+    }
+
+    fn build_data() -> Result<(sam::Header, Vec<u8>), Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE_LENGTH: NonZero<usize> = NonZero::new(1000).unwrap();
+
+        let header = sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(SORT_ORDER, "coordinate")
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        // Write one record per BGZF block.
+        for (reference_sequence_id, start) in [(0, 1), (0, 101), (1, 1), (1, 101), (1, 801)] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_flags(Flags::empty())
+                .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+                .build();
+
+            writer.get_mut().flush()?;
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        Ok((header, data))
+    }
+
+    #[test]
+    fn test_query_with_range_reader() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, data) = build_data()?;
+
+        let index = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        let region: Region = "sq1:1-200".parse()?;
+        let chunks = index.query(1, region.interval())?;
+
+        let range_reader = bgzf::io::range_reader::Builder::default()
+            .set_read_ahead(32)
+            .build_from_range_read(CountingRangeRead {
+                data,
+                requests: RefCell::new(Vec::new()),
+            });
+
+        let mut reader = IndexedReader::new(range_reader, index);
+
+        let starts = reader
+            .query(&header, &region)?
+            .map(|result| result.and_then(|record| record.alignment_start().transpose()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(starts, [Position::new(1), Position::new(101)]);
+
+        let requests = reader.get_ref().get_ref().get_ref().requests.borrow();
+        assert!(!requests.is_empty());
+
+        for request in requests.iter() {
+            assert!(chunks.iter().any(|chunk| {
+                (chunk.start().compressed()..=chunk.end().compressed()).contains(&request.start)
+            }));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_multithreaded_reader() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, data) = build_data()?;
+        let index = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        let decoder = bgzf::io::MultithreadedReader::with_worker_count(
+            NonZero::new(2).unwrap(),
+            io::Cursor::new(data),
+        );
+
+        let mut reader = Builder::default()
+            .set_index(index)
+            .build_from_bgzf_reader(decoder)?;
+
+        reader.read_header()?;
+
+        for (raw_region, expected) in [
+            ("sq1:1-200", &[1, 101][..]),
+            ("sq0", &[1, 101]),
+            ("sq1:700-900", &[801]),
+        ] {
+            let region: Region = raw_region.parse()?;
+
+            let starts = reader
+                .query(&header, &region)?
+                .map(|result| result.and_then(|record| record.alignment_start().transpose()))
+                .collect::<io::Result<Vec<_>>>()?;
+
+            let expected: Vec<_> = expected.iter().copied().map(Position::new).collect();
+            assert_eq!(starts, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, data) = build_data()?;
+        let index = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        let mut reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(io::Cursor::new(data))?;
+
+        reader.read_header()?;
+
+        let regions = ["sq1:1-105".parse()?, "sq0".parse()?, "sq1:100-900".parse()?];
+
+        let actual = reader
+            .query_regions(&header, &regions)?
+            .map(|result| {
+                result.and_then(|(record, indices)| {
+                    let id = record.reference_sequence_id().transpose()?;
+                    let start = record.alignment_start().transpose()?;
+                    Ok((id, start, indices))
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let expected = [
+            (Some(0), Position::new(1), vec![1]),
+            (Some(0), Position::new(101), vec![1]),
+            (Some(1), Position::new(1), vec![0]),
+            (Some(1), Position::new(101), vec![0, 2]),
+            (Some(1), Position::new(801), vec![2]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_aliases() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, data) = build_data()?;
+        let index = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        let mut aliases = Aliases::default();
+        aliases.insert(["sq1", "chr1"]);
+
+        let mut reader = Builder::default()
+            .set_index(index)
+            .set_aliases(aliases)
+            .build_from_reader(io::Cursor::new(data))?;
+
+        reader.read_header()?;
+
+        let region = "chr1:700-900".parse()?;
+
+        let starts = reader
+            .query(&header, &region)?
+            .map(|result| result.and_then(|record| record.alignment_start().transpose()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(starts, [Position::new(801)]);
+
+        let region = "chr2".parse()?;
+        assert!(reader.query(&header, &region).is_err());
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_core::region::Aliases;
 use noodles_csi::{self as csi, BinningIndex};
 
 use super::IndexedReader;
@@ -15,6 +16,7 @@ use crate::bai;
 #[derive(Default)]
 pub struct Builder {
     index: Option<Box<dyn BinningIndex>>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -35,6 +37,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the header are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::indexed_reader::Builder;
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed BAM reader from a path.
     ///
     /// If no index is set, this will attempt to read an associated index at `<src>.bai` or
@@ -60,7 +82,10 @@ impl Builder {
 
         let file = File::open(src)?;
 
-        Ok(IndexedReader::new(file, index))
+        let mut reader = IndexedReader::new(file, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 
     /// Builds an indexed BAM reader from a reader.
@@ -82,7 +107,49 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        let mut reader = IndexedReader::new(reader, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
+    }
+
+    /// Builds an indexed BAM reader from a BGZF reader.
+    ///
+    /// This allows queries to use other BGZF readers, e.g., a [`bgzf::io::MultithreadedReader`],
+    /// which reads and inflates the blocks of the queried chunks in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_bam::{bai, io::indexed_reader::Builder};
+    ///
+    /// let decoder = bgzf::io::MultithreadedReader::with_worker_count(
+    ///     NonZeroUsize::try_from(4)?,
+    ///     io::empty(),
+    /// );
+    ///
+    /// let index = bai::Index::default();
+    /// let reader = Builder::default()
+    ///     .set_index(index)
+    ///     .build_from_bgzf_reader(decoder)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_from_bgzf_reader<R>(self, reader: R) -> io::Result<IndexedReader<R>>
+    where
+        R: bgzf::io::BufRead,
+    {
+        let index = self
+            .index
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
+
+        Ok(IndexedReader {
+            inner: crate::io::Reader::from(reader),
+            index,
+            aliases: self.aliases,
+        })
     }
 }
 
@@ -102,7 +169,7 @@ where
     }
 }
 
-fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
+pub(crate) fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
 where
     P: AsRef<Path>,
     S: AsRef<OsStr>,
@@ -0,0 +1,274 @@
+//! Indexed BAM writer.
+
+mod builder;
+
+use std::{
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::binning_index::{Indexer, PendingRecord, index::reference_sequence::bin::Chunk};
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+pub use self::builder::Builder;
+use super::Writer;
+use crate::{Record, bai};
+
+/// An indexed BAM writer.
+///
+/// An indexed BAM writer builds a BAM index (BAI) while writing records, which avoids reading the
+/// file back to index it (cf. [`crate::fs::index`]).
+///
+/// The records must be coordinate-sorted, and the header must be marked as such, i.e.,
+/// `SO:coordinate`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_csi::BinningIndex;
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{map::{self, header::{sort_order::COORDINATE, tag::SORT_ORDER}}, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .set_header(Map::<map::Header>::builder().insert(SORT_ORDER, COORDINATE).build()?)
+///     .build();
+///
+/// let mut writer = bam::io::IndexedWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let record = bam::Record::default();
+/// writer.write_record(&header, &record)?;
+///
+/// let index = writer.finish(&header)?;
+/// assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::io::Writer<W>>,
+    indexer: Indexer<Vec<bgzf::VirtualPosition>>,
+    pending_record: PendingRecord<AlignmentContext>,
+    index_dst: Option<PathBuf>,
+}
+
+type AlignmentContext = Option<(usize, Position, Position, bool)>;
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer with a default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::io::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Builder::default().build_from_writer(inner)
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &bgzf::io::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// This returns an error if the header is not marked as coordinate-sorted.
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        use crate::fs::index::validate_sort_order;
+
+        validate_sort_order(header)?;
+        self.inner.write_header(header)
+    }
+
+    /// Writes a BAM record.
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        self.write_alignment_record(header, record)
+    }
+
+    /// Shuts down the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.bai`.
+    pub fn finish(&mut self, header: &sam::Header) -> io::Result<bai::Index> {
+        self.inner.get_mut().flush()?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        if let Some((alignment_context, chunk)) = self.pending_record.finish(end_position) {
+            self.indexer.add_record(alignment_context, chunk)?;
+        }
+
+        self.inner.try_finish()?;
+
+        let indexer = mem::take(&mut self.indexer);
+        let index = indexer.build(header.reference_sequences().len());
+
+        if let Some(dst) = self.index_dst.take() {
+            bai::fs::write(dst, &index)?;
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.into_inner().get_ref().is_empty());
+    /// ```
+    pub fn into_inner(self) -> bgzf::io::Writer<W> {
+        self.inner.into_inner()
+    }
+}
+
+impl<W> sam::alignment::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_alignment_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        let alignment_context = match alignment_context(header, record)? {
+            (Some(id), Some(start), Some(end)) => {
+                let is_mapped = !record.flags()?.is_unmapped();
+                Some((id, start, end, is_mapped))
+            }
+            _ => None,
+        };
+
+        if let Some((alignment_context, chunk)) =
+            self.pending_record.replace(alignment_context, chunk)
+        {
+            self.indexer.add_record(alignment_context, chunk)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        IndexedWriter::finish(self, header).map(|_| ())
+    }
+}
+
+fn alignment_context(
+    header: &sam::Header,
+    record: &dyn sam::alignment::Record,
+) -> io::Result<(Option<usize>, Option<Position>, Option<Position>)> {
+    Ok((
+        record.reference_sequence_id(header).transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use noodles_sam::{
+        alignment::{
+            RecordBuf,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+        },
+        header::record::value::{
+            Map,
+            map::{
+                self, ReferenceSequence,
+                header::{sort_order::COORDINATE, tag::SORT_ORDER},
+            },
+        },
+    };
+
+    use super::*;
+    use crate::io::Reader;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE_LENGTH: NonZero<usize> = NonZero::new(100000).unwrap();
+
+        let header = sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(SORT_ORDER, COORDINATE)
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_id, start) in [(0, 1), (0, 20000), (1, 8), (1, 70000)] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_flags(Flags::empty())
+                .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let actual = writer.finish(&header)?;
+
+        let data = writer.into_inner().into_inner();
+        let expected = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_unsorted_header() {
+        let mut writer = IndexedWriter::new(Vec::new());
+        let header = sam::Header::default();
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
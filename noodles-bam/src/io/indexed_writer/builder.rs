@@ -0,0 +1,86 @@
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+use noodles_bgzf::{self as bgzf, io::writer::CompressionLevel};
+
+use super::IndexedWriter;
+use crate::io::{Writer, indexed_reader::build_index_src};
+
+/// An indexed BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_level: Option<CompressionLevel>,
+}
+
+impl Builder {
+    /// Sets a compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::indexed_writer::Builder;
+    /// use noodles_bgzf::io::writer::CompressionLevel;
+    ///
+    /// let builder = Builder::default().set_compression_level(CompressionLevel::BEST);
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Builds an indexed BAM writer from a path.
+    ///
+    /// When the writer is finished, the index is written to `<dst>.bai`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_path("out.bam")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<IndexedWriter<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+        let index_dst = build_index_src(dst, "bai");
+
+        let file = File::create(dst)?;
+
+        let mut writer = self.build_from_writer(file);
+        writer.index_dst = Some(index_dst);
+
+        Ok(writer)
+    }
+
+    /// Builds an indexed BAM writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> IndexedWriter<W>
+    where
+        W: Write,
+    {
+        let mut builder = bgzf::io::writer::Builder::default();
+
+        if let Some(compression_level) = self.compression_level {
+            builder = builder.set_compression_level(compression_level);
+        }
+
+        IndexedWriter {
+            inner: Writer::from(builder.build_from_writer(writer)),
+            indexer: Default::default(),
+            pending_record: Default::default(),
+            index_dst: None,
+        }
+    }
+}
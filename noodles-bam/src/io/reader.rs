@@ -3,23 +3,29 @@
 mod builder;
 pub mod header;
 pub(crate) mod query;
+mod query_regions;
 mod record;
 mod record_buf;
 mod record_bufs;
 mod records;
+mod salvaged_records;
 
 use std::{
     ffi::CStr,
-    io::{self, Read},
+    io::{self, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
 };
 
 use bstr::BString;
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::RegionSet};
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
+pub use self::{
+    builder::Builder, query::Query, query_regions::QueryRegions, record_bufs::RecordBufs,
+    records::Records, salvaged_records::SalvagedRecords,
+};
 use self::{record::read_record, record_buf::read_record_buf};
 use crate::Record;
 
@@ -332,6 +338,82 @@ where
     pub fn new(reader: R) -> Self {
         Self::from(bgzf::io::Reader::new(reader))
     }
+
+    /// Returns an iterator over records that skips corrupt data.
+    ///
+    /// This is meant to be used with a BGZF reader in salvage mode (see
+    /// [`bgzf::io::reader::Builder::set_salvage`]). When the BGZF reader skips invalid blocks, the
+    /// record that overlaps the skipped data is discarded, and reading resumes at the next
+    /// position in the following block that looks like the start of a record.
+    ///
+    /// Records are validated heuristically when resynchronizing, so a record following skipped
+    /// data can be missed or, rarely, be invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let decoder = bgzf::io::reader::Builder::default()
+    ///     .set_salvage(true)
+    ///     .build_from_path("sample.bam")?;
+    /// let mut reader = bam::io::Reader::from(decoder);
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.salvaged_records(&header) {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    ///
+    /// for range in reader.get_ref().skipped_ranges() {
+    ///     eprintln!("skipped compressed range: {range:?}");
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn salvaged_records(&mut self, header: &sam::Header) -> SalvagedRecords<'_, R> {
+        SalvagedRecords::new(self, header)
+    }
+}
+
+impl<R> Reader<bgzf::io::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Verifies the integrity of all BGZF blocks in the underlying stream.
+    ///
+    /// This reads the entire stream from the start and then restores the current position. See
+    /// [`bgzf::io::verify`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io, num::NonZeroUsize, thread};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    ///
+    /// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    /// let report = reader.verify(worker_count)?;
+    ///
+    /// if !report.is_ok() {
+    ///     eprintln!("invalid blocks: {:?}", report.invalid_blocks());
+    ///     eprintln!("has EOF block: {}", report.has_eof_block());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn verify(&mut self, worker_count: NonZeroUsize) -> io::Result<bgzf::io::verify::Report> {
+        let position = self.inner.virtual_position();
+
+        let inner = self.inner.get_mut();
+        inner.seek(SeekFrom::Start(0))?;
+        let report = bgzf::io::verify(inner, worker_count)?;
+
+        self.inner.seek(position)?;
+
+        Ok(report)
+    }
 }
 
 impl<R> Reader<R>
@@ -392,6 +474,54 @@ where
         ))
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The regions are sorted and merged before querying the index, and the chunks of all
+    /// regions are merged. This reads each block and record at most once, and each record is
+    /// paired with the indices of the regions it intersects, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::fs::read("sample.bam.bai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, I>(
+        &'r mut self,
+        header: &sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, R>>
+    where
+        I: BinningIndex,
+    {
+        let region_set = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map(RegionSet::new)?;
+
+        let chunks = noodles_csi::binning_index::query_regions(index, &region_set)?;
+
+        Ok(QueryRegions::new(self.get_mut(), chunks, region_set))
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
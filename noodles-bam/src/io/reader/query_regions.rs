@@ -0,0 +1,73 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::RegionSet;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_sam::alignment::Record as _;
+
+use crate::Record;
+
+/// An iterator over records of a BAM reader that intersect any of a list of regions.
+///
+/// Each record is read at most once and is paired with the indices of the regions it intersects,
+/// in ascending order.
+///
+/// This is created by calling [`super::Reader::query_regions`].
+pub struct QueryRegions<'r, R> {
+    reader: super::Reader<csi::io::Query<'r, R>>,
+    regions: RegionSet,
+    record: Record,
+}
+
+impl<'r, R> QueryRegions<'r, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(reader: &'r mut R, chunks: Vec<Chunk>, regions: RegionSet) -> Self {
+        Self {
+            reader: super::Reader::from(csi::io::Query::new(reader, chunks)),
+            regions,
+            record: Record::default(),
+        }
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<Vec<usize>>> {
+        loop {
+            if self.reader.read_record(&mut self.record)? == 0 {
+                return Ok(None);
+            }
+
+            let indices = overlapping_regions(&self.record, &self.regions)?;
+
+            if !indices.is_empty() {
+                return Ok(Some(indices));
+            }
+        }
+    }
+}
+
+impl<R> Iterator for QueryRegions<'_, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Record, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_record() {
+            Ok(Some(indices)) => Some(Ok((self.record.clone(), indices))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn overlapping_regions(record: &Record, regions: &RegionSet) -> io::Result<Vec<usize>> {
+    match (
+        record.reference_sequence_id().transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ) {
+        (Some(id), Some(start), Some(end)) => Ok(regions.overlapping(id, (start..=end).into())),
+        _ => Ok(Vec::new()),
+    }
+}
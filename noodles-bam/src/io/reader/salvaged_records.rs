@@ -0,0 +1,270 @@
+use std::io::{self, BufRead, Read};
+
+use noodles_bgzf as bgzf;
+use noodles_sam as sam;
+
+use super::Reader;
+use crate::Record;
+
+// block_size (4) + refID (4) + pos (4) + l_read_name (1) + mapq (1) + bin (2) + n_cigar_op (2) +
+// flag (2) + l_seq (4) + next_refID (4) + next_pos (4) + tlen (4)
+const PREFIX_SIZE: usize = 36;
+
+// An upper bound on the size of a record to accept when resynchronizing.
+const MAX_BLOCK_SIZE: u32 = 1 << 28;
+
+/// An iterator over records of a BAM reader that skips corrupt data.
+///
+/// This is created by calling [`Reader::salvaged_records`].
+pub struct SalvagedRecords<'r, R> {
+    reader: &'r mut Reader<bgzf::io::Reader<R>>,
+    reference_sequence_count: usize,
+    record: Record,
+    is_resyncing: bool,
+}
+
+impl<'r, R> SalvagedRecords<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<bgzf::io::Reader<R>>, header: &sam::Header) -> Self {
+        Self {
+            reader,
+            reference_sequence_count: header.reference_sequences().len(),
+            record: Record::default(),
+            is_resyncing: false,
+        }
+    }
+}
+
+impl<R> Iterator for SalvagedRecords<'_, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let skip_count = self.reader.get_ref().skipped_ranges().len();
+
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.is_resyncing = false;
+                    return Some(Ok(self.record.clone()));
+                }
+                Err(e) => {
+                    let is_skipped = self.reader.get_ref().skipped_ranges().len() > skip_count;
+
+                    if !is_skipped && !self.is_resyncing {
+                        return Some(Err(e));
+                    }
+                }
+            }
+
+            self.is_resyncing = true;
+
+            if let Err(e) = resync(self.reader.get_mut(), self.reference_sequence_count) {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+// Discards data up to the next position that looks like the start of a record.
+//
+// Only candidates with a fixed-length prefix and read name fully within the current block are
+// considered.
+fn resync<R>(reader: &mut bgzf::io::Reader<R>, reference_sequence_count: usize) -> io::Result<()>
+where
+    R: Read,
+{
+    loop {
+        let skip_count = reader.skipped_ranges().len();
+
+        let is_eof = match reader.fill_buf().map(|src| src.is_empty()) {
+            Ok(is_eof) => is_eof,
+            Err(_) if reader.skipped_ranges().len() > skip_count => continue,
+            Err(e) => return Err(e),
+        };
+
+        if is_eof {
+            return Ok(());
+        }
+
+        let src = reader.fill_buf()?;
+        let len = src.len();
+
+        match (0..len).find(|&i| is_record_start(&src[i..], reference_sequence_count)) {
+            Some(i) => {
+                reader.consume(i);
+                return Ok(());
+            }
+            None => reader.consume(len),
+        }
+    }
+}
+
+fn is_record_start(src: &[u8], reference_sequence_count: usize) -> bool {
+    fn is_valid_reference_sequence_id(n: i32, reference_sequence_count: usize) -> bool {
+        n == -1 || usize::try_from(n).is_ok_and(|i| i < reference_sequence_count)
+    }
+
+    let Some(prefix) = src.first_chunk::<PREFIX_SIZE>() else {
+        return false;
+    };
+
+    let u32_at =
+        |i: usize| u32::from_le_bytes([prefix[i], prefix[i + 1], prefix[i + 2], prefix[i + 3]]);
+    let i32_at =
+        |i: usize| i32::from_le_bytes([prefix[i], prefix[i + 1], prefix[i + 2], prefix[i + 3]]);
+    let u16_at = |i: usize| u16::from_le_bytes([prefix[i], prefix[i + 1]]);
+
+    let block_size = u32_at(0);
+    let reference_sequence_id = i32_at(4);
+    let position = i32_at(8);
+    let l_read_name = usize::from(prefix[12]);
+    let n_cigar_op = usize::from(u16_at(16));
+    let l_seq = u32_at(20);
+    let mate_reference_sequence_id = i32_at(24);
+    let mate_position = i32_at(28);
+
+    if block_size > MAX_BLOCK_SIZE
+        || !is_valid_reference_sequence_id(reference_sequence_id, reference_sequence_count)
+        || !is_valid_reference_sequence_id(mate_reference_sequence_id, reference_sequence_count)
+        || position < -1
+        || mate_position < -1
+        || l_read_name < 2
+    {
+        return false;
+    }
+
+    // SAFETY: `l_seq <= block_size <= MAX_BLOCK_SIZE`.
+    let l_seq = l_seq as usize;
+
+    let min_block_size =
+        (PREFIX_SIZE - 4) + l_read_name + 4 * n_cigar_op + l_seq.div_ceil(2) + l_seq;
+
+    if min_block_size > block_size as usize {
+        return false;
+    }
+
+    let Some((&nul, name)) = src
+        .get(PREFIX_SIZE..PREFIX_SIZE + l_read_name)
+        .and_then(|buf| buf.split_last())
+    else {
+        return false;
+    };
+
+    // § 1.4 "The alignment section: mandatory fields" (2024-11-06): `[!-?A-~]{1,254}`.
+    nul == 0x00 && name.iter().all(|&b| matches!(b, b'!'..=b'?' | b'A'..=b'~'))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use bstr::BStr;
+    use noodles_sam::{
+        alignment::{RecordBuf, io::Write as _},
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_is_record_start() -> io::Result<()> {
+        let header = sam::Header::default();
+        let record = RecordBuf::builder().set_name("r0").build();
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_alignment_record(&header, &record)?;
+        let src = writer.into_inner();
+
+        assert!(is_record_start(&src, 0));
+        assert!(!is_record_start(&src[1..], 0));
+        assert!(!is_record_start(&src[..PREFIX_SIZE], 0));
+
+        let mut buf = src.clone();
+        buf[4..8].copy_from_slice(&0i32.to_le_bytes()); // refID = 0
+        assert!(!is_record_start(&buf, 0));
+        assert!(is_record_start(&buf, 1));
+
+        let mut buf = src.clone();
+        buf[PREFIX_SIZE] = b'@';
+        assert!(!is_record_start(&buf, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZero;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZero::try_from(8)?))
+            .build();
+
+        let names = ["r0", "r1", "r2", "r3", "r4", "r5"];
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_header(&header)?;
+        let header_len = writer.get_ref().len();
+
+        for name in names {
+            let record = RecordBuf::builder().set_name(name).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner();
+        let record_len = (src.len() - header_len) / names.len();
+
+        // Split the stream into blocks, where block 2 starts and ends within records 1 and 3,
+        // respectively.
+        let ends = [
+            header_len,
+            header_len + record_len + 5,
+            header_len + 3 * record_len + 7,
+            src.len(),
+        ];
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        let mut block_positions = Vec::new();
+        let mut start = 0;
+
+        for end in ends {
+            writer.flush()?;
+            block_positions.push(writer.get_ref().len());
+            writer.write_all(&src[start..end])?;
+            start = end;
+        }
+
+        let mut data = writer.finish()?;
+
+        // Corrupt the CRC32 of block 2.
+        data[block_positions[3] - 8] ^= 0xff;
+
+        let mut reader = Reader::from(
+            bgzf::io::reader::Builder::default()
+                .set_salvage(true)
+                .build_from_reader(&data[..]),
+        );
+
+        reader.read_header()?;
+
+        let records = reader
+            .salvaged_records(&header)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let actual: Vec<_> = records.iter().map(|record| record.name()).collect();
+        let expected = [Some("r0"), Some("r4"), Some("r5")].map(|name| name.map(BStr::new));
+
+        assert_eq!(actual, expected);
+
+        let skipped_range = block_positions[2] as u64..block_positions[3] as u64;
+        assert_eq!(reader.get_ref().skipped_ranges(), [skipped_range]);
+
+        Ok(())
+    }
+}
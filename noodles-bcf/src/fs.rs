@@ -1,6 +1,6 @@
 //! BCF filesystem operations.
 
-mod index;
+pub(crate) mod index;
 
 use std::{fs::File, io, path::Path};
 
@@ -30,7 +30,7 @@ where
     index_inner(&mut reader)
 }
 
-fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<csi::Index>
+pub(crate) fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<csi::Index>
 where
     R: bgzf::io::Read,
 {
@@ -3,17 +3,22 @@
 mod builder;
 
 pub use self::builder::Builder;
+pub(crate) use self::builder::build_index_src;
 
-use std::io::{self, Read};
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+    str,
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 use noodles_csi::BinningIndex;
 use noodles_vcf::{self as vcf, variant::RecordBuf};
 
 use super::{
     Reader,
-    reader::{Query, RecordBufs},
+    reader::{Query, QueryRegions, RecordBufs},
 };
 use crate::Record;
 
@@ -21,6 +26,7 @@ use crate::Record;
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R>
@@ -75,6 +81,16 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<bgzf::io::Reader<R>>
@@ -89,6 +105,7 @@ where
         Self {
             inner: Reader::new(inner),
             index: Box::new(index),
+            aliases: Aliases::default(),
         }
     }
 }
@@ -103,6 +120,38 @@ where
         header: &'h vcf::Header,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = resolve_region(&self.aliases, header, region);
+        self.inner.query(header, &self.index, &region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| resolve_region(&self.aliases, header, region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
+    }
+}
+
+fn resolve_region<'r>(
+    aliases: &Aliases,
+    header: &vcf::Header,
+    region: &'r Region,
+) -> Cow<'r, Region> {
+    let contigs = header.string_maps().contigs();
+
+    aliases.resolve_region(region, |name| {
+        str::from_utf8(name)
+            .ok()
+            .and_then(|name| contigs.get_index_of(name))
+            .is_some()
+    })
 }
@@ -6,6 +6,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_core::region::Aliases;
 use noodles_csi::{self as csi, BinningIndex};
 
 use super::IndexedReader;
@@ -14,6 +15,7 @@ use super::IndexedReader;
 #[derive(Default)]
 pub struct Builder {
     index: Option<Box<dyn BinningIndex>>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -35,6 +37,27 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the header contigs are resolved using these
+    /// aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::indexed_reader::Builder;
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed BCF reader from a path.
     ///
     /// # Examples
@@ -57,7 +80,10 @@ impl Builder {
             None => read_associated_index(src)?,
         };
 
-        Ok(IndexedReader::new(file, index))
+        let mut reader = IndexedReader::new(file, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 
     /// Builds an indexed BCF reader from a reader.
@@ -80,7 +106,10 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        let mut reader = IndexedReader::new(reader, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 }
 
@@ -92,7 +121,7 @@ where
     Ok(Box::new(index))
 }
 
-fn build_index_src<P>(src: P) -> PathBuf
+pub(crate) fn build_index_src<P>(src: P) -> PathBuf
 where
     P: AsRef<Path>,
 {
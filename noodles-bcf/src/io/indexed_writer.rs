@@ -0,0 +1,253 @@
+//! Indexed BCF writer.
+
+mod builder;
+
+use std::{
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        Indexer, PendingRecord,
+        index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+    },
+};
+use noodles_vcf::{self as vcf, variant::io::Write as _};
+
+pub use self::builder::Builder;
+use super::Writer;
+use crate::Record;
+
+/// An indexed BCF writer.
+///
+/// An indexed BCF writer builds a coordinate-sorted index (CSI) while writing records, which
+/// avoids reading the file back to index it (cf. [`crate::fs::index`]).
+///
+/// The records must be coordinate-sorted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf as bcf;
+/// use noodles_core::Position;
+/// use noodles_vcf::{self as vcf, variant::{io::Write, RecordBuf}};
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Default::default())
+///     .build();
+///
+/// let mut writer = bcf::io::IndexedWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// writer.write_variant_record(&header, &record)?;
+///
+/// let index = writer.finish(&header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::io::Writer<W>>,
+    indexer: Indexer<BinnedIndex>,
+    pending_record: PendingRecord<(usize, Position, Position, bool)>,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BCF writer with a default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::io::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Builder::default().build_from_writer(inner)
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &bgzf::io::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a VCF header.
+    pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a BCF record.
+    pub fn write_record(&mut self, header: &vcf::Header, record: &Record) -> io::Result<()> {
+        self.write_variant_record(header, record)
+    }
+
+    /// Shuts down the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.csi`.
+    pub fn finish(&mut self, header: &vcf::Header) -> io::Result<csi::Index> {
+        self.inner.get_mut().flush()?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        if let Some((alignment_context, chunk)) = self.pending_record.finish(end_position) {
+            self.indexer.add_record(Some(alignment_context), chunk)?;
+        }
+
+        self.inner.try_finish()?;
+
+        let indexer = mem::take(&mut self.indexer);
+        let index = indexer.build(header.contigs().len());
+
+        if let Some(dst) = self.index_dst.take() {
+            csi::fs::write(dst, &index)?;
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.into_inner().get_ref().is_empty());
+    /// ```
+    pub fn into_inner(self) -> bgzf::io::Writer<W> {
+        self.inner.into_inner()
+    }
+}
+
+impl<W> vcf::variant::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_variant_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &dyn vcf::variant::Record,
+    ) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_variant_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let reference_sequence_id = header
+            .contigs()
+            .get_index_of(reference_sequence_name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "missing reference sequence name in contigs",
+                )
+            })?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+
+        let end = record.variant_end(header)?;
+
+        let alignment_context = (reference_sequence_id, start, end, true);
+
+        if let Some((alignment_context, chunk)) =
+            self.pending_record.replace(alignment_context, chunk)
+        {
+            self.indexer.add_record(Some(alignment_context), chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::variant::RecordBuf;
+
+    use super::*;
+    use crate::io::Reader;
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Default::default())
+            .add_contig("sq1", Default::default())
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, start) in
+            [("sq0", 1), ("sq0", 20000), ("sq1", 8), ("sq1", 70000)]
+        {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(start).unwrap())
+                .set_reference_bases("ACGT")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let actual = writer.finish(&header)?;
+
+        let data = writer.into_inner().into_inner();
+        let expected = crate::fs::index::index_inner(&mut Reader::new(&data[..]))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_missing_contig() -> io::Result<()> {
+        let header = vcf::Header::default();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -3,11 +3,14 @@
 mod builder;
 pub mod header;
 pub(crate) mod query;
+mod query_regions;
 pub(crate) mod record;
 pub(crate) mod record_buf;
 mod record_bufs;
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{
+    builder::Builder, query::Query, query_regions::QueryRegions, record_bufs::RecordBufs,
+};
 
 use std::{
     io::{self, BufRead, Read},
@@ -15,7 +18,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::RegionSet};
 use noodles_csi::BinningIndex;
 use noodles_vcf::{self as vcf, header::string_maps::ContigStringMap, variant::RecordBuf};
 
@@ -308,6 +311,60 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The regions are sorted and merged before querying the index, and the chunks of all
+    /// regions are merged. This reads each block and record at most once, and each record is
+    /// paired with the indices of the regions it intersects, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = csi::fs::read("sample.bcf.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h, I>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let region_set = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.string_maps().contigs(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map(RegionSet::new)?;
+
+        let chunks = noodles_csi::binning_index::query_regions(index, &region_set)?;
+
+        Ok(QueryRegions::new(
+            &mut self.inner,
+            header,
+            chunks,
+            region_set,
+        ))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
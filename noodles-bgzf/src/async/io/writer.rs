@@ -5,6 +5,7 @@ pub(crate) mod deflate;
 mod deflater;
 
 use std::{
+    collections::{VecDeque, vec_deque},
     pin::Pin,
     task::{Context, Poll, ready},
 };
@@ -16,7 +17,7 @@ use tokio::io::{self, AsyncWrite};
 
 pub use self::builder::Builder;
 use self::{deflate::Deflate, deflater::Deflater};
-use crate::io::writer::MAX_BUF_SIZE;
+use crate::{VirtualPosition, io::writer::MAX_BUF_SIZE};
 
 #[cfg(feature = "libdeflate")]
 type CompressionLevel = libdeflater::CompressionLvl;
@@ -25,6 +26,15 @@ type CompressionLevel = flate2::Compression;
 
 pin_project! {
     /// An async BGZF writer.
+    ///
+    /// Blocks are compressed on blocking tasks (see [`tokio::task::spawn_blocking`]) and written in
+    /// order. The number of blocks being compressed is bounded by the worker count, after which
+    /// writes wait for the oldest block to be written.
+    ///
+    /// Since the compressed position of a block is only known after the blocks before it are
+    /// compressed, virtual positions are reported asynchronously: mark a position using
+    /// [`Writer::mark_virtual_position`], and take the resolved positions using
+    /// [`Writer::take_virtual_positions`].
     pub struct Writer<W> {
         #[pin]
         sink: Buffer<Deflater<W>, Deflate>,
@@ -32,6 +42,10 @@ pin_project! {
         #[pin]
         eof_buf: Bytes,
         compression_level: CompressionLevel,
+        block_count: u64,
+        resolved_block_count: u64,
+        marks: VecDeque<(u64, u16)>,
+        virtual_positions: VecDeque<VirtualPosition>,
     }
 }
 
@@ -64,6 +78,88 @@ where
         self.sink.into_inner().into_inner()
     }
 
+    /// Marks the virtual position of the next byte written, e.g., a record boundary.
+    ///
+    /// The mark is resolved to a virtual position once the block that contains it is written to
+    /// the underlying writer. All marks are resolved after the writer is shut down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut writer = bgzf::r#async::io::Writer::new(Vec::new());
+    ///
+    /// writer.mark_virtual_position();
+    /// writer.write_all(b"noodles").await?;
+    /// writer.mark_virtual_position();
+    /// writer.shutdown().await?;
+    ///
+    /// let virtual_positions: Vec<_> = writer.take_virtual_positions().collect();
+    ///
+    /// assert_eq!(virtual_positions, [
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(7),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mark_virtual_position(&mut self) {
+        // SAFETY: The uncompressed buffer is guaranteed to be <= `MAX_UNCOMPRESSED_POSITION`.
+        let uncompressed_position = self.buf.len() as u16;
+        self.marks
+            .push_back((self.block_count, uncompressed_position));
+    }
+
+    /// Removes and returns the resolved virtual positions of marks, in the order they were
+    /// marked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bgzf::r#async::io::Writer::new(Vec::new());
+    /// assert!(writer.take_virtual_positions().next().is_none());
+    /// ```
+    pub fn take_virtual_positions(&mut self) -> vec_deque::Drain<'_, VirtualPosition> {
+        self.virtual_positions.drain(..)
+    }
+
+    // Resolves the marks in the blocks that were written since the last call.
+    fn resolve_marks(&mut self) {
+        let deflater = self.sink.get_mut();
+
+        while let Some(block) = deflater.written_blocks_mut().pop_front() {
+            let block_index = self.resolved_block_count;
+            self.resolved_block_count += 1;
+
+            while let Some(&(i, uncompressed_position)) = self.marks.front() {
+                if i != block_index {
+                    break;
+                }
+
+                self.marks.pop_front();
+
+                // A mark at the end of a full block is at the start of the next block. This
+                // matches the position reported by the synchronous writer, which eagerly flushes
+                // full blocks.
+                let pos = if usize::from(uncompressed_position) == MAX_BUF_SIZE
+                    && block.uncompressed_size == MAX_BUF_SIZE
+                {
+                    (block.position + block.size, 0)
+                } else {
+                    (block.position, uncompressed_position)
+                };
+
+                self.virtual_positions
+                    .push_back(VirtualPosition::try_from(pos).unwrap());
+            }
+        }
+    }
+
     fn remaining(&self) -> usize {
         MAX_BUF_SIZE - self.buf.len()
     }
@@ -73,6 +169,30 @@ where
     }
 }
 
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn poll_send_block(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+
+        if this.buf.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+
+        ready!(this.sink.as_mut().poll_ready(cx))?;
+
+        let buf = this.buf.split();
+        this.sink
+            .as_mut()
+            .start_send(Deflate::new(buf, *this.compression_level))?;
+
+        *this.block_count += 1;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
 impl<W> AsyncWrite for Writer<W>
 where
     W: AsyncWrite + Unpin,
@@ -94,32 +214,29 @@ where
         Poll::Ready(Ok(amt))
     }
 
-    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
-        let mut this = self.project();
-
-        if this.buf.is_empty() {
-            return Poll::Ready(Ok(()));
-        }
-
-        ready!(this.sink.as_mut().poll_ready(cx))?;
-
-        let buf = this.buf.split();
-        this.sink
-            .as_mut()
-            .start_send(Deflate::new(buf, *this.compression_level))?;
-
-        Poll::Ready(Ok(()))
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let result = self.as_mut().poll_send_block(cx);
+        self.resolve_marks();
+        result
     }
 
     fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         ready!(self.as_mut().poll_flush(cx))?;
 
-        let mut this = self.project();
-        let mut sink = this.sink.as_mut();
+        ready!(self.as_mut().project().sink.poll_close(cx))?;
 
-        ready!(sink.as_mut().poll_close(cx))?;
+        self.resolve_marks();
+
+        // Any remaining marks are at the end of the stream.
+        let position = self.sink.get_ref().position();
+
+        while let Some((_, uncompressed_position)) = self.marks.pop_front() {
+            let pos = VirtualPosition::try_from((position, uncompressed_position)).unwrap();
+            self.virtual_positions.push_back(pos);
+        }
 
-        let mut inner = sink.get_mut().get_mut().get_mut();
+        let mut this = self.project();
+        let mut inner = this.sink.as_mut().get_mut().get_mut().get_mut();
 
         while this.eof_buf.has_remaining() {
             let bytes_written = ready!(Pin::new(&mut inner).poll_write(cx, this.eof_buf.chunk()))?;
@@ -134,3 +251,68 @@ where
         Poll::Ready(Ok(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, num::NonZeroUsize};
+
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mark_virtual_position() -> io::Result<()> {
+        let records: Vec<Vec<u8>> = (0..4096).map(|i| vec![b'n'; 8 + (i * 13) % 89]).collect();
+
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(4).unwrap())
+            .build_from_writer(Vec::new());
+
+        for record in &records {
+            writer.mark_virtual_position();
+            writer.write_all(record).await?;
+        }
+
+        writer.mark_virtual_position();
+        writer.shutdown().await?;
+
+        let actual: Vec<_> = writer.take_virtual_positions().collect();
+        let actual_data = writer.into_inner();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        let mut expected = Vec::with_capacity(records.len() + 1);
+
+        for record in &records {
+            expected.push(writer.virtual_position());
+            writer.write_all(record)?;
+        }
+
+        expected.push(writer.virtual_position());
+        let expected_data = writer.finish()?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual_data, expected_data);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_virtual_position_at_end_of_full_block() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&[0; MAX_BUF_SIZE]).await?;
+        writer.mark_virtual_position();
+        writer.write_all(b"noodles").await?;
+        writer.shutdown().await?;
+
+        let actual: Vec<_> = writer.take_virtual_positions().collect();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_all(&[0; MAX_BUF_SIZE])?;
+        let expected = writer.virtual_position();
+
+        assert_eq!(actual, [expected]);
+        assert_eq!(expected.uncompressed(), 0);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::{num::NonZeroUsize, thread};
+use std::{collections::VecDeque, num::NonZeroUsize, thread};
 
 use bytes::{Bytes, BytesMut};
 use futures::SinkExt;
@@ -78,6 +78,10 @@ impl Builder {
             buf: BytesMut::with_capacity(MAX_BUF_SIZE),
             eof_buf: Bytes::from_static(&BGZF_EOF),
             compression_level: compression_level.into(),
+            block_count: 0,
+            resolved_block_count: 0,
+            marks: VecDeque::new(),
+            virtual_positions: VecDeque::new(),
         }
     }
 }
@@ -1,4 +1,5 @@
 use std::{
+    collections::VecDeque,
     future::Future,
     io,
     pin::Pin,
@@ -11,7 +12,15 @@ use tokio::io::AsyncWrite;
 use tokio_util::codec::FramedWrite;
 
 use super::Deflate;
-use crate::r#async::BlockCodec;
+use crate::{BGZF_HEADER_SIZE, r#async::BlockCodec, gz};
+
+/// A block that was sent to the underlying sink.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WrittenBlock {
+    pub position: u64,
+    pub size: u64,
+    pub uncompressed_size: usize,
+}
 
 pin_project! {
     pub struct Deflater<W> {
@@ -19,6 +28,8 @@ pin_project! {
         sink: FramedWrite<W, BlockCodec>,
         #[pin]
         state: Option<Deflate>,
+        position: u64,
+        written_blocks: VecDeque<WrittenBlock>,
     }
 }
 
@@ -27,7 +38,22 @@ where
     W: AsyncWrite,
 {
     pub fn new(sink: FramedWrite<W, BlockCodec>) -> Self {
-        Self { sink, state: None }
+        Self {
+            sink,
+            state: None,
+            position: 0,
+            written_blocks: VecDeque::new(),
+        }
+    }
+
+    /// Returns the compressed position of the next block.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the blocks that were sent to the underlying sink, in order, since the last call.
+    pub fn written_blocks_mut(&mut self) -> &mut VecDeque<WrittenBlock> {
+        &mut self.written_blocks
     }
 
     pub fn get_mut(&mut self) -> &mut W {
@@ -47,6 +73,18 @@ where
         };
 
         this.state.set(None);
+
+        let (cdata, _, uncompressed_size) = &data;
+        let size = (BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE) as u64;
+
+        this.written_blocks.push_back(WrittenBlock {
+            position: *this.position,
+            size,
+            uncompressed_size: *uncompressed_size,
+        });
+
+        *this.position += size;
+
         this.sink.start_send(data)?;
 
         Poll::Ready(Ok(()))
@@ -1,20 +1,26 @@
 //! BGZF I/O.
 
 mod block;
+mod block_cache;
 mod buf_read;
 pub mod indexed_reader;
-mod multithreaded_reader;
+pub mod multithreaded_reader;
 pub mod multithreaded_writer;
+mod partition;
+mod range_read;
+pub mod range_reader;
 mod read;
 pub mod reader;
 mod seek;
+pub mod verify;
 pub mod writer;
 
 pub(crate) use self::block::Block;
 pub use self::{
-    buf_read::BufRead, indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
-    multithreaded_writer::MultithreadedWriter, read::Read, reader::Reader, seek::Seek,
-    writer::Writer,
+    block_cache::BlockCache, buf_read::BufRead, indexed_reader::IndexedReader,
+    multithreaded_reader::MultithreadedReader, multithreaded_writer::MultithreadedWriter,
+    partition::partition, range_read::RangeRead, range_reader::RangeReader, read::Read,
+    reader::Reader, seek::Seek, verify::verify, writer::Writer,
 };
 
 #[cfg(test)]
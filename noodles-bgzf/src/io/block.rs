@@ -18,6 +18,10 @@ pub struct Block {
 }
 
 impl Block {
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
     pub fn set_position(&mut self, position: u64) {
         self.pos = position;
     }
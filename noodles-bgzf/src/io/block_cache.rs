@@ -0,0 +1,306 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use super::Block;
+
+/// A shared cache of inflated BGZF blocks.
+///
+/// Blocks are keyed by their compressed position, and the least recently used blocks are evicted
+/// when the total size of the cached uncompressed data exceeds the capacity.
+///
+/// A block cache can be shared between readers using an [`Arc`](std::sync::Arc), e.g., to serve many region
+/// queries on the same file without reinflating the same blocks. Since blocks are only keyed by
+/// position, readers that share a cache must read the same underlying data.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use std::sync::Arc;
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::io::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let block_cache = Arc::new(bgzf::io::BlockCache::new(1 << 20));
+///
+/// for _ in 0..2 {
+///     let mut reader = bgzf::io::reader::Builder::default()
+///         .set_block_cache(block_cache.clone())
+///         .build_from_reader(&data[..]);
+///
+///     let mut buf = Vec::new();
+///     reader.read_to_end(&mut buf)?;
+///     assert_eq!(buf, b"noodles");
+/// }
+///
+/// assert_eq!(block_cache.hit_count(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct BlockCache {
+    capacity: usize,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    entries: HashMap<u64, Entry>,
+    lru: BTreeMap<u64, u64>,
+    tick: u64,
+    size: usize,
+    hit_count: u64,
+    miss_count: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    block_size: u64,
+    data: Box<[u8]>,
+    tick: u64,
+}
+
+impl BlockCache {
+    /// Creates a block cache with a capacity in bytes of uncompressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Returns the capacity in bytes of uncompressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert_eq!(block_cache.capacity(), 1 << 20);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert_eq!(block_cache.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert!(block_cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total size in bytes of the cached uncompressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert_eq!(block_cache.size(), 0);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.lock().size
+    }
+
+    /// Returns the number of lookups that found a cached block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert_eq!(block_cache.hit_count(), 0);
+    /// ```
+    pub fn hit_count(&self) -> u64 {
+        self.lock().hit_count
+    }
+
+    /// Returns the number of lookups that did not find a cached block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// assert_eq!(block_cache.miss_count(), 0);
+    /// ```
+    pub fn miss_count(&self) -> u64 {
+        self.lock().miss_count
+    }
+
+    /// Removes all cached blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = bgzf::io::BlockCache::new(1 << 20);
+    /// block_cache.clear();
+    /// ```
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.lru.clear();
+        state.size = 0;
+    }
+
+    /// Fills the block at the given compressed position from the cache.
+    ///
+    /// This returns whether the block was cached.
+    pub(crate) fn get_into(&self, position: u64, block: &mut Block) -> bool {
+        let mut state = self.lock();
+
+        state.tick += 1;
+        let tick = state.tick;
+
+        let Some(entry) = state.entries.get_mut(&position) else {
+            state.miss_count += 1;
+            return false;
+        };
+
+        let prev_tick = entry.tick;
+        entry.tick = tick;
+
+        block.set_size(entry.block_size);
+
+        let data = block.data_mut();
+        data.set_position(0);
+        data.resize(entry.data.len());
+        data.as_mut().copy_from_slice(&entry.data);
+
+        state.lru.remove(&prev_tick);
+        state.lru.insert(tick, position);
+        state.hit_count += 1;
+
+        true
+    }
+
+    /// Adds an inflated block at the given compressed position.
+    pub(crate) fn insert(&self, position: u64, block: &Block) {
+        let data = block.data().as_ref();
+
+        if data.len() > self.capacity {
+            return;
+        }
+
+        let mut state = self.lock();
+
+        state.tick += 1;
+        let tick = state.tick;
+
+        let entry = Entry {
+            block_size: block.size(),
+            data: data.into(),
+            tick,
+        };
+
+        state.size += entry.data.len();
+
+        if let Some(prev_entry) = state.entries.insert(position, entry) {
+            state.size -= prev_entry.data.len();
+            state.lru.remove(&prev_entry.tick);
+        }
+
+        state.lru.insert(tick, position);
+
+        while state.size > self.capacity {
+            let Some((_, position)) = state.lru.pop_first() else {
+                break;
+            };
+
+            if let Some(entry) = state.entries.remove(&position) {
+                state.size -= entry.data.len();
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_block(data: &[u8]) -> Block {
+        let mut block = Block::default();
+        block.set_size(32);
+        block.data_mut().resize(data.len());
+        block.data_mut().as_mut().copy_from_slice(data);
+        block
+    }
+
+    #[test]
+    fn test_get_into() {
+        let block_cache = BlockCache::new(16);
+        block_cache.insert(0, &build_block(b"noodles"));
+
+        let mut block = Block::default();
+        assert!(block_cache.get_into(0, &mut block));
+        assert_eq!(block.size(), 32);
+        assert_eq!(block.data().as_ref(), b"noodles");
+
+        assert!(!block_cache.get_into(32, &mut block));
+
+        assert_eq!(block_cache.hit_count(), 1);
+        assert_eq!(block_cache.miss_count(), 1);
+    }
+
+    #[test]
+    fn test_insert() {
+        let block_cache = BlockCache::new(15);
+
+        block_cache.insert(0, &build_block(b"noodles"));
+        block_cache.insert(32, &build_block(b"bgzf"));
+        assert_eq!(block_cache.len(), 2);
+        assert_eq!(block_cache.size(), 11);
+
+        // Mark block 0 as recently used.
+        let mut block = Block::default();
+        assert!(block_cache.get_into(0, &mut block));
+
+        block_cache.insert(64, &build_block(b"cache"));
+        assert_eq!(block_cache.len(), 2);
+        assert_eq!(block_cache.size(), 12);
+        assert!(block_cache.get_into(0, &mut block));
+        assert!(!block_cache.get_into(32, &mut block));
+
+        // Blocks larger than the capacity are not cached.
+        block_cache.insert(96, &build_block(&[0; 16]));
+        assert!(!block_cache.get_into(96, &mut block));
+
+        block_cache.clear();
+        assert!(block_cache.is_empty());
+        assert_eq!(block_cache.size(), 0);
+    }
+}
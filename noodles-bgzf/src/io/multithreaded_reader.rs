@@ -1,13 +1,22 @@
+//! Multithreaded BGZF reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
+    collections::VecDeque,
     io::{self, BufRead, Read, Seek, SeekFrom},
     mem,
     num::NonZeroUsize,
+    ops::Range,
+    sync::Arc,
     thread::{self, JoinHandle},
 };
 
 use crossbeam_channel::{Receiver, Sender};
 
-use super::Block;
+use super::{Block, BlockCache};
 use crate::{VirtualPosition, gzi};
 
 type BufferedTx = Sender<io::Result<Buffer>>;
@@ -18,6 +27,10 @@ type ReadTx = Sender<BufferedRx>;
 type ReadRx = Receiver<BufferedRx>;
 type RecycleTx = Sender<Buffer>;
 type RecycleRx = Receiver<Buffer>;
+type SeekFn<R> = fn(&mut R, SeekFrom) -> io::Result<u64>;
+
+// The maximum size of a BGZF block, i.e., BSIZE + 1.
+const MAX_BLOCK_SIZE: u64 = 1 << 16;
 
 enum State<R> {
     Paused(R),
@@ -30,6 +43,12 @@ enum State<R> {
     Done,
 }
 
+// A schedule of compressed position ranges to read, set by `Seek::prefetch`.
+struct Prefetch<R> {
+    ranges: VecDeque<Range<u64>>,
+    seek: SeekFn<R>,
+}
+
 #[derive(Debug, Default)]
 struct Buffer {
     buf: Vec<u8>,
@@ -40,9 +59,16 @@ struct Buffer {
 ///
 /// This is a multithreaded BGZF reader that uses a thread pool to decompress block data. It places
 /// the inner reader on its own thread to read raw frames asynchronously.
+///
+/// When the inner reader is seekable, the reader can be given the ranges of an indexed query
+/// using [`crate::io::Seek::prefetch`]. The blocks in these ranges are then read and inflated in
+/// parallel ahead of consumption, and seeks to positions that were already read ahead do not
+/// restart the pipeline.
 pub struct MultithreadedReader<R> {
     state: State<R>,
     worker_count: NonZeroUsize,
+    block_cache: Option<Arc<BlockCache>>,
+    prefetch: Option<Prefetch<R>>,
     position: u64,
     buffer: Buffer,
 }
@@ -139,12 +165,9 @@ where
     /// let reader = bgzf::io::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, io::empty());
     /// ```
     pub fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
-        Self {
-            state: State::Paused(inner),
-            worker_count,
-            position: 0,
-            buffer: Buffer::default(),
-        }
+        Builder::default()
+            .set_worker_count(worker_count)
+            .build_from_reader(inner)
     }
 
     /// Returns a mutable reference to the underlying reader.
@@ -187,8 +210,29 @@ where
             recycle_tx.send(Buffer::default()).unwrap();
         }
 
-        let reader_handle = spawn_reader(inner, inflate_tx, read_tx, recycle_rx);
-        let inflater_handles = spawn_inflaters(self.worker_count, inflate_rx);
+        let (ranges, seek) = match &self.prefetch {
+            Some(prefetch) => (
+                prefetch.ranges.iter().cloned().collect(),
+                Some(prefetch.seek),
+            ),
+            None => {
+                let range = self.position..u64::MAX;
+                (vec![range], None)
+            }
+        };
+
+        let reader_handle = spawn_reader(
+            inner,
+            self.position,
+            ranges,
+            seek,
+            self.block_cache.clone(),
+            inflate_tx,
+            read_tx,
+            recycle_rx,
+        );
+        let inflater_handles =
+            spawn_inflaters(self.worker_count, self.block_cache.clone(), inflate_rx);
 
         self.state = State::Running {
             reader_handle,
@@ -231,30 +275,136 @@ where
     }
 
     fn read_block(&mut self) -> io::Result<()> {
-        self.resume();
+        let mut is_restarted = false;
 
-        let State::Running {
-            read_rx,
-            recycle_tx,
-            ..
-        } = &self.state
-        else {
-            panic!("invalid state");
+        loop {
+            self.resume();
+
+            match self.recv_buffer()? {
+                Some(buffer) if buffer.block.position() == self.position => {
+                    is_restarted = false;
+
+                    if self.set_buffer(buffer) {
+                        break;
+                    }
+                }
+                Some(buffer) => {
+                    // The next prefetched block is not contiguous with the current block, i.e.,
+                    // the stream was read past the end of a prefetched range.
+                    self.recycle_buffer(buffer);
+
+                    if is_restarted {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "unexpected block position",
+                        ));
+                    }
+
+                    self.restart(self.position);
+                    is_restarted = true;
+                }
+                None => {
+                    // The stream is at EOF unless a prefetch schedule ended before it.
+                    let is_eof = self.prefetch.as_ref().is_none_or(|prefetch| {
+                        prefetch
+                            .ranges
+                            .back()
+                            .is_some_and(|range| range.end == u64::MAX)
+                    });
+
+                    if is_eof || is_restarted {
+                        break;
+                    }
+
+                    self.restart(self.position);
+                    is_restarted = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Moves to the block at the given compressed position using the blocks that were already
+    // read ahead, if possible.
+    //
+    // Only blocks within the pipeline window are drained. Otherwise, e.g., for a forward seek far
+    // into a sequentially read range, the caller restarts the pipeline, which seeks the inner
+    // reader directly.
+    fn seek_in_pipeline(&mut self, cpos: u64) -> io::Result<bool> {
+        let block = &self.buffer.block;
+
+        if block.position() == cpos && block.size() > 0 {
+            self.position = block.position() + block.size();
+            return Ok(true);
+        }
+
+        if cpos < self.position || !matches!(self.state, State::Running { .. }) {
+            return Ok(false);
+        }
+
+        let Some(prefetch) = self.prefetch.as_ref() else {
+            return Ok(false);
         };
 
-        while let Some(mut buffer) = recv_buffer(read_rx)? {
-            buffer.block.set_position(self.position);
-            self.position += buffer.block.size();
+        let window = self.worker_count.get() as u64 * MAX_BLOCK_SIZE;
+
+        if !is_in_window(&prefetch.ranges, self.position, cpos, window) {
+            return Ok(false);
+        }
+
+        while let Some(buffer) = self.recv_buffer()? {
+            let position = buffer.block.position();
 
-            let prev_buffer = mem::replace(&mut self.buffer, buffer);
-            recycle_tx.send(prev_buffer).ok();
+            if position < cpos {
+                self.recycle_buffer(buffer);
+            } else if position == cpos {
+                self.position = cpos;
 
-            if self.buffer.block.data().len() > 0 {
+                if !self.set_buffer(buffer) {
+                    self.read_block()?;
+                }
+
+                return Ok(true);
+            } else {
+                self.recycle_buffer(buffer);
                 break;
             }
         }
 
-        Ok(())
+        Ok(false)
+    }
+
+    // Stops the pipeline and reschedules reading from the given compressed position.
+    fn restart(&mut self, cpos: u64) {
+        self.pause();
+
+        if let Some(prefetch) = self.prefetch.as_mut() {
+            reschedule(&mut prefetch.ranges, cpos);
+        }
+
+        self.position = cpos;
+    }
+
+    fn recv_buffer(&self) -> io::Result<Option<Buffer>> {
+        match &self.state {
+            State::Running { read_rx, .. } => recv_buffer(read_rx),
+            _ => Ok(None),
+        }
+    }
+
+    fn recycle_buffer(&self, buffer: Buffer) {
+        if let State::Running { recycle_tx, .. } = &self.state {
+            recycle_tx.send(buffer).ok();
+        }
+    }
+
+    // Replaces the current buffer and returns whether the new block has data.
+    fn set_buffer(&mut self, buffer: Buffer) -> bool {
+        self.position = buffer.block.position() + buffer.block.size();
+        let prev_buffer = mem::replace(&mut self.buffer, buffer);
+        self.recycle_buffer(prev_buffer);
+        self.buffer.block.data().len() > 0
     }
 }
 
@@ -325,10 +475,16 @@ where
     fn seek_to_virtual_position(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
-        self.get_mut().seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
+        if self.prefetch.is_some() {
+            if !self.seek_in_pipeline(cpos)? {
+                self.restart(cpos);
+                self.read_block()?;
+            }
+        } else {
+            self.get_mut().seek(SeekFrom::Start(cpos))?;
+            self.position = cpos;
+            self.read_block()?;
+        }
 
         self.buffer.block.data_mut().set_position(usize::from(upos));
 
@@ -344,6 +500,99 @@ where
         self.seek_to_virtual_position(virtual_position)?;
         Ok(pos)
     }
+
+    fn prefetch(&mut self, ranges: &[Range<VirtualPosition>]) -> io::Result<()> {
+        self.pause();
+
+        let schedule = build_schedule(ranges);
+
+        self.prefetch = if schedule.is_empty() {
+            // The pipeline may have read ahead of the current block.
+            let position = self.position;
+            self.get_mut().seek(SeekFrom::Start(position))?;
+            None
+        } else {
+            Some(Prefetch {
+                ranges: schedule,
+                seek: R::seek,
+            })
+        };
+
+        Ok(())
+    }
+}
+
+// Converts ranges of virtual positions to sorted, disjoint ranges of compressed positions of the
+// blocks to read.
+fn build_schedule(ranges: &[Range<VirtualPosition>]) -> VecDeque<Range<u64>> {
+    let mut compressed_ranges: Vec<_> = ranges
+        .iter()
+        .filter(|range| range.start < range.end)
+        .map(|range| {
+            let start = range.start.compressed();
+
+            // The block at the end position is only read when the range ends within it.
+            let end = if range.end.uncompressed() == 0 {
+                range.end.compressed()
+            } else {
+                range.end.compressed() + 1
+            };
+
+            start..end
+        })
+        .collect();
+
+    compressed_ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut schedule: VecDeque<Range<u64>> = VecDeque::with_capacity(compressed_ranges.len());
+
+    for range in compressed_ranges {
+        match schedule.back_mut() {
+            Some(prev_range) if range.start <= prev_range.end => {
+                prev_range.end = prev_range.end.max(range.end);
+            }
+            _ => schedule.push_back(range),
+        }
+    }
+
+    schedule
+}
+
+// Drops the ranges that end before the given compressed position and ensures the schedule starts
+// at that position.
+fn reschedule(ranges: &mut VecDeque<Range<u64>>, cpos: u64) {
+    while ranges.front().is_some_and(|range| range.end <= cpos) {
+        ranges.pop_front();
+    }
+
+    match ranges.front_mut() {
+        Some(range) if range.start <= cpos => range.start = cpos,
+        Some(range) => {
+            // Read sequentially until the start of the next range.
+            let end = range.start;
+            ranges.push_front(cpos..end);
+        }
+        None => ranges.push_back(cpos..u64::MAX),
+    }
+}
+
+// Returns whether a block starting at `dst` is scheduled and at most `window` scheduled bytes are
+// read from `src` before it.
+fn is_in_window(ranges: &VecDeque<Range<u64>>, src: u64, dst: u64, window: u64) -> bool {
+    if !ranges.iter().any(|range| range.contains(&dst)) {
+        return false;
+    }
+
+    let len: u64 = ranges
+        .iter()
+        .map(|range| {
+            let start = range.start.max(src);
+            let end = range.end.min(dst);
+            end.saturating_sub(start)
+        })
+        .sum();
+
+    len <= window
 }
 
 fn recv_buffer(read_rx: &ReadRx) -> io::Result<Option<Buffer>> {
@@ -358,8 +607,20 @@ fn recv_buffer(read_rx: &ReadRx) -> io::Result<Option<Buffer>> {
 
 struct ReadError<R>(R, io::Error);
 
+// Reads the frames of the blocks that start in the given ranges of compressed positions.
+//
+// The inner reader is expected to be at `position`. When a seek function is given, the reader
+// seeks to the start of the first range and to the start of each noncontiguous range.
+//
+// Blocks in the block cache are sent without reading their frames. The inner reader skips the
+// frames of these blocks before the next frame is read.
+#[allow(clippy::too_many_arguments)]
 fn spawn_reader<R>(
     mut reader: R,
+    mut position: u64,
+    ranges: Vec<Range<u64>>,
+    seek: Option<SeekFn<R>>,
+    block_cache: Option<Arc<BlockCache>>,
     inflate_tx: InflateTx,
     read_tx: ReadTx,
     recycle_rx: RecycleRx,
@@ -370,33 +631,105 @@ where
     use super::reader::frame::read_frame_into;
 
     thread::spawn(move || {
-        while let Ok(mut buffer) = recycle_rx.recv() {
-            match read_frame_into(&mut reader, &mut buffer.buf) {
-                Ok(result) if result.is_none() => break,
-                Ok(_) => {}
-                Err(e) => return Err(ReadError(reader, e)),
+        let mut is_positioned = seek.is_none();
+        let mut cached_len = 0;
+
+        'ranges: for range in ranges {
+            if let Some(seek) = seek {
+                if !is_positioned || position != range.start {
+                    if let Err(e) = seek(&mut reader, SeekFrom::Start(range.start)) {
+                        return Err(ReadError(reader, e));
+                    }
+
+                    position = range.start;
+                    is_positioned = true;
+                    cached_len = 0;
+                }
             }
 
-            let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
+            while position < range.end {
+                let Ok(mut buffer) = recycle_rx.recv() else {
+                    break 'ranges;
+                };
 
-            inflate_tx.send((buffer, buffered_tx)).unwrap();
-            read_tx.send(buffered_rx).unwrap();
+                let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
+
+                let is_cached = block_cache
+                    .as_deref()
+                    .is_some_and(|block_cache| block_cache.get_into(position, &mut buffer.block));
+
+                if is_cached {
+                    buffer.block.set_position(position);
+                    position += buffer.block.size();
+                    cached_len += buffer.block.size();
+
+                    buffered_tx.send(Ok(buffer)).unwrap();
+                } else {
+                    if cached_len > 0 {
+                        if let Err(e) = skip_cached(&mut reader, seek, position, cached_len) {
+                            return Err(ReadError(reader, e));
+                        }
+
+                        cached_len = 0;
+                    }
+
+                    match read_frame_into(&mut reader, &mut buffer.buf) {
+                        Ok(result) if result.is_none() => return Ok(reader),
+                        Ok(_) => {}
+                        Err(e) => return Err(ReadError(reader, e)),
+                    }
+
+                    buffer.block.set_position(position);
+                    position += buffer.buf.len() as u64;
+
+                    inflate_tx.send((buffer, buffered_tx)).unwrap();
+                }
+
+                read_tx.send(buffered_rx).unwrap();
+            }
+        }
+
+        if cached_len > 0 {
+            if let Err(e) = skip_cached(&mut reader, seek, position, cached_len) {
+                return Err(ReadError(reader, e));
+            }
         }
 
         Ok(reader)
     })
 }
 
-fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<JoinHandle<()>> {
-    use super::reader::frame::parse_block;
+// Moves the inner reader to `position`, past the frames of `len` bytes of cached blocks.
+fn skip_cached<R>(
+    reader: &mut R,
+    seek: Option<SeekFn<R>>,
+    position: u64,
+    len: u64,
+) -> io::Result<()>
+where
+    R: Read,
+{
+    use super::reader::discard;
 
+    match seek {
+        Some(seek) => seek(reader, SeekFrom::Start(position)).map(|_| ()),
+        None => discard(reader, len),
+    }
+}
+
+fn spawn_inflaters(
+    worker_count: NonZeroUsize,
+    block_cache: Option<Arc<BlockCache>>,
+    inflate_rx: InflateRx,
+) -> Vec<JoinHandle<()>> {
     (0..worker_count.get())
         .map(|_| {
             let inflate_rx = inflate_rx.clone();
+            let block_cache = block_cache.clone();
 
             thread::spawn(move || {
                 while let Ok((mut buffer, buffered_tx)) = inflate_rx.recv() {
-                    let result = parse_block(&buffer.buf, &mut buffer.block).map(|_| buffer);
+                    let result = inflate(block_cache.as_deref(), &mut buffer).map(|_| buffer);
                     buffered_tx.send(result).unwrap();
                 }
             })
@@ -404,6 +737,19 @@ fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<Joi
         .collect()
 }
 
+// Inflates a block that is not in the block cache and adds it to the cache.
+fn inflate(block_cache: Option<&BlockCache>, buffer: &mut Buffer) -> io::Result<()> {
+    use super::reader::frame::parse_block;
+
+    parse_block(&buffer.buf, &mut buffer.block)?;
+
+    if let Some(block_cache) = block_cache {
+        block_cache.insert(buffer.block.position(), &buffer.block);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
@@ -453,4 +799,174 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_with_block_cache() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::io::{Writer, reader};
+
+        let mut writer = Writer::new(Vec::new());
+
+        for chunk in [&b"noodles"[..], b"-", b"bgzf"] {
+            writer.write_all(chunk)?;
+            writer.flush()?;
+        }
+
+        let data = writer.finish()?;
+
+        let block_cache = Arc::new(BlockCache::new(1 << 20));
+
+        let mut reader = reader::Builder::default()
+            .set_block_cache(block_cache.clone())
+            .build_from_reader(&data[..]);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+        assert_eq!(block_cache.len(), 4);
+        assert_eq!(block_cache.hit_count(), 0);
+
+        // The frames of cached blocks are not read.
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2).unwrap())
+            .set_block_cache(block_cache.clone())
+            .build_from_reader(Cursor::new(vec![0; data.len()]));
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+        assert_eq!(block_cache.hit_count(), 4);
+
+        let inner = reader.finish()?;
+        assert_eq!(inner.position(), data.len() as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prefetch() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::io::{Seek as _, Writer};
+
+        fn read_range<R>(
+            reader: &mut MultithreadedReader<R>,
+            range: &Range<VirtualPosition>,
+            dst: &mut Vec<u8>,
+        ) -> io::Result<()>
+        where
+            R: Read + Seek + Send + 'static,
+        {
+            reader.seek_to_virtual_position(range.start)?;
+
+            while reader.virtual_position() < range.end {
+                let src = reader.fill_buf()?;
+                dst.extend(src);
+                let len = src.len();
+                reader.consume(len);
+            }
+
+            Ok(())
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        let mut positions = Vec::new();
+
+        for i in 0..8 {
+            positions.push(writer.virtual_position());
+            write!(writer, "block{i}")?;
+            writer.flush()?;
+        }
+
+        positions.push(writer.virtual_position());
+        let data = writer.finish()?;
+
+        let ranges = [positions[1]..positions[3], positions[5]..positions[6]];
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2).unwrap())
+            .build_from_reader(Cursor::new(data));
+
+        reader.prefetch(&ranges)?;
+
+        let mut buf = Vec::new();
+
+        for range in &ranges {
+            read_range(&mut reader, range, &mut buf)?;
+        }
+
+        assert_eq!(buf, b"block1block2block5");
+
+        // Seeks outside of the prefetched ranges.
+        buf.clear();
+        read_range(&mut reader, &(positions[0]..positions[1]), &mut buf)?;
+        assert_eq!(buf, b"block0");
+
+        // Reads past the end of a prefetched range.
+        buf.clear();
+        reader.seek_to_virtual_position(positions[5])?;
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"block5block6block7");
+
+        // Clears the schedule.
+        reader.prefetch(&[])?;
+        assert!(reader.prefetch.is_none());
+
+        buf.clear();
+        reader.seek_to_virtual_position(positions[2])?;
+        reader.prefetch(&[positions[2]..positions[3]])?;
+        let len = reader.fill_buf()?.len();
+        reader.consume(len);
+        reader.prefetch(&[])?;
+        assert!(reader.prefetch.is_none());
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"block3block4block5block6block7");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_schedule() {
+        fn vp(cpos: u64, upos: u16) -> VirtualPosition {
+            VirtualPosition::try_from((cpos, upos)).unwrap()
+        }
+
+        let ranges = [
+            vp(89, 0)..vp(144, 0),
+            vp(8, 13)..vp(21, 0),
+            vp(21, 0)..vp(34, 5),
+            vp(55, 0)..vp(55, 0),
+        ];
+
+        assert_eq!(build_schedule(&ranges), [8..35, 89..144]);
+    }
+
+    #[test]
+    fn test_is_in_window() {
+        let ranges = VecDeque::from([8..21, 34..u64::MAX]);
+
+        assert!(is_in_window(&ranges, 8, 13, 5));
+        assert!(!is_in_window(&ranges, 8, 13, 4));
+        assert!(!is_in_window(&ranges, 8, 21, 16));
+        assert!(is_in_window(&ranges, 13, 34, 8));
+        assert!(is_in_window(&ranges, 34, 89, 55));
+        assert!(!is_in_window(&ranges, 34, 1 << 20, 55));
+    }
+
+    #[test]
+    fn test_reschedule() {
+        let mut ranges = VecDeque::from([8..21, 34..55]);
+        reschedule(&mut ranges, 13);
+        assert_eq!(ranges, [13..21, 34..55]);
+
+        let mut ranges = VecDeque::from([8..21, 34..55]);
+        reschedule(&mut ranges, 21);
+        assert_eq!(ranges, [21..34, 34..55]);
+
+        let mut ranges = VecDeque::from([8..21, 34..55]);
+        reschedule(&mut ranges, 55);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0], 55..u64::MAX);
+    }
 }
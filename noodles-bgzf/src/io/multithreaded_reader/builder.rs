@@ -0,0 +1,80 @@
+use std::{io::Read, num::NonZeroUsize, sync::Arc};
+
+use super::{Buffer, MultithreadedReader, State};
+use crate::io::BlockCache;
+
+/// A multithreaded BGZF reader builder.
+pub struct Builder {
+    worker_count: NonZeroUsize,
+    block_cache: Option<Arc<BlockCache>>,
+}
+
+impl Builder {
+    /// Sets the worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let builder = bgzf::io::multithreaded_reader::Builder::default()
+    ///     .set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets a shared block cache.
+    ///
+    /// Workers fill blocks from the cache, when present, instead of inflating them. Raw frames
+    /// are still read from the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block_cache = Arc::new(bgzf::io::BlockCache::new(1 << 20));
+    /// let builder = bgzf::io::multithreaded_reader::Builder::default()
+    ///     .set_block_cache(block_cache);
+    /// ```
+    pub fn set_block_cache(mut self, block_cache: Arc<BlockCache>) -> Self {
+        self.block_cache = Some(block_cache);
+        self
+    }
+
+    /// Builds a multithreaded BGZF reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::multithreaded_reader::Builder::default().build_from_reader(io::empty());
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> MultithreadedReader<R>
+    where
+        R: Read + Send + 'static,
+    {
+        MultithreadedReader {
+            state: State::Paused(reader),
+            worker_count: self.worker_count,
+            block_cache: self.block_cache,
+            prefetch: None,
+            position: 0,
+            buffer: Buffer::default(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            worker_count: NonZeroUsize::MIN,
+            block_cache: None,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+};
+
+use super::reader::frame::{HeaderBuf, MIN_FRAME_SIZE, is_valid_header};
+use crate::{BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+
+// A block can be at most `BGZF_MAX_ISIZE` bytes. A window of two maximum block sizes always holds
+// a block header and the header of the block that follows it.
+const WINDOW_SIZE: u64 = 2 * BGZF_MAX_ISIZE as u64 + BGZF_HEADER_SIZE as u64;
+
+/// Splits a BGZF stream into partitions at block boundaries.
+///
+/// This returns the compressed positions of the first block of each partition, in ascending
+/// order. The first partition always starts at 0. The stream is split into at most `count`
+/// partitions of roughly equal compressed size, e.g., to process a file in parallel.
+///
+/// A block boundary is found by searching for a valid block header that is directly followed by
+/// another valid block header or the end of the stream. This is a heuristic: the data of a block
+/// could contain bytes that look like block headers, in which case the returned position is not a
+/// block boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{io::{self, Cursor, Write}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::io::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// writer.flush()?;
+/// writer.write_all(b"bgzf")?;
+/// let data = writer.finish()?;
+///
+/// let positions = bgzf::io::partition(&mut Cursor::new(data), NonZeroUsize::MIN)?;
+/// assert_eq!(positions, [0]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn partition<R>(reader: &mut R, count: NonZeroUsize) -> io::Result<Vec<u64>>
+where
+    R: Read + Seek,
+{
+    let len = reader.seek(SeekFrom::End(0))?;
+    let count = count.get() as u64;
+
+    let mut positions = vec![0];
+    let mut buf = Vec::new();
+
+    for i in 1..count {
+        let target = len * i / count;
+
+        // SAFETY: `positions` is non-empty.
+        if target <= *positions.last().unwrap() {
+            continue;
+        }
+
+        reader.seek(SeekFrom::Start(target))?;
+
+        buf.clear();
+        reader.take(WINDOW_SIZE).read_to_end(&mut buf)?;
+
+        if let Some(j) = find_block_start(&buf, len - target) {
+            let position = target + j as u64;
+
+            if position < len && position > *positions.last().unwrap() {
+                positions.push(position);
+            }
+        }
+    }
+
+    Ok(positions)
+}
+
+fn find_block_start(buf: &[u8], remaining_len: u64) -> Option<usize> {
+    (0..buf.len()).find(|&i| {
+        let Some(block_size) = frame_size(&buf[i..]) else {
+            return false;
+        };
+
+        let next_start = i + block_size;
+
+        if next_start as u64 == remaining_len {
+            true
+        } else {
+            frame_size(&buf[next_start.min(buf.len())..]).is_some()
+        }
+    })
+}
+
+fn frame_size(src: &[u8]) -> Option<usize> {
+    let header: &HeaderBuf = src.get(..BGZF_HEADER_SIZE)?.try_into().ok()?;
+
+    if !is_valid_header(header) {
+        return None;
+    }
+
+    let bsize = header.last_chunk().map(|b| u16::from_le_bytes(*b))?;
+    let block_size = usize::from(bsize) + 1;
+
+    (block_size >= MIN_FRAME_SIZE).then_some(block_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_partition() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        let mut block_positions = Vec::new();
+
+        for i in 0..64u32 {
+            block_positions.push(writer.get_ref().len() as u64);
+            writer.write_all(&i.to_le_bytes().repeat(1024))?;
+            writer.flush()?;
+        }
+
+        let data = writer.finish()?;
+        let positions = partition(&mut Cursor::new(&data), NonZeroUsize::try_from(4).unwrap())?;
+
+        assert_eq!(positions.len(), 4);
+        assert_eq!(positions[0], 0);
+        assert!(positions.is_sorted());
+        assert!(positions.iter().all(|pos| block_positions.contains(pos)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_with_more_partitions_than_blocks() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        let positions = partition(&mut Cursor::new(&data), NonZeroUsize::try_from(8).unwrap())?;
+
+        // The stream has a data block and an EOF block.
+        assert!(positions.len() <= 2);
+        assert_eq!(positions[0], 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::{fs::File, io, sync::Arc};
+
+/// A source of bytes that supports positional reads.
+///
+/// Unlike [`std::io::Read`] + [`std::io::Seek`], a range read does not have a cursor, and reads
+/// take a shared reference. This allows implementations for backends that fetch byte ranges on
+/// demand, e.g., memory-mapped files, object stores, or HTTP servers that support range
+/// requests.
+///
+/// Use [`super::RangeReader`] to adapt a range read to a seekable stream, e.g., for the
+/// underlying reader of a BGZF reader.
+pub trait RangeRead {
+    /// Reads bytes starting at the given position into `buf`.
+    ///
+    /// This returns the number of bytes read, which may be less than the length of `buf`. A
+    /// return value of 0 for a nonempty buffer means the position is at or past the end of the
+    /// source.
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Returns the size of the source in bytes.
+    ///
+    /// This is only required to seek relative to the end of the source. By default, this returns
+    /// an error of kind [`io::ErrorKind::Unsupported`].
+    fn size(&self) -> io::Result<u64> {
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+impl RangeRead for [u8] {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        let src = usize::try_from(pos)
+            .ok()
+            .and_then(|i| self.get(i..))
+            .unwrap_or_default();
+
+        let len = src.len().min(buf.len());
+        buf[..len].copy_from_slice(&src[..len]);
+
+        Ok(len)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.len() as u64)
+    }
+}
+
+impl RangeRead for Vec<u8> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_slice().read_at(pos, buf)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        self.as_slice().size()
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl RangeRead for File {
+    #[cfg(unix)]
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        use std::os::unix::fs::FileExt;
+        FileExt::read_at(self, buf, pos)
+    }
+
+    #[cfg(windows)]
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        use std::os::windows::fs::FileExt;
+        self.seek_read(buf, pos)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        self.metadata().map(|metadata| metadata.len())
+    }
+}
+
+impl<T> RangeRead for &T
+where
+    T: RangeRead + ?Sized,
+{
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        (**self).read_at(pos, buf)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        (**self).size()
+    }
+}
+
+impl<T> RangeRead for Box<T>
+where
+    T: RangeRead + ?Sized,
+{
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        (**self).read_at(pos, buf)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        (**self).size()
+    }
+}
+
+impl<T> RangeRead for Arc<T>
+where
+    T: RangeRead + ?Sized,
+{
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        (**self).read_at(pos, buf)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        (**self).size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_at() -> io::Result<()> {
+        let src = b"noodles";
+        let mut buf = [0; 4];
+
+        assert_eq!(src[..].read_at(0, &mut buf)?, 4);
+        assert_eq!(&buf, b"nood");
+
+        assert_eq!(src[..].read_at(5, &mut buf)?, 2);
+        assert_eq!(&buf[..2], b"es");
+
+        assert_eq!(src[..].read_at(7, &mut buf)?, 0);
+        assert_eq!(src[..].read_at(u64::MAX, &mut buf)?, 0);
+
+        Ok(())
+    }
+}
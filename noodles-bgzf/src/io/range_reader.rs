@@ -0,0 +1,259 @@
+//! BGZF range reader.
+
+mod builder;
+mod cache;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use self::cache::Cache;
+use super::RangeRead;
+
+/// A seekable stream over a range read.
+///
+/// A range reader adapts a [`RangeRead`] to [`std::io::Read`] + [`std::io::Seek`]. Fetched byte
+/// ranges are cached, and small reads are extended by a read-ahead length, so that reading a
+/// BGZF block header and its following data, or reading consecutive blocks, is served by a
+/// single request.
+///
+/// This is typically used as the underlying reader of a BGZF reader, which allows indexed
+/// readers (e.g., `bam::io::IndexedReader`) to query remote or memory-mapped data.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::io::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let mut reader = bgzf::io::Reader::new(bgzf::io::RangeReader::new(data));
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+///
+/// assert_eq!(buf, b"noodles");
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct RangeReader<T> {
+    inner: T,
+    position: u64,
+    read_ahead: usize,
+    cache: Cache,
+}
+
+impl<T> RangeReader<T> {
+    /// Creates a range reader with the default read-ahead length and cache capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::RangeReader::new(Vec::<u8>::new());
+    /// ```
+    pub fn new(inner: T) -> Self {
+        Builder::default().build_from_range_read(inner)
+    }
+
+    /// Returns a reference to the underlying range read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::RangeReader::new(Vec::<u8>::new());
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps and returns the underlying range read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::RangeReader::new(Vec::<u8>::new());
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the current position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::RangeReader::new(Vec::<u8>::new());
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl<T> Read for RangeReader<T>
+where
+    T: RangeRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if let Some(src) = self.cache.get(self.position) {
+            let len = src.len().min(buf.len());
+            buf[..len].copy_from_slice(&src[..len]);
+            self.position += len as u64;
+            return Ok(len);
+        }
+
+        // Large reads are not cached.
+        if buf.len() >= self.read_ahead {
+            let len = read_at(&self.inner, self.position, buf)?;
+            self.position += len as u64;
+            return Ok(len);
+        }
+
+        let mut fetched_buf = vec![0; self.read_ahead];
+        let fetched_len = read_at(&self.inner, self.position, &mut fetched_buf)?;
+        fetched_buf.truncate(fetched_len);
+
+        let len = fetched_len.min(buf.len());
+        buf[..len].copy_from_slice(&fetched_buf[..len]);
+
+        if fetched_len > 0 {
+            self.cache.insert(self.position, fetched_buf);
+        }
+
+        self.position += len as u64;
+
+        Ok(len)
+    }
+}
+
+impl<T> Seek for RangeReader<T>
+where
+    T: RangeRead,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => {
+                self.position = n;
+                return Ok(n);
+            }
+            SeekFrom::Current(n) => (self.position, n),
+            SeekFrom::End(n) => (self.inner.size()?, n),
+        };
+
+        self.position = base.checked_add_signed(offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        Ok(self.position)
+    }
+}
+
+// Reads until `buf` is full or the end of the source is reached.
+fn read_at<T>(inner: &T, pos: u64, buf: &mut [u8]) -> io::Result<usize>
+where
+    T: RangeRead + ?Sized,
+{
+    let mut len = 0;
+
+    while len < buf.len() {
+        match inner.read_at(pos + len as u64, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, ops::Range};
+
+    use super::*;
+
+    struct CountingRangeRead {
+        data: Vec<u8>,
+        requests: RefCell<Vec<Range<u64>>>,
+    }
+
+    impl RangeRead for CountingRangeRead {
+        fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.data.read_at(pos, buf)?;
+            self.requests.borrow_mut().push(pos..pos + len as u64);
+            Ok(len)
+        }
+
+        fn size(&self) -> io::Result<u64> {
+            self.data.size()
+        }
+    }
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let inner = CountingRangeRead {
+            data: b"noodles-bgzf".to_vec(),
+            requests: RefCell::new(Vec::new()),
+        };
+
+        let mut reader = Builder::default()
+            .set_read_ahead(8)
+            .build_from_range_read(inner);
+
+        let mut buf = [0; 4];
+
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"nood");
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"les-");
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"bgzf");
+        assert_eq!(reader.read(&mut buf)?, 0);
+
+        reader.seek(SeekFrom::Start(2))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"odle");
+
+        let mut buf = [0; 8];
+        reader.seek(SeekFrom::End(-8))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"les-bgzf");
+
+        // The request at 12 is a short read at the end of the source.
+        assert_eq!(
+            *reader.get_ref().requests.borrow(),
+            [0..8, 8..12, 12..12, 12..12]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let mut reader = RangeReader::new(b"noodles".to_vec());
+
+        assert_eq!(reader.seek(SeekFrom::Start(3))?, 3);
+        assert_eq!(reader.seek(SeekFrom::Current(2))?, 5);
+        assert_eq!(reader.seek(SeekFrom::Current(-5))?, 0);
+        assert_eq!(reader.seek(SeekFrom::End(-1))?, 6);
+        assert!(reader.seek(SeekFrom::Current(-7)).is_err());
+
+        Ok(())
+    }
+}
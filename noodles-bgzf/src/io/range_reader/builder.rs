@@ -0,0 +1,74 @@
+use super::{Cache, RangeReader};
+use crate::BGZF_MAX_ISIZE;
+
+const DEFAULT_READ_AHEAD: usize = BGZF_MAX_ISIZE;
+const DEFAULT_CACHE_CAPACITY: usize = 16 * BGZF_MAX_ISIZE;
+
+/// A range reader builder.
+#[derive(Debug)]
+pub struct Builder {
+    read_ahead: usize,
+    cache_capacity: usize,
+}
+
+impl Builder {
+    /// Sets the minimum number of bytes to fetch per request.
+    ///
+    /// Reads smaller than this fetch the following bytes with the same request, so that
+    /// subsequent reads of nearby blocks are served from the cache. The default is 64 KiB, i.e.,
+    /// the maximum size of a BGZF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::io::range_reader::Builder::default().set_read_ahead(1 << 20);
+    /// ```
+    pub fn set_read_ahead(mut self, read_ahead: usize) -> Self {
+        self.read_ahead = read_ahead;
+        self
+    }
+
+    /// Sets the capacity of the cache of fetched byte ranges, in bytes.
+    ///
+    /// When the capacity is exceeded, the oldest fetched ranges are evicted. The most recently
+    /// fetched range is always kept. The default is 1 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::io::range_reader::Builder::default().set_cache_capacity(1 << 24);
+    /// ```
+    pub fn set_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity = cache_capacity;
+        self
+    }
+
+    /// Builds a range reader from a range read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data: Vec<u8> = Vec::new();
+    /// let reader = bgzf::io::range_reader::Builder::default().build_from_range_read(data);
+    /// ```
+    pub fn build_from_range_read<T>(self, inner: T) -> RangeReader<T> {
+        RangeReader {
+            inner,
+            position: 0,
+            read_ahead: self.read_ahead,
+            cache: Cache::new(self.cache_capacity),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            read_ahead: DEFAULT_READ_AHEAD,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+/// A cache of fetched byte ranges.
+///
+/// Ranges are evicted in least recently used order.
+#[derive(Debug)]
+pub(super) struct Cache {
+    capacity: usize,
+    len: usize,
+    tick: u64,
+    segments: BTreeMap<u64, Segment>,
+    // The start positions of segments by last use.
+    lru: BTreeMap<u64, u64>,
+}
+
+#[derive(Debug)]
+struct Segment {
+    buf: Vec<u8>,
+    tick: u64,
+}
+
+impl Cache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            len: 0,
+            tick: 0,
+            segments: BTreeMap::new(),
+            lru: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the cached bytes starting at the given position, if any.
+    ///
+    /// A hit marks the range as most recently used.
+    pub fn get(&mut self, pos: u64) -> Option<&[u8]> {
+        let (&start, segment) = self.segments.range_mut(..=pos).next_back()?;
+        let i = usize::try_from(pos - start).ok()?;
+
+        if i >= segment.buf.len() {
+            return None;
+        }
+
+        if segment.tick != self.tick {
+            self.tick += 1;
+            self.lru.remove(&segment.tick);
+            self.lru.insert(self.tick, start);
+            segment.tick = self.tick;
+        }
+
+        Some(&segment.buf[i..])
+    }
+
+    pub fn insert(&mut self, pos: u64, buf: Vec<u8>) {
+        if let Some(prev_segment) = self.segments.remove(&pos) {
+            self.len -= prev_segment.buf.len();
+            self.lru.remove(&prev_segment.tick);
+        }
+
+        self.tick += 1;
+        self.len += buf.len();
+        self.segments.insert(
+            pos,
+            Segment {
+                buf,
+                tick: self.tick,
+            },
+        );
+        self.lru.insert(self.tick, pos);
+
+        while self.len > self.capacity && self.lru.len() > 1 {
+            // SAFETY: `self.lru` is nonempty.
+            let (_, start) = self.lru.pop_first().unwrap();
+
+            if let Some(segment) = self.segments.remove(&start) {
+                self.len -= segment.buf.len();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let mut cache = Cache::new(8);
+        cache.insert(2, b"ndls".to_vec());
+
+        assert!(cache.get(0).is_none());
+        assert_eq!(cache.get(2), Some(&b"ndls"[..]));
+        assert_eq!(cache.get(4), Some(&b"ls"[..]));
+        assert!(cache.get(6).is_none());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut cache = Cache::new(8);
+
+        cache.insert(0, b"nood".to_vec());
+        cache.insert(8, b"les".to_vec());
+        assert_eq!(cache.len, 7);
+
+        cache.insert(16, b"bgzf".to_vec());
+        assert_eq!(cache.len, 7);
+        assert!(cache.get(0).is_none());
+        assert_eq!(cache.get(8), Some(&b"les"[..]));
+
+        cache.insert(32, vec![0; 16]);
+        assert_eq!(cache.len, 16);
+        assert_eq!(cache.lru.values().copied().collect::<Vec<_>>(), [32]);
+    }
+
+    #[test]
+    fn test_insert_evicts_least_recently_used() {
+        let mut cache = Cache::new(8);
+
+        cache.insert(0, b"nood".to_vec());
+        cache.insert(8, b"les".to_vec());
+
+        // Marks the range at 0 as most recently used.
+        assert!(cache.get(1).is_some());
+
+        cache.insert(16, b"bgzf".to_vec());
+        assert_eq!(cache.get(0), Some(&b"nood"[..]));
+        assert!(cache.get(8).is_none());
+    }
+}
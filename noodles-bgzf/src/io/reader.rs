@@ -2,12 +2,18 @@
 
 mod builder;
 pub(crate) mod frame;
+pub(crate) mod salvage;
 
 pub use self::builder::Builder;
 
-use std::io::{self, BufRead, Read, Seek, SeekFrom};
+use std::{
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
 
-use super::Block;
+use self::salvage::Salvage;
+use super::{Block, BlockCache};
 use crate::{BGZF_MAX_ISIZE, VirtualPosition, gzi};
 
 /// A BGZF reader.
@@ -32,6 +38,11 @@ pub struct Reader<R> {
     buf: Vec<u8>,
     position: u64,
     block: Block,
+    salvage: Option<Salvage>,
+    block_cache: Option<Arc<BlockCache>>,
+    // The compressed length of blocks read from the block cache that are not yet read from the
+    // underlying reader.
+    cached_len: u64,
 }
 
 impl<R> Reader<R> {
@@ -92,7 +103,7 @@ where
     /// let reader = bgzf::io::Reader::new(io::empty());
     /// ```
     pub fn new(inner: R) -> Self {
-        Builder.build_from_reader(inner)
+        Builder::default().build_from_reader(inner)
     }
 
     /// Returns the current position of the stream.
@@ -123,12 +134,61 @@ where
         self.block.virtual_position()
     }
 
+    /// Returns the compressed ranges that were skipped in salvage mode.
+    ///
+    /// This is always empty when salvage mode is disabled. See [`Builder::set_salvage`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::reader::Builder::default()
+    ///     .set_salvage(true)
+    ///     .build_from_reader(io::empty());
+    /// assert!(reader.skipped_ranges().is_empty());
+    /// ```
+    pub fn skipped_ranges(&self) -> &[Range<u64>] {
+        self.salvage
+            .as_ref()
+            .map(|salvage| salvage.skipped_ranges())
+            .unwrap_or_default()
+    }
+
+    /// Returns the last uncompressed byte of the most recently skipped data in salvage mode, if
+    /// known.
+    ///
+    /// This is only known when the last skipped block is complete and can be inflated, e.g., when
+    /// only its checksum is invalid. Since the block is invalid, the byte is unverified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::io::reader::Builder::default()
+    ///     .set_salvage(true)
+    ///     .build_from_reader(io::empty());
+    /// assert!(reader.last_skipped_byte().is_none());
+    /// ```
+    pub fn last_skipped_byte(&self) -> Option<u8> {
+        self.salvage
+            .as_ref()
+            .and_then(|salvage| salvage.last_skipped_byte())
+    }
+
     fn read_nonempty_block_with<F>(&mut self, mut f: F) -> io::Result<usize>
     where
         F: FnMut(&[u8], &mut Block) -> io::Result<()>,
     {
         use self::frame::read_frame_into;
 
+        if self.salvage.is_some() {
+            return self.read_nonempty_block_salvaged();
+        } else if self.block_cache.is_some() {
+            return self.read_nonempty_block_cached();
+        }
+
         while read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
             f(&self.buf, &mut self.block)?;
 
@@ -143,6 +203,116 @@ where
         Ok(self.block.data().len())
     }
 
+    fn read_nonempty_block_cached(&mut self) -> io::Result<usize> {
+        use self::frame::{parse_block, read_frame_into};
+
+        // SAFETY: This is only called with a block cache.
+        let block_cache = self.block_cache.as_ref().unwrap();
+
+        loop {
+            if block_cache.get_into(self.position, &mut self.block) {
+                self.cached_len += self.block.size();
+            } else {
+                if self.cached_len > 0 {
+                    discard(&mut self.inner, self.cached_len)?;
+                    self.cached_len = 0;
+                }
+
+                if read_frame_into(&mut self.inner, &mut self.buf)?.is_none() {
+                    break;
+                }
+
+                parse_block(&self.buf, &mut self.block)?;
+                block_cache.insert(self.position, &self.block);
+            }
+
+            self.block.set_position(self.position);
+            self.position += self.block.size();
+
+            if self.block.data().len() > 0 {
+                break;
+            }
+        }
+
+        Ok(self.block.data().len())
+    }
+
+    fn read_nonempty_block_salvaged(&mut self) -> io::Result<usize> {
+        use self::{
+            frame::{inflate_last_byte_unchecked, parse_block},
+            salvage::Frame,
+        };
+
+        // SAFETY: This is only called in salvage mode.
+        let salvage = self.salvage.as_mut().unwrap();
+
+        loop {
+            let frame = salvage.read_frame(&mut self.inner, &mut self.buf)?;
+
+            match frame {
+                Frame::Eof => break,
+                Frame::Complete if parse_block(&self.buf, &mut self.block).is_ok() => {
+                    self.block.set_position(self.position);
+                    self.position += self.block.size();
+
+                    if self.block.data().len() > 0 {
+                        break;
+                    }
+                }
+                Frame::Complete | Frame::Invalid | Frame::Truncated => {
+                    let start = self.position;
+
+                    // The last byte of the skipped data is only known when the skipped data ends
+                    // with a complete block.
+                    let mut last_byte = match frame {
+                        Frame::Complete => inflate_last_byte_unchecked(&self.buf),
+                        _ => None,
+                    };
+
+                    loop {
+                        let frame_len = self.buf.len();
+                        let len = salvage.resync(&mut self.inner, &mut self.buf)?;
+                        self.position += len as u64;
+
+                        if len != frame_len {
+                            last_byte = None;
+                        }
+
+                        match salvage.read_frame(&mut self.inner, &mut self.buf)? {
+                            Frame::Eof => {
+                                self.block.set_size(0);
+                                let data = self.block.data_mut();
+                                data.set_position(0);
+                                data.resize(0);
+                                break;
+                            }
+                            Frame::Complete if parse_block(&self.buf, &mut self.block).is_ok() => {
+                                break;
+                            }
+                            Frame::Complete => {
+                                last_byte = inflate_last_byte_unchecked(&self.buf);
+                            }
+                            Frame::Invalid | Frame::Truncated => last_byte = None,
+                        }
+                    }
+
+                    let end = self.position;
+                    salvage.push_skipped_range(start..end, last_byte);
+
+                    self.block.set_position(self.position);
+                    self.position += self.block.size();
+
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("skipped invalid BGZF data at compressed range {start}..{end}"),
+                    ));
+                }
+            }
+        }
+
+        Ok(self.block.data().len())
+    }
+
     fn read_block(&mut self) -> io::Result<usize> {
         use self::frame::parse_block;
         self.read_nonempty_block_with(parse_block)
@@ -177,6 +347,11 @@ where
 
         self.inner.seek(SeekFrom::Start(cpos))?;
         self.position = cpos;
+        self.cached_len = 0;
+
+        if let Some(salvage) = self.salvage.as_mut() {
+            salvage.clear();
+        }
 
         self.read_block()?;
 
@@ -218,7 +393,11 @@ where
         // If a new block is about to be read and the given buffer is guaranteed to be larger than
         // the next block, reading to the block buffer can be skipped. The uncompressed data is
         // decoded into the given buffer to avoid having to subsequently recopy it from the block.
-        if !self.block.data().has_remaining() && buf.len() >= BGZF_MAX_ISIZE {
+        if !self.block.data().has_remaining()
+            && buf.len() >= BGZF_MAX_ISIZE
+            && self.salvage.is_none()
+            && self.block_cache.is_none()
+        {
             self.read_block_into_buf(buf)
         } else {
             let mut src = self.fill_buf()?;
@@ -283,6 +462,19 @@ where
     }
 }
 
+pub(super) fn discard<R>(reader: &mut R, len: u64) -> io::Result<()>
+where
+    R: Read,
+{
+    let n = io::copy(&mut reader.take(len), &mut io::sink())?;
+
+    if n == len {
+        Ok(())
+    } else {
+        Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
+}
+
 pub(crate) fn default_read_exact<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<()>
 where
     R: Read,
@@ -407,4 +599,96 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_with_salvage() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::io::Writer;
+
+        fn read_salvaged(src: &[u8]) -> (Vec<u8>, usize, Vec<(u64, u64)>) {
+            let mut reader = Builder::default().set_salvage(true).build_from_reader(src);
+
+            let mut buf = Vec::new();
+            let mut error_count = 0;
+
+            loop {
+                match reader.read_to_end(&mut buf) {
+                    Ok(_) => break,
+                    Err(e) => {
+                        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                        error_count += 1;
+                    }
+                }
+            }
+
+            let skipped_ranges = reader
+                .skipped_ranges()
+                .iter()
+                .map(|range| (range.start, range.end))
+                .collect();
+
+            (buf, error_count, skipped_ranges)
+        }
+
+        let mut writer = Writer::new(Vec::new());
+
+        // The compressed block sizes depend on the deflate backend, so the block ends are taken
+        // from the writer.
+        let mut block_ends = Vec::new();
+
+        for chunk in [&b"noodles"[..], b"-", b"bgzf"] {
+            writer.write_all(chunk)?;
+            writer.flush()?;
+            block_ends.push(writer.get_ref().len());
+        }
+
+        let data = writer.finish()?;
+
+        // block 0 = b"noodles", block 1 = b"-", block 2 = b"bgzf"
+        let (block_0_end, block_1_end, block_2_end) = (block_ends[0], block_ends[1], block_ends[2]);
+
+        let (buf, error_count, skipped_ranges) = read_salvaged(&data);
+        assert_eq!(buf, b"noodles-bgzf");
+        assert_eq!(error_count, 0);
+        assert!(skipped_ranges.is_empty());
+
+        // checksum mismatch (CRC32 = block[-8..-4])
+        let mut src = data.clone();
+        src[block_1_end - 8] ^= 0xff;
+        let (buf, error_count, skipped_ranges) = read_salvaged(&src);
+        assert_eq!(buf, b"noodlesbgzf");
+        assert_eq!(error_count, 1);
+        assert_eq!(skipped_ranges, [(block_0_end as u64, block_1_end as u64)]);
+
+        // The data of a block with a checksum mismatch can still be inflated.
+        let mut reader = Builder::default()
+            .set_salvage(true)
+            .build_from_reader(&src[..]);
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end(&mut buf).is_err());
+        assert_eq!(reader.last_skipped_byte(), Some(b'-'));
+
+        // invalid header
+        let mut src = data.clone();
+        src[0] = 0x00;
+        let (buf, error_count, skipped_ranges) = read_salvaged(&src);
+        assert_eq!(buf, b"-bgzf");
+        assert_eq!(error_count, 1);
+        assert_eq!(skipped_ranges, [(0, block_0_end as u64)]);
+
+        // truncated
+        let truncated_end = block_1_end + (block_2_end - block_1_end) / 2;
+        let (buf, error_count, skipped_ranges) = read_salvaged(&data[..truncated_end]);
+        assert_eq!(buf, b"noodles-");
+        assert_eq!(error_count, 1);
+        assert_eq!(skipped_ranges, [(block_1_end as u64, truncated_end as u64)]);
+
+        // The default reader fails on the first invalid block.
+        let mut reader = Reader::new(&src[..]);
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end(&mut buf).is_err());
+
+        Ok(())
+    }
 }
@@ -2,16 +2,62 @@ use std::{
     fs::File,
     io::{self, Read},
     path::Path,
+    sync::Arc,
 };
 
-use super::Reader;
-use crate::io::Block;
+use super::{Reader, salvage::Salvage};
+use crate::io::{Block, BlockCache};
 
 /// A BGZF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    salvage: bool,
+    block_cache: Option<Arc<BlockCache>>,
+}
 
 impl Builder {
+    /// Sets whether to salvage data from corrupt input.
+    ///
+    /// By default, a reader fails on the first invalid block. In salvage mode, an invalid,
+    /// corrupt, or truncated block is instead skipped by resynchronizing on the next valid block
+    /// header. The read that encounters the invalid block returns an error of kind
+    /// [`io::ErrorKind::InvalidData`], and reading can be continued from the start of the next
+    /// valid block. The compressed ranges that were skipped are available from
+    /// [`Reader::skipped_ranges`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::io::reader::Builder::default().set_salvage(true);
+    /// ```
+    pub fn set_salvage(mut self, salvage: bool) -> Self {
+        self.salvage = salvage;
+        self
+    }
+
+    /// Sets a shared block cache.
+    ///
+    /// Blocks are read from the cache, when present, instead of being read and inflated. Since
+    /// the underlying reader is then not read, its position is only advanced lazily, i.e., when
+    /// an uncached block is read or the reader seeks.
+    ///
+    /// The block cache is not used in salvage mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block_cache = Arc::new(bgzf::io::BlockCache::new(1 << 20));
+    /// let builder = bgzf::io::reader::Builder::default().set_block_cache(block_cache);
+    /// ```
+    pub fn set_block_cache(mut self, block_cache: Arc<BlockCache>) -> Self {
+        self.block_cache = Some(block_cache);
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -48,6 +94,9 @@ impl Builder {
             buf: Vec::new(),
             position: 0,
             block: Block::default(),
+            salvage: self.salvage.then(Salvage::default),
+            block_cache: self.block_cache,
+            cached_len: 0,
         }
     }
 }
@@ -0,0 +1,133 @@
+use std::{
+    io::{self, Read},
+    mem,
+    ops::Range,
+};
+
+use super::frame::{HeaderBuf, MIN_FRAME_SIZE, is_valid_header};
+use crate::BGZF_HEADER_SIZE;
+
+const CHUNK_SIZE: u64 = 1 << 16;
+
+pub(crate) enum Frame {
+    Eof,
+    Complete,
+    Invalid,
+    Truncated,
+}
+
+/// The state of a reader in salvage mode.
+///
+/// Bytes that were read from the underlying reader while searching for the next block header but
+/// belong to a following frame are kept as pending input.
+#[derive(Debug, Default)]
+pub(crate) struct Salvage {
+    pending: Vec<u8>,
+    skipped_ranges: Vec<Range<u64>>,
+    last_skipped_byte: Option<u8>,
+}
+
+impl Salvage {
+    pub fn skipped_ranges(&self) -> &[Range<u64>] {
+        &self.skipped_ranges
+    }
+
+    pub fn last_skipped_byte(&self) -> Option<u8> {
+        self.last_skipped_byte
+    }
+
+    pub fn push_skipped_range(&mut self, range: Range<u64>, last_byte: Option<u8>) {
+        self.skipped_ranges.push(range);
+        self.last_skipped_byte = last_byte;
+    }
+
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Reads a frame into `buf`.
+    ///
+    /// Unlike `frame::read_frame_into`, an incomplete or malformed frame is not an error. `buf`
+    /// then holds the bytes that were read.
+    pub fn read_frame<R>(&mut self, reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Frame>
+    where
+        R: Read,
+    {
+        buf.clear();
+
+        if !self.fill(reader, buf, BGZF_HEADER_SIZE)? {
+            return if buf.is_empty() {
+                Ok(Frame::Eof)
+            } else {
+                Ok(Frame::Truncated)
+            };
+        }
+
+        let Some(block_size) = frame_size(&buf[..BGZF_HEADER_SIZE]) else {
+            return Ok(Frame::Invalid);
+        };
+
+        if self.fill(reader, buf, block_size)? {
+            Ok(Frame::Complete)
+        } else {
+            Ok(Frame::Truncated)
+        }
+    }
+
+    /// Discards the start of an invalid frame and searches for the next valid block header.
+    ///
+    /// `buf` holds the bytes of the invalid frame. This returns the number of bytes skipped. If
+    /// no header is found, all remaining input is skipped.
+    pub fn resync<R>(&mut self, reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+    where
+        R: Read,
+    {
+        let mut data = mem::take(buf);
+        data.append(&mut self.pending);
+
+        let mut i = 1;
+
+        loop {
+            while i + BGZF_HEADER_SIZE <= data.len() {
+                if frame_size(&data[i..i + BGZF_HEADER_SIZE]).is_some() {
+                    self.pending = data.split_off(i);
+                    return Ok(i);
+                }
+
+                i += 1;
+            }
+
+            if reader.take(CHUNK_SIZE).read_to_end(&mut data)? == 0 {
+                return Ok(data.len());
+            }
+        }
+    }
+
+    fn fill<R>(&mut self, reader: &mut R, buf: &mut Vec<u8>, len: usize) -> io::Result<bool>
+    where
+        R: Read,
+    {
+        let n = (len - buf.len()).min(self.pending.len());
+        buf.extend(self.pending.drain(..n));
+
+        if buf.len() < len {
+            let m = (len - buf.len()) as u64;
+            reader.take(m).read_to_end(buf)?;
+        }
+
+        Ok(buf.len() == len)
+    }
+}
+
+fn frame_size(src: &[u8]) -> Option<usize> {
+    let header: &HeaderBuf = src.try_into().ok()?;
+
+    if !is_valid_header(header) {
+        return None;
+    }
+
+    let bsize = header.last_chunk().map(|b| u16::from_le_bytes(*b))?;
+    let block_size = usize::from(bsize) + 1;
+
+    (block_size >= MIN_FRAME_SIZE).then_some(block_size)
+}
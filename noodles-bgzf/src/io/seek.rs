@@ -1,4 +1,7 @@
-use std::io::{self, SeekFrom};
+use std::{
+    io::{self, SeekFrom},
+    ops::Range,
+};
 
 use crate::{VirtualPosition, gzi};
 
@@ -9,4 +12,17 @@ pub trait Seek {
 
     /// Seeks the stream to the given position using an index.
     fn seek_with_index(&mut self, index: &gzi::Index, pos: SeekFrom) -> io::Result<u64>;
+
+    /// Hints the ranges of virtual positions that are about to be read.
+    ///
+    /// This is typically called with the chunks of an indexed query before seeking to each of
+    /// them. Readers may use the hint to read and inflate the blocks in these ranges ahead of
+    /// consumption, e.g., [`super::MultithreadedReader`] inflates them in parallel.
+    ///
+    /// Empty ranges clear the hint, e.g., when the query ends.
+    ///
+    /// The default implementation does nothing.
+    fn prefetch(&mut self, _ranges: &[Range<VirtualPosition>]) -> io::Result<()> {
+        Ok(())
+    }
 }
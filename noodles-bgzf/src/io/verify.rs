@@ -0,0 +1,361 @@
+//! BGZF block verification.
+
+use std::{
+    io::{self, Read},
+    num::NonZeroUsize,
+    thread,
+};
+
+use flate2::Crc;
+
+use super::{
+    reader::{
+        frame::{TrailerBuf, parse_trailer},
+        salvage::{Frame, Salvage},
+    },
+    writer::BGZF_EOF,
+};
+use crate::{BGZF_HEADER_SIZE, BGZF_MAX_ISIZE, gz};
+
+/// A kind of invalid block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidBlockKind {
+    /// The block header is invalid.
+    ///
+    /// The data following an invalid header is skipped up to the next valid block header.
+    InvalidHeader,
+    /// The block is incomplete, i.e., the input ends within the block.
+    Truncated,
+    /// The compressed data cannot be inflated.
+    InvalidData,
+    /// The checksum (`CRC32`) of the uncompressed data does not match.
+    ChecksumMismatch,
+    /// The size of the uncompressed data does not match `ISIZE`.
+    SizeMismatch,
+}
+
+/// An invalid block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidBlock {
+    position: u64,
+    kind: InvalidBlockKind,
+}
+
+impl InvalidBlock {
+    /// Returns the compressed position of the start of the block.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the kind of invalid block.
+    pub fn kind(&self) -> InvalidBlockKind {
+        self.kind
+    }
+}
+
+/// A BGZF verification report.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    block_count: u64,
+    invalid_blocks: Vec<InvalidBlock>,
+    has_eof_block: bool,
+}
+
+impl Report {
+    /// Returns the number of blocks read, including invalid blocks.
+    pub fn block_count(&self) -> u64 {
+        self.block_count
+    }
+
+    /// Returns the invalid blocks, sorted by position.
+    pub fn invalid_blocks(&self) -> &[InvalidBlock] {
+        &self.invalid_blocks
+    }
+
+    /// Returns whether the input ends with a BGZF EOF block.
+    pub fn has_eof_block(&self) -> bool {
+        self.has_eof_block
+    }
+
+    /// Returns whether the input ends within a block.
+    pub fn is_truncated(&self) -> bool {
+        self.invalid_blocks
+            .last()
+            .is_some_and(|block| block.kind == InvalidBlockKind::Truncated)
+    }
+
+    /// Returns whether all blocks are valid and the input ends with an EOF block.
+    pub fn is_ok(&self) -> bool {
+        self.invalid_blocks.is_empty() && self.has_eof_block
+    }
+}
+
+/// Verifies the integrity of all blocks in a BGZF stream.
+///
+/// Each block is checked for a valid header, that its compressed data inflates, and that the
+/// uncompressed data matches the checksum (`CRC32`) and size (`ISIZE`) in its trailer. Blocks are
+/// inflated in parallel using the given number of workers.
+///
+/// When a block header is invalid, the stream is resynchronized on the next valid block header.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{io::{self, Write}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::io::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let report = bgzf::io::verify(&data[..], NonZeroUsize::MIN)?;
+/// assert_eq!(report.block_count(), 2);
+/// assert!(report.is_ok());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn verify<R>(mut reader: R, worker_count: NonZeroUsize) -> io::Result<Report>
+where
+    R: Read,
+{
+    let worker_count = worker_count.get();
+
+    let (block_tx, block_rx) = crossbeam_channel::bounded::<(u64, Vec<u8>)>(worker_count);
+    let (result_tx, result_rx) = crossbeam_channel::unbounded();
+    let (recycle_tx, recycle_rx) = crossbeam_channel::unbounded();
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count {
+            let block_rx = block_rx.clone();
+            let result_tx = result_tx.clone();
+            let recycle_tx = recycle_tx.clone();
+
+            scope.spawn(move || {
+                let mut dst = Vec::with_capacity(BGZF_MAX_ISIZE);
+
+                while let Ok((position, src)) = block_rx.recv() {
+                    if let Some(kind) = check_block(&src, &mut dst) {
+                        result_tx.send(InvalidBlock { position, kind }).ok();
+                    }
+
+                    recycle_tx.send(src).ok();
+                }
+            });
+        }
+
+        drop(block_rx);
+        drop(result_tx);
+        drop(recycle_tx);
+
+        let mut report = Report::default();
+        let mut salvage = Salvage::default();
+        let mut position = 0;
+        let mut buf = Vec::new();
+
+        loop {
+            let frame = salvage.read_frame(&mut reader, &mut buf)?;
+
+            let kind = match frame {
+                Frame::Eof => break,
+                Frame::Complete => {
+                    report.block_count += 1;
+                    report.has_eof_block = buf == BGZF_EOF;
+
+                    let len = buf.len() as u64;
+                    let next_buf = recycle_rx.try_recv().unwrap_or_default();
+                    let src = std::mem::replace(&mut buf, next_buf);
+
+                    if block_tx.send((position, src)).is_err() {
+                        return Err(io::Error::other("verification worker failed"));
+                    }
+
+                    position += len;
+
+                    continue;
+                }
+                Frame::Invalid => InvalidBlockKind::InvalidHeader,
+                Frame::Truncated => InvalidBlockKind::Truncated,
+            };
+
+            report.block_count += 1;
+            report.has_eof_block = false;
+            report.invalid_blocks.push(InvalidBlock { position, kind });
+
+            let len = salvage.resync(&mut reader, &mut buf)?;
+            position += len as u64;
+        }
+
+        drop(block_tx);
+
+        report.invalid_blocks.extend(result_rx.iter());
+        report.invalid_blocks.sort_by_key(|block| block.position);
+
+        Ok(report)
+    })
+}
+
+fn check_block(src: &[u8], dst: &mut Vec<u8>) -> Option<InvalidBlockKind> {
+    use flate2::read::DeflateDecoder;
+
+    let end = src.len() - gz::TRAILER_SIZE;
+    let cdata = &src[BGZF_HEADER_SIZE..end];
+
+    // SAFETY: `src.len() >= gz::TRAILER_SIZE`.
+    let trailer: &TrailerBuf = src.last_chunk().unwrap();
+
+    let Ok((crc32, isize)) = parse_trailer(trailer) else {
+        return Some(InvalidBlockKind::SizeMismatch);
+    };
+
+    dst.clear();
+
+    let mut decoder = DeflateDecoder::new(cdata).take(BGZF_MAX_ISIZE as u64 + 1);
+
+    if decoder.read_to_end(dst).is_err() {
+        return Some(InvalidBlockKind::InvalidData);
+    }
+
+    if dst.len() != isize {
+        return Some(InvalidBlockKind::SizeMismatch);
+    }
+
+    let mut crc = Crc::new();
+    crc.update(dst);
+
+    if crc.sum() != crc32 {
+        return Some(InvalidBlockKind::ChecksumMismatch);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::io::Writer;
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+
+        for chunk in [&b"noodles"[..], b"-", b"bgzf"] {
+            writer.write_all(chunk)?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    fn block_positions(data: &[u8]) -> Vec<usize> {
+        let mut positions = Vec::new();
+        let mut i = 0;
+
+        while i < data.len() {
+            positions.push(i);
+            let bsize = u16::from_le_bytes([data[i + 16], data[i + 17]]);
+            i += usize::from(bsize) + 1;
+        }
+
+        positions
+    }
+
+    #[test]
+    fn test_verify() -> io::Result<()> {
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+
+        let data = build_data()?;
+        let report = verify(&data[..], worker_count)?;
+        assert_eq!(report.block_count(), 4);
+        assert!(report.invalid_blocks().is_empty());
+        assert!(report.has_eof_block());
+        assert!(!report.is_truncated());
+        assert!(report.is_ok());
+
+        let report = verify(io::empty(), worker_count)?;
+        assert_eq!(report, Report::default());
+        assert!(!report.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_invalid_blocks() -> io::Result<()> {
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+
+        let data = build_data()?;
+        let positions = block_positions(&data);
+
+        // CRC32
+        let mut src = data.clone();
+        let i = positions[2] - gz::TRAILER_SIZE;
+        src[i] ^= 0xff;
+        let report = verify(&src[..], worker_count)?;
+        assert_eq!(
+            report.invalid_blocks(),
+            [InvalidBlock {
+                position: positions[1] as u64,
+                kind: InvalidBlockKind::ChecksumMismatch,
+            }]
+        );
+        assert!(report.has_eof_block());
+
+        // ISIZE
+        let mut src = data.clone();
+        let i = positions[1] - 4;
+        src[i] += 1;
+        let report = verify(&src[..], worker_count)?;
+        assert_eq!(
+            report.invalid_blocks(),
+            [InvalidBlock {
+                position: 0,
+                kind: InvalidBlockKind::SizeMismatch,
+            }]
+        );
+
+        // header
+        let mut src = data.clone();
+        src[positions[1]] = 0x00;
+        let report = verify(&src[..], worker_count)?;
+        assert_eq!(report.block_count(), 4);
+        assert_eq!(
+            report.invalid_blocks(),
+            [InvalidBlock {
+                position: positions[1] as u64,
+                kind: InvalidBlockKind::InvalidHeader,
+            }]
+        );
+        assert!(report.has_eof_block());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_truncated_input() -> io::Result<()> {
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+
+        let data = build_data()?;
+        let positions = block_positions(&data);
+
+        // missing EOF block
+        let report = verify(&data[..positions[3]], worker_count)?;
+        assert_eq!(report.block_count(), 3);
+        assert!(report.invalid_blocks().is_empty());
+        assert!(!report.has_eof_block());
+        assert!(!report.is_truncated());
+
+        let report = verify(&data[..positions[2] + 8], worker_count)?;
+        assert_eq!(report.block_count(), 3);
+        assert_eq!(
+            report.invalid_blocks(),
+            [InvalidBlock {
+                position: positions[2] as u64,
+                kind: InvalidBlockKind::Truncated,
+            }]
+        );
+        assert!(!report.has_eof_block());
+        assert!(report.is_truncated());
+
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 //! Genomic region.
 
+mod aliases;
 pub mod interval;
+mod set;
 
 use bstr::{BStr, BString};
 
-pub use self::interval::Interval;
+pub use self::{aliases::Aliases, interval::Interval, set::RegionSet};
 
 use std::{
     error, fmt,
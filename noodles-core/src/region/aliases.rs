@@ -0,0 +1,273 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    io::{self, BufRead},
+};
+
+use bstr::{BString, ByteSlice};
+
+use super::Region;
+
+/// Reference sequence name aliases.
+///
+/// Aliases are groups of names that refer to the same reference sequence, e.g., `chr1`, `1`, and
+/// `NC_000001.11`. They are used to resolve a name to the name used by a particular file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Aliases {
+    groups: Vec<Vec<BString>>,
+    indices: HashMap<BString, usize>,
+}
+
+impl Aliases {
+    /// Reads aliases from a tab-separated file.
+    ///
+    /// Each line is a group of names that refer to the same reference sequence. Empty fields are
+    /// ignored, as are empty lines and lines starting with `#`. This reads both simple alias
+    /// tables (e.g., `chr1\t1`) and the UCSC chromAlias format, which has a header line of naming
+    /// authorities (e.g., `# ucsc\tassembly\tensembl\tgenbank\trefseq`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let data = b"# ucsc\tensembl\trefseq\nchr1\t1\tNC_000001.11\nchrM\tMT\t\n";
+    /// let aliases = Aliases::read(&data[..])?;
+    ///
+    /// assert_eq!(aliases.len(), 2);
+    /// assert_eq!(aliases.get(b"1").map(|names| names.len()), Some(3));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read<R>(reader: R) -> io::Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut aliases = Self::default();
+
+        for result in reader.split(b'\n') {
+            let line = result?;
+            let line = line.strip_suffix(b"\r").unwrap_or(&line);
+
+            if line.is_empty() || line.starts_with(b"#") {
+                continue;
+            }
+
+            let names = line
+                .split(|&b| b == b'\t')
+                .map(|name| name.trim_ascii())
+                .filter(|name| !name.is_empty());
+
+            aliases.insert(names);
+        }
+
+        Ok(aliases)
+    }
+
+    /// Returns the number of alias groups.
+    pub fn len(&self) -> usize {
+        self.groups.iter().filter(|group| !group.is_empty()).count()
+    }
+
+    /// Returns whether there are any alias groups.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a group of names that refer to the same reference sequence.
+    ///
+    /// If any of the names are already in a group, the groups are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    /// aliases.insert(["1", "NC_000001.11"]);
+    ///
+    /// assert_eq!(aliases.len(), 1);
+    /// assert_eq!(aliases.get(b"chr1").map(|names| names.len()), Some(3));
+    /// ```
+    pub fn insert<I, N>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<[u8]>,
+    {
+        let names: Vec<BString> = names
+            .into_iter()
+            .map(|name| BString::from(name.as_ref()))
+            .collect();
+
+        let mut i = None;
+
+        for name in &names {
+            let Some(&j) = self.indices.get(name) else {
+                continue;
+            };
+
+            match i {
+                None => i = Some(j),
+                Some(i) if i != j => {
+                    let group = std::mem::take(&mut self.groups[j]);
+
+                    for name in group {
+                        self.indices.insert(name.clone(), i);
+                        self.groups[i].push(name);
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        let i = i.unwrap_or_else(|| {
+            self.groups.push(Vec::new());
+            self.groups.len() - 1
+        });
+
+        for name in names {
+            if !self.indices.contains_key(&name) {
+                self.indices.insert(name.clone(), i);
+                self.groups[i].push(name);
+            }
+        }
+    }
+
+    /// Returns the group of names that includes the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// assert_eq!(aliases.get(b"1"), Some(&["chr1".into(), "1".into()][..]));
+    /// assert!(aliases.get(b"2").is_none());
+    /// ```
+    pub fn get(&self, name: &[u8]) -> Option<&[BString]> {
+        self.indices
+            .get(name.as_bstr())
+            .map(|&i| self.groups[i].as_slice())
+    }
+
+    /// Resolves a name to a name accepted by the given predicate.
+    ///
+    /// If the predicate accepts the name itself, the name is returned. Otherwise, this returns
+    /// the first alias of the name that the predicate accepts, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let names = ["1", "2"];
+    /// let is_valid = |name: &[u8]| names.iter().any(|n| n.as_bytes() == name);
+    ///
+    /// assert_eq!(aliases.resolve(b"chr1", is_valid), Some(&b"1"[..]));
+    /// assert_eq!(aliases.resolve(b"2", is_valid), Some(&b"2"[..]));
+    /// assert!(aliases.resolve(b"chr2", is_valid).is_none());
+    /// ```
+    pub fn resolve<'a, F>(&'a self, name: &'a [u8], mut f: F) -> Option<&'a [u8]>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        if f(name) {
+            return Some(name);
+        }
+
+        self.get(name)?
+            .iter()
+            .map(|alias| alias.as_bytes())
+            .find(|alias| f(alias))
+    }
+
+    /// Resolves the name of a region to a name accepted by the given predicate.
+    ///
+    /// If the name of the region cannot be resolved, the region is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Aliases, Region};
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let region: Region = "chr1:8-13".parse()?;
+    /// let actual = aliases.resolve_region(&region, |name| name == b"1");
+    ///
+    /// assert_eq!(actual.name(), "1");
+    /// assert_eq!(actual.interval(), region.interval());
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn resolve_region<'r, F>(&self, region: &'r Region, f: F) -> Cow<'r, Region>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        match self.resolve(region.name(), f) {
+            Some(name) if name != region.name() => Cow::Owned(Region::new(name, region.interval())),
+            _ => Cow::Borrowed(region),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data = b"\
+# ucsc\tensembl\trefseq
+chr1\t1\tNC_000001.11\r
+
+chrM\tMT\t
+";
+
+        let aliases = Aliases::read(&data[..])?;
+
+        assert_eq!(aliases.len(), 2);
+
+        let expected: [BString; 3] = ["chr1".into(), "1".into(), "NC_000001.11".into()];
+        assert_eq!(aliases.get(b"NC_000001.11"), Some(&expected[..]));
+
+        let expected: [BString; 2] = ["chrM".into(), "MT".into()];
+        assert_eq!(aliases.get(b"MT"), Some(&expected[..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut aliases = Aliases::default();
+        aliases.insert(["chr1", "1"]);
+        aliases.insert(["chr2", "2"]);
+        assert_eq!(aliases.len(), 2);
+
+        aliases.insert(["1", "2"]);
+        assert_eq!(aliases.len(), 1);
+
+        let expected: [BString; 4] = ["chr1".into(), "1".into(), "chr2".into(), "2".into()];
+        assert_eq!(aliases.get(b"2"), Some(&expected[..]));
+        assert_eq!(aliases.get(b"chr1"), Some(&expected[..]));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut aliases = Aliases::default();
+        aliases.insert(["chr1", "1", "NC_000001.11"]);
+
+        let is_valid = |name: &[u8]| name == b"NC_000001.11";
+        assert_eq!(aliases.resolve(b"1", is_valid), Some(&b"NC_000001.11"[..]));
+        assert_eq!(
+            aliases.resolve(b"NC_000001.11", is_valid),
+            Some(&b"NC_000001.11"[..])
+        );
+        assert!(aliases.resolve(b"chr2", is_valid).is_none());
+    }
+}
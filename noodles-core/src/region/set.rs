@@ -0,0 +1,290 @@
+use super::Interval;
+use crate::Position;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Entry {
+    reference_sequence_id: usize,
+    start: Position,
+    // `None` is unbounded.
+    end: Option<Position>,
+    index: usize,
+}
+
+/// A set of regions resolved to reference sequence IDs.
+///
+/// A region set is used to query many regions at once. Regions are sorted by reference sequence
+/// and start position, and overlapping or adjacent regions are merged when querying an index
+/// ([`Self::merged_intervals`]). Each record that is read can then be tagged with the indices of
+/// the original regions it overlaps ([`Self::overlapping`]).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegionSet {
+    // Sorted by reference sequence ID and start position.
+    entries: Vec<Entry>,
+    // The maximum end of each entry and the entries before it on the same reference sequence.
+    max_ends: Vec<Option<Position>>,
+}
+
+impl RegionSet {
+    /// Creates a region set.
+    ///
+    /// Each region is a reference sequence ID and an interval. Regions are identified by their
+    /// index in the given list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, region::RegionSet};
+    ///
+    /// let regions = RegionSet::new([
+    ///     (0, (Position::try_from(8)?..=Position::try_from(13)?).into()),
+    ///     (1, (..).into()),
+    /// ]);
+    ///
+    /// assert_eq!(regions.len(), 2);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new<I>(regions: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, Interval)>,
+    {
+        let mut entries: Vec<_> = regions
+            .into_iter()
+            .enumerate()
+            .map(|(index, (reference_sequence_id, interval))| Entry {
+                reference_sequence_id,
+                start: interval.start().unwrap_or(Position::MIN),
+                end: interval.end(),
+                index,
+            })
+            .collect();
+
+        entries.sort_by_key(|entry| (entry.reference_sequence_id, entry.start, entry.index));
+
+        let mut max_ends = Vec::with_capacity(entries.len());
+        let mut prev_entry: Option<&Entry> = None;
+
+        for entry in &entries {
+            let max_end = match (prev_entry, max_ends.last()) {
+                (Some(prev), Some(&max_end))
+                    if prev.reference_sequence_id == entry.reference_sequence_id =>
+                {
+                    max_position(max_end, entry.end)
+                }
+                _ => entry.end,
+            };
+
+            max_ends.push(max_end);
+            prev_entry = Some(entry);
+        }
+
+        Self { entries, max_ends }
+    }
+
+    /// Returns the number of regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    /// let regions = RegionSet::default();
+    /// assert_eq!(regions.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are any regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::RegionSet;
+    /// let regions = RegionSet::default();
+    /// assert!(regions.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the merged intervals of the regions.
+    ///
+    /// Overlapping and adjacent intervals on the same reference sequence are merged. The result
+    /// is sorted by reference sequence ID and start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, region::{Interval, RegionSet}};
+    ///
+    /// let interval = |start, end| -> Result<Interval, noodles_core::position::TryFromIntError> {
+    ///     Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+    /// };
+    ///
+    /// let regions = RegionSet::new([
+    ///     (0, interval(21, 34)?),
+    ///     (0, interval(8, 13)?),
+    ///     (0, interval(14, 21)?),
+    ///     (1, interval(5, 8)?),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     regions.merged_intervals(),
+    ///     [(0, interval(8, 34)?), (1, interval(5, 8)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn merged_intervals(&self) -> Vec<(usize, Interval)> {
+        let mut intervals: Vec<(usize, Position, Option<Position>)> = Vec::new();
+
+        for entry in &self.entries {
+            if let Some((reference_sequence_id, _, end)) = intervals.last_mut() {
+                let is_mergeable = *reference_sequence_id == entry.reference_sequence_id
+                    && end.is_none_or(|end| {
+                        end.checked_add(1)
+                            .is_none_or(|next_start| entry.start <= next_start)
+                    });
+
+                if is_mergeable {
+                    *end = max_position(*end, entry.end);
+                    continue;
+                }
+            }
+
+            intervals.push((entry.reference_sequence_id, entry.start, entry.end));
+        }
+
+        intervals
+            .into_iter()
+            .map(|(reference_sequence_id, start, end)| {
+                let interval = match end {
+                    Some(end) => Interval::from(start..=end),
+                    None => Interval::from(start..),
+                };
+
+                (reference_sequence_id, interval)
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the regions that overlap the given interval.
+    ///
+    /// The indices are in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, region::{Interval, RegionSet}};
+    ///
+    /// let interval = |start, end| -> Result<Interval, noodles_core::position::TryFromIntError> {
+    ///     Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+    /// };
+    ///
+    /// let regions = RegionSet::new([
+    ///     (0, interval(21, 34)?),
+    ///     (0, interval(8, 13)?),
+    ///     (1, interval(5, 8)?),
+    /// ]);
+    ///
+    /// assert_eq!(regions.overlapping(0, interval(13, 21)?), [0, 1]);
+    /// assert_eq!(regions.overlapping(0, interval(14, 20)?), []);
+    /// assert_eq!(regions.overlapping(1, interval(1, 5)?), [2]);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn overlapping(&self, reference_sequence_id: usize, interval: Interval) -> Vec<usize> {
+        let start = interval.start().unwrap_or(Position::MIN);
+
+        let lo = self
+            .entries
+            .partition_point(|entry| entry.reference_sequence_id < reference_sequence_id);
+
+        let hi = self.entries.partition_point(|entry| {
+            entry.reference_sequence_id < reference_sequence_id
+                || (entry.reference_sequence_id == reference_sequence_id
+                    && interval.end().is_none_or(|end| entry.start <= end))
+        });
+
+        let mut indices = Vec::new();
+
+        // Entries are sorted by start position, so the search can stop once no entry before the
+        // current one ends at or after the start of the interval.
+        for i in (lo..hi).rev() {
+            if self.max_ends[i].is_some_and(|max_end| max_end < start) {
+                break;
+            }
+
+            let entry = &self.entries[i];
+
+            if entry.end.is_none_or(|end| end >= start) {
+                indices.push(entry.index);
+            }
+        }
+
+        indices.sort_unstable();
+
+        indices
+    }
+}
+
+fn max_position(a: Option<Position>, b: Option<Position>) -> Option<Position> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: usize, end: usize) -> Result<Interval, crate::position::TryFromIntError> {
+        Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+    }
+
+    #[test]
+    fn test_merged_intervals() -> Result<(), crate::position::TryFromIntError> {
+        let regions = RegionSet::new([
+            (1, interval(55, 89)?),
+            (0, interval(8, 13)?),
+            (0, interval(21, 34)?),
+            (0, (Position::try_from(30)?..).into()),
+            (1, (..=Position::try_from(5)?).into()),
+            (0, interval(89, 144)?),
+            (2, (..).into()),
+        ]);
+
+        let expected = [
+            (0, interval(8, 13)?),
+            (0, (Position::try_from(21)?..).into()),
+            (1, interval(1, 5)?),
+            (1, interval(55, 89)?),
+            (2, (Position::MIN..).into()),
+        ];
+
+        assert_eq!(regions.merged_intervals(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlapping() -> Result<(), crate::position::TryFromIntError> {
+        let regions = RegionSet::new([
+            (0, interval(8, 100)?),
+            (0, interval(13, 21)?),
+            (0, interval(34, 55)?),
+            (0, interval(89, 144)?),
+            (1, (..).into()),
+        ]);
+
+        assert_eq!(regions.overlapping(0, interval(1, 5)?), []);
+        assert_eq!(regions.overlapping(0, interval(1, 8)?), [0]);
+        assert_eq!(regions.overlapping(0, interval(22, 33)?), [0]);
+        assert_eq!(regions.overlapping(0, interval(20, 34)?), [0, 1, 2]);
+        assert_eq!(regions.overlapping(0, interval(101, 200)?), [3]);
+        assert_eq!(regions.overlapping(0, interval(145, 200)?), []);
+        assert_eq!(regions.overlapping(0, (..).into()), [0, 1, 2, 3]);
+        assert_eq!(regions.overlapping(1, interval(1000, 2000)?), [4]);
+        assert_eq!(regions.overlapping(2, interval(1, 5)?), []);
+
+        Ok(())
+    }
+}
@@ -16,12 +16,16 @@ use tokio::io::{self, AsyncRead, AsyncSeek, AsyncSeekExt, SeekFrom};
 
 pub use self::builder::Builder;
 use self::{container::read_container, crc_reader::CrcReader, header::read_header};
-use crate::{FileDefinition, crai, io::reader::Container};
+use crate::{
+    FileDefinition, crai,
+    io::reader::{Container, Fields},
+};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    fields: Fields,
 }
 
 impl<R> Reader<R> {
@@ -7,11 +7,13 @@ use tokio::{
 };
 
 use super::Reader;
+use crate::io::reader::Fields;
 
 /// An async CRAM reader builder.
 #[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    fields: Fields,
 }
 
 impl Builder {
@@ -36,6 +38,23 @@ impl Builder {
         self
     }
 
+    /// Sets the fields to decode.
+    ///
+    /// By default, all fields are decoded. Data series only used by fields that are not in the
+    /// projection are skipped, and their external blocks are not decompressed. When the sequence
+    /// is not requested, the reference sequence is not fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{io::reader::Fields, r#async::io::reader::Builder};
+    /// let builder = Builder::default().set_fields(Fields::empty());
+    /// ```
+    pub fn set_fields(mut self, fields: Fields) -> Self {
+        self.fields = fields;
+        self
+    }
+
     /// Builds an async CRAM reader from a path.
     ///
     /// # Examples
@@ -73,6 +92,7 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            fields: self.fields,
         }
     }
 }
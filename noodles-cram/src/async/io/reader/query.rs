@@ -6,7 +6,10 @@ use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{crai, io::reader::Container};
+use crate::{
+    crai,
+    io::reader::{Container, container::slice::Projection},
+};
 
 struct Context<'r, 'h: 'r, 'i: 'r, R> {
     reader: &'r mut Reader<R>,
@@ -97,20 +100,24 @@ where
         Err(e) => return Some(Err(e)),
     };
 
+    let projection = Projection::new(&compression_header, ctx.reader.fields);
+
     let records = container
         .slices()
         .map(|result| {
             let slice = result?;
 
-            let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+            let (core_data_src, external_data_srcs) =
+                slice.decode_blocks_with_projection(&projection)?;
 
             slice
-                .records(
+                .records_with_projection(
                     ctx.reader.reference_sequence_repository.clone(),
                     ctx.header,
                     &compression_header,
                     &core_data_src,
                     &external_data_srcs,
+                    &projection,
                 )
                 .and_then(|records| {
                     records
@@ -5,7 +5,7 @@ use noodles_sam as sam;
 use tokio::io::{self, AsyncRead};
 
 use super::Reader;
-use crate::io::reader::Container;
+use crate::io::reader::{Container, container::slice::Projection};
 
 struct Context<'r, 'h: 'r, R>
 where
@@ -62,21 +62,25 @@ where
         Err(e) => return Some(Err(e)),
     };
 
+    let projection = Projection::new(&compression_header, ctx.reader.fields);
+
     let records = ctx
         .container
         .slices()
         .map(|result| {
             let slice = result?;
 
-            let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+            let (core_data_src, external_data_srcs) =
+                slice.decode_blocks_with_projection(&projection)?;
 
             slice
-                .records(
+                .records_with_projection(
                     ctx.reader.reference_sequence_repository.clone(),
                     ctx.header,
                     &compression_header,
                     &core_data_src,
                     &external_data_srcs,
+                    &projection,
                 )
                 .and_then(|records| {
                     records
@@ -1,14 +1,21 @@
 //! CRAM block content codecs.
+//!
+//! The codecs can be used independently of the CRAM format, either with the whole-buffer
+//! `encode`/`decode` functions of each codec or with the chunked [`io::Reader`] and
+//! [`io::Writer`].
 
 pub mod aac;
 pub(crate) mod bzip2;
-pub(crate) mod fqzcomp;
+pub mod fqzcomp;
 pub(crate) mod gzip;
+pub mod io;
 pub(crate) mod lzma;
-pub(crate) mod name_tokenizer;
+pub mod name_tokenizer;
 pub mod rans_4x8;
 pub mod rans_nx16;
 
+use crate::container::block::CompressionMethod;
+
 /// A CRAM block content encoder.
 #[derive(Clone, Debug)]
 pub enum Encoder {
@@ -29,3 +36,113 @@ pub enum Encoder {
     /// fqzcomp
     Fqzcomp,
 }
+
+impl Encoder {
+    /// Encodes data.
+    ///
+    /// fqzcomp requires record lengths and is not supported here. Use [`fqzcomp::encode`]
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::codecs::{rans_nx16, Encoder};
+    /// let encoder = Encoder::RansNx16(rans_nx16::Flags::ORDER);
+    /// let compressed_data = encoder.encode(b"noodles")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn encode(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(compression_level) => gzip::encode(*compression_level, src),
+            Self::Bzip2(compression_level) => bzip2::encode(*compression_level, src),
+            Self::Lzma(compression_level) => lzma::encode(*compression_level, src),
+            Self::Rans4x8(order) => rans_4x8::encode(*order, src),
+            Self::RansNx16(flags) => rans_nx16::encode(*flags, src),
+            Self::AdaptiveArithmeticCoding(flags) => aac::encode(*flags, src),
+            Self::NameTokenizer => name_tokenizer::encode(src),
+            Self::Fqzcomp => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "fqzcomp encoding requires record lengths",
+            )),
+        }
+    }
+
+    /// Returns the compression method of the encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{codecs::Encoder, container::block::CompressionMethod};
+    /// let encoder = Encoder::NameTokenizer;
+    /// assert_eq!(encoder.compression_method(), CompressionMethod::NameTokenizer);
+    /// ```
+    pub fn compression_method(&self) -> CompressionMethod {
+        match self {
+            Self::Gzip(_) => CompressionMethod::Gzip,
+            Self::Bzip2(_) => CompressionMethod::Bzip2,
+            Self::Lzma(_) => CompressionMethod::Lzma,
+            Self::Rans4x8(_) => CompressionMethod::Rans4x8,
+            Self::RansNx16(_) => CompressionMethod::RansNx16,
+            Self::AdaptiveArithmeticCoding(_) => CompressionMethod::AdaptiveArithmeticCoding,
+            Self::NameTokenizer => CompressionMethod::NameTokenizer,
+            Self::Fqzcomp => CompressionMethod::Fqzcomp,
+        }
+    }
+}
+
+/// Decodes data compressed with the given compression method.
+///
+/// `uncompressed_size` is the size of the decoded data. It is required by the gzip, bzip2, and
+/// LZMA decoders, and by the rANS Nx16 and adaptive arithmetic decoders when the data was encoded
+/// without a size.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::{
+///     codecs::{self, Encoder},
+///     container::block::CompressionMethod,
+/// };
+///
+/// let encoder = Encoder::Gzip(Default::default());
+/// let compressed_data = encoder.encode(b"noodles")?;
+///
+/// let data = codecs::decode(CompressionMethod::Gzip, &compressed_data, 7)?;
+/// assert_eq!(data, b"noodles");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn decode(
+    compression_method: CompressionMethod,
+    src: &[u8],
+    uncompressed_size: usize,
+) -> std::io::Result<Vec<u8>> {
+    match compression_method {
+        CompressionMethod::None => Ok(src.to_vec()),
+        CompressionMethod::Gzip => {
+            let mut dst = vec![0; uncompressed_size];
+            gzip::decode(src, &mut dst)?;
+            Ok(dst)
+        }
+        CompressionMethod::Bzip2 => {
+            let mut dst = vec![0; uncompressed_size];
+            bzip2::decode(src, &mut dst)?;
+            Ok(dst)
+        }
+        CompressionMethod::Lzma => {
+            let mut dst = vec![0; uncompressed_size];
+            lzma::decode(src, &mut dst)?;
+            Ok(dst)
+        }
+        CompressionMethod::Rans4x8 => rans_4x8::decode(&mut &src[..]),
+        CompressionMethod::RansNx16 => rans_nx16::decode::decode_with_max_len(
+            &mut &src[..],
+            uncompressed_size,
+            uncompressed_size,
+        ),
+        CompressionMethod::AdaptiveArithmeticCoding => {
+            aac::decode::decode_with_max_len(&mut &src[..], uncompressed_size, uncompressed_size)
+        }
+        CompressionMethod::Fqzcomp => fqzcomp::decode(&mut &src[..]),
+        CompressionMethod::NameTokenizer => name_tokenizer::decode(&mut &src[..]),
+    }
+}
@@ -1,10 +1,12 @@
 //! Adaptive arithmetic coder.
 
-mod decode;
+pub(crate) mod decode;
 mod encode;
 mod flags;
 mod model;
+mod parameters;
 mod range_coder;
 
-pub use self::flags::Flags;
-pub(crate) use self::{decode::decode, encode::encode, model::Model, range_coder::RangeCoder};
+pub use self::{decode::decode, encode::encode};
+pub use self::{flags::Flags, parameters::Parameters};
+pub(crate) use self::{model::Model, range_coder::RangeCoder};
@@ -5,19 +5,46 @@ use byteorder::ReadBytesExt;
 use super::{Flags, Model, RangeCoder};
 use crate::io::reader::num::read_uint7;
 
-pub fn decode<R>(reader: &mut R, mut len: usize) -> io::Result<Vec<u8>>
+/// Decodes data encoded by the adaptive arithmetic coder.
+///
+/// `len` is the uncompressed size. It is only used when the stream was encoded with
+/// [`Flags::NO_SIZE`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::aac::{self, Flags};
+/// let compressed_data = aac::encode(Flags::empty(), b"noodles")?;
+/// let data = aac::decode(&mut &compressed_data[..], 7)?;
+/// assert_eq!(data, b"noodles");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn decode<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
-    use crate::codecs::rans_nx16::decode::{decode_pack_meta, pack};
+    decode_with_max_len(reader, len, usize::MAX)
+}
+
+// Decodes data encoded by the adaptive arithmetic coder with an uncompressed size of at most
+// `max_len`.
+pub(crate) fn decode_with_max_len<R>(
+    reader: &mut R,
+    mut len: usize,
+    max_len: usize,
+) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    use crate::codecs::rans_nx16::decode::{check_size, decode_pack_meta, pack, read_size};
 
     let flags = reader.read_u8().map(Flags::from)?;
 
-    if !flags.contains(Flags::NO_SIZE) {
-        len = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
-    }
+    len = if flags.contains(Flags::NO_SIZE) {
+        check_size(len, max_len)?
+    } else {
+        read_size(reader, max_len)?
+    };
 
     if flags.contains(Flags::STRIPE) {
         return decode_stripe(reader, len);
@@ -28,7 +55,7 @@ where
     let pack_len = len;
 
     if flags.contains(Flags::PACK) {
-        let (q, n, new_len) = decode_pack_meta(reader)?;
+        let (q, n, new_len) = decode_pack_meta(reader, len)?;
         p = Some(q);
         n_sym = Some(n);
         len = new_len;
@@ -86,7 +113,7 @@ where
             ulen += 1;
         }
 
-        let chunk = decode(reader, ulen)?;
+        let chunk = decode_with_max_len(reader, ulen, ulen)?;
 
         ulens.push(ulen);
         t.push(chunk);
@@ -339,4 +366,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_with_max_len() {
+        fn decode_invalid(data: &[u8]) -> bool {
+            matches!(
+                decode_with_max_len(&mut &data[..], 0, 8),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            )
+        }
+
+        // uncompressed len = 9
+        assert!(decode_invalid(&[0x00, 0x09]));
+
+        // flags = PACK, uncompressed len = 7, symbol count = 1, symbols = [n], packed len = 8
+        assert!(decode_invalid(&[0x80, 0x07, 0x01, b'n', 0x08]));
+    }
 }
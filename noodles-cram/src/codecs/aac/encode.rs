@@ -5,6 +5,15 @@ use byteorder::WriteBytesExt;
 use super::{Flags, Model, RangeCoder};
 use crate::io::writer::num::write_uint7;
 
+/// Encodes data using the adaptive arithmetic coder.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::aac::{self, Flags};
+/// let compressed_data = aac::encode(Flags::ORDER, b"noodles")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(mut flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     use crate::codecs::rans_nx16::encode::pack;
 
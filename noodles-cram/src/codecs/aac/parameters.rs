@@ -0,0 +1,121 @@
+use super::Flags;
+use crate::codecs::rans_4x8::Order;
+
+/// Adaptive arithmetic coder encoding parameters.
+///
+/// These map to the coder [`Flags`]. By default, data is order-0 encoded with no transforms and
+/// the uncompressed size.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::{
+///     aac::{self, Flags, Parameters},
+///     rans_4x8::Order,
+/// };
+///
+/// let parameters = Parameters::default().set_order(Order::One).set_pack(true);
+/// assert_eq!(Flags::from(parameters), Flags::ORDER | Flags::PACK);
+///
+/// let compressed_data = aac::encode(parameters.into(), b"noodles")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Parameters {
+    order: Order,
+    ext: bool,
+    stripe: bool,
+    rle: bool,
+    pack: bool,
+    cat: bool,
+    size: bool,
+}
+
+impl Parameters {
+    /// Sets the order of the model.
+    pub fn set_order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether the data is compressed with bzip2 instead.
+    pub fn set_ext(mut self, ext: bool) -> Self {
+        self.ext = ext;
+        self
+    }
+
+    /// Sets whether the data is split into 4 interleaved byte streams.
+    pub fn set_stripe(mut self, stripe: bool) -> Self {
+        self.stripe = stripe;
+        self
+    }
+
+    /// Sets whether runs are modeled with run lengths.
+    pub fn set_rle(mut self, rle: bool) -> Self {
+        self.rle = rle;
+        self
+    }
+
+    /// Sets whether symbols are bit packed when there are at most 16 distinct values.
+    pub fn set_pack(mut self, pack: bool) -> Self {
+        self.pack = pack;
+        self
+    }
+
+    /// Sets whether the data is stored uncompressed.
+    pub fn set_cat(mut self, cat: bool) -> Self {
+        self.cat = cat;
+        self
+    }
+
+    /// Sets whether the uncompressed size is written.
+    ///
+    /// If not, the uncompressed size must be passed to [`super::decode`].
+    pub fn set_size(mut self, size: bool) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            order: Order::Zero,
+            ext: false,
+            stripe: false,
+            rle: false,
+            pack: false,
+            cat: false,
+            size: true,
+        }
+    }
+}
+
+impl From<Parameters> for Flags {
+    fn from(parameters: Parameters) -> Self {
+        let mut flags = Self::empty();
+
+        flags.set(Self::ORDER, parameters.order == Order::One);
+        flags.set(Self::EXT, parameters.ext);
+        flags.set(Self::STRIPE, parameters.stripe);
+        flags.set(Self::RLE, parameters.rle);
+        flags.set(Self::PACK, parameters.pack);
+        flags.set(Self::CAT, parameters.cat);
+        flags.set(Self::NO_SIZE, !parameters.size);
+
+        flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parameters_for_flags() {
+        assert_eq!(Flags::from(Parameters::default()), Flags::empty());
+
+        let parameters = Parameters::default().set_ext(true).set_size(false);
+        assert_eq!(Flags::from(parameters), Flags::EXT | Flags::NO_SIZE);
+    }
+}
@@ -1,3 +1,5 @@
+//! fqzcomp quality score codec.
+
 mod decode;
 mod encode;
 mod parameter;
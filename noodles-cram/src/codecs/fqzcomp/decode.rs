@@ -10,6 +10,17 @@ use super::{
 };
 use crate::{codecs::aac::RangeCoder, io::reader::num::read_uint7};
 
+/// Decodes fqzcomp-encoded quality scores.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::fqzcomp;
+/// let compressed_data = fqzcomp::encode(&[4, 2], &[0, 1, 2, 3, 3, 1])?;
+/// let data = fqzcomp::decode(&mut &compressed_data[..])?;
+/// assert_eq!(data, [0, 1, 2, 3, 3, 1]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
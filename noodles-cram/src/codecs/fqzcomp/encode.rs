@@ -5,6 +5,18 @@ use byteorder::{LittleEndian, WriteBytesExt};
 use super::{Models, parameter, parameters};
 use crate::{codecs::aac::RangeCoder, io::writer::num::write_uint7};
 
+/// Encodes quality scores using the fqzcomp codec.
+///
+/// `lens` are the lengths of each record's quality scores, and `src` is the concatenation of all
+/// quality scores.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::fqzcomp;
+/// let compressed_data = fqzcomp::encode(&[4, 2], &[0, 1, 2, 3, 3, 1])?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
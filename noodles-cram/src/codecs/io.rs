@@ -0,0 +1,63 @@
+//! Chunked streaming codec I/O.
+//!
+//! A [`Writer`] splits a stream into chunks and encodes each chunk independently. A [`Reader`]
+//! decodes the chunks back into a continuous stream.
+//!
+//! Each chunk is written as a frame:
+//!
+//! | field             | type     | description                                       |
+//! |-------------------|----------|---------------------------------------------------|
+//! | method            | u8       | compression method, using CRAM block method IDs   |
+//! | uncompressed size | u32 (LE) | size of the decoded chunk                         |
+//! | compressed size   | u32 (LE) | size of the encoded chunk                         |
+//! | data              | [u8]     | encoded chunk                                     |
+//! | CRC32             | u32 (LE) | CRC32 of the encoded chunk                        |
+
+pub mod reader;
+pub mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Read, Write},
+        num::NonZeroUsize,
+    };
+
+    use super::*;
+    use crate::codecs::{Encoder, aac, rans_4x8, rans_nx16};
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let data: Vec<_> = (0..4096u32).map(|i| (i % 7) as u8 + b'A').collect();
+
+        let encoders = [
+            None,
+            Some(Encoder::Gzip(Default::default())),
+            Some(Encoder::Rans4x8(rans_4x8::Order::One)),
+            Some(Encoder::RansNx16(
+                rans_nx16::Flags::ORDER | rans_nx16::Flags::N32,
+            )),
+            Some(Encoder::AdaptiveArithmeticCoding(aac::Flags::RLE)),
+        ];
+
+        for encoder in encoders {
+            let mut writer = writer::Builder::default()
+                .set_encoder(encoder)
+                .set_chunk_size(const { NonZeroUsize::new(1000).unwrap() })
+                .build_from_writer(Vec::new());
+
+            writer.write_all(&data)?;
+            let src = writer.finish()?;
+
+            let mut reader = Reader::new(&src[..]);
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+
+            assert_eq!(buf, data);
+        }
+
+        Ok(())
+    }
+}
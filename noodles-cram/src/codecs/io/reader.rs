@@ -0,0 +1,263 @@
+//! Chunked streaming codec reader.
+
+mod builder;
+
+use std::io::{self, BufRead, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use flate2::Crc;
+
+pub use self::builder::Builder;
+use crate::{
+    container::block::CompressionMethod, io::reader::container::block::compression_method,
+};
+
+/// A chunked streaming codec reader.
+///
+/// This implements [`std::io::Read`] and [`std::io::BufRead`], consuming encoded frames and
+/// emitting decoded data.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_cram::codecs::{self, Encoder};
+///
+/// let mut writer = codecs::io::Writer::new(Vec::new(), Some(Encoder::Gzip(Default::default())));
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+///
+/// let mut reader = codecs::io::Reader::new(&src[..]);
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+///
+/// assert_eq!(buf, b"noodles");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Reader<R> {
+    inner: R,
+    max_chunk_size: usize,
+    buf: Vec<u8>,
+    position: usize,
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    /// Creates a chunked streaming codec reader with a default maximum chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::codecs;
+    /// let reader = codecs::io::Reader::new(io::empty());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Builder::default().build_from_reader(inner)
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads and decodes the next chunk.
+    ///
+    /// This returns `None` at the end of the stream.
+    pub fn read_chunk(&mut self) -> io::Result<Option<Vec<u8>>> {
+        read_frame(&mut self.inner, self.max_chunk_size)
+    }
+}
+
+impl<R> Read for Reader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut src = self.fill_buf()?;
+        let amt = src.read(buf)?;
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<R> BufRead for Reader<R>
+where
+    R: Read,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.position >= self.buf.len() {
+            match read_frame(&mut self.inner, self.max_chunk_size)? {
+                Some(buf) => {
+                    self.buf = buf;
+                    self.position = 0;
+                }
+                None => return Ok(&[]),
+            }
+        }
+
+        Ok(&self.buf[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = (self.position + amt).min(self.buf.len());
+    }
+}
+
+fn read_frame<R>(reader: &mut R, max_chunk_size: usize) -> io::Result<Option<Vec<u8>>>
+where
+    R: Read,
+{
+    let mut method = [0; 1];
+
+    if reader.read(&mut method)? == 0 {
+        return Ok(None);
+    }
+
+    let compression_method = compression_method::decode(method[0])?;
+
+    let uncompressed_size = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let compressed_size = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    if uncompressed_size > max_chunk_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "frame uncompressed size ({uncompressed_size}) exceeds maximum chunk size ({max_chunk_size})"
+            ),
+        ));
+    }
+
+    if compression_method == CompressionMethod::None && compressed_size != uncompressed_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "frame compressed size mismatch",
+        ));
+    }
+
+    // The compressed size is not trusted to preallocate the buffer. Only the data that is
+    // actually read is buffered.
+    let mut src = Vec::new();
+    let len = u64::try_from(compressed_size)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    reader.take(len).read_to_end(&mut src)?;
+
+    if src.len() != compressed_size {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+
+    let mut crc = Crc::new();
+    crc.update(&src);
+    let actual_crc32 = crc.sum();
+
+    if actual_crc32 != expected_crc32 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "frame checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+            ),
+        ));
+    }
+
+    let dst = crate::codecs::decode(compression_method, &src, uncompressed_size)?;
+
+    if dst.len() != uncompressed_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "frame uncompressed size mismatch",
+        ));
+    }
+
+    Ok(Some(dst))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_CHUNK_SIZE: usize = 1 << 10;
+
+    #[test]
+    fn test_read_frame() -> io::Result<()> {
+        let src = [
+            0x00, // method = none
+            0x02, 0x00, 0x00, 0x00, // uncompressed size = 2
+            0x02, 0x00, 0x00, 0x00, // compressed size = 2
+            b'n', b'd', // data
+            0x97, 0xf1, 0x78, 0xf0, // CRC32
+        ];
+
+        let mut reader = &src[..];
+        assert_eq!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE)?,
+            Some(b"nd".to_vec())
+        );
+        assert!(read_frame(&mut reader, MAX_CHUNK_SIZE)?.is_none());
+
+        let mut reader = &src[..];
+        assert!(matches!(
+            read_frame(&mut reader, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = &src[..10];
+        assert!(matches!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut data = src;
+        data[9] = b'N';
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut data = src;
+        data[5] = 0xff; // compressed size = 255
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = [
+            0x01, // method = gzip
+            0x00, 0x00, 0x00, 0x40, // uncompressed size = 1 GiB
+            0xff, 0xff, 0xff, 0xff, // compressed size = 4 GiB - 1
+        ];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = [
+            0x01, // method = gzip
+            0x02, 0x00, 0x00, 0x00, // uncompressed size = 2
+            0xff, 0xff, 0xff, 0xff, // compressed size = 4 GiB - 1
+        ];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_frame(&mut reader, MAX_CHUNK_SIZE),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::{io::Read, num::NonZeroUsize};
+
+use super::Reader;
+
+// 64 MiB
+const DEFAULT_MAX_CHUNK_SIZE: usize = 1 << 26;
+
+/// A chunked streaming codec reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    max_chunk_size: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets the maximum uncompressed size of a chunk.
+    ///
+    /// Frames with a larger uncompressed size are rejected before they are decoded. By default,
+    /// the maximum chunk size is 64 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::codecs;
+    /// let builder = codecs::io::reader::Builder::default()
+    ///     .set_max_chunk_size(NonZeroUsize::new(1 << 20).unwrap());
+    /// ```
+    pub fn set_max_chunk_size(mut self, max_chunk_size: NonZeroUsize) -> Self {
+        self.max_chunk_size = Some(max_chunk_size);
+        self
+    }
+
+    /// Builds a chunked streaming codec reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::codecs;
+    /// let reader = codecs::io::reader::Builder::default().build_from_reader(io::empty());
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: Read,
+    {
+        let max_chunk_size = self
+            .max_chunk_size
+            .map(usize::from)
+            .unwrap_or(DEFAULT_MAX_CHUNK_SIZE);
+
+        Reader {
+            inner: reader,
+            max_chunk_size,
+            buf: Vec::new(),
+            position: 0,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Chunked streaming codec writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Write};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use flate2::Crc;
+
+use crate::{
+    codecs::Encoder, container::block::CompressionMethod,
+    io::writer::container::block::compression_method,
+};
+
+/// A chunked streaming codec writer.
+///
+/// This implements [`std::io::Write`], consuming uncompressed data and emitting encoded frames.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_cram::codecs::{self, rans_nx16, Encoder};
+///
+/// let mut writer = codecs::io::writer::Builder::default()
+///     .set_encoder(Some(Encoder::RansNx16(rans_nx16::Flags::ORDER)))
+///     .build_from_writer(Vec::new());
+///
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Writer<W>
+where
+    W: Write,
+{
+    inner: Option<W>,
+    encoder: Option<Encoder>,
+    chunk_size: usize,
+    buf: Vec<u8>,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a chunked streaming codec writer with a default chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::codecs::{self, Encoder};
+    /// let writer = codecs::io::Writer::new(io::sink(), Some(Encoder::NameTokenizer));
+    /// ```
+    pub fn new(inner: W, encoder: Option<Encoder>) -> Self {
+        Builder::default()
+            .set_encoder(encoder)
+            .build_from_writer(inner)
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Returns the underlying writer after finishing the output stream.
+    ///
+    /// This method can only be called once. Any further usage of the writer may result in a panic.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush()?;
+        let inner = self.inner.take().unwrap();
+        Ok(inner)
+    }
+
+    fn flush_chunk(&mut self, end: usize) -> io::Result<()> {
+        let src = &self.buf[..end];
+
+        let (compression_method, dst) = match &self.encoder {
+            Some(encoder) => (encoder.compression_method(), encoder.encode(src)?),
+            None => (CompressionMethod::None, src.to_vec()),
+        };
+
+        let inner = self.inner.as_mut().unwrap();
+        write_frame(inner, compression_method, src.len(), &dst)?;
+
+        self.buf.drain(..end);
+
+        Ok(())
+    }
+}
+
+impl<W> Drop for Writer<W>
+where
+    W: Write,
+{
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.flush();
+        }
+    }
+}
+
+impl<W> Write for Writer<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let amt = (self.chunk_size - self.buf.len()).min(buf.len());
+        self.buf.extend(&buf[..amt]);
+
+        if self.buf.len() >= self.chunk_size {
+            self.flush_chunk(self.chunk_size)?;
+        }
+
+        Ok(amt)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.flush_chunk(self.buf.len())?;
+        }
+
+        self.inner.as_mut().unwrap().flush()
+    }
+}
+
+fn write_frame<W>(
+    writer: &mut W,
+    compression_method: CompressionMethod,
+    uncompressed_size: usize,
+    data: &[u8],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let uncompressed_size = u32::try_from(uncompressed_size)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let compressed_size =
+        u32::try_from(data.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    writer.write_u8(compression_method::encode(compression_method))?;
+    writer.write_u32::<LittleEndian>(uncompressed_size)?;
+    writer.write_u32::<LittleEndian>(compressed_size)?;
+    writer.write_all(data)?;
+
+    let mut crc = Crc::new();
+    crc.update(data);
+    writer.write_u32::<LittleEndian>(crc.sum())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_frame() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_frame(&mut buf, CompressionMethod::None, 2, b"nd")?;
+
+        let expected = [
+            0x00, // method = none
+            0x02, 0x00, 0x00, 0x00, // uncompressed size = 2
+            0x02, 0x00, 0x00, 0x00, // compressed size = 2
+            b'n', b'd', // data
+            0x97, 0xf1, 0x78, 0xf0, // CRC32
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+}
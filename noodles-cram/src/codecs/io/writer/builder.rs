@@ -0,0 +1,75 @@
+use std::{io::Write, num::NonZeroUsize};
+
+use super::Writer;
+use crate::codecs::Encoder;
+
+// 1 MiB
+const DEFAULT_CHUNK_SIZE: usize = 1 << 20;
+
+/// A chunked streaming codec writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    encoder: Option<Encoder>,
+    chunk_size: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets the encoder.
+    ///
+    /// If `None`, chunks are written uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::codecs::{self, rans_4x8, Encoder};
+    /// let builder = codecs::io::writer::Builder::default()
+    ///     .set_encoder(Some(Encoder::Rans4x8(rans_4x8::Order::One)));
+    /// ```
+    pub fn set_encoder(mut self, encoder: Option<Encoder>) -> Self {
+        self.encoder = encoder;
+        self
+    }
+
+    /// Sets the uncompressed size of each chunk.
+    ///
+    /// By default, the chunk size is 1 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::codecs;
+    /// let builder = codecs::io::writer::Builder::default()
+    ///     .set_chunk_size(NonZeroUsize::new(1 << 16).unwrap());
+    /// ```
+    pub fn set_chunk_size(mut self, chunk_size: NonZeroUsize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Builds a chunked streaming codec writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::codecs;
+    /// let writer = codecs::io::writer::Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        let chunk_size = self
+            .chunk_size
+            .map(usize::from)
+            .unwrap_or(DEFAULT_CHUNK_SIZE);
+
+        Writer {
+            inner: Some(writer),
+            encoder: self.encoder,
+            chunk_size,
+            buf: Vec::with_capacity(chunk_size),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+//! Name tokenizer codec.
+
 mod decode;
 mod encode;
 
@@ -8,6 +8,19 @@ use crate::{
     io::reader::num::read_uint7,
 };
 
+/// Decodes tokenized read names.
+///
+/// The output is a list of NUL-terminated names.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::name_tokenizer;
+/// let compressed_data = name_tokenizer::encode(b"r:1\0r:2\0")?;
+/// let data = name_tokenizer::decode(&mut &compressed_data[..])?;
+/// assert_eq!(data, b"r:1\0r:2\0");
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
@@ -11,6 +11,17 @@ use crate::io::writer::num::write_uint7;
 
 const NUL: u8 = 0x00;
 
+/// Encodes read names using the name tokenizer codec.
+///
+/// `src` is a list of NUL-terminated names.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::name_tokenizer;
+/// let compressed_data = name_tokenizer::encode(b"r:1\0r:2\0")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(mut src: &[u8]) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
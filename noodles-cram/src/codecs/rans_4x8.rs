@@ -5,7 +5,7 @@ mod encode;
 mod order;
 
 pub use self::order::Order;
-pub(crate) use self::{decode::decode, encode::encode};
+pub use self::{decode::decode, encode::encode};
 
 // § 2.2 "rANS entropy encoding" (2023-03-15)
 const ALPHABET_SIZE: usize = 256; // b
@@ -9,6 +9,17 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use self::header::read_header;
 use super::{LOWER_BOUND, Order, STATE_COUNT};
 
+/// Decodes rANS 4x8-encoded data.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_4x8::{self, Order};
+/// let compressed_data = rans_4x8::encode(Order::One, b"noodles")?;
+/// let data = rans_4x8::decode(&mut &compressed_data[..])?;
+/// assert_eq!(data, b"noodles");
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
@@ -9,6 +9,15 @@ use byteorder::{LittleEndian, WriteBytesExt};
 use self::header::write_header;
 use super::{LOWER_BOUND, Order, STATE_COUNT};
 
+/// Encodes data using the rANS 4x8 codec.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_4x8::{self, Order};
+/// let compressed_data = rans_4x8::encode(Order::Zero, b"noodles")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(order: Order, src: &[u8]) -> io::Result<Vec<u8>> {
     match order {
         Order::Zero => order_0::encode(src),
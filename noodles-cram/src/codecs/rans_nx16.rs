@@ -1,8 +1,9 @@
-//! rANS 4x8 codec.
+//! rANS Nx16 codec.
 
 pub(crate) mod decode;
 pub(crate) mod encode;
 mod flags;
+mod parameters;
 
-pub use self::flags::Flags;
-pub(crate) use self::{decode::decode, encode::encode};
+pub use self::{decode::decode, encode::encode};
+pub use self::{flags::Flags, parameters::Parameters};
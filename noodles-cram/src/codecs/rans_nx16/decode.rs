@@ -2,6 +2,8 @@ mod order_0;
 mod order_1;
 pub mod pack;
 mod rle;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+mod simd;
 
 use std::{
     io::{self, Cursor, Read},
@@ -13,17 +15,46 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use super::Flags;
 use crate::io::reader::num::read_uint7;
 
-pub fn decode<R>(reader: &mut R, mut len: usize) -> io::Result<Vec<u8>>
+/// Decodes rANS Nx16-encoded data.
+///
+/// `len` is the uncompressed size. It is only used when the stream was encoded with
+/// [`Flags::NO_SIZE`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_nx16::{self, Flags};
+/// let compressed_data = rans_nx16::encode(Flags::RLE, b"noodles")?;
+/// let data = rans_nx16::decode(&mut &compressed_data[..], 7)?;
+/// assert_eq!(data, b"noodles");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn decode<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    decode_with_max_len(reader, len, usize::MAX)
+}
+
+// Decodes rANS Nx16-encoded data with an uncompressed size of at most `max_len`.
+//
+// Sizes read from the stream are checked against bounds derived from `max_len` before they are
+// used to allocate buffers.
+pub(crate) fn decode_with_max_len<R>(
+    reader: &mut R,
+    mut len: usize,
+    max_len: usize,
+) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
     let flags = reader.read_u8().map(Flags::from)?;
 
-    if !flags.contains(Flags::NO_SIZE) {
-        len = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
-    }
+    len = if flags.contains(Flags::NO_SIZE) {
+        check_size(len, max_len)?
+    } else {
+        read_size(reader, max_len)?
+    };
 
     let n = if flags.contains(Flags::N32) { 32 } else { 4 };
 
@@ -36,7 +67,7 @@ where
     let pack_len = len;
 
     if flags.contains(Flags::PACK) {
-        let (q, n, new_len) = decode_pack_meta(reader)?;
+        let (q, n, new_len) = decode_pack_meta(reader, len)?;
         p = Some(q);
         n_sym = Some(n);
         len = new_len;
@@ -47,7 +78,7 @@ where
     let rle_len = len;
 
     if flags.contains(Flags::RLE) {
-        let (m, meta, new_len) = decode_rle_meta(reader, n)?;
+        let (m, meta, new_len) = decode_rle_meta(reader, n, len)?;
         l = Some(m);
         rle_meta = Some(meta);
         len = new_len;
@@ -166,7 +197,7 @@ where
             ulen += 1;
         }
 
-        let chunk = decode(reader, ulen)?;
+        let chunk = decode_with_max_len(reader, ulen, ulen)?;
 
         ulens.push(ulen);
         t.push(chunk);
@@ -183,29 +214,35 @@ where
     Ok(dst)
 }
 
-fn decode_rle_meta<R>(reader: &mut R, n: u32) -> io::Result<([bool; 256], Cursor<Vec<u8>>, usize)>
+// `rle_len` is the length of the run-length decoded data.
+fn decode_rle_meta<R>(
+    reader: &mut R,
+    n: u32,
+    rle_len: usize,
+) -> io::Result<([bool; 256], Cursor<Vec<u8>>, usize)>
 where
     R: Read,
 {
+    // The metadata is the symbol count, up to 256 symbols, and a uint7-encoded (≤ 5 bytes) run
+    // length for each run-length encoded literal.
+    let max_rle_meta_len = rle_len.saturating_mul(5).saturating_add(257);
+
     let rle_meta_len = read_uint7(reader).and_then(|n| {
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     })?;
 
-    let len = read_uint7(reader).and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    check_size(rle_meta_len / 2, max_rle_meta_len)?;
+
+    // Each literal expands to at least one symbol.
+    let len = read_size(reader, rle_len)?;
 
     let rle_meta = if rle_meta_len & 1 == 1 {
         let mut buf = vec![0; rle_meta_len / 2];
         reader.read_exact(&mut buf)?;
         buf
     } else {
-        let comp_meta_len = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
-
-        let mut buf = vec![0; comp_meta_len];
-        reader.read_exact(&mut buf)?;
+        let comp_meta_len = read_uint7(reader).map(u64::from)?;
+        let buf = read_to_vec(reader, comp_meta_len)?;
 
         let mut buf_reader = &buf[..];
         let mut dst = vec![0; rle_meta_len / 2];
@@ -232,7 +269,11 @@ where
     Ok((l, rle_meta_reader, len))
 }
 
-pub fn decode_pack_meta<R>(reader: &mut R) -> io::Result<(Vec<u8>, NonZeroUsize, usize)>
+// `pack_len` is the length of the unpacked data.
+pub fn decode_pack_meta<R>(
+    reader: &mut R,
+    pack_len: usize,
+) -> io::Result<(Vec<u8>, NonZeroUsize, usize)>
 where
     R: Read,
 {
@@ -245,17 +286,64 @@ where
     let mut p = vec![0; symbol_count.get()];
     reader.read_exact(&mut p)?;
 
-    let len = read_uint7(reader).and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // Packing never increases the length.
+    let len = read_size(reader, pack_len)?;
 
     Ok((p, symbol_count, len))
 }
 
+// Reads a uint7-encoded size and checks that it is at most `max_len`.
+pub(crate) fn read_size<R>(reader: &mut R, max_len: usize) -> io::Result<usize>
+where
+    R: Read,
+{
+    read_uint7(reader)
+        .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+        .and_then(|len| check_size(len, max_len))
+}
+
+pub(crate) fn check_size(len: usize, max_len: usize) -> io::Result<usize> {
+    if len <= max_len {
+        Ok(len)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("size ({len}) exceeds maximum ({max_len})"),
+        ))
+    }
+}
+
+// Reads `len` bytes without trusting `len` to preallocate the buffer.
+pub(crate) fn read_to_vec<R>(reader: &mut R, len: u64) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut buf = Vec::new();
+    reader.take(len).read_to_end(&mut buf)?;
+
+    if buf.len() as u64 == len {
+        Ok(buf)
+    } else {
+        Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // xorshift64
+    pub(super) struct Rng(pub(super) u64);
+
+    impl Rng {
+        pub(super) fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
     #[test]
     fn test_decode_order_0() -> io::Result<()> {
         let data = [
@@ -358,4 +446,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_with_max_len() {
+        fn decode_invalid(data: &[u8]) -> bool {
+            matches!(
+                decode_with_max_len(&mut &data[..], 0, 8),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            )
+        }
+
+        // uncompressed len = 9
+        assert!(decode_invalid(&[0x00, 0x09]));
+
+        // flags = NO_SIZE
+        assert!(matches!(
+            decode_with_max_len(&mut &[0x10][..], 9, 8),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // flags = PACK, uncompressed len = 7, symbol count = 1, symbols = [n], packed len = 8
+        assert!(decode_invalid(&[0x80, 0x07, 0x01, b'n', 0x08]));
+
+        // flags = RLE, uncompressed len = 8, RLE metadata len = 1000
+        assert!(decode_invalid(&[0x40, 0x08, 0x87, 0x68]));
+
+        // flags = RLE, uncompressed len = 8, RLE metadata len = 3, literals len = 9
+        assert!(decode_invalid(&[0x40, 0x08, 0x03, 0x09]));
+    }
 }
@@ -8,11 +8,13 @@ pub fn decode<R>(reader: &mut R, output: &mut [u8], n: u32) -> io::Result<()>
 where
     R: Read,
 {
-    use super::{
-        rans_advance_step_nx16, rans_get_cumulative_freq_nx16, rans_get_symbol_from_freq,
-        rans_renorm_nx16,
-    };
+    decode_with(reader, output, n, true)
+}
 
+fn decode_with<R>(reader: &mut R, output: &mut [u8], n: u32, use_simd: bool) -> io::Result<()>
+where
+    R: Read,
+{
     let mut freqs = [0; 256];
     let mut cumulative_freqs = [0; 256];
 
@@ -24,11 +26,89 @@ where
         *s = reader.read_u32::<LittleEndian>()?;
     }
 
+    // The SIMD decoders only handle complete rows of interleaved states. The remaining output is
+    // decoded by the scalar decoder, which continues from the same states.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    let output = if use_simd {
+        let len = decode_simd(reader, &freqs, &cumulative_freqs, &mut state, output)?;
+        &mut output[len..]
+    } else {
+        output
+    };
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = use_simd;
+
+    decode_scalar(reader, &freqs, &cumulative_freqs, &mut state, output)
+}
+
+// Decodes complete rows of 32 interleaved states and returns the number of bytes written, which
+// is 0 if SIMD decoding is not available or not applicable.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn decode_simd<R>(
+    reader: &mut R,
+    freqs: &[u32; 256],
+    cumulative_freqs: &[u32; 256],
+    state: &mut [u32],
+    output: &mut [u8],
+) -> io::Result<usize>
+where
+    R: Read,
+{
+    use super::simd::{self, STATE_COUNT, Table};
+
+    let Ok(state) = <&mut [u32; STATE_COUNT]>::try_from(state) else {
+        return Ok(0);
+    };
+
+    let len = (output.len() / STATE_COUNT) * STATE_COUNT;
+
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let Some(mut table) = Table::new(1, 12, len) else {
+        return Ok(0);
+    };
+
+    if !table.set_frequencies(0, freqs, cumulative_freqs) {
+        return Ok(0);
+    }
+
+    let contexts = [0; STATE_COUNT];
+    let mut syms = [0; STATE_COUNT];
+
+    for row in output[..len].chunks_exact_mut(STATE_COUNT) {
+        simd::decode_row(reader, &table, state, &contexts, &mut syms)?;
+
+        for (dst, &sym) in row.iter_mut().zip(&syms) {
+            *dst = sym as u8;
+        }
+    }
+
+    Ok(len)
+}
+
+fn decode_scalar<R>(
+    reader: &mut R,
+    freqs: &[u32; 256],
+    cumulative_freqs: &[u32; 256],
+    state: &mut [u32],
+    output: &mut [u8],
+) -> io::Result<()>
+where
+    R: Read,
+{
+    use super::{
+        rans_advance_step_nx16, rans_get_cumulative_freq_nx16, rans_get_symbol_from_freq,
+        rans_renorm_nx16,
+    };
+
     for (i, b) in output.iter_mut().enumerate() {
-        let j = i % (n as usize);
+        let j = i % state.len();
 
         let f = rans_get_cumulative_freq_nx16(state[j], 12);
-        let s = rans_get_symbol_from_freq(&cumulative_freqs, f);
+        let s = rans_get_symbol_from_freq(cumulative_freqs, f);
 
         *b = s;
 
@@ -92,3 +172,49 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::rans_nx16::{Flags, decode::tests::Rng, encode};
+
+    #[test]
+    fn test_decode_with_simd_matches_scalar() -> io::Result<()> {
+        let mut rng = Rng(0x6e6f6f646c6573);
+
+        for _ in 0..256 {
+            let len = (rng.next() % 16384) as usize;
+            let alphabet_size = (rng.next() % 64) + 1;
+            let skew = (rng.next() % 4) as u32;
+
+            let src: Vec<_> = (0..len)
+                .map(|_| {
+                    let mut n = rng.next() % alphabet_size;
+
+                    for _ in 0..skew {
+                        n = n.min(rng.next() % alphabet_size);
+                    }
+
+                    n as u8 + b'!'
+                })
+                .collect();
+
+            let compressed_data = encode(Flags::N32, &src)?;
+
+            // Skip the flags and uncompressed size.
+            let mut reader = &compressed_data[1..];
+            read_uint7(&mut reader)?;
+
+            let mut scalar_output = vec![0; len];
+            decode_with(&mut &reader[..], &mut scalar_output, 32, false)?;
+
+            let mut simd_output = vec![0; len];
+            decode_with(&mut reader, &mut simd_output, 32, true)?;
+
+            assert_eq!(scalar_output, src);
+            assert_eq!(simd_output, src);
+        }
+
+        Ok(())
+    }
+}
@@ -4,7 +4,18 @@ use byteorder::{LittleEndian, ReadBytesExt};
 
 use crate::io::reader::num::read_uint7;
 
+// An upper bound of the size of the frequency table: an alphabet (≤ 512 bytes) and, for each of
+// up to 256 contexts, a uint7-encoded frequency (≤ 2 bytes) or zero run (2 bytes) per symbol.
+const MAX_FREQUENCY_TABLE_SIZE: usize = 512 + 256 * 256 * 2;
+
 pub fn decode<R>(reader: &mut R, output: &mut [u8], n: u32) -> io::Result<()>
+where
+    R: Read,
+{
+    decode_with(reader, output, n, true)
+}
+
+fn decode_with<R>(reader: &mut R, output: &mut [u8], n: u32, use_simd: bool) -> io::Result<()>
 where
     R: Read,
 {
@@ -27,6 +38,24 @@ where
     let mut i = 0;
     let mut last_syms = vec![0; state.len()];
 
+    // The SIMD decoders only handle complete rows of interleaved states. The remaining rows and
+    // output are decoded by the scalar decoder, which continues from the same states.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    if use_simd {
+        i = decode_simd(
+            reader,
+            &freqs,
+            &cumulative_freqs,
+            bits,
+            &mut state,
+            &mut last_syms,
+            output,
+        )?;
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = use_simd;
+
     while i < output.len() / (n as usize) {
         for j in 0..(n as usize) {
             let f = rans_get_cumulative_freq_nx16(state[j], bits);
@@ -75,6 +104,80 @@ where
     Ok(())
 }
 
+// Decodes complete rows of 32 interleaved states and returns the number of rows decoded, which is 0
+// if SIMD decoding is not available or not applicable.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn decode_simd<R>(
+    reader: &mut R,
+    freqs: &[Vec<u32>],
+    cumulative_freqs: &[Vec<u32>],
+    bits: u32,
+    state: &mut [u32],
+    last_syms: &mut [usize],
+    output: &mut [u8],
+) -> io::Result<usize>
+where
+    R: Read,
+{
+    use super::simd::{self, STATE_COUNT, Table};
+
+    let Ok(state) = <&mut [u32; STATE_COUNT]>::try_from(state) else {
+        return Ok(0);
+    };
+
+    let row_count = output.len() / STATE_COUNT;
+
+    if row_count == 0 {
+        return Ok(0);
+    }
+
+    // The table only has entries for the contexts that are used, i.e., those with frequencies.
+    // Symbols are mapped to the index of their context in the table. Unused contexts are not
+    // decoded from valid streams and are mapped to the first context.
+    let mut context_indices = [0; 256];
+    let mut context_count = 0;
+
+    for (sym, f) in freqs.iter().enumerate() {
+        if f.iter().any(|&n| n > 0) {
+            context_indices[sym] = context_count;
+            context_count += 1;
+        }
+    }
+
+    let Some(mut table) = Table::new(context_count as usize, bits, output.len()) else {
+        return Ok(0);
+    };
+
+    for ((f, c), &i) in freqs.iter().zip(cumulative_freqs).zip(&context_indices) {
+        if f.iter().any(|&n| n > 0) && !table.set_frequencies(i as usize, f, c) {
+            return Ok(0);
+        }
+    }
+
+    let mut contexts = [0; STATE_COUNT];
+
+    for (context, &sym) in contexts.iter_mut().zip(last_syms.iter()) {
+        *context = context_indices[sym];
+    }
+
+    let mut syms = [0; STATE_COUNT];
+
+    for i in 0..row_count {
+        simd::decode_row(reader, &table, state, &contexts, &mut syms)?;
+
+        for (j, (&sym, context)) in syms.iter().zip(&mut contexts).enumerate() {
+            output[i + j * row_count] = sym as u8;
+            *context = context_indices[sym as usize];
+        }
+    }
+
+    for (last_sym, &sym) in last_syms.iter_mut().zip(&syms) {
+        *last_sym = sym as usize;
+    }
+
+    Ok(row_count)
+}
+
 fn read_frequencies<R>(
     reader: &mut R,
     freqs: &mut [Vec<u32>],
@@ -89,16 +192,11 @@ where
     let bits = u32::from(comp >> 4);
 
     if comp & 0x01 != 0 {
-        let u_size = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
-
-        let c_size = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
+        use super::{read_size, read_to_vec};
 
-        let mut c_data = vec![0; c_size];
-        reader.read_exact(&mut c_data)?;
+        let u_size = read_size(reader, MAX_FREQUENCY_TABLE_SIZE)?;
+        let c_size = read_uint7(reader).map(u64::from)?;
+        let c_data = read_to_vec(reader, c_size)?;
 
         let mut c_data_reader = &c_data[..];
         let mut u_data = vec![0; u_size];
@@ -162,3 +260,54 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::rans_nx16::{Flags, decode::tests::Rng, encode};
+
+    #[test]
+    fn test_decode_with_simd_matches_scalar() -> io::Result<()> {
+        let mut rng = Rng(0x6e6f6f646c6573);
+
+        // The SIMD decoder is only used when the output is at least as long as its table, i.e.,
+        // 4096 entries per context.
+        for _ in 0..64 {
+            let len = (rng.next() % 40960) as usize;
+            let alphabet_size = (rng.next() % 4) + 1;
+
+            // Each symbol is likely to be close to the previous one, which makes the order-1
+            // frequencies differ by context.
+            let mut prev = 0;
+
+            let src: Vec<_> = (0..len)
+                .map(|_| {
+                    prev = if rng.next() % 4 == 0 {
+                        rng.next() % alphabet_size
+                    } else {
+                        (prev + rng.next() % 3) % alphabet_size
+                    };
+
+                    prev as u8 + b'!'
+                })
+                .collect();
+
+            let compressed_data = encode(Flags::ORDER | Flags::N32, &src)?;
+
+            // Skip the flags and uncompressed size.
+            let mut reader = &compressed_data[1..];
+            read_uint7(&mut reader)?;
+
+            let mut scalar_output = vec![0; len];
+            decode_with(&mut &reader[..], &mut scalar_output, 32, false)?;
+
+            let mut simd_output = vec![0; len];
+            decode_with(&mut reader, &mut simd_output, 32, true)?;
+
+            assert_eq!(scalar_output, src);
+            assert_eq!(simd_output, src);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+//! SIMD rANS Nx16 decoding.
+//!
+//! These decode rows of 32 interleaved states at a time. Symbol lookup and state advancement are
+//! vectorized, and renormalization is done in state order, which keeps the stream consumption
+//! identical to the scalar decoder.
+
+use std::io::{self, Read};
+
+use super::rans_renorm_nx16;
+
+pub(super) const STATE_COUNT: usize = 32;
+
+const MAX_BITS: u32 = 12;
+const CONTEXT_SIZE: usize = 1 << MAX_BITS;
+
+/// A lookup table from a context and the cumulative frequency of a state to its symbol.
+///
+/// Each context has its own block of entries. An entry is packed as
+/// `offset << 20 | (freq - 1) << 8 | sym`, where `offset` is the cumulative frequency minus the
+/// symbol's cumulative frequency.
+pub(super) struct Table {
+    entries: Vec<u32>,
+    bits: u32,
+}
+
+impl Table {
+    /// Creates an empty table for the given number of contexts and frequency bits.
+    ///
+    /// `len` is the length of the output to decode. This returns `None` if SIMD decoding is not
+    /// available or not applicable, including when the output is shorter than the table, i.e.,
+    /// when filling the table costs more than decoding with the scalar decoder.
+    pub(super) fn new(context_count: usize, bits: u32, len: usize) -> Option<Self> {
+        let entry_count = context_count.checked_mul(CONTEXT_SIZE)?;
+
+        if bits > MAX_BITS || len < entry_count || !is_available() {
+            return None;
+        }
+
+        Some(Self {
+            entries: vec![0; entry_count],
+            bits,
+        })
+    }
+
+    /// Sets the symbol frequencies of a context.
+    ///
+    /// This returns whether the frequencies sum to the total frequency. If not, the table cannot
+    /// be used, and the scalar decoder must be used instead.
+    pub(super) fn set_frequencies(
+        &mut self,
+        context: usize,
+        freqs: &[u32],
+        cumulative_freqs: &[u32],
+    ) -> bool {
+        let total: u32 = freqs.iter().sum();
+
+        if total != 1 << self.bits {
+            return false;
+        }
+
+        let start = context * CONTEXT_SIZE;
+        let entries = &mut self.entries[start..start + CONTEXT_SIZE];
+
+        for (sym, (&freq, &cumulative_freq)) in freqs.iter().zip(cumulative_freqs).enumerate() {
+            let start = cumulative_freq as usize;
+            let end = start + freq as usize;
+
+            for (offset, entry) in entries[start..end].iter_mut().enumerate() {
+                *entry = ((offset as u32) << 20) | ((freq - 1) << 8) | sym as u32;
+            }
+        }
+
+        true
+    }
+}
+
+/// Decodes one symbol from each of the 32 interleaved states.
+///
+/// The context of each state selects the frequency table used to decode it. Symbols are written
+/// to `syms`, and the states are advanced and renormalized.
+pub(super) fn decode_row<R>(
+    reader: &mut R,
+    table: &Table,
+    state: &mut [u32; STATE_COUNT],
+    contexts: &[u32; STATE_COUNT],
+    syms: &mut [u32; STATE_COUNT],
+) -> io::Result<()>
+where
+    R: Read,
+{
+    let context_count = table.entries.len() / CONTEXT_SIZE;
+    assert!(
+        contexts
+            .iter()
+            .all(|&context| (context as usize) < context_count)
+    );
+
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: AVX2 support is checked when the table is created, and the contexts are in bounds.
+    unsafe {
+        x86_64::advance(table, state, contexts, syms);
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    // SAFETY: NEON support is checked when the table is created, and the contexts are in bounds.
+    unsafe {
+        aarch64::advance(table, state, contexts, syms);
+    }
+
+    for s in state.iter_mut() {
+        *s = rans_renorm_nx16(reader, *s)?;
+    }
+
+    Ok(())
+}
+
+fn is_available() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::arch::is_x86_feature_detected!("avx2")
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        std::arch::is_aarch64_feature_detected!("neon")
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use std::arch::x86_64::{
+        __m256i, _mm_cvtsi32_si128, _mm256_add_epi32, _mm256_and_si256, _mm256_i32gather_epi32,
+        _mm256_loadu_si256, _mm256_mullo_epi32, _mm256_or_si256, _mm256_set1_epi32,
+        _mm256_slli_epi32, _mm256_srl_epi32, _mm256_srli_epi32, _mm256_storeu_si256,
+    };
+
+    use super::{MAX_BITS, STATE_COUNT, Table};
+
+    const LANE_COUNT: usize = 8;
+
+    /// # Safety
+    ///
+    /// The caller must ensure AVX2 is available and that all contexts are in bounds of the table.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn advance(
+        table: &Table,
+        state: &mut [u32; STATE_COUNT],
+        contexts: &[u32; STATE_COUNT],
+        syms: &mut [u32; STATE_COUNT],
+    ) {
+        let mask_f = _mm256_set1_epi32((1 << table.bits) - 1);
+        let mask_8 = _mm256_set1_epi32(0xff);
+        let mask_12 = _mm256_set1_epi32(0xfff);
+        let one = _mm256_set1_epi32(1);
+        let bits = _mm_cvtsi32_si128(table.bits as i32);
+
+        for i in (0..STATE_COUNT).step_by(LANE_COUNT) {
+            // SAFETY: `i + LANE_COUNT <= STATE_COUNT`, and every index is a context in bounds of
+            // the table and a cumulative frequency masked to at most 12 bits.
+            unsafe {
+                let s = _mm256_loadu_si256(state.as_ptr().add(i).cast::<__m256i>());
+                let context = _mm256_loadu_si256(contexts.as_ptr().add(i).cast::<__m256i>());
+
+                let f = _mm256_and_si256(s, mask_f);
+                let j = _mm256_or_si256(_mm256_slli_epi32::<{ MAX_BITS as i32 }>(context), f);
+                let entry = _mm256_i32gather_epi32::<4>(table.entries.as_ptr().cast::<i32>(), j);
+
+                let sym = _mm256_and_si256(entry, mask_8);
+                let freq = _mm256_add_epi32(
+                    _mm256_and_si256(_mm256_srli_epi32::<8>(entry), mask_12),
+                    one,
+                );
+                let offset = _mm256_srli_epi32::<20>(entry);
+
+                let x =
+                    _mm256_add_epi32(_mm256_mullo_epi32(freq, _mm256_srl_epi32(s, bits)), offset);
+
+                _mm256_storeu_si256(state.as_mut_ptr().add(i).cast::<__m256i>(), x);
+                _mm256_storeu_si256(syms.as_mut_ptr().add(i).cast::<__m256i>(), sym);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use std::arch::aarch64::{
+        vaddq_u32, vandq_u32, vdupq_n_s32, vdupq_n_u32, vld1q_u32, vmlaq_u32, vshlq_u32,
+        vshrq_n_u32, vst1q_u32,
+    };
+
+    use super::{MAX_BITS, STATE_COUNT, Table};
+
+    const LANE_COUNT: usize = 4;
+
+    /// # Safety
+    ///
+    /// The caller must ensure NEON is available and that all contexts are in bounds of the table.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn advance(
+        table: &Table,
+        state: &mut [u32; STATE_COUNT],
+        contexts: &[u32; STATE_COUNT],
+        syms: &mut [u32; STATE_COUNT],
+    ) {
+        let mask_f = (1 << table.bits) - 1;
+
+        // NEON has no gather instruction, so the table lookups are scalar.
+        for ((sym, &s), &context) in syms.iter_mut().zip(state.iter()).zip(contexts) {
+            let j = ((context as usize) << MAX_BITS) | (s & mask_f) as usize;
+            *sym = table.entries[j];
+        }
+
+        let mask_12 = vdupq_n_u32(0xfff);
+        let one = vdupq_n_u32(1);
+        let shift = vdupq_n_s32(-(table.bits as i32));
+
+        for i in (0..STATE_COUNT).step_by(LANE_COUNT) {
+            // SAFETY: `i + LANE_COUNT <= STATE_COUNT`.
+            unsafe {
+                let s = vld1q_u32(state.as_ptr().add(i));
+                let entry = vld1q_u32(syms.as_ptr().add(i));
+
+                let freq = vaddq_u32(vandq_u32(vshrq_n_u32::<8>(entry), mask_12), one);
+                let offset = vshrq_n_u32::<20>(entry);
+
+                let x = vmlaq_u32(offset, freq, vshlq_u32(s, shift));
+
+                vst1q_u32(state.as_mut_ptr().add(i), x);
+            }
+        }
+
+        for sym in syms.iter_mut() {
+            *sym &= 0xff;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_frequencies() {
+        let Some(mut table) = Table::new(2, 12, 2 * CONTEXT_SIZE) else {
+            return;
+        };
+
+        let mut freqs = [0; 256];
+        freqs[usize::from(b'a')] = 1;
+        freqs[usize::from(b'b')] = 4095;
+
+        let mut cumulative_freqs = [0; 256];
+
+        for i in 0..255 {
+            cumulative_freqs[i + 1] = cumulative_freqs[i] + freqs[i];
+        }
+
+        assert!(table.set_frequencies(1, &freqs, &cumulative_freqs));
+
+        let entries = &table.entries[CONTEXT_SIZE..];
+        assert_eq!(entries[0], u32::from(b'a'));
+        assert_eq!(entries[1], (4094 << 8) | u32::from(b'b'));
+        assert_eq!(entries[4095], (4094 << 20) | (4094 << 8) | u32::from(b'b'));
+
+        freqs[usize::from(b'b')] = 4094;
+        assert!(!table.set_frequencies(0, &freqs, &cumulative_freqs));
+    }
+}
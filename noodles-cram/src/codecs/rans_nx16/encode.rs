@@ -10,6 +10,19 @@ use byteorder::WriteBytesExt;
 use super::Flags;
 use crate::io::writer::num::write_uint7;
 
+/// Encodes data using the rANS Nx16 codec.
+///
+/// The flags select the order (`ORDER`), number of interleaved states (`N32`), and which
+/// transforms are applied (`STRIPE`, `RLE`, `PACK`, and `CAT`). If `NO_SIZE` is set, the
+/// uncompressed size is not written and must be passed to [`super::decode`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_nx16::{self, Flags};
+/// let compressed_data = rans_nx16::encode(Flags::ORDER | Flags::N32, b"noodles")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(mut flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     let mut src = src.to_vec();
     let mut dst = Vec::new();
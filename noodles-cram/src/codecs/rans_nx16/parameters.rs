@@ -0,0 +1,138 @@
+use super::Flags;
+use crate::codecs::rans_4x8::Order;
+
+/// rANS Nx16 encoding parameters.
+///
+/// This is an alternative to setting [`Flags`] directly. By default, data is order-0 encoded with
+/// 4 interleaved states, no transforms, and the uncompressed size.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::{
+///     rans_4x8::Order,
+///     rans_nx16::{self, Flags, Parameters},
+/// };
+///
+/// let parameters = Parameters::default().set_order(Order::One).set_rle(true);
+/// assert_eq!(Flags::from(parameters), Flags::ORDER | Flags::RLE);
+///
+/// let compressed_data = rans_nx16::encode(parameters.into(), b"noodles")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Parameters {
+    order: Order,
+    n32: bool,
+    stripe: bool,
+    rle: bool,
+    pack: bool,
+    cat: bool,
+    size: bool,
+}
+
+impl Parameters {
+    /// Sets the order of the entropy coder.
+    pub fn set_order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether 32 rANS states are interleaved instead of 4.
+    pub fn set_n32(mut self, n32: bool) -> Self {
+        self.n32 = n32;
+        self
+    }
+
+    /// Sets whether the data is split into 4 interleaved byte streams.
+    pub fn set_stripe(mut self, stripe: bool) -> Self {
+        self.stripe = stripe;
+        self
+    }
+
+    /// Sets whether runs are run-length encoded.
+    pub fn set_rle(mut self, rle: bool) -> Self {
+        self.rle = rle;
+        self
+    }
+
+    /// Sets whether symbols are bit packed when there are at most 16 distinct values.
+    pub fn set_pack(mut self, pack: bool) -> Self {
+        self.pack = pack;
+        self
+    }
+
+    /// Sets whether the data is stored uncompressed.
+    pub fn set_cat(mut self, cat: bool) -> Self {
+        self.cat = cat;
+        self
+    }
+
+    /// Sets whether the uncompressed size is written.
+    ///
+    /// If not, the uncompressed size must be passed to [`super::decode`].
+    pub fn set_size(mut self, size: bool) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            order: Order::Zero,
+            n32: false,
+            stripe: false,
+            rle: false,
+            pack: false,
+            cat: false,
+            size: true,
+        }
+    }
+}
+
+impl From<Parameters> for Flags {
+    fn from(parameters: Parameters) -> Self {
+        let mut flags = Self::empty();
+
+        flags.set(Self::ORDER, parameters.order == Order::One);
+        flags.set(Self::N32, parameters.n32);
+        flags.set(Self::STRIPE, parameters.stripe);
+        flags.set(Self::RLE, parameters.rle);
+        flags.set(Self::PACK, parameters.pack);
+        flags.set(Self::CAT, parameters.cat);
+        flags.set(Self::NO_SIZE, !parameters.size);
+
+        flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parameters_for_flags() {
+        assert_eq!(Flags::from(Parameters::default()), Flags::empty());
+
+        let parameters = Parameters::default()
+            .set_order(Order::One)
+            .set_n32(true)
+            .set_stripe(true)
+            .set_rle(true)
+            .set_pack(true)
+            .set_cat(true)
+            .set_size(false);
+
+        assert_eq!(
+            Flags::from(parameters),
+            Flags::ORDER
+                | Flags::N32
+                | Flags::STRIPE
+                | Flags::RLE
+                | Flags::PACK
+                | Flags::CAT
+                | Flags::NO_SIZE
+        );
+    }
+}
@@ -1,6 +1,9 @@
+//! CRAM container block.
+
 mod compression_method;
 mod content_type;
 
 pub use self::{compression_method::CompressionMethod, content_type::ContentType};
 
+/// A CRAM container block content ID.
 pub type ContentId = i32;
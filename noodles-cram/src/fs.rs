@@ -1,5 +1,5 @@
 //! CRAM filesystem operations.
 
-mod index;
+pub(crate) mod index;
 
 pub use self::index::index;
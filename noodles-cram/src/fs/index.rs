@@ -1,4 +1,10 @@
-use std::{cmp, collections::HashMap, fs::File, io, path::Path};
+use std::{
+    cmp,
+    collections::HashMap,
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
 
 use noodles_core::Position;
 use noodles_fasta as fasta;
@@ -9,7 +15,10 @@ use crate::{
     crai,
     io::{
         Reader,
-        reader::{Container, container::Slice},
+        reader::{
+            Container, Fields,
+            container::{Slice, slice::Projection},
+        },
     },
 };
 
@@ -27,6 +36,13 @@ where
     P: AsRef<Path>,
 {
     let mut reader = File::open(src).map(Reader::new)?;
+    index_from_reader(&mut reader)
+}
+
+pub(crate) fn index_from_reader<R>(reader: &mut Reader<R>) -> io::Result<crai::Index>
+where
+    R: Read + Seek,
+{
     let header = reader.read_header()?;
 
     let mut index = Vec::new();
@@ -40,36 +56,54 @@ where
             n => n,
         };
 
-        let compression_header = container.compression_header()?;
+        push_container_index_records(
+            &mut index,
+            &header,
+            &container,
+            container_position,
+            container_len,
+        )?;
 
-        let landmarks = container.header().landmarks();
-        let slice_count = landmarks.len();
+        container_position = reader.position()?;
+    }
 
-        for (i, result) in container.slices().enumerate() {
-            let slice = result?;
-            let landmark = landmarks[i];
+    Ok(index)
+}
 
-            let slice_length = if i < slice_count - 1 {
-                landmarks[i + 1] - landmark
-            } else {
-                container_len - landmark
-            };
-
-            push_index_records(
-                &mut index,
-                &header,
-                &compression_header,
-                &slice,
-                container_position,
-                landmark as u64,
-                slice_length as u64,
-            )?;
-        }
+fn push_container_index_records(
+    index: &mut crai::Index,
+    header: &sam::Header,
+    container: &Container,
+    container_position: u64,
+    container_len: usize,
+) -> io::Result<()> {
+    let compression_header = container.compression_header()?;
 
-        container_position = reader.position()?;
+    let landmarks = container.header().landmarks();
+    let slice_count = landmarks.len();
+
+    for (i, result) in container.slices().enumerate() {
+        let slice = result?;
+        let landmark = landmarks[i];
+
+        let slice_length = if i < slice_count - 1 {
+            landmarks[i + 1] - landmark
+        } else {
+            container_len - landmark
+        };
+
+        push_index_records(
+            index,
+            header,
+            &compression_header,
+            &slice,
+            container_position,
+            landmark as u64,
+            slice_length as u64,
+        )?;
     }
 
-    Ok(index)
+    Ok(())
 }
 
 fn push_index_records(
@@ -131,14 +165,18 @@ fn push_index_records_for_multi_reference_slice(
         SliceReferenceSequenceAlignmentRangeInclusive,
     > = HashMap::new();
 
-    let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+    // Only positions are needed to build index records.
+    let projection = Projection::new(compression_header, Fields::empty());
+
+    let (core_data_src, external_data_srcs) = slice.decode_blocks_with_projection(&projection)?;
 
-    for record in slice.records(
-        fasta::Repository::default(), // TODO
+    for record in slice.records_with_projection(
+        fasta::Repository::default(),
         header,
         compression_header,
         &core_data_src,
         &external_data_srcs,
+        &projection,
     )? {
         let range = reference_sequence_ids
             .entry(record.reference_sequence_id)
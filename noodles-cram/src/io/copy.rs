@@ -0,0 +1,482 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use noodles_core::{Region, region::Interval};
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+use super::{
+    Reader, Writer,
+    reader::{Container, container::slice::Projection},
+};
+use crate::crai;
+
+/// Copies all containers from a CRAM reader to a CRAM writer without re-encoding records.
+///
+/// The reader must be positioned at the start of a container, i.e., its header must have been
+/// read. The source header must be compatible with the destination header, i.e., both must have
+/// the same reference sequences and read groups in the same order.
+///
+/// This can be used to concatenate CRAM files. The record counters of copied containers are
+/// rewritten to follow the records already written.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_cram as cram;
+///
+/// let mut readers = ["sample.1.cram", "sample.2.cram"]
+///     .into_iter()
+///     .map(|src| File::open(src).map(cram::io::Reader::new))
+///     .collect::<io::Result<Vec<_>>>()?;
+///
+/// let headers = readers
+///     .iter_mut()
+///     .map(|reader| reader.read_header())
+///     .collect::<io::Result<Vec<_>>>()?;
+///
+/// let header = &headers[0];
+///
+/// let mut writer = cram::io::Writer::new(io::sink());
+/// writer.write_header(header)?;
+///
+/// for (reader, src_header) in readers.iter_mut().zip(&headers) {
+///     cram::io::copy_containers(reader, src_header, &mut writer, header)?;
+/// }
+///
+/// writer.try_finish(header)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn copy_containers<R, W>(
+    reader: &mut Reader<R>,
+    src_header: &sam::Header,
+    writer: &mut Writer<W>,
+    dst_header: &sam::Header,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    validate_headers(src_header, dst_header)?;
+
+    let mut container = Container::default();
+
+    while reader.read_container(&mut container)? != 0 {
+        writer.write_container(dst_header, &container)?;
+    }
+
+    Ok(())
+}
+
+/// Copies records that intersect the given region from a CRAM reader to a CRAM writer.
+///
+/// Containers that only have records within the region are copied verbatim. Only containers on
+/// the boundaries of the region are decoded, and their intersecting records are re-encoded.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_cram::{self as cram, crai};
+///
+/// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+/// let header = reader.read_header()?;
+/// let index = crai::fs::read("sample.cram.crai")?;
+///
+/// let mut writer = cram::io::Writer::new(io::sink());
+/// writer.write_header(&header)?;
+///
+/// let region = "sq0:8-13".parse()?;
+/// cram::io::copy_region(&mut reader, &header, &index, &region, &mut writer)?;
+///
+/// writer.try_finish(&header)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn copy_region<R, W>(
+    reader: &mut Reader<R>,
+    header: &sam::Header,
+    index: &crai::Index,
+    region: &Region,
+    writer: &mut Writer<W>,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+    W: Write,
+{
+    let reference_sequence_id = header
+        .reference_sequences()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid reference sequence name",
+            )
+        })?;
+
+    let interval = region.interval();
+
+    let mut offsets: Vec<_> = index
+        .iter()
+        .filter(|record| {
+            record.reference_sequence_id() == Some(reference_sequence_id)
+                && record_intersects(record, interval)
+        })
+        .map(|record| record.offset())
+        .collect();
+
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    let mut container = Container::default();
+
+    for offset in offsets {
+        reader.seek(SeekFrom::Start(offset))?;
+
+        if reader.read_container(&mut container)? == 0 {
+            break;
+        }
+
+        let is_contained = index
+            .iter()
+            .filter(|record| record.offset() == offset)
+            .all(|record| {
+                record.reference_sequence_id() == Some(reference_sequence_id)
+                    && record_is_contained(record, interval)
+            });
+
+        if is_contained {
+            writer.write_container(header, &container)?;
+        } else {
+            for record in read_container_records(reader, header, &container)? {
+                if alignment_record_intersects(&record, interval) {
+                    writer.write_alignment_record(header, &record)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_headers(src_header: &sam::Header, dst_header: &sam::Header) -> io::Result<()> {
+    let src_reference_sequences = src_header.reference_sequences();
+    let dst_reference_sequences = dst_header.reference_sequences();
+
+    let reference_sequences_are_eq = src_reference_sequences.len() == dst_reference_sequences.len()
+        && src_reference_sequences
+            .iter()
+            .zip(dst_reference_sequences)
+            .all(|((src_name, src_map), (dst_name, dst_map))| {
+                src_name == dst_name && src_map.length() == dst_map.length()
+            });
+
+    if !reference_sequences_are_eq {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "incompatible headers: reference sequences differ",
+        ));
+    }
+
+    // Read group IDs are stored as indices into the header read groups.
+    let read_groups_are_eq = src_header
+        .read_groups()
+        .keys()
+        .eq(dst_header.read_groups().keys());
+
+    if !read_groups_are_eq {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "incompatible headers: read groups differ",
+        ));
+    }
+
+    Ok(())
+}
+
+fn record_interval(record: &crai::Record) -> Option<Interval> {
+    let start = record.alignment_start()?;
+    let end = start.checked_add(record.alignment_span().checked_sub(1)?)?;
+    Some((start..=end).into())
+}
+
+fn record_intersects(record: &crai::Record, interval: Interval) -> bool {
+    record_interval(record).is_some_and(|record_interval| record_interval.intersects(interval))
+}
+
+fn record_is_contained(record: &crai::Record, interval: Interval) -> bool {
+    record_interval(record).is_some_and(|record_interval| {
+        record_interval
+            .start()
+            .zip(record_interval.end())
+            .is_some_and(|(start, end)| interval.contains(start) && interval.contains(end))
+    })
+}
+
+fn alignment_record_intersects(record: &sam::alignment::RecordBuf, interval: Interval) -> bool {
+    match (record.alignment_start(), record.alignment_end()) {
+        (Some(start), Some(end)) => {
+            let alignment_interval = (start..=end).into();
+            interval.intersects(alignment_interval)
+        }
+        _ => false,
+    }
+}
+
+fn read_container_records<R>(
+    reader: &Reader<R>,
+    header: &sam::Header,
+    container: &Container,
+) -> io::Result<Vec<sam::alignment::RecordBuf>>
+where
+    R: Read,
+{
+    let compression_header = container.compression_header()?;
+    let projection = Projection::all();
+
+    let mut records = Vec::with_capacity(container.header().record_count());
+
+    for result in container.slices() {
+        let slice = result?;
+
+        let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+
+        for record in slice.records_with_projection(
+            reader.reference_sequence_repository().clone(),
+            header,
+            &compression_header,
+            &core_data_src,
+            &external_data_srcs,
+            &projection,
+        )? {
+            let record = sam::alignment::RecordBuf::try_from_alignment_record(header, &record)?;
+            records.push(record);
+        }
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+            record_buf::{Cigar, QualityScores, Sequence},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+
+    const SQ0_LENGTH: usize = 100;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(SQ0_LENGTH)?),
+            )
+            .build())
+    }
+
+    fn build_record(
+        alignment_start: usize,
+    ) -> Result<sam::alignment::RecordBuf, Box<dyn std::error::Error>> {
+        Ok(sam::alignment::RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+            .set_sequence(Sequence::from(b"NNNN"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .build())
+    }
+
+    fn reference_sequence_repository() -> noodles_fasta::Repository {
+        use noodles_fasta::{self as fasta, record::Definition};
+
+        noodles_fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            fasta::record::Sequence::from(vec![b'N'; SQ0_LENGTH]),
+        )])
+    }
+
+    fn write_container(
+        header: &sam::Header,
+        records: &[sam::alignment::RecordBuf],
+    ) -> io::Result<Vec<u8>> {
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        writer.try_finish(header)?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    fn concat(header: &sam::Header, srcs: &[Vec<u8>]) -> io::Result<Vec<u8>> {
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository())
+            .build_from_writer(Vec::new());
+        writer.write_header(header)?;
+
+        for src in srcs {
+            let mut reader = Reader::new(&src[..]);
+            let src_header = reader.read_header()?;
+            copy_containers(&mut reader, &src_header, &mut writer, header)?;
+        }
+
+        writer.try_finish(header)?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    fn read_records(src: &[u8]) -> io::Result<Vec<sam::alignment::RecordBuf>> {
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository())
+            .build_from_reader(src);
+
+        let header = reader.read_header()?;
+        reader.records(&header).collect()
+    }
+
+    fn read_record_counters(src: &[u8]) -> io::Result<Vec<u64>> {
+        let mut reader = Reader::new(src);
+        reader.read_header()?;
+
+        let mut container = Container::default();
+        let mut record_counters = Vec::new();
+
+        while reader.read_container(&mut container)? != 0 {
+            record_counters.push(container.header().record_counter());
+
+            for result in container.slices() {
+                let slice = result?;
+                assert_eq!(
+                    slice.header().record_counter(),
+                    container.header().record_counter()
+                );
+            }
+        }
+
+        Ok(record_counters)
+    }
+
+    #[test]
+    fn test_copy_containers() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let containers = [
+            vec![build_record(1)?, build_record(5)?],
+            vec![build_record(8)?],
+            vec![build_record(13)?],
+        ];
+
+        let srcs = containers
+            .iter()
+            .map(|records| write_container(&header, records))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let dst = concat(&header, &srcs)?;
+
+        assert_eq!(read_record_counters(&dst)?, [0, 2, 3]);
+
+        let actual = read_records(&dst)?;
+        let expected: Vec<_> = containers.into_iter().flatten().collect();
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&expected) {
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_containers_with_incompatible_headers() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let src = write_container(&header, &[build_record(1)?])?;
+
+        let mut reader = Reader::new(&src[..]);
+        let src_header = reader.read_header()?;
+
+        let dst_header = sam::Header::default();
+        let mut writer = Writer::new(io::sink());
+
+        assert!(matches!(
+            copy_containers(&mut reader, &src_header, &mut writer, &dst_header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_region() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let containers = [
+            vec![build_record(1)?, build_record(5)?],
+            vec![build_record(21)?, build_record(25)?],
+            vec![build_record(41)?, build_record(61)?],
+        ];
+
+        let srcs = containers
+            .iter()
+            .map(|records| write_container(&header, records))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let src = concat(&header, &srcs)?;
+
+        let mut reader = Reader::new(io::Cursor::new(&src));
+        let index = crate::fs::index::index_from_reader(&mut reader)?;
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository())
+            .build_from_reader(io::Cursor::new(&src));
+        reader.read_header()?;
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository())
+            .build_from_writer(Vec::new());
+        writer.write_header(&header)?;
+
+        let region = "sq0:6-44".parse()?;
+        copy_region(&mut reader, &header, &index, &region, &mut writer)?;
+
+        writer.try_finish(&header)?;
+
+        let dst = writer.get_ref();
+
+        // The first container is re-encoded, the second is copied, and the third is re-encoded.
+        assert_eq!(read_record_counters(dst)?, [0, 1, 3]);
+
+        let actual: Vec<_> = read_records(dst)?
+            .iter()
+            .map(|record| record.alignment_start())
+            .collect();
+
+        let expected = [5, 21, 25, 41]
+            .into_iter()
+            .map(Position::new)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -3,16 +3,20 @@
 mod builder;
 
 pub use self::builder::Builder;
+pub(crate) use self::builder::build_index_src;
 
-use std::io::{self, Read, Seek};
+use std::{
+    borrow::Cow,
+    io::{self, Read, Seek},
+};
 
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::{
     Reader,
-    reader::{Container, Query, Records},
+    reader::{Container, Query, QueryRegions, Records},
 };
 use crate::{FileDefinition, crai};
 
@@ -20,6 +24,7 @@ use crate::{FileDefinition, crai};
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: crai::Index,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R>
@@ -31,6 +36,7 @@ where
         Self {
             inner: Reader::new(inner),
             index,
+            aliases: Aliases::default(),
         }
     }
 
@@ -83,6 +89,16 @@ where
     pub fn index(&self) -> &crai::Index {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -95,6 +111,32 @@ where
         header: &'h sam::Header,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, 'r, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = resolve_region(&self.aliases, header, region);
+        self.inner.query(header, &self.index, &region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| resolve_region(&self.aliases, header, region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
+    }
+}
+
+fn resolve_region<'r>(
+    aliases: &Aliases,
+    header: &sam::Header,
+    region: &'r Region,
+) -> Cow<'r, Region> {
+    let reference_sequences = header.reference_sequences();
+    aliases.resolve_region(region, |name| reference_sequences.contains_key(name))
 }
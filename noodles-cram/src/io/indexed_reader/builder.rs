@@ -5,6 +5,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use noodles_core::region::Aliases;
 use noodles_fasta as fasta;
 
 use super::IndexedReader;
@@ -15,6 +16,7 @@ use crate::crai;
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     index: Option<crai::Index>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -52,6 +54,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the header are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_cram::io::indexed_reader::Builder;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed CRAM reader from a path.
     ///
     /// If no index is set, this will attempt to read an associated index at `<src>.crai`.
@@ -104,11 +126,15 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader { inner, index })
+        Ok(IndexedReader {
+            inner,
+            index,
+            aliases: self.aliases,
+        })
     }
 }
 
-fn build_index_src<P>(src: P) -> PathBuf
+pub(crate) fn build_index_src<P>(src: P) -> PathBuf
 where
     P: AsRef<Path>,
 {
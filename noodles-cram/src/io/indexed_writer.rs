@@ -0,0 +1,265 @@
+//! Indexed CRAM writer.
+
+mod builder;
+
+use std::{
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+pub use self::builder::Builder;
+use super::Writer;
+use crate::crai;
+
+/// An indexed CRAM writer.
+///
+/// An indexed CRAM writer builds a CRAM index (CRAI) while writing records, which avoids reading
+/// the file back to index it (cf. [`crate::fs::index`]). Each container is indexed as soon as it
+/// is written.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram as cram;
+/// use noodles_sam::{self as sam, alignment::io::Write};
+///
+/// let mut writer = cram::io::IndexedWriter::new(Vec::new());
+///
+/// let header = sam::Header::default();
+/// writer.write_header(&header)?;
+///
+/// let record = sam::Record::default();
+/// writer.write_alignment_record(&header, &record)?;
+///
+/// let index = writer.finish(&header)?;
+/// assert_eq!(index.len(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<Recorder<W>>,
+    index: crai::Index,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed CRAM writer with default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let writer = cram::io::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Builder::default().build_from_writer(inner)
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let writer = cram::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner.get_ref().inner
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// This writes the CRAM magic number, the file definition, and file header using the given SAM
+    /// header.
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a CRAM record.
+    pub fn write_record(
+        &mut self,
+        header: &sam::Header,
+        record: &crate::Record<'_>,
+    ) -> io::Result<()> {
+        self.write_alignment_record(header, record)
+    }
+
+    /// Shuts down the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.crai`.
+    pub fn finish(&mut self, header: &sam::Header) -> io::Result<crai::Index> {
+        let container_position = self.inner.get_ref().position;
+        self.inner
+            .flush_with_index(header, Some((&mut self.index, container_position)))?;
+        self.inner.try_finish(header)?;
+
+        let index = mem::take(&mut self.index);
+
+        if let Some(dst) = self.index_dst.take() {
+            crai::fs::write(dst, &index)?;
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let writer = cram::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner().inner
+    }
+}
+
+impl<W> sam::alignment::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        let container_position = self.inner.get_ref().position;
+
+        self.inner.add_record_with_index(
+            header,
+            record,
+            Some((&mut self.index, container_position)),
+        )
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        IndexedWriter::finish(self, header).map(|_| ())
+    }
+}
+
+// A writer that tracks the stream position, i.e., the position of the next container.
+struct Recorder<W> {
+    inner: W,
+    position: u64,
+}
+
+impl<W> Recorder<W> {
+    fn new(inner: W) -> Self {
+        Self { inner, position: 0 }
+    }
+}
+
+impl<W> Write for Recorder<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use noodles_core::Position;
+    use noodles_fasta::{self as fasta, record::Definition};
+    use noodles_sam::{
+        alignment::{
+            RecordBuf,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+            record_buf::{QualityScores, Sequence},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::io::Reader;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE_LENGTH: NonZero<usize> = NonZero::new(100000).unwrap();
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(REFERENCE_SEQUENCE_LENGTH),
+            )
+            .build();
+
+        let reference_sequence_repository = fasta::Repository::new(
+            ["sq0", "sq1"]
+                .into_iter()
+                .map(|name| {
+                    fasta::Record::new(
+                        Definition::new(name, None),
+                        fasta::record::Sequence::from(vec![b'N'; REFERENCE_SEQUENCE_LENGTH.get()]),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let records = [(0, 1), (0, 20000), (1, 8), (1, 70000)];
+
+        // Exceeds the number of records per container.
+        for i in 0..crate::io::writer::RECORDS_PER_CONTAINER + 1 {
+            let (reference_sequence_id, start) = records[i % records.len()];
+
+            let record = RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_flags(Flags::empty())
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"NNNN"))
+                .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let actual = writer.finish(&header)?;
+
+        let data = writer.into_inner();
+        let expected =
+            crate::fs::index::index_from_reader(&mut Reader::new(io::Cursor::new(data)))?;
+
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+use noodles_fasta as fasta;
+
+use super::{IndexedWriter, Recorder};
+use crate::io::{indexed_reader::build_index_src, writer};
+
+/// An indexed CRAM writer builder.
+///
+/// Writer options can be set using a CRAM writer builder, which can be converted to an indexed
+/// CRAM writer builder.
+#[derive(Default)]
+pub struct Builder {
+    inner: writer::Builder,
+}
+
+impl Builder {
+    /// Sets the reference sequence repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::indexed_writer::Builder;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let repository = fasta::Repository::default();
+    /// let builder = Builder::default().set_reference_sequence_repository(repository);
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .set_reference_sequence_repository(reference_sequence_repository);
+
+        self
+    }
+
+    /// Builds an indexed CRAM writer from a path.
+    ///
+    /// When the writer is finished, the index is written to `<dst>.crai`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_cram::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_path("out.cram")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<IndexedWriter<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+        let index_dst = build_index_src(dst);
+
+        let file = File::create(dst)?;
+
+        let mut writer = self.build_from_writer(file);
+        writer.index_dst = Some(index_dst);
+
+        Ok(writer)
+    }
+
+    /// Builds an indexed CRAM writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> IndexedWriter<W>
+    where
+        W: Write,
+    {
+        IndexedWriter {
+            inner: self.inner.build_from_writer(Recorder::new(writer)),
+            index: Vec::new(),
+            index_dst: None,
+        }
+    }
+}
+
+impl From<writer::Builder> for Builder {
+    fn from(inner: writer::Builder) -> Self {
+        Self { inner }
+    }
+}
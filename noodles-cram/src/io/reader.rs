@@ -3,18 +3,23 @@
 mod builder;
 pub(crate) mod collections;
 pub(crate) mod container;
+mod fields;
 pub mod header;
 pub(crate) mod num;
 mod query;
+mod query_regions;
 mod records;
 
 use std::io::{self, Read, Seek, SeekFrom};
 
-use noodles_core::Region;
+use noodles_core::{Region, region::RegionSet};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-pub use self::{builder::Builder, container::Container, query::Query, records::Records};
+pub use self::{
+    builder::Builder, container::Container, fields::Fields, query::Query,
+    query_regions::QueryRegions, records::Records,
+};
 use self::{container::read_container, header::read_header};
 use crate::{FileDefinition, crai};
 
@@ -43,6 +48,7 @@ use crate::{FileDefinition, crai};
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    fields: Fields,
 }
 
 impl<R> Reader<R> {
@@ -336,6 +342,57 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The regions are sorted and merged before selecting containers from the index. This reads
+    /// each container at most once, and each record is paired with the indices of the regions it
+    /// intersects, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_cram::{self as cram, crai};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = crai::fs::read("sample.cram.crai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        index: &crai::Index,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        let region_set = regions
+            .iter()
+            .map(|region| {
+                header
+                    .reference_sequences()
+                    .get_index_of(region.name())
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "invalid reference sequence name",
+                        )
+                    })
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map(RegionSet::new)?;
+
+        Ok(QueryRegions::new(self, header, index, region_set))
+    }
 }
 
 impl<R> sam::alignment::io::Read<R> for Reader<R>
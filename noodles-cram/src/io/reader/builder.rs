@@ -6,12 +6,13 @@ use std::{
 
 use noodles_fasta as fasta;
 
-use super::Reader;
+use super::{Fields, Reader};
 
 /// A CRAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    fields: Fields,
 }
 
 impl Builder {
@@ -36,6 +37,23 @@ impl Builder {
         self
     }
 
+    /// Sets the fields to decode.
+    ///
+    /// By default, all fields are decoded. Data series only used by fields that are not in the
+    /// projection are skipped, and their external blocks are not decompressed. When the sequence
+    /// is not requested, the reference sequence is not fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::{Builder, Fields};
+    /// let builder = Builder::default().set_fields(Fields::empty());
+    /// ```
+    pub fn set_fields(mut self, fields: Fields) -> Self {
+        self.fields = fields;
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -69,6 +87,7 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            fields: self.fields,
         }
     }
 }
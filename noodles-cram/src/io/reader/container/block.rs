@@ -22,35 +22,7 @@ pub struct Block<'c> {
 
 impl Block<'_> {
     pub fn decode(&self) -> io::Result<Vec<u8>> {
-        use crate::codecs::{aac, bzip2, fqzcomp, gzip, lzma, name_tokenizer, rans_4x8, rans_nx16};
-
-        match self.compression_method {
-            CompressionMethod::None => Ok(self.src.to_vec()),
-            CompressionMethod::Gzip => {
-                let mut dst = vec![0; self.uncompressed_size];
-                gzip::decode(self.src, &mut dst)?;
-                Ok(dst)
-            }
-            CompressionMethod::Bzip2 => {
-                let mut dst = vec![0; self.uncompressed_size];
-                bzip2::decode(self.src, &mut dst)?;
-                Ok(dst)
-            }
-            CompressionMethod::Lzma => {
-                let mut dst = vec![0; self.uncompressed_size];
-                lzma::decode(self.src, &mut dst)?;
-                Ok(dst)
-            }
-            CompressionMethod::Rans4x8 => rans_4x8::decode(&mut &self.src[..]),
-            CompressionMethod::RansNx16 => {
-                rans_nx16::decode(&mut &self.src[..], self.uncompressed_size)
-            }
-            CompressionMethod::AdaptiveArithmeticCoding => {
-                aac::decode(&mut &self.src[..], self.uncompressed_size)
-            }
-            CompressionMethod::Fqzcomp => fqzcomp::decode(&mut &self.src[..]),
-            CompressionMethod::NameTokenizer => name_tokenizer::decode(&mut &self.src[..]),
-        }
+        crate::codecs::decode(self.compression_method, self.src, self.uncompressed_size)
     }
 }
 
@@ -1,4 +1,5 @@
 mod header;
+mod projection;
 pub mod records;
 
 use std::{borrow::Cow, io};
@@ -7,6 +8,7 @@ use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, alignment::Record as _};
 
+pub(crate) use self::projection::Projection;
 use self::{
     header::read_header,
     records::{ExternalDataReaders, Records},
@@ -19,8 +21,8 @@ use crate::{
         block::{self, ContentType},
         slice::Header,
     },
-    io::BitReader,
-    record::Feature,
+    io::{BitReader, reader::Fields},
+    record::{Feature, Flags},
 };
 
 /// A container slice.
@@ -37,20 +39,47 @@ impl<'c> Slice<'c> {
         &self.header
     }
 
+    /// Returns the raw core data and external data blocks.
+    pub(crate) fn blocks_src(&self) -> &'c [u8] {
+        self.src
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn decode_blocks(&self) -> io::Result<(Vec<u8>, Vec<(block::ContentId, Vec<u8>)>)> {
+        self.decode_blocks_with_projection(&Projection::all())
+    }
+
+    /// Decodes the core data block and the external blocks used by the given projection.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn decode_blocks_with_projection(
+        &self,
+        projection: &Projection,
+    ) -> io::Result<(Vec<u8>, Vec<(block::ContentId, Vec<u8>)>)> {
         let mut src = self.src;
 
         let block = read_block_as(&mut src, ContentType::CoreData)?;
         let core_data_src = block.decode()?;
 
+        let embedded_reference_bases_block_content_id =
+            if projection.fields().contains(Fields::SEQUENCE) {
+                self.header.embedded_reference_bases_block_content_id()
+            } else {
+                None
+            };
+
         let external_data_block_count = self.header.block_count() - 1;
-        let external_data_srcs = (0..external_data_block_count)
-            .map(|_| {
-                let block = read_block_as(&mut src, ContentType::ExternalData)?;
-                block.decode().map(|src| (block.content_id, src))
-            })
-            .collect::<io::Result<_>>()?;
+        let mut external_data_srcs = Vec::with_capacity(external_data_block_count);
+
+        for _ in 0..external_data_block_count {
+            let block = read_block_as(&mut src, ContentType::ExternalData)?;
+
+            if projection.is_block_used(block.content_id)
+                || Some(block.content_id) == embedded_reference_bases_block_content_id
+            {
+                let dst = block.decode()?;
+                external_data_srcs.push((block.content_id, dst));
+            }
+        }
 
         Ok((core_data_src, external_data_srcs))
     }
@@ -98,7 +127,28 @@ impl<'c> Slice<'c> {
         compression_header: &'ch CompressionHeader,
         core_data_src: &'c [u8],
         external_data_srcs: &'c [(block::ContentId, Vec<u8>)],
-    ) -> io::Result<Vec<Record>> {
+    ) -> io::Result<Vec<Record<'_>>> {
+        self.records_with_projection(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            core_data_src,
+            external_data_srcs,
+            &Projection::all(),
+        )
+    }
+
+    /// Reads and returns a list of records in this slice, only decoding the fields in the given
+    /// projection.
+    pub(crate) fn records_with_projection<'h: 'c, 'ch: 'c>(
+        &self,
+        reference_sequence_repository: fasta::Repository,
+        header: &'h sam::Header,
+        compression_header: &'ch CompressionHeader,
+        core_data_src: &'c [u8],
+        external_data_srcs: &'c [(block::ContentId, Vec<u8>)],
+        projection: &Projection,
+    ) -> io::Result<Vec<Record<'_>>> {
         let core_data_reader = BitReader::new(core_data_src);
 
         let mut external_data_readers = ExternalDataReaders::new();
@@ -116,15 +166,22 @@ impl<'c> Slice<'c> {
             external_data_readers,
             reference_sequence_context,
             initial_id,
+            projection,
         );
 
-        let slice_reference_sequence = get_slice_reference_sequence(
-            &reference_sequence_repository.clone(),
-            header,
-            compression_header,
-            &self.header,
-            external_data_srcs,
-        )?;
+        let sequence_is_requested = projection.fields().contains(Fields::SEQUENCE);
+
+        let slice_reference_sequence = if sequence_is_requested {
+            get_slice_reference_sequence(
+                &reference_sequence_repository.clone(),
+                header,
+                compression_header,
+                &self.header,
+                external_data_srcs,
+            )?
+        } else {
+            None
+        };
 
         let substitution_matrix = compression_header.preservation_map().substitution_matrix();
 
@@ -135,7 +192,10 @@ impl<'c> Slice<'c> {
 
             record.header = Some(header);
 
-            if !record.bam_flags.is_unmapped() && !record.cram_flags.sequence_is_missing() {
+            if !sequence_is_requested {
+                record.cram_flags.insert(Flags::SEQUENCE_IS_MISSING);
+                record.sequence = &[];
+            } else if !record.bam_flags.is_unmapped() && !record.cram_flags.sequence_is_missing() {
                 record.reference_sequence = if reference_sequence_context.is_many() {
                     get_record_reference_sequence(&reference_sequence_repository, header, record)?
                 } else {
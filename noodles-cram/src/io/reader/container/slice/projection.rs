@@ -0,0 +1,346 @@
+use std::collections::HashSet;
+
+use crate::{
+    container::{
+        CompressionHeader, block,
+        compression_header::{
+            Encoding,
+            data_series_encodings::{DataSeries, data_series::STANDARD_DATA_SERIES},
+            encoding::codec::{Byte, ByteArray, Integer},
+        },
+    },
+    io::reader::Fields,
+};
+
+/// A plan of which data series and blocks to decode for a set of fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Projection {
+    fields: Fields,
+    skipped_data_series: HashSet<DataSeries>,
+    skipped_tag_ids: HashSet<block::ContentId>,
+    block_content_ids: Option<HashSet<block::ContentId>>,
+}
+
+impl Projection {
+    /// Creates a projection that decodes all data series and blocks.
+    pub fn all() -> Self {
+        Self {
+            fields: Fields::all(),
+            skipped_data_series: HashSet::new(),
+            skipped_tag_ids: HashSet::new(),
+            block_content_ids: None,
+        }
+    }
+
+    /// Creates a projection for the given fields using the encodings of a compression header.
+    ///
+    /// A data series or tag is only skipped when it does not read from the core data block and
+    /// none of its external blocks are shared with a decoded data series or tag.
+    pub fn new(compression_header: &CompressionHeader, fields: Fields) -> Self {
+        if fields.is_all() {
+            return Self::all();
+        }
+
+        let mut items = Vec::new();
+
+        for &data_series in STANDARD_DATA_SERIES {
+            if let Some(usage) = data_series_usage(compression_header, data_series) {
+                let is_candidate = !fields.contains(data_series_field(data_series));
+                items.push((Item::DataSeries(data_series), usage, is_candidate));
+            }
+        }
+
+        for (&id, encoding) in compression_header.tag_encodings() {
+            let mut usage = Usage::default();
+            usage.push_byte_array(encoding);
+            let is_candidate = !fields.contains(Fields::DATA);
+            items.push((Item::Tag(id), usage, is_candidate));
+        }
+
+        let mut is_skipped: Vec<_> = items
+            .iter()
+            .map(|(_, usage, is_candidate)| *is_candidate && !usage.uses_core_data)
+            .collect();
+
+        let block_content_ids = loop {
+            let block_content_ids: HashSet<_> = items
+                .iter()
+                .zip(&is_skipped)
+                .filter(|(_, is_skipped)| !**is_skipped)
+                .flat_map(|((_, usage, _), _)| usage.block_content_ids.iter().copied())
+                .collect();
+
+            let mut is_changed = false;
+
+            for ((_, usage, _), is_skipped) in items.iter().zip(&mut is_skipped) {
+                if *is_skipped
+                    && usage
+                        .block_content_ids
+                        .iter()
+                        .any(|id| block_content_ids.contains(id))
+                {
+                    *is_skipped = false;
+                    is_changed = true;
+                }
+            }
+
+            if !is_changed {
+                break block_content_ids;
+            }
+        };
+
+        let mut skipped_data_series = HashSet::new();
+        let mut skipped_tag_ids = HashSet::new();
+
+        for ((item, _, _), is_skipped) in items.iter().zip(is_skipped) {
+            if is_skipped {
+                match item {
+                    Item::DataSeries(data_series) => {
+                        skipped_data_series.insert(*data_series);
+                    }
+                    Item::Tag(id) => {
+                        skipped_tag_ids.insert(*id);
+                    }
+                }
+            }
+        }
+
+        Self {
+            fields,
+            skipped_data_series,
+            skipped_tag_ids,
+            block_content_ids: Some(block_content_ids),
+        }
+    }
+
+    /// Returns the fields to decode.
+    pub fn fields(&self) -> Fields {
+        self.fields
+    }
+
+    /// Returns whether the given data series is not decoded.
+    pub fn is_data_series_skipped(&self, data_series: DataSeries) -> bool {
+        self.skipped_data_series.contains(&data_series)
+    }
+
+    /// Returns whether the values of the given tag are not decoded.
+    pub fn is_tag_skipped(&self, id: block::ContentId) -> bool {
+        self.skipped_tag_ids.contains(&id)
+    }
+
+    /// Returns whether an external block is to be decompressed.
+    pub fn is_block_used(&self, id: block::ContentId) -> bool {
+        self.block_content_ids
+            .as_ref()
+            .is_none_or(|ids| ids.contains(&id))
+    }
+}
+
+enum Item {
+    DataSeries(DataSeries),
+    Tag(block::ContentId),
+}
+
+#[derive(Default)]
+struct Usage {
+    uses_core_data: bool,
+    block_content_ids: Vec<block::ContentId>,
+}
+
+impl Usage {
+    fn push_integer(&mut self, encoding: &Encoding<Integer>) {
+        match encoding.get() {
+            Integer::External { block_content_id } => {
+                self.block_content_ids.push(*block_content_id)
+            }
+            _ => self.uses_core_data = true,
+        }
+    }
+
+    fn push_byte(&mut self, encoding: &Encoding<Byte>) {
+        match encoding.get() {
+            Byte::External { block_content_id } => self.block_content_ids.push(*block_content_id),
+            Byte::Huffman { .. } => self.uses_core_data = true,
+        }
+    }
+
+    fn push_byte_array(&mut self, encoding: &Encoding<ByteArray>) {
+        match encoding.get() {
+            ByteArray::ByteArrayLength {
+                len_encoding,
+                value_encoding,
+            } => {
+                self.push_integer(len_encoding);
+                self.push_byte(value_encoding);
+            }
+            ByteArray::ByteArrayStop {
+                block_content_id, ..
+            } => self.block_content_ids.push(*block_content_id),
+        }
+    }
+}
+
+fn data_series_field(data_series: DataSeries) -> Fields {
+    match data_series {
+        DataSeries::Names => Fields::NAME,
+        DataSeries::Bases | DataSeries::BaseSubstitutionCodes => Fields::SEQUENCE,
+        DataSeries::QualityScores | DataSeries::StretchesOfQualityScores => Fields::QUALITY_SCORES,
+        _ => Fields::empty(),
+    }
+}
+
+fn data_series_usage(
+    compression_header: &CompressionHeader,
+    data_series: DataSeries,
+) -> Option<Usage> {
+    enum Ref<'a> {
+        Integer(Option<&'a Encoding<Integer>>),
+        Byte(Option<&'a Encoding<Byte>>),
+        ByteArray(Option<&'a Encoding<ByteArray>>),
+    }
+
+    let encodings = compression_header.data_series_encodings();
+
+    let encoding = match data_series {
+        DataSeries::BamFlags => Ref::Integer(encodings.bam_flags()),
+        DataSeries::CramFlags => Ref::Integer(encodings.cram_flags()),
+        DataSeries::ReferenceSequenceIds => Ref::Integer(encodings.reference_sequence_ids()),
+        DataSeries::ReadLengths => Ref::Integer(encodings.read_lengths()),
+        DataSeries::AlignmentStarts => Ref::Integer(encodings.alignment_starts()),
+        DataSeries::ReadGroupIds => Ref::Integer(encodings.read_group_ids()),
+        DataSeries::Names => Ref::ByteArray(encodings.names()),
+        DataSeries::MateFlags => Ref::Integer(encodings.mate_flags()),
+        DataSeries::MateReferenceSequenceIds => {
+            Ref::Integer(encodings.mate_reference_sequence_ids())
+        }
+        DataSeries::MateAlignmentStarts => Ref::Integer(encodings.mate_alignment_starts()),
+        DataSeries::TemplateLengths => Ref::Integer(encodings.template_lengths()),
+        DataSeries::MateDistances => Ref::Integer(encodings.mate_distances()),
+        DataSeries::TagSetIds => Ref::Integer(encodings.tag_set_ids()),
+        DataSeries::FeatureCounts => Ref::Integer(encodings.feature_counts()),
+        DataSeries::FeatureCodes => Ref::Byte(encodings.feature_codes()),
+        DataSeries::FeaturePositionDeltas => Ref::Integer(encodings.feature_position_deltas()),
+        DataSeries::DeletionLengths => Ref::Integer(encodings.deletion_lengths()),
+        DataSeries::StretchesOfBases => Ref::ByteArray(encodings.stretches_of_bases()),
+        DataSeries::StretchesOfQualityScores => {
+            Ref::ByteArray(encodings.stretches_of_quality_scores())
+        }
+        DataSeries::BaseSubstitutionCodes => Ref::Byte(encodings.base_substitution_codes()),
+        DataSeries::InsertionBases => Ref::ByteArray(encodings.insertion_bases()),
+        DataSeries::ReferenceSkipLengths => Ref::Integer(encodings.reference_skip_lengths()),
+        DataSeries::PaddingLengths => Ref::Integer(encodings.padding_lengths()),
+        DataSeries::HardClipLengths => Ref::Integer(encodings.hard_clip_lengths()),
+        DataSeries::SoftClipBases => Ref::ByteArray(encodings.soft_clip_bases()),
+        DataSeries::MappingQualities => Ref::Integer(encodings.mapping_qualities()),
+        DataSeries::Bases => Ref::Byte(encodings.bases()),
+        DataSeries::QualityScores => Ref::Byte(encodings.quality_scores()),
+        DataSeries::ReservedTc | DataSeries::ReservedTn => return None,
+    };
+
+    let mut usage = Usage::default();
+
+    match encoding {
+        Ref::Integer(encoding) => usage.push_integer(encoding?),
+        Ref::Byte(encoding) => usage.push_byte(encoding?),
+        Ref::ByteArray(encoding) => usage.push_byte_array(encoding?),
+    }
+
+    Some(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::compression_header::{
+        DataSeriesEncodings, PreservationMap, TagEncodings,
+    };
+
+    fn build_compression_header(names: Encoding<ByteArray>) -> CompressionHeader {
+        let data_series_encodings = DataSeriesEncodings {
+            bam_flags: Some(Encoding::new(Integer::External {
+                block_content_id: 1,
+            })),
+            names: Some(names),
+            quality_scores: Some(Encoding::new(Byte::External {
+                block_content_id: 2,
+            })),
+            bases: Some(Encoding::new(Byte::External {
+                block_content_id: 2,
+            })),
+            ..Default::default()
+        };
+
+        let tag_encodings: TagEncodings = [(
+            0x4e4d43, // NM:C
+            Encoding::new(ByteArray::ByteArrayLength {
+                len_encoding: Encoding::new(Integer::External {
+                    block_content_id: 3,
+                }),
+                value_encoding: Encoding::new(Byte::External {
+                    block_content_id: 4,
+                }),
+            }),
+        )]
+        .into_iter()
+        .collect();
+
+        CompressionHeader::new(
+            PreservationMap::default(),
+            data_series_encodings,
+            tag_encodings,
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let compression_header =
+            build_compression_header(Encoding::new(ByteArray::ByteArrayStop {
+                stop_byte: 0x00,
+                block_content_id: 5,
+            }));
+
+        let projection = Projection::new(&compression_header, Fields::all());
+        assert_eq!(projection, Projection::all());
+
+        // The bases and quality scores share a block.
+        let projection = Projection::new(&compression_header, Fields::SEQUENCE);
+        assert!(projection.is_data_series_skipped(DataSeries::Names));
+        assert!(!projection.is_data_series_skipped(DataSeries::QualityScores));
+        assert!(!projection.is_data_series_skipped(DataSeries::Bases));
+        assert!(!projection.is_data_series_skipped(DataSeries::BamFlags));
+        assert!(projection.is_tag_skipped(0x4e4d43));
+        assert!(projection.is_block_used(1));
+        assert!(projection.is_block_used(2));
+        assert!(!projection.is_block_used(3));
+        assert!(!projection.is_block_used(4));
+        assert!(!projection.is_block_used(5));
+
+        let projection = Projection::new(&compression_header, Fields::NAME);
+        assert!(!projection.is_data_series_skipped(DataSeries::Names));
+        assert!(projection.is_data_series_skipped(DataSeries::QualityScores));
+        assert!(projection.is_data_series_skipped(DataSeries::Bases));
+        assert!(!projection.is_block_used(2));
+        assert!(!projection.is_block_used(3));
+        assert!(projection.is_block_used(5));
+
+        let projection = Projection::new(&compression_header, Fields::empty());
+        assert!(projection.is_data_series_skipped(DataSeries::QualityScores));
+        assert!(projection.is_data_series_skipped(DataSeries::Bases));
+        assert!(!projection.is_block_used(2));
+    }
+
+    #[test]
+    fn test_new_with_core_data_encoding() {
+        let compression_header =
+            build_compression_header(Encoding::new(ByteArray::ByteArrayLength {
+                len_encoding: Encoding::new(Integer::Beta { offset: 0, len: 8 }),
+                value_encoding: Encoding::new(Byte::External {
+                    block_content_id: 5,
+                }),
+            }));
+
+        let projection = Projection::new(&compression_header, Fields::empty());
+        assert!(!projection.is_data_series_skipped(DataSeries::Names));
+        assert!(projection.is_block_used(5));
+    }
+}
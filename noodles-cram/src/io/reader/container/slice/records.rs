@@ -14,10 +14,12 @@ use crate::{
         CompressionHeader, ReferenceSequenceContext, block,
         compression_header::{data_series_encodings::DataSeries, preservation_map::tag_sets},
     },
-    io::BitReader,
+    io::{BitReader, reader::Fields},
     record::{Feature, Flags, MateFlags, feature},
 };
 
+use super::Projection;
+
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReadRecordError {
@@ -38,22 +40,24 @@ impl fmt::Display for ReadRecordError {
     }
 }
 
-pub struct Records<'c, 'ch: 'c> {
+pub struct Records<'c, 'ch: 'c, 'p> {
     compression_header: &'ch CompressionHeader,
     core_data_reader: BitReader<'c>,
     external_data_readers: ExternalDataReaders<'c>,
     reference_sequence_context: ReferenceSequenceContext,
     id: u64,
     prev_alignment_start: Option<Position>,
+    projection: &'p Projection,
 }
 
-impl<'c, 'ch: 'c> Records<'c, 'ch> {
+impl<'c, 'ch: 'c, 'p> Records<'c, 'ch, 'p> {
     pub fn new(
         compression_header: &'ch CompressionHeader,
         core_data_reader: BitReader<'c>,
         external_data_readers: ExternalDataReaders<'c>,
         reference_sequence_context: ReferenceSequenceContext,
         initial_id: u64,
+        projection: &'p Projection,
     ) -> Self {
         let initial_alignment_start = match reference_sequence_context {
             ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
@@ -67,6 +71,7 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             reference_sequence_context,
             id: initial_id,
             prev_alignment_start: initial_alignment_start,
+            projection,
         }
     }
 
@@ -222,7 +227,12 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     fn read_name(&mut self) -> io::Result<Option<Cow<'c, [u8]>>> {
         const MISSING: &[u8] = b"*\x00";
 
-        self.compression_header
+        if self.projection.is_data_series_skipped(DataSeries::Names) {
+            return Ok(None);
+        }
+
+        let name = self
+            .compression_header
             .data_series_encodings()
             .names()
             .ok_or_else(|| missing_data_series_encoding_error(DataSeries::Names))?
@@ -230,7 +240,13 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             .map(|buf| match buf {
                 MISSING => None,
                 _ => Some(Cow::from(buf)),
-            })
+            })?;
+
+        if self.projection.fields().contains(Fields::NAME) {
+            Ok(name)
+        } else {
+            Ok(None)
+        }
     }
 
     fn read_mate(&mut self, record: &mut Record<'c>) -> io::Result<()> {
@@ -333,11 +349,19 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             .get(tag_set_id)
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing tag set"))?;
 
-        record.data.reserve(tag_set.len());
+        let data_is_requested = self.projection.fields().contains(Fields::DATA);
+
+        if data_is_requested {
+            record.data.reserve(tag_set.len());
+        }
 
         for &key in tag_set {
             let id = block::ContentId::from(key);
 
+            if self.projection.is_tag_skipped(id) {
+                continue;
+            }
+
             let value = self
                 .compression_header
                 .tag_encodings()
@@ -351,7 +375,9 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
                 .decode(&mut self.core_data_reader, &mut self.external_data_readers)
                 .and_then(|src| self::data::read_value(src, key.ty()))?;
 
-            record.data.push((key.tag(), value));
+            if data_is_requested {
+                record.data.push((key.tag(), value));
+            }
         }
 
         Ok(())
@@ -385,6 +411,8 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             record.quality_scores = self.read_quality_scores(record.read_length)?;
         }
 
+        self.discard_unrequested_quality_scores(record);
+
         Ok(())
     }
 
@@ -506,6 +534,13 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     }
 
     fn read_stretches_of_quality_scores(&mut self) -> io::Result<&'c [u8]> {
+        if self
+            .projection
+            .is_data_series_skipped(DataSeries::StretchesOfQualityScores)
+        {
+            return Ok(&[]);
+        }
+
         self.compression_header
             .data_series_encodings()
             .stretches_of_quality_scores()
@@ -516,6 +551,12 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     }
 
     fn read_base(&mut self) -> io::Result<u8> {
+        const MISSING: u8 = b'N';
+
+        if self.projection.is_data_series_skipped(DataSeries::Bases) {
+            return Ok(MISSING);
+        }
+
         self.compression_header
             .data_series_encodings()
             .bases()
@@ -524,6 +565,15 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     }
 
     fn read_quality_score(&mut self) -> io::Result<u8> {
+        const MISSING: u8 = 0xff;
+
+        if self
+            .projection
+            .is_data_series_skipped(DataSeries::QualityScores)
+        {
+            return Ok(MISSING);
+        }
+
         self.compression_header
             .data_series_encodings()
             .quality_scores()
@@ -532,6 +582,13 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     }
 
     fn read_base_substitution_code(&mut self) -> io::Result<u8> {
+        if self
+            .projection
+            .is_data_series_skipped(DataSeries::BaseSubstitutionCodes)
+        {
+            return Ok(0);
+        }
+
         self.compression_header
             .data_series_encodings()
             .base_substitution_codes()
@@ -620,10 +677,25 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             record.quality_scores = self.read_quality_scores(record.read_length)?;
         }
 
+        self.discard_unrequested_quality_scores(record);
+
         Ok(())
     }
 
+    fn discard_unrequested_quality_scores(&self, record: &mut Record<'c>) {
+        if !self.projection.fields().contains(Fields::QUALITY_SCORES) {
+            record
+                .cram_flags
+                .insert(Flags::QUALITY_SCORES_ARE_STORED_AS_ARRAY);
+            record.quality_scores = &[];
+        }
+    }
+
     fn read_sequence(&mut self, read_length: usize) -> io::Result<&'c [u8]> {
+        if self.projection.is_data_series_skipped(DataSeries::Bases) {
+            return Ok(&[]);
+        }
+
         let encoding = self
             .compression_header
             .data_series_encodings()
@@ -640,6 +712,13 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
     fn read_quality_scores(&mut self, read_length: usize) -> io::Result<&'c [u8]> {
         const MISSING: u8 = 0xff;
 
+        if self
+            .projection
+            .is_data_series_skipped(DataSeries::QualityScores)
+        {
+            return Ok(&[]);
+        }
+
         let encoding = self
             .compression_header
             .data_series_encodings()
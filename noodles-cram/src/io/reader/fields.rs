@@ -0,0 +1,45 @@
+bitflags::bitflags! {
+    /// CRAM record fields to decode.
+    ///
+    /// This is a projection of the record fields a reader decodes. Data series that are only used
+    /// by fields not in the projection are skipped, and their external blocks are not
+    /// decompressed. Record flags, positions, mapping qualities, CIGAR operations, and mate
+    /// fields are always decoded.
+    ///
+    /// The default is to decode all fields.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Fields: u8 {
+        /// Read name.
+        ///
+        /// If not decoded, read names are generated from record IDs.
+        const NAME = 0x01;
+        /// Sequence.
+        ///
+        /// If not decoded, the reference sequence is not fetched, and sequences are empty.
+        const SEQUENCE = 0x02;
+        /// Quality scores.
+        ///
+        /// If not decoded, quality scores are empty.
+        const QUALITY_SCORES = 0x04;
+        /// Data.
+        ///
+        /// If not decoded, data only contains the read group (`RG`), if set.
+        const DATA = 0x08;
+    }
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Fields::default(), Fields::all());
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 use noodles_core::region::Interval;
 use noodles_sam as sam;
 
-use super::{Container, Reader};
+use super::{Container, Reader, container::slice::Projection};
 use crate::crai;
 
 /// An iterator over records that intersect a given region.
@@ -60,62 +60,13 @@ where
             return Some(Ok(()));
         }
 
-        if let Err(e) = self.reader.seek(SeekFrom::Start(index_record.offset())) {
-            return Some(Err(e));
-        }
-
-        let mut container = Container::default();
-
-        match self.reader.read_container(&mut container) {
-            Ok(0) => return None,
-            Ok(_) => {}
-            Err(e) => return Some(Err(e)),
-        };
-
-        let compression_header = match container.compression_header() {
-            Ok(compression_header) => compression_header,
-            Err(e) => return Some(Err(e)),
-        };
-
-        let records = container
-            .slices()
-            .map(|result| {
-                let slice = result?;
-
-                let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
-
-                slice
-                    .records(
-                        self.reader.reference_sequence_repository.clone(),
-                        self.header,
-                        &compression_header,
-                        &core_data_src,
-                        &external_data_srcs,
-                    )
-                    .and_then(|records| {
-                        records
-                            .into_iter()
-                            .map(|record| {
-                                sam::alignment::RecordBuf::try_from_alignment_record(
-                                    self.header,
-                                    &record,
-                                )
-                            })
-                            .collect::<io::Result<Vec<_>>>()
-                    })
-            })
-            .collect::<Result<Vec<_>, _>>();
-
-        let records = match records {
-            Ok(records) => records,
+        match read_container_records(self.reader, self.header, index_record.offset()) {
+            Ok(Some(records)) => {
+                self.records = records.into_iter();
+            }
+            Ok(None) => return None,
             Err(e) => return Some(Err(e)),
-        };
-
-        self.records = records
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
+        }
 
         Some(Ok(()))
     }
@@ -145,6 +96,59 @@ where
     }
 }
 
+// Reads and decodes the records of the container at the given offset.
+//
+// This returns `None` at the end of the stream.
+pub(super) fn read_container_records<R>(
+    reader: &mut Reader<R>,
+    header: &sam::Header,
+    offset: u64,
+) -> io::Result<Option<Vec<sam::alignment::RecordBuf>>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let mut container = Container::default();
+
+    if reader.read_container(&mut container)? == 0 {
+        return Ok(None);
+    }
+
+    let compression_header = container.compression_header()?;
+    let projection = Projection::new(&compression_header, reader.fields);
+
+    let records = container
+        .slices()
+        .map(|result| {
+            let slice = result?;
+
+            let (core_data_src, external_data_srcs) =
+                slice.decode_blocks_with_projection(&projection)?;
+
+            slice
+                .records_with_projection(
+                    reader.reference_sequence_repository.clone(),
+                    header,
+                    &compression_header,
+                    &core_data_src,
+                    &external_data_srcs,
+                    &projection,
+                )
+                .and_then(|records| {
+                    records
+                        .into_iter()
+                        .map(|record| {
+                            sam::alignment::RecordBuf::try_from_alignment_record(header, &record)
+                        })
+                        .collect::<io::Result<Vec<_>>>()
+                })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(Some(records.into_iter().flatten().collect()))
+}
+
 fn intersects(record: &sam::alignment::RecordBuf, region_interval: Interval) -> bool {
     match (record.alignment_start(), record.alignment_end()) {
         (Some(start), Some(end)) => {
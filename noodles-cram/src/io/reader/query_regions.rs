@@ -0,0 +1,162 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use noodles_core::region::RegionSet;
+use noodles_sam as sam;
+
+use super::{Reader, query::read_container_records};
+use crate::crai;
+
+/// An iterator over records that intersect any of a list of regions.
+///
+/// Each container is read at most once, and each record is paired with the indices of the regions
+/// it intersects, in ascending order.
+///
+/// This is created by calling [`Reader::query_regions`].
+pub struct QueryRegions<'r, 'h: 'r, R>
+where
+    R: Read + Seek,
+{
+    reader: &'r mut Reader<R>,
+    header: &'h sam::Header,
+    offsets: vec::IntoIter<u64>,
+    regions: RegionSet,
+    records: vec::IntoIter<sam::alignment::RecordBuf>,
+}
+
+impl<'r, 'h: 'r, R> QueryRegions<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut Reader<R>,
+        header: &'h sam::Header,
+        index: &crai::Index,
+        regions: RegionSet,
+    ) -> Self {
+        let offsets = container_offsets(index, &regions);
+
+        Self {
+            reader,
+            header,
+            offsets: offsets.into_iter(),
+            regions,
+            records: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<R> Iterator for QueryRegions<'_, '_, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<(sam::alignment::RecordBuf, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(record) => {
+                    let indices = overlapping_regions(&record, &self.regions);
+
+                    if !indices.is_empty() {
+                        return Some(Ok((record, indices)));
+                    }
+                }
+                None => {
+                    let offset = self.offsets.next()?;
+
+                    match read_container_records(self.reader, self.header, offset) {
+                        Ok(Some(records)) => self.records = records.into_iter(),
+                        Ok(None) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Returns the sorted, unique offsets of the containers that intersect any of the regions.
+fn container_offsets(index: &crai::Index, regions: &RegionSet) -> Vec<u64> {
+    let intervals = regions.merged_intervals();
+
+    let mut offsets: Vec<_> = index
+        .iter()
+        .filter(|record| {
+            let Some(reference_sequence_id) = record.reference_sequence_id() else {
+                return false;
+            };
+
+            let Some(start) = record.alignment_start() else {
+                return false;
+            };
+
+            let end = record
+                .alignment_span()
+                .checked_sub(1)
+                .and_then(|n| start.checked_add(n))
+                .unwrap_or(start);
+
+            let record_interval = (start..=end).into();
+
+            intervals.iter().any(|(id, interval)| {
+                *id == reference_sequence_id && interval.intersects(record_interval)
+            })
+        })
+        .map(|record| record.offset())
+        .collect();
+
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    offsets
+}
+
+fn overlapping_regions(record: &sam::alignment::RecordBuf, regions: &RegionSet) -> Vec<usize> {
+    match (
+        record.reference_sequence_id(),
+        record.alignment_start(),
+        record.alignment_end(),
+    ) {
+        (Some(id), Some(start), Some(end)) => regions.overlapping(id, (start..=end).into()),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noodles_core::{Position, region::Interval};
+
+    #[test]
+    fn test_container_offsets() -> Result<(), noodles_core::position::TryFromIntError> {
+        let index = vec![
+            crai::Record::new(Some(0), Position::new(1), 10, 100, 0, 0),
+            crai::Record::new(Some(0), Position::new(11), 10, 200, 0, 0),
+            crai::Record::new(Some(0), Position::new(21), 10, 200, 50, 0),
+            crai::Record::new(Some(1), Position::new(1), 10, 300, 0, 0),
+            crai::Record::new(None, None, 0, 400, 0, 0),
+        ];
+
+        let regions = RegionSet::new([
+            (
+                0,
+                Interval::from(Position::try_from(15)?..=Position::try_from(25)?),
+            ),
+            (
+                0,
+                Interval::from(Position::try_from(20)?..=Position::try_from(22)?),
+            ),
+            (
+                1,
+                Interval::from(Position::try_from(10)?..=Position::try_from(10)?),
+            ),
+        ]);
+
+        assert_eq!(container_offsets(&index, &regions), [200, 300]);
+
+        Ok(())
+    }
+}
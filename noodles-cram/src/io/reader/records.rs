@@ -5,7 +5,7 @@ use std::{
 
 use noodles_sam as sam;
 
-use super::{Container, Reader};
+use super::{Container, Reader, container::slice::Projection};
 
 /// An iterator over records of a CRAM reader.
 ///
@@ -40,21 +40,25 @@ where
 
         let compression_header = self.container.compression_header()?;
 
+        let projection = Projection::new(&compression_header, self.reader.fields);
+
         self.records = self
             .container
             .slices()
             .map(|result| {
                 let slice = result?;
 
-                let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+                let (core_data_src, external_data_srcs) =
+                    slice.decode_blocks_with_projection(&projection)?;
 
                 slice
-                    .records(
+                    .records_with_projection(
                         self.reader.reference_sequence_repository.clone(),
                         self.header,
                         &compression_header,
                         &core_data_src,
                         &external_data_srcs,
+                        &projection,
                     )
                     .and_then(|records| {
                         records
@@ -97,3 +101,130 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_fasta as fasta;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                Flags, MappingQuality,
+                cigar::{Op, op::Kind},
+                data::field::Tag,
+            },
+            record_buf::{Cigar, Data, QualityScores, Sequence, data::field::Value},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::io::reader::{Builder, Fields};
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build())
+    }
+
+    fn build_records() -> Result<Vec<sam::alignment::RecordBuf>, Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        let mapped_record = sam::alignment::RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+            .set_sequence(Sequence::from(b"CGTT"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .set_data(Data::from_iter([(
+                Tag::ALIGNMENT_HIT_COUNT,
+                Value::from(1),
+            )]))
+            .build();
+
+        let unmapped_record = sam::alignment::RecordBuf::builder()
+            .set_name("r1")
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+            .build();
+
+        Ok(vec![mapped_record, unmapped_record])
+    }
+
+    fn write(
+        header: &sam::Header,
+        records: &[sam::alignment::RecordBuf],
+        reference_sequence_repository: fasta::Repository,
+    ) -> io::Result<Vec<u8>> {
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        writer.try_finish(header)?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    #[test]
+    fn test_next_with_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTTACG".to_vec()),
+        )]);
+
+        let header = build_header()?;
+        let expected = build_records()?;
+        let src = write(&header, &expected, reference_sequence_repository.clone())?;
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build_from_reader(&src[..]);
+        reader.read_header()?;
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual, expected);
+
+        // The reference sequence repository is not set, as it is not used when the sequence is
+        // not requested.
+        let mut reader = Builder::default()
+            .set_fields(Fields::NAME)
+            .build_from_reader(&src[..]);
+        reader.read_header()?;
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&expected) {
+            assert_eq!(actual_record.name(), expected_record.name());
+            assert_eq!(actual_record.flags(), expected_record.flags());
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+            assert_eq!(
+                actual_record.mapping_quality(),
+                expected_record.mapping_quality()
+            );
+            assert_eq!(actual_record.cigar(), expected_record.cigar());
+            assert!(actual_record.sequence().is_empty());
+            assert!(actual_record.quality_scores().is_empty());
+            assert!(actual_record.data().is_empty());
+        }
+
+        Ok(())
+    }
+}
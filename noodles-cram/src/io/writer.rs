@@ -15,11 +15,11 @@ use noodles_sam::{self as sam, alignment::io::Write as _};
 
 pub use self::builder::Builder;
 use self::{
-    container::write_container,
+    container::{push_index_records, write_container, write_raw_container},
     header::{write_file_definition, write_file_header, write_header},
 };
 pub(crate) use self::{options::Options, record::Record};
-use crate::FileDefinition;
+use crate::{FileDefinition, crai, io::reader::Container};
 
 const DEFAULT_SLICES_PER_CONTAINER: usize = 1;
 const DEFAULT_RECORDS_PER_SLICE: usize = 10240;
@@ -242,18 +242,86 @@ where
         self.write_alignment_record(header, record)
     }
 
-    fn add_record(&mut self, header: &sam::Header, record: Record) -> io::Result<()> {
+    /// Writes a container read from another CRAM stream without re-encoding its records.
+    ///
+    /// Any pending records are first written to a new container. The copied container is written
+    /// verbatim, except for its record counters, which are rewritten to follow the records
+    /// already written.
+    ///
+    /// The container must have been written with a header that has the same reference sequences
+    /// and read groups as the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::{self as cram, io::reader::Container};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut writer = cram::io::Writer::new(io::sink());
+    /// writer.write_header(&header)?;
+    ///
+    /// let mut container = Container::default();
+    ///
+    /// while reader.read_container(&mut container)? != 0 {
+    ///     writer.write_container(&header, &container)?;
+    /// }
+    ///
+    /// writer.try_finish(&header)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_container(
+        &mut self,
+        header: &sam::Header,
+        container: &Container,
+    ) -> io::Result<()> {
+        self.flush(header)?;
+
+        write_raw_container(&mut self.inner, container, self.record_counter)?;
+
+        let record_count = u64::try_from(container.header().record_count())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.record_counter += record_count;
+
+        Ok(())
+    }
+
+    /// Writes an alignment record.
+    ///
+    /// If this fills a container, the container is written, and, when an index and the current
+    /// stream position are given, index records for it are added to the index.
+    pub(crate) fn add_record_with_index(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+        index: Option<(&mut crai::Index, u64)>,
+    ) -> io::Result<()> {
+        let record = Record::try_from_alignment_record(header, record)?;
         self.records.push(record);
 
         if self.records.len() >= self.records.capacity() {
-            self.flush(header)?;
+            self.flush_with_index(header, index)?;
         }
 
         Ok(())
     }
 
     fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
-        write_container(
+        self.flush_with_index(header, None)
+    }
+
+    /// Writes any pending records to a container.
+    ///
+    /// When an index and the current stream position are given, index records for the written
+    /// container are added to the index.
+    pub(crate) fn flush_with_index(
+        &mut self,
+        header: &sam::Header,
+        index: Option<(&mut crai::Index, u64)>,
+    ) -> io::Result<()> {
+        let container = write_container(
             &mut self.inner,
             &self.reference_sequence_repository,
             &self.options,
@@ -262,6 +330,18 @@ where
             &mut self.records,
         )?;
 
+        if let (Some((container_header, container_size)), Some((index, container_position))) =
+            (container, index)
+        {
+            push_index_records(
+                index,
+                container_position,
+                &container_header,
+                container_size,
+                &self.records,
+            )?;
+        }
+
         let record_count = u64::try_from(self.records.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
         self.record_counter += record_count;
@@ -285,8 +365,7 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        let record = Record::try_from_alignment_record(header, record)?;
-        self.add_record(header, record)
+        self.add_record_with_index(header, record, None)
     }
 
     fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
@@ -1,13 +1,15 @@
-mod block;
+pub(crate) mod block;
 pub(crate) mod compression_header;
 mod header;
 pub(crate) mod slice;
 
 use std::{
     cmp,
+    collections::BTreeMap,
     io::{self, Write},
 };
 
+use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -20,7 +22,11 @@ use self::{
     slice::{Slice, build_slice},
 };
 use super::{DEFAULT_RECORDS_PER_SLICE, Options, Record};
-use crate::container::{Header, ReferenceSequenceContext, block::ContentType};
+use crate::{
+    container::{Header, ReferenceSequenceContext, block::ContentType},
+    crai,
+    io::reader::Container,
+};
 
 pub fn write_container<W>(
     writer: &mut W,
@@ -29,12 +35,12 @@ pub fn write_container<W>(
     header: &sam::Header,
     record_counter: u64,
     records: &mut [Record],
-) -> io::Result<()>
+) -> io::Result<Option<(Header, usize)>>
 where
     W: Write,
 {
     if records.is_empty() {
-        return Ok(());
+        return Ok(None);
     }
 
     let (header, container_size, blocks) = build_container(
@@ -51,6 +57,167 @@ where
         write_block(writer, &block)?;
     }
 
+    Ok(Some((header, container_size)))
+}
+
+/// Adds index records for a container written from the given records.
+///
+/// The container header and size are the values returned by [`write_container`]. Each slice of
+/// the container gets one index record per reference sequence it spans.
+pub fn push_index_records(
+    index: &mut crai::Index,
+    container_position: u64,
+    header: &Header,
+    container_size: usize,
+    records: &[Record],
+) -> io::Result<()> {
+    let landmarks = header.landmarks();
+
+    for (i, slice_records) in records.chunks(DEFAULT_RECORDS_PER_SLICE).enumerate() {
+        let landmark = landmarks[i];
+        let slice_end = landmarks.get(i + 1).copied().unwrap_or(container_size);
+        let slice_length = slice_end - landmark;
+
+        push_slice_index_records(
+            index,
+            container_position,
+            landmark as u64,
+            slice_length as u64,
+            slice_records,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn push_slice_index_records(
+    index: &mut crai::Index,
+    container_position: u64,
+    landmark: u64,
+    slice_length: u64,
+    records: &[Record],
+) -> io::Result<()> {
+    let mut push_index_record = |reference_sequence_id, alignment_start, alignment_span| {
+        index.push(crai::Record::new(
+            reference_sequence_id,
+            alignment_start,
+            alignment_span,
+            container_position,
+            landmark,
+            slice_length,
+        ));
+    };
+
+    match slice::get_reference_sequence_context(records) {
+        ReferenceSequenceContext::Some(context) => push_index_record(
+            Some(context.reference_sequence_id()),
+            Some(context.alignment_start()),
+            context.alignment_span(),
+        ),
+        ReferenceSequenceContext::None => push_index_record(None, None, 0),
+        ReferenceSequenceContext::Many => {
+            let mut intervals: BTreeMap<Option<usize>, (Option<Position>, Option<Position>)> =
+                BTreeMap::new();
+
+            for record in records {
+                let (start, end) = intervals
+                    .entry(record.reference_sequence_id)
+                    .or_insert((Position::new(usize::MAX), None));
+
+                *start = cmp::min(*start, record.alignment_start);
+                *end = cmp::max(*end, record.alignment_end());
+            }
+
+            for (reference_sequence_id, interval) in intervals {
+                if reference_sequence_id.is_none() {
+                    push_index_record(None, None, 0);
+                    continue;
+                }
+
+                let (Some(start), Some(end)) = interval else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "missing alignment interval for mapped records",
+                    ));
+                };
+
+                let span = usize::from(end) - usize::from(start) + 1;
+                push_index_record(reference_sequence_id, Some(start), span);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a container read from another CRAM stream.
+///
+/// The compression header, core data blocks, and external data blocks are copied verbatim. The
+/// record counters of the container header and slice headers are rewritten to start at the given
+/// record counter.
+pub fn write_raw_container<W>(
+    writer: &mut W,
+    container: &Container,
+    record_counter: u64,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let src_header = container.header();
+    let src = &container.src[..];
+
+    let compression_header_end = src_header.landmarks().first().copied().unwrap_or(src.len());
+
+    let compression_header_src = src
+        .get(..compression_header_end)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid container landmarks"))?;
+
+    let mut container_size = compression_header_src.len();
+    let mut landmarks = Vec::with_capacity(src_header.landmarks().len());
+    let mut slices = Vec::with_capacity(src_header.landmarks().len());
+    let mut buf = Vec::new();
+
+    for result in container.slices() {
+        let slice = result?;
+
+        let mut slice_header = slice.header().clone();
+
+        let offset = slice_header
+            .record_counter
+            .checked_sub(src_header.record_counter())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid slice record counter")
+            })?;
+
+        slice_header.record_counter = record_counter + offset;
+
+        buf.clear();
+        slice::write_header(&mut buf, &slice_header)?;
+        let slice_header_block = build_slice_header_block(&buf)?;
+
+        landmarks.push(container_size);
+
+        let blocks_src = slice.blocks_src();
+        container_size += slice_header_block.size()? + blocks_src.len();
+
+        slices.push((slice_header_block, blocks_src));
+    }
+
+    let header = Header {
+        record_counter,
+        landmarks,
+        ..src_header.clone()
+    };
+
+    write_header(writer, &header, container_size)?;
+
+    writer.write_all(compression_header_src)?;
+
+    for (slice_header_block, blocks_src) in slices {
+        write_block(writer, &slice_header_block)?;
+        writer.write_all(blocks_src)?;
+    }
+
     Ok(())
 }
 
@@ -97,6 +264,9 @@ fn build_container(
     let mut landmarks = Vec::with_capacity(slices.len());
 
     for slice in slices {
+        // A landmark is the offset of the start of a slice from the end of the container header.
+        landmarks.push(container_size);
+
         buf.clear();
 
         slice::write_header(&mut buf, &slice.header)?;
@@ -114,10 +284,6 @@ fn build_container(
 
         blocks.extend(slice.external_data_blocks);
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_size;
-        landmarks.push(landmark);
-
         container_size += slice_size;
     }
 
@@ -212,3 +378,32 @@ where
 {
     writer.write_all(&EOF)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_container_landmarks() -> io::Result<()> {
+        let mut records = [Record::default(), Record::default()];
+
+        let (header, container_size, blocks) = build_container(
+            &fasta::Repository::default(),
+            &Options::default(),
+            &sam::Header::default(),
+            0,
+            &mut records,
+        )?;
+
+        let compression_header_block_size = blocks[0].size()?;
+        assert_eq!(header.landmarks(), [compression_header_block_size]);
+
+        let blocks_size = blocks
+            .iter()
+            .map(|block| block.size())
+            .sum::<io::Result<usize>>()?;
+        assert_eq!(container_size, blocks_size);
+
+        Ok(())
+    }
+}
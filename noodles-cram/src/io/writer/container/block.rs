@@ -1,4 +1,4 @@
-mod compression_method;
+pub(crate) mod compression_method;
 mod content_type;
 
 use std::{
@@ -31,37 +31,9 @@ impl Block {
         encoder: Option<&Encoder>,
         src: &[u8],
     ) -> io::Result<Self> {
-        use crate::codecs::{aac, bzip2, gzip, lzma, name_tokenizer, rans_4x8, rans_nx16};
-
         let (compression_method, buf) = match encoder {
             None => (CompressionMethod::None, src.to_vec()),
-            Some(Encoder::Gzip(compression_level)) => (
-                CompressionMethod::Gzip,
-                gzip::encode(*compression_level, src)?,
-            ),
-            Some(Encoder::Bzip2(compression_level)) => (
-                CompressionMethod::Bzip2,
-                bzip2::encode(*compression_level, src)?,
-            ),
-            Some(Encoder::Lzma(compression_level)) => (
-                CompressionMethod::Lzma,
-                lzma::encode(*compression_level, src)?,
-            ),
-            Some(Encoder::Rans4x8(order)) => {
-                (CompressionMethod::Rans4x8, rans_4x8::encode(*order, src)?)
-            }
-            Some(Encoder::RansNx16(flags)) => {
-                (CompressionMethod::RansNx16, rans_nx16::encode(*flags, src)?)
-            }
-            Some(Encoder::AdaptiveArithmeticCoding(flags)) => (
-                CompressionMethod::AdaptiveArithmeticCoding,
-                aac::encode(*flags, src)?,
-            ),
-            Some(Encoder::NameTokenizer) => (
-                CompressionMethod::NameTokenizer,
-                name_tokenizer::encode(src)?,
-            ),
-            Some(Encoder::Fqzcomp) => unimplemented!(),
+            Some(encoder) => (encoder.compression_method(), encoder.encode(src)?),
         };
 
         Ok(Self {
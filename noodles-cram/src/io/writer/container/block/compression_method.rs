@@ -15,7 +15,7 @@ where
     writer.write_u8(n)
 }
 
-fn encode(compression_method: CompressionMethod) -> u8 {
+pub(crate) fn encode(compression_method: CompressionMethod) -> u8 {
     match compression_method {
         CompressionMethod::None => 0,
         CompressionMethod::Gzip => 1,
@@ -0,0 +1,32 @@
+//! Rebins a CSI using different binning parameters.
+//!
+//! This writes the output to stdout rather than `<src>.csi`.
+
+use std::{env, io};
+
+use noodles_csi as csi;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let min_shift = args
+        .next()
+        .expect("missing min_shift")
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let depth = args
+        .next()
+        .expect("missing depth")
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let index = csi::fs::read(src)?;
+    let rebinned_index = index.rebin(min_shift, depth)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = csi::io::Writer::new(stdout);
+    writer.write_index(&rebinned_index)?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Prints statistics of a CSI.
+//!
+//! Each row has the reference sequence index, the number of mapped and unmapped records, the number
+//! of bins and chunks, the number of bins at each level, and the number of bins with a first record
+//! offset.
+
+use std::{env, io};
+
+use noodles_csi as csi;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = csi::fs::read(src)?;
+    let statistics = index.statistics();
+
+    println!(
+        "# min_shift = {}, depth = {}",
+        statistics.min_shift(),
+        statistics.depth()
+    );
+
+    for (i, reference_sequence) in statistics.reference_sequences().iter().enumerate() {
+        let bin_counts_by_level: Vec<_> = reference_sequence
+            .bin_counts_by_level()
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+
+        println!(
+            "{i}\t{}\t{}\t{}\t{}\t{}\t{}",
+            reference_sequence.mapped_record_count().unwrap_or_default(),
+            reference_sequence
+                .unmapped_record_count()
+                .unwrap_or_default(),
+            reference_sequence.bin_count(),
+            reference_sequence.chunk_count(),
+            bin_counts_by_level.join(","),
+            reference_sequence.index_entry_count(),
+        );
+    }
+
+    println!(
+        "*\t0\t{}",
+        statistics
+            .unplaced_unmapped_record_count()
+            .unwrap_or_default()
+    );
+
+    Ok(())
+}
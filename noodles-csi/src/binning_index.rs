@@ -2,15 +2,20 @@
 
 pub mod index;
 mod indexer;
+mod pending_record;
 mod reference_sequence;
+pub mod validator;
 
 use std::io;
 
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, RegionSet};
 
 use self::index::{Header, reference_sequence::bin::Chunk};
-pub use self::{index::Index, indexer::Indexer, reference_sequence::ReferenceSequence};
+pub use self::{
+    index::Index, indexer::Indexer, pending_record::PendingRecord,
+    reference_sequence::ReferenceSequence, validator::Validator,
+};
 
 /// A binning index.
 pub trait BinningIndex {
@@ -164,6 +169,38 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Returns the chunks that overlap with any of the given regions.
+///
+/// The index is queried once per merged interval of the region set (see
+/// [`RegionSet::merged_intervals`]), and the resulting chunks of all regions are merged (see
+/// [`merge_chunks`]). Reading the chunks in order reads each block and record at most once.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::region::RegionSet;
+/// use noodles_csi::{self as csi, binning_index::query_regions};
+///
+/// let index = csi::Index::default();
+/// let regions = RegionSet::default();
+///
+/// let chunks = query_regions(&index, &regions)?;
+/// assert!(chunks.is_empty());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn query_regions<I>(index: &I, regions: &RegionSet) -> io::Result<Vec<Chunk>>
+where
+    I: BinningIndex + ?Sized,
+{
+    let mut chunks = Vec::new();
+
+    for (reference_sequence_id, interval) in regions.merged_intervals() {
+        chunks.extend(index.query(reference_sequence_id, interval)?);
+    }
+
+    Ok(merge_chunks(&chunks))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +282,58 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use self::index::reference_sequence::index::BinnedIndex;
+
+        let mut indexer = Indexer::<BinnedIndex>::new(14, 5);
+        let mut position = 0;
+
+        for (reference_sequence_id, start, end) in
+            [(0, 8, 13), (0, 21, 34), (0, 20000, 20100), (1, 55, 89)]
+        {
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(position),
+                bgzf::VirtualPosition::from(position + 8),
+            );
+
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+
+            position += 8;
+        }
+
+        let index = indexer.build(2);
+
+        let interval = |start, end| -> Result<Interval, noodles_core::position::TryFromIntError> {
+            Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+        };
+
+        let regions = RegionSet::new([
+            (0, interval(21, 34)?),
+            (1, interval(1, 100)?),
+            (0, interval(1, 30)?),
+        ]);
+
+        let actual = query_regions(&index, &regions)?;
+
+        let expected = [
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(16),
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::from(24),
+                bgzf::VirtualPosition::from(32),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
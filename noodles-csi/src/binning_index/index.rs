@@ -1,10 +1,14 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+mod convert;
 pub mod header;
 pub mod reference_sequence;
+pub mod statistics;
 
-pub use self::{builder::Builder, header::Header, reference_sequence::ReferenceSequence};
+pub use self::{
+    builder::Builder, header::Header, reference_sequence::ReferenceSequence, statistics::Statistics,
+};
 
 use std::io;
 
@@ -0,0 +1,535 @@
+//! Conversions between binning index flavors.
+//!
+//! A BAI or tabix index has a linear index of 16 kbp windows, whereas a CSI has a first record
+//! offset per bin. A linear index can always be converted to bin offsets, but a binned index can
+//! only be converted to a linear index if its binning parameters are the fixed BAI/tabix ones,
+//! i.e., a minimum shift of 14 and a depth of 5.
+
+use std::{collections::HashMap, io, ops::Range};
+
+use indexmap::IndexMap;
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{
+    Index, ReferenceSequence,
+    reference_sequence::{
+        Bin,
+        bin::Chunk,
+        bin_interval,
+        index::{BinnedIndex, LINEAR_INDEX_WINDOW_SIZE, LinearIndex},
+        parent_id, reg2bin,
+    },
+};
+
+const LINEAR_INDEX_MIN_SHIFT: u8 = 14;
+const LINEAR_INDEX_DEPTH: u8 = 5;
+
+// `CSIv1.pdf` (2020-07-21) § 1.2: "...the maximum depth is 10..."
+const MAX_DEPTH: u8 = 10;
+
+impl From<Index<LinearIndex>> for Index<BinnedIndex> {
+    /// Converts a BAI or tabix index to a CSI.
+    ///
+    /// The first record offset of a bin is the linear index offset of the first window the bin
+    /// covers. The header, if any, is kept.
+    fn from(index: Index<LinearIndex>) -> Self {
+        let (min_shift, depth) = (index.min_shift, index.depth);
+
+        let reference_sequences = index
+            .reference_sequences
+            .into_iter()
+            .map(|reference_sequence| {
+                let (bins, linear_index, metadata) = reference_sequence.into_parts();
+
+                let binned_index = bins
+                    .iter()
+                    .map(|(&id, bin)| {
+                        let position = bin_interval(id, min_shift, depth)
+                            .and_then(|(_, interval)| {
+                                let i = interval.start / LINEAR_INDEX_WINDOW_SIZE;
+                                linear_index.get(i).copied()
+                            })
+                            .unwrap_or_else(|| first_chunk_start(bin));
+
+                        (id, position)
+                    })
+                    .collect();
+
+                ReferenceSequence::new(bins, binned_index, metadata)
+            })
+            .collect();
+
+        Self {
+            min_shift,
+            depth,
+            header: index.header,
+            reference_sequences,
+            unplaced_unmapped_record_count: index.unplaced_unmapped_record_count,
+        }
+    }
+}
+
+impl TryFrom<Index<BinnedIndex>> for Index<LinearIndex> {
+    type Error = io::Error;
+
+    /// Converts a CSI to a BAI or tabix index.
+    ///
+    /// This fails if the CSI does not use a minimum shift of 14 and a depth of 5.
+    ///
+    /// The offset of a linear index window is the smallest first record offset of the bins that
+    /// contain the window. A window that is not contained by any bin has no overlapping records
+    /// and takes the offset of the following window.
+    fn try_from(index: Index<BinnedIndex>) -> Result<Self, Self::Error> {
+        let (min_shift, depth) = (index.min_shift, index.depth);
+
+        if (min_shift, depth) != (LINEAR_INDEX_MIN_SHIFT, LINEAR_INDEX_DEPTH) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid binning parameters: expected (min_shift, depth) = ({LINEAR_INDEX_MIN_SHIFT}, {LINEAR_INDEX_DEPTH}), got ({min_shift}, {depth})"
+                ),
+            ));
+        }
+
+        let reference_sequences = index
+            .reference_sequences
+            .into_iter()
+            .map(|reference_sequence| {
+                let (bins, binned_index, metadata) = reference_sequence.into_parts();
+                let linear_index = build_linear_index(&bins, &binned_index, min_shift, depth);
+                ReferenceSequence::new(bins, linear_index, metadata)
+            })
+            .collect();
+
+        Ok(Self {
+            min_shift,
+            depth,
+            header: index.header,
+            reference_sequences,
+            unplaced_unmapped_record_count: index.unplaced_unmapped_record_count,
+        })
+    }
+}
+
+impl Index<BinnedIndex> {
+    /// Rebins the index using different binning parameters.
+    ///
+    /// This is typically used to tune a CSI, e.g., to index reference sequences longer than
+    /// 2^29 - 1 bases, which requires `min_shift + 3 * depth > 29`.
+    ///
+    /// The chunks of each bin are moved to the smallest bin that contains the interval of the
+    /// original bin. The first record offset of a new bin is the smallest offset of the original
+    /// bins moved to it and of the smallest original bin that contains it. Queries on the rebinned
+    /// index return every record the original index would, though bins can be coarser than if the
+    /// index were built from the records with the new binning parameters.
+    ///
+    /// This fails if the depth is greater than 10 or if a bin starts past the maximum position
+    /// of the new binning parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    ///
+    /// let index = csi::Index::default();
+    /// let rebinned_index = index.rebin(12, 7)?;
+    ///
+    /// assert_eq!(rebinned_index.min_shift(), 12);
+    /// assert_eq!(rebinned_index.depth(), 7);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rebin(&self, min_shift: u8, depth: u8) -> io::Result<Self> {
+        if min_shift == 0 || depth > MAX_DEPTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid binning parameters: (min_shift, depth) = ({min_shift}, {depth})"),
+            ));
+        }
+
+        let max_end = 1usize
+            .checked_shl(u32::from(min_shift) + 3 * u32::from(depth))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid binning parameters: maximum position overflows",
+                )
+            })?;
+
+        let reference_sequences = self
+            .reference_sequences
+            .iter()
+            .map(|reference_sequence| {
+                rebin_reference_sequence(
+                    reference_sequence,
+                    (self.min_shift, self.depth),
+                    (min_shift, depth),
+                    max_end,
+                )
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            min_shift,
+            depth,
+            header: self.header.clone(),
+            reference_sequences,
+            unplaced_unmapped_record_count: self.unplaced_unmapped_record_count,
+        })
+    }
+}
+
+fn first_chunk_start(bin: &Bin) -> bgzf::VirtualPosition {
+    bin.chunks()
+        .iter()
+        .map(|chunk| chunk.start())
+        .min()
+        .unwrap_or_default()
+}
+
+fn build_linear_index(
+    bins: &IndexMap<usize, Bin>,
+    binned_index: &BinnedIndex,
+    min_shift: u8,
+    depth: u8,
+) -> LinearIndex {
+    let first_leaf_id = (1 << (3 * usize::from(depth))) / 7;
+
+    let window_count = bins
+        .keys()
+        .chain(binned_index.keys())
+        .filter_map(|&id| bin_interval(id, min_shift, depth))
+        .map(|(_, interval)| interval.start / LINEAR_INDEX_WINDOW_SIZE + 1)
+        .max()
+        .unwrap_or_default();
+
+    let mut offsets: Vec<_> = (0..window_count)
+        .map(|i| {
+            let mut id = Some(first_leaf_id + i);
+            let mut offset: Option<bgzf::VirtualPosition> = None;
+
+            while let Some(bin_id) = id {
+                if let Some(&position) = binned_index.get(&bin_id) {
+                    offset = Some(offset.map_or(position, |offset| offset.min(position)));
+                }
+
+                id = parent_id(bin_id);
+            }
+
+            offset
+        })
+        .collect();
+
+    let mut next_offset = bgzf::VirtualPosition::default();
+
+    for offset in offsets.iter_mut().rev() {
+        match offset {
+            Some(position) => next_offset = *position,
+            None => *offset = Some(next_offset),
+        }
+    }
+
+    offsets.into_iter().flatten().collect()
+}
+
+fn rebin_reference_sequence(
+    reference_sequence: &ReferenceSequence<BinnedIndex>,
+    (src_min_shift, src_depth): (u8, u8),
+    (dst_min_shift, dst_depth): (u8, u8),
+    max_end: usize,
+) -> io::Result<ReferenceSequence<BinnedIndex>> {
+    use super::super::ReferenceSequence as _;
+
+    let resolve_interval = |id| {
+        bin_interval(id, src_min_shift, src_depth)
+            .map(|(_, interval)| interval)
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("invalid bin ID: {id}"))
+            })
+    };
+
+    let resolve_dst_id = |id, interval: &Range<usize>| {
+        if interval.start >= max_end {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("bin {id} starts past the maximum position of the binning parameters"),
+            ));
+        }
+
+        let start = Position::try_from(interval.start + 1)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let end = Position::try_from(interval.end.min(max_end))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(reg2bin(start, end, dst_min_shift, dst_depth))
+    };
+
+    let mut chunks: IndexMap<usize, Vec<Chunk>> = IndexMap::new();
+
+    for (&id, bin) in reference_sequence.bins() {
+        let interval = resolve_interval(id)?;
+        let dst_id = resolve_dst_id(id, &interval)?;
+
+        chunks
+            .entry(dst_id)
+            .or_default()
+            .extend_from_slice(bin.chunks());
+    }
+
+    // The smallest offset of the original bins moved to each new bin.
+    let mut moved_offsets: HashMap<usize, bgzf::VirtualPosition> = HashMap::new();
+
+    for (&id, &position) in reference_sequence.index() {
+        let interval = resolve_interval(id)?;
+        let dst_id = resolve_dst_id(id, &interval)?;
+
+        moved_offsets
+            .entry(dst_id)
+            .and_modify(|offset| *offset = (*offset).min(position))
+            .or_insert(position);
+    }
+
+    // SAFETY: The root bin is valid for any binning parameters.
+    let (_, src_root_interval) = bin_interval(0, src_min_shift, src_depth).unwrap();
+
+    let mut bins = IndexMap::with_capacity(chunks.len());
+    let mut binned_index = BinnedIndex::with_capacity(chunks.len());
+
+    for (id, mut bin_chunks) in chunks {
+        bin_chunks.sort_unstable_by_key(|chunk| chunk.start());
+
+        let mut bin = Bin::new(Vec::new());
+
+        for chunk in bin_chunks {
+            bin.add_chunk(chunk);
+        }
+
+        // SAFETY: `id` was computed by `reg2bin` with the same binning parameters.
+        let (_, interval) = bin_interval(id, dst_min_shift, dst_depth).unwrap();
+
+        // Bin intervals are aligned powers of 2, so they either nest or are disjoint. A query
+        // starting in the new bin uses the offset of either an original bin moved to it or the
+        // smallest original bin that contains it. The original bins that contain the new bin are
+        // the smallest one and its ancestors.
+        let moved_offset = moved_offsets.get(&id).copied();
+
+        let containing_offset = if interval.end <= src_root_interval.end {
+            let start = Position::try_from(interval.start + 1)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let end = Position::try_from(interval.end)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let mut src_id = Some(reg2bin(start, end, src_min_shift, src_depth));
+            let mut offset = None;
+
+            while let Some(bin_id) = src_id {
+                if let Some(&position) = reference_sequence.index().get(&bin_id) {
+                    offset = Some(position);
+                    break;
+                }
+
+                src_id = parent_id(bin_id);
+            }
+
+            offset
+        } else {
+            None
+        };
+
+        let offset = match (moved_offset, containing_offset) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(position), None) | (None, Some(position)) => position,
+            (None, None) => first_chunk_start(&bin),
+        };
+
+        binned_index.insert(id, offset);
+        bins.insert(id, bin);
+    }
+
+    Ok(ReferenceSequence::new(
+        bins,
+        binned_index,
+        reference_sequence.metadata().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binning_index::{BinningIndex, Indexer};
+
+    type Record = (usize, Position, Position, Chunk);
+
+    fn build_records() -> Result<Vec<Record>, Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        for (reference_sequence_id, start, len) in [
+            (0, 8, 13),
+            (0, 16000, 800),
+            (0, 40000, 10),
+            (0, 65536, 200000),
+            (0, (1 << 20) + 8, 100),
+            (1, 1, 34),
+            (1, 50000, 5),
+        ] {
+            let start = Position::try_from(start)?;
+            let end = start.checked_add(len).unwrap();
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(position),
+                bgzf::VirtualPosition::from(position + 100),
+            );
+            records.push((reference_sequence_id, start, end, chunk));
+            position += 100;
+        }
+
+        Ok(records)
+    }
+
+    fn build_index<I>(min_shift: u8, depth: u8, records: &[Record]) -> io::Result<Index<I>>
+    where
+        I: super::super::reference_sequence::Index + Default,
+    {
+        let mut indexer = Indexer::new(min_shift, depth);
+
+        for &(reference_sequence_id, start, end, chunk) in records {
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+        }
+
+        Ok(indexer.build(2))
+    }
+
+    // Every index must return at least the chunks of the records that overlap the interval.
+    fn assert_queries<A, B>(
+        actual: &A,
+        expected: &B,
+        records: &[Record],
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        A: BinningIndex,
+        B: BinningIndex,
+    {
+        for &(reference_sequence_id, start, end, chunk) in records {
+            let interval = (start..=end).into();
+
+            let actual_chunks = actual.query(reference_sequence_id, interval)?;
+            let expected_chunks = expected.query(reference_sequence_id, interval)?;
+
+            let contains = |chunks: &[Chunk]| {
+                chunks
+                    .iter()
+                    .any(|c| c.start() <= chunk.start() && chunk.end() <= c.end())
+            };
+
+            assert!(contains(&expected_chunks));
+            assert!(contains(&actual_chunks));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_index_with_linear_index_for_index_with_binned_index()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let src: Index<LinearIndex> = build_index(14, 5, &records)?;
+
+        let actual = Index::<BinnedIndex>::from(src.clone());
+
+        assert_eq!(actual.min_shift(), 14);
+        assert_eq!(actual.depth(), 5);
+        assert_eq!(actual.reference_sequences().len(), 2);
+
+        for (a, b) in actual
+            .reference_sequences()
+            .iter()
+            .zip(src.reference_sequences())
+        {
+            assert_eq!(a.bins(), b.bins());
+        }
+
+        assert_queries(&actual, &src, &records)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_index_with_binned_index_for_index_with_linear_index()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+
+        let src: Index<BinnedIndex> = build_index(14, 5, &records)?;
+        let actual = Index::<LinearIndex>::try_from(src.clone())?;
+        assert_queries(&actual, &src, &records)?;
+
+        let expected: Index<LinearIndex> = build_index(14, 5, &records)?;
+
+        for (a, b) in actual
+            .reference_sequences()
+            .iter()
+            .zip(expected.reference_sequences())
+        {
+            assert_eq!(a.bins(), b.bins());
+
+            // A derived offset is never after the exact offset. Past its end, a linear index
+            // defaults to the start of the file.
+            assert!(a.index().len() <= b.index().len());
+            assert!(a.index().iter().zip(b.index()).all(|(a, b)| a <= b));
+        }
+
+        let src: Index<BinnedIndex> = build_index(12, 7, &records)?;
+        assert!(matches!(
+            Index::<LinearIndex>::try_from(src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebin() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let src: Index<BinnedIndex> = build_index(14, 5, &records)?;
+
+        for (min_shift, depth) in [(14, 5), (12, 7), (16, 4), (14, 6)] {
+            let actual = src.rebin(min_shift, depth)?;
+
+            assert_eq!(actual.min_shift(), min_shift);
+            assert_eq!(actual.depth(), depth);
+
+            assert_queries(&actual, &src, &records)?;
+
+            for (a, b) in actual
+                .reference_sequences()
+                .iter()
+                .zip(src.reference_sequences())
+            {
+                use crate::binning_index::ReferenceSequence as _;
+                assert_eq!(a.metadata(), b.metadata());
+            }
+        }
+
+        let actual = src.rebin(14, 5)?;
+
+        for (a, b) in actual
+            .reference_sequences()
+            .iter()
+            .zip(src.reference_sequences())
+        {
+            assert_eq!(a.bins(), b.bins());
+            assert_eq!(a.index(), b.index());
+        }
+
+        assert!(matches!(
+            src.rebin(14, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            src.rebin(14, 11),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
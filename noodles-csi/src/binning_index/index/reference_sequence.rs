@@ -6,7 +6,7 @@ mod metadata;
 
 pub use self::{bin::Bin, index::Index, metadata::Metadata};
 
-use std::{io, num::NonZeroUsize};
+use std::{io, num::NonZeroUsize, ops::Range};
 
 use bit_vec::BitVec;
 use indexmap::IndexMap;
@@ -228,6 +228,41 @@ where
 
         metadata.update(is_mapped, chunk);
     }
+
+    pub(crate) fn as_parts(&self) -> (&IndexMap<usize, Bin>, &I, Option<&Metadata>) {
+        (&self.bins, &self.index, self.metadata.as_ref())
+    }
+
+    pub(crate) fn into_parts(self) -> (IndexMap<usize, Bin>, I, Option<Metadata>) {
+        (self.bins, self.index, self.metadata)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bins.is_empty() && self.metadata.is_none()
+    }
+
+    pub(crate) fn merge(&mut self, other: Self) {
+        for (id, bin) in other.bins {
+            match self.bins.get_mut(&id) {
+                Some(b) => {
+                    for &chunk in bin.chunks() {
+                        b.add_chunk(chunk);
+                    }
+                }
+                None => {
+                    self.bins.insert(id, bin);
+                }
+            }
+        }
+
+        self.index.merge(other.index);
+
+        match (self.metadata.as_mut(), other.metadata) {
+            (Some(metadata), Some(other_metadata)) => metadata.merge(other_metadata),
+            (None, Some(other_metadata)) => self.metadata = Some(other_metadata),
+            (_, None) => {}
+        }
+    }
 }
 
 impl<I> binning_index::ReferenceSequence for ReferenceSequence<I>
@@ -249,8 +284,28 @@ pub(crate) fn parent_id(id: usize) -> Option<usize> {
     if id > 0 { Some((id - 1) / M) } else { None }
 }
 
+// Returns the level of a bin and the 0-based, half-open interval it covers.
+pub(crate) fn bin_interval(id: usize, min_shift: u8, depth: u8) -> Option<(u8, Range<usize>)> {
+    let mut first_id = 0;
+
+    for level in 0..=depth {
+        let bin_count = 1 << (3 * usize::from(level));
+
+        if id < first_id + bin_count {
+            let shift = usize::from(min_shift) + 3 * usize::from(depth - level);
+            let start = (id - first_id) << shift;
+            let end = start + (1 << shift);
+            return Some((level, start..end));
+        }
+
+        first_id += bin_count;
+    }
+
+    None
+}
+
 // `CSIv1.pdf` (2020-07-21)
-fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
+pub(crate) fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
     // [beg, end), 0-based
     let beg = usize::from(start) - 1;
     let end = usize::from(end);
@@ -320,6 +375,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bin_interval() {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        assert_eq!(bin_interval(0, MIN_SHIFT, DEPTH), Some((0, 0..1024)));
+        assert_eq!(bin_interval(2, MIN_SHIFT, DEPTH), Some((1, 128..256)));
+        assert_eq!(bin_interval(9, MIN_SHIFT, DEPTH), Some((2, 0..16)));
+        assert_eq!(bin_interval(17, MIN_SHIFT, DEPTH), Some((2, 128..144)));
+        assert_eq!(bin_interval(72, MIN_SHIFT, DEPTH), Some((2, 1008..1024)));
+        assert!(bin_interval(73, MIN_SHIFT, DEPTH).is_none());
+    }
+
     #[test]
     fn test_reg2bin() -> Result<(), noodles_core::position::TryFromIntError> {
         const MIN_SHIFT: u8 = 4;
@@ -6,6 +6,7 @@ mod linear_index;
 use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
+pub(crate) use self::linear_index::WINDOW_SIZE as LINEAR_INDEX_WINDOW_SIZE;
 pub use self::{binned_index::BinnedIndex, linear_index::LinearIndex};
 use super::bin::Chunk;
 
@@ -20,4 +21,22 @@ pub trait Index {
 
     /// Adds a record to the index.
     fn update(&mut self, min_shift: u8, depth: u8, start: Position, end: Position, chunk: Chunk);
+
+    /// Merges an index built from the records that follow the records of this index.
+    fn merge(&mut self, other: Self)
+    where
+        Self: Sized;
+
+    /// Returns an iterator over the start positions in the index.
+    ///
+    /// Each start position is paired with its key, i.e., the window index of a linear index or the
+    /// bin ID of a binned index.
+    fn entries(&self) -> Box<dyn Iterator<Item = (usize, bgzf::VirtualPosition)> + '_>;
+
+    /// Returns the key of the first entry that is out of order, if any.
+    ///
+    /// By default, entries are unordered, and this returns `None`.
+    fn find_unsorted_entry(&self) -> Option<usize> {
+        None
+    }
 }
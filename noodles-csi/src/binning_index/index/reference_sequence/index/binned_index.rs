@@ -42,6 +42,22 @@ impl Index for BinnedIndex {
             })
             .or_insert(chunk.start());
     }
+
+    fn merge(&mut self, other: Self) {
+        for (bin_id, position) in other {
+            self.entry(bin_id)
+                .and_modify(|loffset| {
+                    if position < *loffset {
+                        *loffset = position;
+                    }
+                })
+                .or_insert(position);
+        }
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (usize, bgzf::VirtualPosition)> + '_> {
+        Box::new(self.iter().map(|(&id, &position)| (id, position)))
+    }
 }
 
 #[cfg(test)]
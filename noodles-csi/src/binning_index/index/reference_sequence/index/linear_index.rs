@@ -9,7 +9,7 @@ pub type LinearIndex = Vec<bgzf::VirtualPosition>;
 
 // _Sequence Alignment/Map Format Specification_ (2023-05-24) § 5.1.3 "Combining with linear
 // index": "...each tiling 16384bp window..."
-const WINDOW_SIZE: usize = 1 << 14;
+pub(crate) const WINDOW_SIZE: usize = 1 << 14;
 
 impl Index for LinearIndex {
     fn min_offset(&self, _: u8, _: u8, start: Position) -> bgzf::VirtualPosition {
@@ -29,6 +29,23 @@ impl Index for LinearIndex {
             self.resize(new_len, chunk.start());
         }
     }
+
+    fn merge(&mut self, other: Self) {
+        if other.len() > self.len() {
+            self.extend_from_slice(&other[self.len()..]);
+        }
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (usize, bgzf::VirtualPosition)> + '_> {
+        Box::new(self.iter().copied().enumerate())
+    }
+
+    // The start positions of a linear index are nondecreasing.
+    fn find_unsorted_entry(&self) -> Option<usize> {
+        self.windows(2)
+            .position(|pair| pair[1] < pair[0])
+            .map(|i| i + 1)
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +118,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_find_unsorted_entry() {
+        let index: LinearIndex = [3, 5, 5, 8]
+            .into_iter()
+            .map(bgzf::VirtualPosition::from)
+            .collect();
+        assert!(index.find_unsorted_entry().is_none());
+
+        let index: LinearIndex = [3, 5, 2, 8]
+            .into_iter()
+            .map(bgzf::VirtualPosition::from)
+            .collect();
+        assert_eq!(index.find_unsorted_entry(), Some(2));
+    }
 }
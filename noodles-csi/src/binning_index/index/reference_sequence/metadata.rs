@@ -135,4 +135,11 @@ impl Metadata {
         self.start_position = self.start_position.min(chunk.start());
         self.end_position = self.end_position.max(chunk.end());
     }
+
+    pub(super) fn merge(&mut self, other: Self) {
+        self.start_position = self.start_position.min(other.start_position);
+        self.end_position = self.end_position.max(other.end_position);
+        self.mapped_record_count += other.mapped_record_count;
+        self.unmapped_record_count += other.unmapped_record_count;
+    }
 }
@@ -0,0 +1,288 @@
+//! Binning index statistics.
+
+use super::{
+    Index, ReferenceSequence,
+    reference_sequence::{self, bin_interval},
+};
+
+/// Binning index statistics.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Statistics {
+    min_shift: u8,
+    depth: u8,
+    reference_sequences: Vec<ReferenceSequenceStatistics>,
+    unplaced_unmapped_record_count: Option<u64>,
+}
+
+impl Statistics {
+    /// Returns the number of bits for the minimum interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert_eq!(statistics.min_shift(), 14);
+    /// ```
+    pub fn min_shift(&self) -> u8 {
+        self.min_shift
+    }
+
+    /// Returns the depth of the binning index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert_eq!(statistics.depth(), 5);
+    /// ```
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the statistics of each reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert!(statistics.reference_sequences().is_empty());
+    /// ```
+    pub fn reference_sequences(&self) -> &[ReferenceSequenceStatistics] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unplaced, unmapped records in the associated file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert!(statistics.unplaced_unmapped_record_count().is_none());
+    /// ```
+    pub fn unplaced_unmapped_record_count(&self) -> Option<u64> {
+        self.unplaced_unmapped_record_count
+    }
+
+    /// Returns the total number of mapped records.
+    ///
+    /// This is `None` if any reference sequence is missing metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert_eq!(statistics.mapped_record_count(), Some(0));
+    /// ```
+    pub fn mapped_record_count(&self) -> Option<u64> {
+        self.reference_sequences
+            .iter()
+            .map(|reference_sequence| reference_sequence.mapped_record_count())
+            .sum()
+    }
+
+    /// Returns the total number of unmapped records, including unplaced, unmapped records.
+    ///
+    /// This is `None` if any reference sequence is missing metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let statistics = csi::Index::default().statistics();
+    /// assert_eq!(statistics.unmapped_record_count(), Some(0));
+    /// ```
+    pub fn unmapped_record_count(&self) -> Option<u64> {
+        self.reference_sequences
+            .iter()
+            .map(|reference_sequence| reference_sequence.unmapped_record_count())
+            .sum::<Option<u64>>()
+            .map(|n| n + self.unplaced_unmapped_record_count.unwrap_or_default())
+    }
+}
+
+/// Statistics of a binning index reference sequence.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequenceStatistics {
+    mapped_record_count: Option<u64>,
+    unmapped_record_count: Option<u64>,
+    bin_count: usize,
+    chunk_count: usize,
+    bin_counts_by_level: Vec<usize>,
+    index_entry_count: usize,
+}
+
+impl ReferenceSequenceStatistics {
+    /// Returns the number of mapped records, if the reference sequence has metadata.
+    pub fn mapped_record_count(&self) -> Option<u64> {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records, if the reference sequence has metadata.
+    pub fn unmapped_record_count(&self) -> Option<u64> {
+        self.unmapped_record_count
+    }
+
+    /// Returns the number of occupied bins.
+    ///
+    /// This excludes the metadata pseudo-bin.
+    pub fn bin_count(&self) -> usize {
+        self.bin_count
+    }
+
+    /// Returns the total number of chunks in all bins.
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_count
+    }
+
+    /// Returns the number of occupied bins at each level.
+    ///
+    /// Level 0 is the root bin, and the last level has the bins of the minimum interval size.
+    /// The list has `depth + 1` levels.
+    pub fn bin_counts_by_level(&self) -> &[usize] {
+        &self.bin_counts_by_level
+    }
+
+    /// Returns the number of index entries.
+    ///
+    /// For a linear index (BAI and tabix), this is the number of 16 kbp windows covered. For a
+    /// binned index (CSI), this is the number of bins with a first record offset.
+    pub fn index_entry_count(&self) -> usize {
+        self.index_entry_count
+    }
+}
+
+impl<I> Index<I>
+where
+    I: reference_sequence::Index,
+{
+    /// Returns statistics of the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// let statistics = index.statistics();
+    /// assert!(statistics.reference_sequences().is_empty());
+    /// ```
+    pub fn statistics(&self) -> Statistics {
+        let reference_sequences = self
+            .reference_sequences
+            .iter()
+            .map(|reference_sequence| {
+                build_reference_sequence_statistics(reference_sequence, self.min_shift, self.depth)
+            })
+            .collect();
+
+        Statistics {
+            min_shift: self.min_shift,
+            depth: self.depth,
+            reference_sequences,
+            unplaced_unmapped_record_count: self.unplaced_unmapped_record_count,
+        }
+    }
+}
+
+fn build_reference_sequence_statistics<I>(
+    reference_sequence: &ReferenceSequence<I>,
+    min_shift: u8,
+    depth: u8,
+) -> ReferenceSequenceStatistics
+where
+    I: reference_sequence::Index,
+{
+    let (bins, index, metadata) = reference_sequence.as_parts();
+
+    let mut bin_counts_by_level = vec![0; usize::from(depth) + 1];
+
+    for &id in bins.keys() {
+        if let Some((level, _)) = bin_interval(id, min_shift, depth) {
+            bin_counts_by_level[usize::from(level)] += 1;
+        }
+    }
+
+    ReferenceSequenceStatistics {
+        mapped_record_count: metadata.map(|m| m.mapped_record_count()),
+        unmapped_record_count: metadata.map(|m| m.unmapped_record_count()),
+        bin_count: bins.len(),
+        chunk_count: bins.values().map(|bin| bin.chunks().len()).sum(),
+        bin_counts_by_level,
+        index_entry_count: index.entries().count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::binning_index::{
+        Indexer,
+        index::reference_sequence::{
+            bin::Chunk,
+            index::{BinnedIndex, LinearIndex},
+        },
+    };
+
+    #[test]
+    fn test_statistics() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_index<I>() -> Result<Index<I>, Box<dyn std::error::Error>>
+        where
+            I: crate::binning_index::index::reference_sequence::Index + Default,
+        {
+            let mut indexer = Indexer::new(14, 5);
+
+            for (i, (start, end, is_mapped)) in
+                [(8, 13, true), (8, 13, false), (16000, 17000, true)]
+                    .into_iter()
+                    .enumerate()
+            {
+                let chunk = Chunk::new(
+                    bgzf::VirtualPosition::from(i as u64 * 8),
+                    bgzf::VirtualPosition::from(i as u64 * 8 + 8),
+                );
+
+                let start = Position::try_from(start)?;
+                let end = Position::try_from(end)?;
+                indexer.add_record(Some((0, start, end, is_mapped)), chunk)?;
+            }
+
+            indexer.add_record(None, Chunk::new(24.into(), 32.into()))?;
+
+            Ok(indexer.build(1))
+        }
+
+        let index: Index<LinearIndex> = build_index()?;
+        let statistics = index.statistics();
+
+        assert_eq!(statistics.mapped_record_count(), Some(2));
+        assert_eq!(statistics.unmapped_record_count(), Some(2));
+
+        let expected = [ReferenceSequenceStatistics {
+            mapped_record_count: Some(2),
+            unmapped_record_count: Some(1),
+            bin_count: 2,
+            chunk_count: 2,
+            bin_counts_by_level: vec![0, 0, 0, 0, 1, 1],
+            index_entry_count: 2,
+        }];
+        assert_eq!(statistics.reference_sequences(), expected);
+
+        let index: Index<BinnedIndex> = build_index()?;
+        let statistics = index.statistics();
+
+        let expected = [ReferenceSequenceStatistics {
+            index_entry_count: 2,
+            ..expected[0].clone()
+        }];
+        assert_eq!(statistics.reference_sequences(), expected);
+
+        Ok(())
+    }
+}
@@ -120,6 +120,102 @@ where
         Ok(())
     }
 
+    /// Merges an indexer that indexed the records directly following the records of this indexer.
+    ///
+    /// This allows building partial indexes in parallel, e.g., one per partition of a file. The
+    /// indexers must be merged in the order of their records, and the result is the same as
+    /// adding all the records to a single indexer.
+    ///
+    /// This returns an error if the indexers have different binning parameters or if the records
+    /// of `other` are not sorted after those of this indexer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::binning_index::{
+    ///     index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+    ///     Indexer,
+    /// };
+    ///
+    /// let mut indexer = Indexer::<BinnedIndex>::new(14, 5);
+    /// indexer.add_record(
+    ///     Some((0, Position::try_from(8)?, Position::try_from(13)?, true)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(144), bgzf::VirtualPosition::from(233)),
+    /// )?;
+    ///
+    /// let mut other = Indexer::<BinnedIndex>::new(14, 5);
+    /// other.add_record(
+    ///     Some((1, Position::try_from(21)?, Position::try_from(34)?, true)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(233), bgzf::VirtualPosition::from(377)),
+    /// )?;
+    ///
+    /// indexer.merge(other)?;
+    ///
+    /// let index = indexer.build(2);
+    /// assert_eq!(index.reference_sequences().len(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(&mut self, other: Self) -> io::Result<()> {
+        self.merge_with_offset(other, 0)
+    }
+
+    // Merges an indexer whose reference sequence IDs are shifted by `reference_sequence_id_offset`.
+    //
+    // This is used by the tabix indexer, whose partial indexers assign IDs independently.
+    #[doc(hidden)]
+    pub fn merge_with_offset(
+        &mut self,
+        other: Self,
+        reference_sequence_id_offset: usize,
+    ) -> io::Result<()> {
+        if (self.min_shift, self.depth) != (other.min_shift, other.depth) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "binning parameters mismatch",
+            ));
+        }
+
+        // The reference sequence IDs of `other` are sorted, so only the first used one can
+        // precede the current reference sequence.
+        let first_reference_sequence_id = other
+            .reference_sequences
+            .iter()
+            .position(|reference_sequence| !reference_sequence.is_empty())
+            .map(|i| i + reference_sequence_id_offset);
+
+        if let (Some(reference_sequence_id), Some(current_reference_sequence_id)) = (
+            first_reference_sequence_id,
+            self.reference_sequences.len().checked_sub(1),
+        ) {
+            if reference_sequence_id < current_reference_sequence_id {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid reference sequence ID",
+                ));
+            }
+        }
+
+        self.unplaced_unmapped_record_count += other.unplaced_unmapped_record_count;
+
+        for (i, reference_sequence) in other.reference_sequences.into_iter().enumerate() {
+            if reference_sequence.is_empty() {
+                continue;
+            }
+
+            let reference_sequence_id = i + reference_sequence_id_offset;
+
+            if reference_sequence_id >= self.reference_sequences.len() {
+                self.add_reference_sequences_until(reference_sequence_id);
+            }
+
+            self.reference_sequences[reference_sequence_id].merge(reference_sequence);
+        }
+
+        Ok(())
+    }
+
     /// Builds a binning index.
     ///
     /// # Examples
@@ -270,6 +366,101 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::binning_index::index::reference_sequence::index::BinnedIndex;
+
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        for (id, start, len, is_mapped) in [
+            (0, 8, 13, true),
+            (0, 21, 34, false),
+            (0, 16384, 10, true),
+            (0, 40000, 100000, true),
+            (1, 1, 5, true),
+            (1, 20000, 8, true),
+            (2, 55, 89, true),
+        ] {
+            let start = Position::try_from(start)?;
+            let end = start.checked_add(len).unwrap();
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(position),
+                bgzf::VirtualPosition::from(position + 100),
+            );
+            records.push((Some((id, start, end, is_mapped)), chunk));
+            position += 100;
+        }
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(position),
+            bgzf::VirtualPosition::from(position + 100),
+        );
+        records.push((None, chunk));
+
+        for i in 0..=records.len() {
+            let mut actual = Indexer::<BinnedIndex>::default();
+
+            for (alignment_context, chunk) in records[..i].iter().copied() {
+                actual.add_record(alignment_context, chunk)?;
+            }
+
+            let mut other = Indexer::default();
+
+            for (alignment_context, chunk) in records[i..].iter().copied() {
+                other.add_record(alignment_context, chunk)?;
+            }
+
+            actual.merge(other)?;
+
+            let mut expected = Indexer::<BinnedIndex>::default();
+
+            for (alignment_context, chunk) in records.iter().copied() {
+                expected.add_record(alignment_context, chunk)?;
+            }
+
+            assert_eq!(actual.build(3), expected.build(3));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(9),
+        );
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+        indexer.add_record(
+            Some((1, Position::try_from(8)?, Position::try_from(13)?, true)),
+            chunk,
+        )?;
+
+        let mut expected = Indexer::<LinearIndex>::default();
+        expected.add_record(
+            Some((1, Position::try_from(8)?, Position::try_from(13)?, true)),
+            chunk,
+        )?;
+
+        let mut other = Indexer::default();
+        other.add_record(None, chunk)?;
+        other.add_record(
+            Some((0, Position::try_from(8)?, Position::try_from(13)?, true)),
+            chunk,
+        )?;
+
+        assert!(matches!(
+            indexer.merge(other),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(indexer.build(2), expected.build(2));
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_with_reference_sequence_count() {
         let index = Indexer::<LinearIndex>::default().build(2);
@@ -0,0 +1,120 @@
+use noodles_bgzf as bgzf;
+
+use super::index::reference_sequence::bin::Chunk;
+
+/// A written record pending indexing.
+///
+/// The chunk of a record written to a BGZF stream ends at the virtual position of the writer
+/// after the record is written. If the block is then flushed, e.g., because it is full, the same
+/// end position is instead at the start of the next block. An indexed writer holds the last
+/// record written and indexes it when the next record is written, whose start position is the
+/// final end position, or when the writer is finished.
+///
+/// The context `T` is whatever the indexer needs to add the record, e.g., the alignment context of
+/// the record.
+#[derive(Debug)]
+pub struct PendingRecord<T>(Option<(T, Chunk)>);
+
+impl<T> PendingRecord<T> {
+    /// Replaces the pending record and returns the previous one, which can be indexed.
+    ///
+    /// The end position of the chunk of the previous record is set to the start position of the
+    /// given chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::bin::Chunk, PendingRecord};
+    ///
+    /// let mut pending_record = PendingRecord::default();
+    ///
+    /// let chunk = Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(8));
+    /// assert!(pending_record.replace(0, chunk).is_none());
+    ///
+    /// // The block was flushed after the first record was written.
+    /// let start_position = bgzf::VirtualPosition::try_from((21, 0))?;
+    /// let chunk = Chunk::new(start_position, bgzf::VirtualPosition::try_from((21, 5))?);
+    /// assert_eq!(
+    ///     pending_record.replace(1, chunk),
+    ///     Some((0, Chunk::new(bgzf::VirtualPosition::from(0), start_position))),
+    /// );
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn replace(&mut self, context: T, chunk: Chunk) -> Option<(T, Chunk)> {
+        self.0
+            .replace((context, chunk))
+            .map(|(prev_context, prev_chunk)| {
+                (prev_context, Chunk::new(prev_chunk.start(), chunk.start()))
+            })
+    }
+
+    /// Takes the pending record when the writer is finished.
+    ///
+    /// `end_position` is the virtual position of the writer after the stream is flushed, which is
+    /// the end of the last record written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::bin::Chunk, PendingRecord};
+    ///
+    /// let mut pending_record = PendingRecord::default();
+    ///
+    /// let chunk = Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(8));
+    /// pending_record.replace(0, chunk);
+    ///
+    /// let end_position = bgzf::VirtualPosition::try_from((21, 0))?;
+    ///
+    /// assert_eq!(
+    ///     pending_record.finish(end_position),
+    ///     Some((0, Chunk::new(bgzf::VirtualPosition::from(0), end_position))),
+    /// );
+    ///
+    /// assert!(pending_record.finish(end_position).is_none());
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn finish(&mut self, end_position: bgzf::VirtualPosition) -> Option<(T, Chunk)> {
+        self.0
+            .take()
+            .map(|(context, chunk)| (context, Chunk::new(chunk.start(), end_position)))
+    }
+}
+
+impl<T> Default for PendingRecord<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace() -> Result<(), bgzf::virtual_position::TryFromU64U16TupleError> {
+        let mut pending_record = PendingRecord::default();
+
+        // The first record fills the block, which is flushed when the second record is written.
+        let end_position = bgzf::VirtualPosition::try_from((0, 65280))?;
+        let chunk = Chunk::new(bgzf::VirtualPosition::default(), end_position);
+        assert!(pending_record.replace(0, chunk).is_none());
+
+        let start_position = bgzf::VirtualPosition::try_from((21, 0))?;
+        let end_position = bgzf::VirtualPosition::try_from((21, 8))?;
+        let chunk = Chunk::new(start_position, end_position);
+
+        assert_eq!(
+            pending_record.replace(1, chunk),
+            Some((
+                0,
+                Chunk::new(bgzf::VirtualPosition::default(), start_position)
+            ))
+        );
+
+        assert_eq!(pending_record.finish(end_position), Some((1, chunk)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,660 @@
+//! Binning index validator.
+
+mod report;
+
+pub use self::report::{Issue, Report};
+
+use std::{collections::HashSet, io};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{
+    BinningIndex, Indexer, ReferenceSequence as _,
+    index::{
+        Header, Index,
+        reference_sequence::{self, Bin, bin::Chunk, parent_id, reg2bin},
+    },
+};
+
+/// A binning index validator.
+///
+/// A validator checks an index against the records of its associated file. The records must be
+/// added in the order they appear in the file, with chunks that span each record, i.e., the
+/// virtual positions of the stream before and after reading the record.
+///
+/// The validator checks that
+///
+///   * every chunk and start position in the index is at a record boundary in the file,
+///   * the start positions of a linear index are nondecreasing,
+///   * the record counts in the metadata of each reference sequence and the number of unplaced,
+///     unmapped records match the file,
+///   * every record is in a chunk of its bin, and
+///   * no query that overlaps a record skips the record.
+///
+/// A new index is built from the records while validating. If the index has issues, the report
+/// includes this index as a repaired index.
+pub struct Validator<'a, I> {
+    index: &'a Index<I>,
+    indexer: Indexer<I>,
+    header: Option<Header>,
+    boundaries: Boundaries,
+    record_counts: Vec<(u64, u64)>,
+    unplaced_unmapped_record_count: u64,
+    issues: Vec<Issue>,
+    reported_bins: HashSet<(bool, usize, usize)>,
+}
+
+impl<'a, I> Validator<'a, I>
+where
+    I: reference_sequence::Index + Default,
+{
+    /// Creates a binning index validator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, binning_index::Validator};
+    /// let index = csi::Index::default();
+    /// let validator = Validator::new(&index);
+    /// ```
+    pub fn new(index: &'a Index<I>) -> Self {
+        let boundaries = Boundaries::new(index);
+
+        Self {
+            index,
+            indexer: Indexer::new(index.min_shift(), index.depth()),
+            header: index.header().cloned(),
+            boundaries,
+            record_counts: Vec::new(),
+            unplaced_unmapped_record_count: 0,
+            issues: Vec::new(),
+            reported_bins: HashSet::new(),
+        }
+    }
+
+    /// Sets the tabix header of the repaired index.
+    ///
+    /// By default, the repaired index has the header of the validated index. This replaces it,
+    /// e.g., to include reference sequence names that are missing from the validated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::{index::Header, Validator},
+    /// };
+    ///
+    /// let index = csi::Index::default();
+    /// let mut validator = Validator::new(&index);
+    /// validator.set_header(Header::default());
+    /// ```
+    pub fn set_header(&mut self, header: Header) {
+        self.header = Some(header);
+    }
+
+    /// Adds a record.
+    ///
+    /// The alignment context is the same as the one given to [`Indexer::add_record`], i.e., the
+    /// reference sequence ID, start and end positions, and whether the record is mapped; or
+    /// `None` for an unplaced, unmapped record.
+    ///
+    /// This returns an error if the records are not sorted by reference sequence ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::{index::reference_sequence::bin::Chunk, Validator},
+    /// };
+    ///
+    /// let index = csi::Index::default();
+    /// let mut validator = Validator::new(&index);
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// );
+    ///
+    /// validator.add_record(Some((0, start, end, true)), chunk)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(
+        &mut self,
+        alignment_context: Option<(usize, Position, Position, bool)>,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        self.indexer.add_record(alignment_context, chunk)?;
+
+        self.boundaries.visit(chunk.start());
+        self.boundaries.visit(chunk.end());
+
+        let Some((reference_sequence_id, start, end, is_mapped)) = alignment_context else {
+            self.unplaced_unmapped_record_count += 1;
+            return Ok(());
+        };
+
+        if reference_sequence_id >= self.record_counts.len() {
+            self.record_counts
+                .resize(reference_sequence_id + 1, Default::default());
+        }
+
+        let counts = &mut self.record_counts[reference_sequence_id];
+        let is_first_record = *counts == (0, 0);
+
+        if is_mapped {
+            counts.0 += 1;
+        } else {
+            counts.1 += 1;
+        }
+
+        let Some(reference_sequence) = self.index.reference_sequences().get(reference_sequence_id)
+        else {
+            if is_first_record {
+                self.issues.push(Issue::MissingReferenceSequence {
+                    reference_sequence_id,
+                });
+            }
+
+            return Ok(());
+        };
+
+        let (min_shift, depth) = (self.index.min_shift(), self.index.depth());
+        let bin_id = reg2bin(start, end, min_shift, depth);
+
+        // htslib moves the chunks of small bins into their parent bins (`compress_binning`), so a
+        // record can be in a chunk of any ancestor of its bin.
+        let metadata_id = Bin::metadata_id(depth);
+
+        let is_in_bin = std::iter::successors(Some(bin_id), |&id| parent_id(id))
+            .filter(|&id| id != metadata_id)
+            .filter_map(|id| reference_sequence.bins().get(&id))
+            .any(|bin| contains(bin.chunks(), chunk));
+
+        if !is_in_bin
+            && self
+                .reported_bins
+                .insert((false, reference_sequence_id, bin_id))
+        {
+            self.issues.push(Issue::MissingRecord {
+                reference_sequence_id,
+                bin_id,
+                chunk,
+            });
+        }
+
+        let min_offset = reference_sequence.min_offset(min_shift, depth, start);
+
+        if min_offset > chunk.start()
+            && self
+                .reported_bins
+                .insert((true, reference_sequence_id, bin_id))
+        {
+            self.issues.push(Issue::ExcludedRecord {
+                reference_sequence_id,
+                start,
+                chunk,
+                min_offset,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Finishes validating the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, binning_index::Validator};
+    ///
+    /// let index = csi::Index::builder().set_unplaced_unmapped_record_count(0).build();
+    /// let validator = Validator::new(&index);
+    ///
+    /// let report = validator.finish();
+    /// assert!(report.is_ok());
+    /// ```
+    pub fn finish(self) -> Report<I> {
+        let mut issues = self.check_index();
+        issues.extend(self.issues);
+
+        let repaired_index = if issues.is_empty() {
+            None
+        } else {
+            let mut indexer = self.indexer;
+
+            if let Some(header) = self.header {
+                indexer = indexer.set_header(header);
+            }
+
+            let reference_sequence_count = self
+                .index
+                .reference_sequences()
+                .len()
+                .max(self.record_counts.len());
+
+            Some(indexer.build(reference_sequence_count))
+        };
+
+        Report::new(issues, repaired_index)
+    }
+
+    fn check_index(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for (reference_sequence_id, reference_sequence) in
+            self.index.reference_sequences().iter().enumerate()
+        {
+            for (&bin_id, bin) in reference_sequence.bins() {
+                for &chunk in bin.chunks() {
+                    if chunk.start() > chunk.end()
+                        || !self.boundaries.is_visited(chunk.start())
+                        || !self.boundaries.is_visited(chunk.end())
+                    {
+                        issues.push(Issue::InvalidChunk {
+                            reference_sequence_id,
+                            bin_id,
+                            chunk,
+                        });
+                    }
+                }
+            }
+
+            for (key, position) in reference_sequence.index().entries() {
+                if position != bgzf::VirtualPosition::default()
+                    && !self.boundaries.is_visited(position)
+                {
+                    issues.push(Issue::InvalidOffset {
+                        reference_sequence_id,
+                        key,
+                        position,
+                    });
+                }
+            }
+
+            if let Some(key) = reference_sequence.index().find_unsorted_entry() {
+                issues.push(Issue::UnsortedOffsets {
+                    reference_sequence_id,
+                    key,
+                });
+            }
+
+            if let Some(metadata) = reference_sequence.metadata() {
+                let expected = self
+                    .record_counts
+                    .get(reference_sequence_id)
+                    .copied()
+                    .unwrap_or_default();
+
+                let actual = (
+                    metadata.mapped_record_count(),
+                    metadata.unmapped_record_count(),
+                );
+
+                if actual != expected {
+                    issues.push(Issue::RecordCountMismatch {
+                        reference_sequence_id,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        if let Some(actual) = self.index.unplaced_unmapped_record_count() {
+            let expected = self.unplaced_unmapped_record_count;
+
+            if actual != expected {
+                issues.push(Issue::UnplacedUnmappedRecordCountMismatch { expected, actual });
+            }
+        }
+
+        issues
+    }
+}
+
+// Returns whether a chunk is within any of the given chunks, which are sorted and non-overlapping.
+fn contains(chunks: &[Chunk], chunk: Chunk) -> bool {
+    let i = chunks.partition_point(|c| c.start() <= chunk.start());
+
+    i > 0 && chunk.end() <= chunks[i - 1].end()
+}
+
+// The positions referenced by an index, marked when they are seen as record boundaries in the
+// associated file.
+//
+// Record boundaries are visited in increasing order, so this only keeps the positions of the index
+// rather than every boundary in the file.
+struct Boundaries {
+    positions: Vec<bgzf::VirtualPosition>,
+    is_visited: Vec<bool>,
+    cursor: usize,
+}
+
+impl Boundaries {
+    fn new<I>(index: &Index<I>) -> Self
+    where
+        I: reference_sequence::Index,
+    {
+        let mut positions = Vec::new();
+
+        for reference_sequence in index.reference_sequences() {
+            for bin in reference_sequence.bins().values() {
+                for chunk in bin.chunks() {
+                    positions.push(chunk.start());
+                    positions.push(chunk.end());
+                }
+            }
+
+            positions.extend(
+                reference_sequence
+                    .index()
+                    .entries()
+                    .map(|(_, position)| position),
+            );
+        }
+
+        positions.sort_unstable();
+        positions.dedup();
+
+        let is_visited = vec![false; positions.len()];
+
+        Self {
+            positions,
+            is_visited,
+            cursor: 0,
+        }
+    }
+
+    fn visit(&mut self, position: bgzf::VirtualPosition) {
+        while self
+            .positions
+            .get(self.cursor)
+            .is_some_and(|p| *p < position)
+        {
+            self.cursor += 1;
+        }
+
+        if self.positions.get(self.cursor) == Some(&position) {
+            self.is_visited[self.cursor] = true;
+        }
+    }
+
+    fn is_visited(&self, position: bgzf::VirtualPosition) -> bool {
+        self.positions
+            .binary_search(&position)
+            .map(|i| self.is_visited[i])
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binning_index::index::reference_sequence::index::{BinnedIndex, LinearIndex};
+
+    type Record = (Option<(usize, Position, Position, bool)>, Chunk);
+
+    fn build_records() -> Result<Vec<Record>, Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        for (alignment_context, len) in [
+            (Some((0, 8, 13, true)), 100),
+            (Some((0, 21, 34, false)), 80),
+            (Some((0, 20000, 20100, true)), 120),
+            (Some((1, 55, 89, true)), 60),
+            (None, 40),
+        ] {
+            let alignment_context = match alignment_context {
+                Some((id, start, end, is_mapped)) => Some((
+                    id,
+                    Position::try_from(start)?,
+                    Position::try_from(end)?,
+                    is_mapped,
+                )),
+                None => None,
+            };
+
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(position),
+                bgzf::VirtualPosition::from(position + len),
+            );
+
+            records.push((alignment_context, chunk));
+            position += len;
+        }
+
+        Ok(records)
+    }
+
+    fn build_index<I>(records: &[Record], reference_sequence_count: usize) -> io::Result<Index<I>>
+    where
+        I: reference_sequence::Index + Default,
+    {
+        let mut indexer = Indexer::new(14, 5);
+
+        for &(alignment_context, chunk) in records {
+            indexer.add_record(alignment_context, chunk)?;
+        }
+
+        Ok(indexer.build(reference_sequence_count))
+    }
+
+    fn validate<I>(index: &Index<I>, records: &[Record]) -> io::Result<Report<I>>
+    where
+        I: reference_sequence::Index + Default,
+    {
+        let mut validator = Validator::new(index);
+
+        for &(alignment_context, chunk) in records {
+            validator.add_record(alignment_context, chunk)?;
+        }
+
+        Ok(validator.finish())
+    }
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+
+        let index: Index<LinearIndex> = build_index(&records, 2)?;
+        let report = validate(&index, &records)?;
+        assert!(report.is_ok());
+        assert!(report.repaired_index().is_none());
+
+        let index: Index<BinnedIndex> = build_index(&records, 2)?;
+        let report = validate(&index, &records)?;
+        assert!(report.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_chunks_in_parent_bins() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let index: Index<LinearIndex> = build_index(&records, 2)?;
+
+        // Move the chunks of bin 4682 into its parent bin (585), as `compress_binning` does.
+        let reference_sequences = index
+            .reference_sequences()
+            .iter()
+            .cloned()
+            .map(|reference_sequence| {
+                let (mut bins, linear_index, metadata) = reference_sequence.into_parts();
+
+                if let Some(bin) = bins.shift_remove(&4682) {
+                    let parent = bins.entry(585).or_insert_with(|| Bin::new(Vec::new()));
+
+                    for &chunk in bin.chunks() {
+                        parent.add_chunk(chunk);
+                    }
+                }
+
+                reference_sequence::ReferenceSequence::new(bins, linear_index, metadata)
+            })
+            .collect();
+
+        let compressed_index = Index::builder()
+            .set_min_shift(index.min_shift())
+            .set_depth(index.depth())
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(1)
+            .build();
+
+        assert!(
+            !compressed_index.reference_sequences()[0]
+                .bins()
+                .contains_key(&4682)
+        );
+
+        let report = validate(&compressed_index, &records)?;
+        assert!(report.is_ok(), "{:?}", report.issues());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_stale_index() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let index: Index<LinearIndex> = build_index(&records, 2)?;
+
+        // The second record grows by 8 bytes, shifting every following record.
+        let stale_records: Vec<_> = records
+            .iter()
+            .enumerate()
+            .map(|(i, &(alignment_context, chunk))| {
+                let shift = |position: bgzf::VirtualPosition, n: u64| {
+                    bgzf::VirtualPosition::from(u64::from(position) + n)
+                };
+
+                let chunk = match i {
+                    0 => chunk,
+                    1 => Chunk::new(chunk.start(), shift(chunk.end(), 8)),
+                    _ => Chunk::new(shift(chunk.start(), 8), shift(chunk.end(), 8)),
+                };
+
+                (alignment_context, chunk)
+            })
+            .collect();
+
+        let report = validate(&index, &stale_records)?;
+        assert!(!report.is_ok());
+
+        let issues = report.issues();
+
+        assert!(issues.contains(&Issue::InvalidChunk {
+            reference_sequence_id: 1,
+            bin_id: 4681,
+            chunk: records[3].1,
+        }));
+
+        assert!(issues.contains(&Issue::MissingRecord {
+            reference_sequence_id: 1,
+            bin_id: 4681,
+            chunk: stale_records[3].1,
+        }));
+
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            Issue::InvalidOffset {
+                reference_sequence_id: 0,
+                key: 1,
+                ..
+            }
+        )));
+
+        let repaired_index = report.repaired_index().unwrap();
+        assert_eq!(repaired_index, &build_index(&stale_records, 2)?);
+        assert!(validate(repaired_index, &stale_records)?.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_mismatched_record_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let index: Index<LinearIndex> = build_index(&records, 2)?;
+
+        let mut unmapped_records = records.clone();
+        unmapped_records[0].0 = unmapped_records[0]
+            .0
+            .map(|(id, start, end, _)| (id, start, end, false));
+        unmapped_records.push((
+            None,
+            Chunk::new(
+                bgzf::VirtualPosition::from(400),
+                bgzf::VirtualPosition::from(440),
+            ),
+        ));
+
+        let report = validate(&index, &unmapped_records)?;
+
+        assert_eq!(
+            report.issues(),
+            [
+                Issue::RecordCountMismatch {
+                    reference_sequence_id: 0,
+                    expected: (1, 2),
+                    actual: (2, 1),
+                },
+                Issue::UnplacedUnmappedRecordCountMismatch {
+                    expected: 2,
+                    actual: 1,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+        let index: Index<LinearIndex> = build_index(&records[..3], 1)?;
+
+        let report = validate(&index, &records)?;
+
+        assert!(report.issues().contains(&Issue::MissingReferenceSequence {
+            reference_sequence_id: 1
+        }));
+
+        let repaired_index = report.repaired_index().unwrap();
+        assert_eq!(repaired_index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains() {
+        let chunks = [
+            Chunk::new(
+                bgzf::VirtualPosition::from(8),
+                bgzf::VirtualPosition::from(13),
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::from(21),
+                bgzf::VirtualPosition::from(55),
+            ),
+        ];
+
+        let chunk = |start: u64, end: u64| {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        };
+
+        assert!(contains(&chunks, chunk(8, 13)));
+        assert!(contains(&chunks, chunk(34, 55)));
+        assert!(!contains(&chunks, chunk(5, 8)));
+        assert!(!contains(&chunks, chunk(13, 21)));
+        assert!(!contains(&chunks, chunk(34, 89)));
+    }
+}
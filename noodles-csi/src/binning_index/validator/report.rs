@@ -0,0 +1,188 @@
+use std::fmt;
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use crate::binning_index::{Index, index::reference_sequence::bin::Chunk};
+
+/// An issue found when validating a binning index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Issue {
+    /// A bin chunk does not start and end at record boundaries in the associated file.
+    InvalidChunk {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+        /// The chunk.
+        chunk: Chunk,
+    },
+    /// A start position in the index is not at a record boundary in the associated file.
+    InvalidOffset {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The window index of a linear index or the bin ID of a binned index.
+        key: usize,
+        /// The start position.
+        position: bgzf::VirtualPosition,
+    },
+    /// The start positions of a linear index decrease.
+    UnsortedOffsets {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The window index of the first start position that is out of order.
+        key: usize,
+    },
+    /// The mapped and unmapped record counts of a reference sequence differ from the associated
+    /// file.
+    RecordCountMismatch {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The mapped and unmapped record counts in the associated file.
+        expected: (u64, u64),
+        /// The mapped and unmapped record counts in the index.
+        actual: (u64, u64),
+    },
+    /// The number of unplaced, unmapped records differs from the associated file.
+    UnplacedUnmappedRecordCountMismatch {
+        /// The number of unplaced, unmapped records in the associated file.
+        expected: u64,
+        /// The number of unplaced, unmapped records in the index.
+        actual: u64,
+    },
+    /// A record refers to a reference sequence that is not in the index.
+    MissingReferenceSequence {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+    },
+    /// A record is not in any chunk of its bin.
+    ///
+    /// This is only reported for the first such record in a bin.
+    MissingRecord {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+        /// The chunk of the record.
+        chunk: Chunk,
+    },
+    /// A query that overlaps the start of a record would skip the record.
+    ///
+    /// This is only reported for the first such record in a bin.
+    ExcludedRecord {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The start position of the record.
+        start: Position,
+        /// The chunk of the record.
+        chunk: Chunk,
+        /// The minimum offset of a query that starts at the record start position.
+        min_offset: bgzf::VirtualPosition,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChunk {
+                reference_sequence_id,
+                bin_id,
+                chunk,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: bin {bin_id}: invalid chunk: {:?}..{:?}",
+                chunk.start(),
+                chunk.end()
+            ),
+            Self::InvalidOffset {
+                reference_sequence_id,
+                key,
+                position,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: index entry {key}: invalid offset: {position:?}"
+            ),
+            Self::UnsortedOffsets {
+                reference_sequence_id,
+                key,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: index entry {key}: offset is less than the previous offset"
+            ),
+            Self::RecordCountMismatch {
+                reference_sequence_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: record count mismatch: expected (mapped, unmapped) = {expected:?}, got {actual:?}"
+            ),
+            Self::UnplacedUnmappedRecordCountMismatch { expected, actual } => write!(
+                f,
+                "unplaced, unmapped record count mismatch: expected {expected}, got {actual}"
+            ),
+            Self::MissingReferenceSequence {
+                reference_sequence_id,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: missing from index"
+            ),
+            Self::MissingRecord {
+                reference_sequence_id,
+                bin_id,
+                chunk,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: bin {bin_id}: missing record at {:?}",
+                chunk.start()
+            ),
+            Self::ExcludedRecord {
+                reference_sequence_id,
+                start,
+                chunk,
+                min_offset,
+            } => write!(
+                f,
+                "reference sequence {reference_sequence_id}: record at {:?} starting at {start} is before the minimum offset {min_offset:?}",
+                chunk.start()
+            ),
+        }
+    }
+}
+
+/// A binning index validation report.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report<I> {
+    issues: Vec<Issue>,
+    repaired_index: Option<Index<I>>,
+}
+
+impl<I> Report<I> {
+    pub(super) fn new(issues: Vec<Issue>, repaired_index: Option<Index<I>>) -> Self {
+        Self {
+            issues,
+            repaired_index,
+        }
+    }
+
+    /// Returns the issues found in the index.
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Returns whether the index has no issues.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns an index built from the associated file, if the validated index has issues.
+    pub fn repaired_index(&self) -> Option<&Index<I>> {
+        self.repaired_index.as_ref()
+    }
+
+    /// Returns the repaired index, consuming the report.
+    pub fn into_repaired_index(self) -> Option<Index<I>> {
+        self.repaired_index
+    }
+}
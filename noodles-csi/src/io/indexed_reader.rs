@@ -1,10 +1,11 @@
 use std::io::{self, Read, Seek};
 
+use bstr::BStr;
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::RegionSet};
 
-use super::{Query, indexed_records::Record};
-use crate::BinningIndex;
+use super::{IndexedRecord, Query, indexed_records::Record};
+use crate::{BinningIndex, binning_index::query_regions};
 
 /// An indexed reader.
 pub struct IndexedReader<R, I> {
@@ -77,4 +78,143 @@ where
             .indexed_records(header)
             .filter_by_region(region))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Each record is read at most once, even if it intersects several regions, and is paired with
+    /// the indices of the regions it intersects, in ascending order.
+    pub fn query_regions<'r>(
+        &'r mut self,
+        regions: &[Region],
+    ) -> io::Result<impl Iterator<Item = io::Result<(Record, Vec<usize>)>> + 'r> {
+        let header = self
+            .index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let reference_sequence_names = header.reference_sequence_names();
+
+        let region_set = regions
+            .iter()
+            .map(|region| {
+                reference_sequence_names
+                    .get_index_of(region.name())
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "missing reference sequence name",
+                        )
+                    })
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map(RegionSet::new)?;
+
+        let chunks = query_regions(&self.index, &region_set)?;
+
+        Ok(Query::new(&mut self.inner, chunks)
+            .indexed_records(header)
+            .filter_map(move |result| {
+                let record = match result {
+                    Ok(record) => record,
+                    Err(e) => return Some(Err(e)),
+                };
+
+                let reference_sequence_name = BStr::new(record.indexed_reference_sequence_name());
+                let reference_sequence_id =
+                    reference_sequence_names.get_index_of(reference_sequence_name)?;
+
+                let indices =
+                    region_set.overlapping(reference_sequence_id, record.indexed_interval());
+
+                if indices.is_empty() {
+                    None
+                } else {
+                    Some(Ok((record, indices)))
+                }
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::binning_index::{
+        Indexer,
+        index::{
+            header,
+            reference_sequence::{bin::Chunk, index::LinearIndex},
+        },
+    };
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        const RECORDS: [(&str, usize, usize); 5] = [
+            ("sq0", 8, 13),
+            ("sq0", 21, 34),
+            ("sq0", 55, 89),
+            ("sq1", 8, 13),
+            ("sq2", 8, 13),
+        ];
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        for (i, (reference_sequence_name, start, end)) in RECORDS.into_iter().enumerate() {
+            let chunk_start = writer.virtual_position();
+            writeln!(writer, "{reference_sequence_name}\t.\t.\t{start}\t{end}")?;
+            let chunk_end = writer.virtual_position();
+
+            let reference_sequence_id = i.saturating_sub(2);
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+            let chunk = Chunk::new(chunk_start, chunk_end);
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+        }
+
+        let data = writer.finish()?;
+
+        let header = header::Builder::gff()
+            .set_reference_sequence_names(
+                ["sq0", "sq1", "sq2"]
+                    .into_iter()
+                    .map(|name| name.into())
+                    .collect(),
+            )
+            .build();
+
+        let index = indexer.set_header(header).build(3);
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+
+        let regions = ["sq0:21-60".parse()?, "sq2".parse()?, "sq0:1-30".parse()?];
+
+        let actual: Vec<_> = reader
+            .query_regions(&regions)?
+            .map(|result| {
+                result.map(|(record, indices)| {
+                    (
+                        record.indexed_reference_sequence_name().to_string(),
+                        usize::from(record.indexed_start_position()),
+                        indices,
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (String::from("sq0"), 8, vec![2]),
+            (String::from("sq0"), 21, vec![0, 2]),
+            (String::from("sq0"), 55, vec![0]),
+            (String::from("sq2"), 8, vec![1]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
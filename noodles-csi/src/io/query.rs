@@ -9,6 +9,7 @@ use super::IndexedRecords;
 use crate::binning_index::index::{Header, reference_sequence::bin::Chunk};
 
 enum State {
+    Prefetch,
     Seek,
     Read(bgzf::VirtualPosition),
     Done,
@@ -17,6 +18,9 @@ enum State {
 /// A query reader.
 ///
 /// This reader returns the uncompressed data between all the given chunks.
+///
+/// Before the first read, the chunks are given to the underlying reader as a prefetch hint (see
+/// [`bgzf::io::Seek::prefetch`]). The hint is cleared after the last chunk is read.
 pub struct Query<'r, R> {
     reader: &'r mut R,
     chunks: vec::IntoIter<Chunk>,
@@ -29,7 +33,7 @@ impl<'r, R> Query<'r, R> {
         Self {
             reader,
             chunks: chunks.into_iter(),
-            state: State::Seek,
+            state: State::Prefetch,
         }
     }
 }
@@ -63,13 +67,28 @@ where
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         loop {
             match self.state {
+                State::Prefetch => {
+                    let ranges: Vec<_> = self
+                        .chunks
+                        .as_slice()
+                        .iter()
+                        .map(|chunk| chunk.start()..chunk.end())
+                        .collect();
+
+                    self.reader.prefetch(&ranges)?;
+
+                    self.state = State::Seek;
+                }
                 State::Seek => {
                     self.state = match self.chunks.next() {
                         Some(chunk) => {
                             self.reader.seek_to_virtual_position(chunk.start())?;
                             State::Read(chunk.end())
                         }
-                        None => State::Done,
+                        None => {
+                            self.reader.prefetch(&[])?;
+                            State::Done
+                        }
                     }
                 }
                 State::Read(chunk_end) => {
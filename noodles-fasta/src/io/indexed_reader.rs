@@ -6,7 +6,7 @@ pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Seek};
 
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 
 use super::Reader;
 use crate::{Record, fai};
@@ -15,6 +15,7 @@ use crate::{Record, fai};
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: fai::Index,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R>
@@ -26,6 +27,7 @@ where
         Self {
             inner: Reader::new(inner),
             index,
+            aliases: Aliases::default(),
         }
     }
 
@@ -58,6 +60,16 @@ where
     pub fn index(&self) -> &fai::Index {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -66,6 +78,12 @@ where
 {
     /// Returns a record of the given region.
     pub fn query(&mut self, region: &Region) -> io::Result<Record> {
-        self.inner.query(&self.index, region)
+        let records = self.index.as_ref();
+
+        let region = self.aliases.resolve_region(region, |name| {
+            records.iter().any(|record| record.name() == name)
+        });
+
+        self.inner.query(&self.index, &region)
     }
 }
@@ -6,6 +6,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_core::region::Aliases;
 
 use super::IndexedReader;
 use crate::fai;
@@ -14,6 +15,7 @@ use crate::fai;
 #[derive(Default)]
 pub struct Builder {
     index: Option<fai::Index>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -31,6 +33,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the index are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_fasta::io::indexed_reader::Builder;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed FASTA reader from a path.
     ///
     /// # Examples
@@ -63,7 +85,10 @@ impl Builder {
                 .map(crate::io::BufReader::Uncompressed)?,
         };
 
-        Ok(IndexedReader::new(reader, index))
+        let mut reader = IndexedReader::new(reader, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 
     /// Builds an indexed FASTA reader from a reader.
@@ -88,7 +113,10 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        let mut reader = IndexedReader::new(reader, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 }
 
@@ -11,24 +11,75 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use super::record::Sequence;
+use noodles_core::region::Aliases;
+
+use super::{Record, record::Sequence};
 
 struct AdapterCache {
     adapter: Box<dyn Adapter>,
+    aliases: Aliases,
     cache: HashMap<Vec<u8>, Sequence>,
 }
 
+impl AdapterCache {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+        if let Some(result) = self.adapter.get(name) {
+            return Some(result);
+        }
+
+        let aliases = self.aliases.get(name)?;
+
+        aliases
+            .iter()
+            .filter(|alias| alias.as_slice() != name)
+            .find_map(|alias| self.adapter.get(alias))
+    }
+}
+
 /// A caching sequence repository.
 pub struct Repository(Arc<RwLock<AdapterCache>>);
 
 impl Repository {
     /// Creates a sequence repository.
     pub fn new<A>(adapter: A) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::with_aliases(adapter, Aliases::default())
+    }
+
+    /// Creates a sequence repository with reference sequence name aliases.
+    ///
+    /// If the adapter does not have a sequence for a requested name, the aliases of the name are
+    /// tried in order. The sequence is cached under the requested name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_fasta::{
+    ///     self as fasta,
+    ///     record::{Definition, Sequence},
+    /// };
+    ///
+    /// let records = vec![fasta::Record::new(
+    ///     Definition::new("1", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )];
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let repository = fasta::Repository::with_aliases(records, aliases);
+    /// assert!(repository.get(b"chr1").is_some());
+    /// ```
+    pub fn with_aliases<A>(adapter: A, aliases: Aliases) -> Self
     where
         A: Adapter + 'static,
     {
         Self(Arc::new(RwLock::new(AdapterCache {
             adapter: Box::new(adapter),
+            aliases,
             cache: HashMap::new(),
         })))
     }
@@ -45,7 +96,7 @@ impl Repository {
 
         let mut lock = self.0.write().unwrap();
 
-        let record = match lock.adapter.get(name)? {
+        let record = match lock.get(name)? {
             Ok(record) => record,
             Err(e) => return Some(Err(e)),
         };
@@ -117,4 +168,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_aliases() -> io::Result<()> {
+        let sq0 = Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+
+        let mut aliases = Aliases::default();
+        aliases.insert(["chr0", "sq0"]);
+        aliases.insert(["chr1", "sq1"]);
+
+        let repository = Repository::with_aliases(vec![sq0.clone()], aliases);
+
+        assert_eq!(
+            repository.get(b"chr0").transpose()?,
+            Some(sq0.sequence().clone())
+        );
+        assert_eq!(repository.get(b"chr1").transpose()?, None);
+        assert_eq!(repository.len(), 1);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,35 @@
+//! Interleaves paired-end FASTQ files.
+//!
+//! The inputs can be uncompressed, gzip-compressed, or bgzip-compressed. The mate names of each
+//! read pair are validated. The results are written to stdout.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+    num::NonZero,
+};
+
+use noodles_fastq as fastq;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let first_src = args.next().expect("missing first_src");
+    let second_src = args.next().expect("missing second_src");
+
+    let worker_count = NonZero::new(2).unwrap();
+
+    let mut reader = fastq::io::paired_reader::Builder::default()
+        .set_worker_count(worker_count)
+        .build_from_paths(first_src, second_src)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = fastq::io::InterleavedWriter::new(BufWriter::new(stdout));
+
+    for result in reader.record_pairs() {
+        let (first, second) = result?;
+        writer.write_record_pair(&first, &second)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Prints a QC report of a FASTQ file as JSON.
+//!
+//! The quality score encoding is detected from the first 1000 records.
+
+use std::{env, io};
+
+use noodles_fastq::{
+    self as fastq,
+    qc::{QualityEncoding, Statistics},
+};
+
+const SAMPLE_SIZE: usize = 1000;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = fastq::io::reader::Builder::default().build_from_path(src)?;
+    let mut records = reader.records();
+
+    let mut sample = Vec::with_capacity(SAMPLE_SIZE);
+
+    for result in records.by_ref().take(SAMPLE_SIZE) {
+        sample.push(result?);
+    }
+
+    let quality_encoding =
+        QualityEncoding::detect(sample.iter().map(|record| record.quality_scores()))
+            .unwrap_or(QualityEncoding::Phred33);
+
+    let mut statistics = Statistics::builder()
+        .set_quality_encoding(quality_encoding)
+        .build();
+
+    for record in &sample {
+        statistics.add(record)?;
+    }
+
+    for result in records {
+        let record = result?;
+        statistics.add(&record)?;
+    }
+
+    let report = statistics.report();
+    serde_json::to_writer_pretty(io::stdout().lock(), &report)?;
+    println!();
+
+    Ok(())
+}
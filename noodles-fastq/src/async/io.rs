@@ -1,6 +1,11 @@
 //! Async FASTQ I/O.
 
+pub mod interleaved_reader;
+pub mod paired_reader;
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{
+    interleaved_reader::InterleavedReader, paired_reader::PairedReader, reader::Reader,
+    writer::Writer,
+};
@@ -0,0 +1,198 @@
+//! Async interleaved paired-end FASTQ reader.
+
+mod builder;
+
+use futures::{Stream, stream};
+use tokio::io::{self, AsyncBufRead};
+
+pub use self::builder::Builder;
+use super::Reader;
+use crate::{Record, io::paired_reader::validate_record_pair};
+
+/// An async interleaved paired-end FASTQ reader.
+///
+/// See [`crate::io::InterleavedReader`].
+pub struct InterleavedReader<R> {
+    inner: Reader<R>,
+    validate_names: bool,
+    record_number: u64,
+}
+
+impl<R> InterleavedReader<R> {
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    /// let reader = fastq::r#async::io::InterleavedReader::new(io::empty());
+    /// let _inner = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    /// let reader = fastq::r#async::io::InterleavedReader::new(io::empty());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Returns the number of read pairs read.
+    pub fn record_number(&self) -> u64 {
+        self.record_number
+    }
+}
+
+impl<R> InterleavedReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Creates an async interleaved paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let reader = fastq::r#async::io::InterleavedReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Builder::default().build_from_reader(inner)
+    }
+
+    fn from_reader(inner: R, validate_names: bool) -> Self {
+        Self {
+            inner: Reader::new(inner),
+            validate_names,
+            record_number: 0,
+        }
+    }
+
+    /// Reads a read pair.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, the stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::r#async::io::InterleavedReader::new(&data[..]);
+    ///
+    /// let mut first = fastq::Record::default();
+    /// let mut second = fastq::Record::default();
+    /// reader.read_record_pair(&mut first, &mut second).await?;
+    ///
+    /// assert_eq!(first.name(), "r0/1");
+    /// assert_eq!(second.name(), "r0/2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_record_pair(
+        &mut self,
+        first: &mut Record,
+        second: &mut Record,
+    ) -> io::Result<usize> {
+        let m = self.inner.read_record(first).await?;
+
+        if m == 0 {
+            return Ok(0);
+        }
+
+        let n = self.inner.read_record(second).await?;
+
+        self.record_number += 1;
+        validate_record_pair(
+            self.record_number,
+            self.validate_names,
+            (m, first),
+            (n, second),
+        )?;
+
+        Ok(m + n)
+    }
+
+    /// Returns a stream over read pairs starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::r#async::io::InterleavedReader::new(&data[..]);
+    ///
+    /// let mut record_pairs = reader.record_pairs();
+    ///
+    /// while let Some((first, second)) = record_pairs.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record_pairs(&mut self) -> impl Stream<Item = io::Result<(Record, Record)>> + '_ {
+        Box::pin(stream::try_unfold(
+            (self, Record::default(), Record::default()),
+            |(reader, mut first, mut second)| async {
+                reader
+                    .read_record_pair(&mut first, &mut second)
+                    .await
+                    .map(|n| match n {
+                        0 => None,
+                        _ => Some(((first.clone(), second.clone()), (reader, first, second))),
+                    })
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_pairs() -> io::Result<()> {
+        let data = b"@r0/1\nA\n+\nN\n@r0/2\nC\n+\nD\n@r1/1\nG\n+\nL\n@r2/2\nT\n+\nS\n";
+
+        let mut reader = InterleavedReader::new(&data[..]);
+        let mut record_pairs = reader.record_pairs();
+
+        assert!(record_pairs.try_next().await?.is_some());
+
+        let e = record_pairs.try_next().await.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "mate names do not match at record 2: r1/1 != r2/2"
+        );
+
+        let mut reader = Builder::default()
+            .set_name_validation(false)
+            .build_from_reader(&data[..]);
+        assert_eq!(
+            reader.record_pairs().try_collect::<Vec<_>>().await?.len(),
+            2
+        );
+
+        Ok(())
+    }
+}
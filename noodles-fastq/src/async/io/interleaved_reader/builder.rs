@@ -0,0 +1,55 @@
+use tokio::io::AsyncBufRead;
+
+use super::InterleavedReader;
+
+/// An async interleaved paired-end FASTQ reader builder.
+#[derive(Debug)]
+pub struct Builder {
+    validate_names: bool,
+}
+
+impl Builder {
+    /// Sets whether to check that the names of the mates match.
+    ///
+    /// By default, names are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let builder = fastq::r#async::io::interleaved_reader::Builder::default()
+    ///     .set_name_validation(false);
+    /// ```
+    pub fn set_name_validation(mut self, validate_names: bool) -> Self {
+        self.validate_names = validate_names;
+        self
+    }
+
+    /// Builds an async interleaved paired-end FASTQ reader from an async reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r1/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let reader = fastq::r#async::io::interleaved_reader::Builder::default()
+    ///     .set_name_validation(false)
+    ///     .build_from_reader(&data[..]);
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> InterleavedReader<R>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        InterleavedReader::from_reader(reader, self.validate_names)
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            validate_names: true,
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Async paired-end FASTQ reader.
+
+mod builder;
+
+use futures::{Stream, stream};
+use tokio::io::{self, AsyncBufRead};
+
+pub use self::builder::Builder;
+use super::Reader;
+use crate::{Record, io::paired_reader::validate_record_pair};
+
+/// An async paired-end FASTQ reader.
+///
+/// See [`crate::io::PairedReader`].
+pub struct PairedReader<R1, R2> {
+    first: Reader<R1>,
+    second: Reader<R2>,
+    validate_names: bool,
+    record_number: u64,
+}
+
+impl<R1, R2> PairedReader<R1, R2> {
+    /// Returns references to the underlying readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    /// let reader = fastq::r#async::io::PairedReader::new(io::empty(), io::empty());
+    /// let (_first, _second) = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&R1, &R2) {
+        (self.first.get_ref(), self.second.get_ref())
+    }
+
+    /// Unwraps and returns the underlying readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    /// let reader = fastq::r#async::io::PairedReader::new(io::empty(), io::empty());
+    /// let (_first, _second) = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (R1, R2) {
+        (self.first.into_inner(), self.second.into_inner())
+    }
+
+    /// Returns the number of read pairs read.
+    pub fn record_number(&self) -> u64 {
+        self.record_number
+    }
+}
+
+impl<R1, R2> PairedReader<R1, R2>
+where
+    R1: AsyncBufRead + Unpin,
+    R2: AsyncBufRead + Unpin,
+{
+    /// Creates an async paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let reader = fastq::r#async::io::PairedReader::new(&r1[..], &r2[..]);
+    /// ```
+    pub fn new(first: R1, second: R2) -> Self {
+        Builder::default().build_from_readers(first, second)
+    }
+
+    fn from_readers(first: R1, second: R2, validate_names: bool) -> Self {
+        Self {
+            first: Reader::new(first),
+            second: Reader::new(second),
+            validate_names,
+            record_number: 0,
+        }
+    }
+
+    /// Reads a read pair.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, both streams reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::r#async::io::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut first = fastq::Record::default();
+    /// let mut second = fastq::Record::default();
+    /// reader.read_record_pair(&mut first, &mut second).await?;
+    ///
+    /// assert_eq!(first.name(), "r0/1");
+    /// assert_eq!(second.name(), "r0/2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_record_pair(
+        &mut self,
+        first: &mut Record,
+        second: &mut Record,
+    ) -> io::Result<usize> {
+        let m = self.first.read_record(first).await?;
+        let n = self.second.read_record(second).await?;
+
+        if m == 0 && n == 0 {
+            return Ok(0);
+        }
+
+        self.record_number += 1;
+        validate_record_pair(
+            self.record_number,
+            self.validate_names,
+            (m, first),
+            (n, second),
+        )?;
+
+        Ok(m + n)
+    }
+
+    /// Returns a stream over read pairs starting from the current stream positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::r#async::io::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut record_pairs = reader.record_pairs();
+    ///
+    /// while let Some((first, second)) = record_pairs.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record_pairs(&mut self) -> impl Stream<Item = io::Result<(Record, Record)>> + '_ {
+        Box::pin(stream::try_unfold(
+            (self, Record::default(), Record::default()),
+            |(reader, mut first, mut second)| async {
+                reader
+                    .read_record_pair(&mut first, &mut second)
+                    .await
+                    .map(|n| match n {
+                        0 => None,
+                        _ => Some(((first.clone(), second.clone()), (reader, first, second))),
+                    })
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::io::paired_reader::{Mate, PairError};
+
+    #[tokio::test]
+    async fn test_record_pairs() -> io::Result<()> {
+        let r1 = b"@r0/1\nA\n+\nN\n@r1/1\nC\n+\nD\n";
+        let r2 = b"@r0/2\nG\n+\nL\n";
+
+        let mut reader = PairedReader::new(&r1[..], &r2[..]);
+        let mut record_pairs = reader.record_pairs();
+
+        let (first, second) = record_pairs.try_next().await?.unwrap();
+        assert_eq!(first.name(), "r0/1");
+        assert_eq!(second.name(), "r0/2");
+
+        let e = record_pairs.try_next().await.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            e.get_ref().and_then(|e| e.downcast_ref::<PairError>()),
+            Some(&PairError::MissingMate {
+                record_number: 2,
+                mate: Mate::Second,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_pairs_without_name_validation() -> io::Result<()> {
+        let r1 = b"@r0/1\nA\n+\nN\n";
+        let r2 = b"@r1/2\nG\n+\nL\n";
+
+        let mut reader = PairedReader::new(&r1[..], &r2[..]);
+        let e = reader.record_pairs().try_next().await.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let mut reader = Builder::default()
+            .set_name_validation(false)
+            .build_from_readers(&r1[..], &r2[..]);
+        assert_eq!(
+            reader.record_pairs().try_collect::<Vec<_>>().await?.len(),
+            1
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use tokio::io::AsyncBufRead;
+
+use super::PairedReader;
+
+/// An async paired-end FASTQ reader builder.
+#[derive(Debug)]
+pub struct Builder {
+    validate_names: bool,
+}
+
+impl Builder {
+    /// Sets whether to check that the names of the mates match.
+    ///
+    /// By default, names are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let builder = fastq::r#async::io::paired_reader::Builder::default()
+    ///     .set_name_validation(false);
+    /// ```
+    pub fn set_name_validation(mut self, validate_names: bool) -> Self {
+        self.validate_names = validate_names;
+        self
+    }
+
+    /// Builds an async paired-end FASTQ reader from async readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r1/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let reader = fastq::r#async::io::paired_reader::Builder::default()
+    ///     .set_name_validation(false)
+    ///     .build_from_readers(&r1[..], &r2[..]);
+    /// ```
+    pub fn build_from_readers<R1, R2>(self, first: R1, second: R2) -> PairedReader<R1, R2>
+    where
+        R1: AsyncBufRead + Unpin,
+        R2: AsyncBufRead + Unpin,
+    {
+        PairedReader::from_readers(first, second, self.validate_names)
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            validate_names: true,
+        }
+    }
+}
@@ -1,7 +1,13 @@
 //! FASTQ I/O.
 
 mod indexer;
+pub mod interleaved_reader;
+mod interleaved_writer;
+pub mod paired_reader;
 pub mod reader;
 pub mod writer;
 
-pub use self::{indexer::Indexer, reader::Reader, writer::Writer};
+pub use self::{
+    indexer::Indexer, interleaved_reader::InterleavedReader, interleaved_writer::InterleavedWriter,
+    paired_reader::PairedReader, reader::Reader, writer::Writer,
+};
@@ -0,0 +1,186 @@
+//! Interleaved paired-end FASTQ reader.
+
+mod builder;
+
+use std::io::{self, BufRead};
+
+pub use self::builder::Builder;
+use super::{Reader, paired_reader::validate_record_pair};
+use crate::Record;
+
+/// An interleaved paired-end FASTQ reader.
+///
+/// This reads read pairs from a single stream where the second mate of each pair immediately
+/// follows the first mate. Mate names are validated as in [`super::PairedReader`].
+pub struct InterleavedReader<R> {
+    inner: Reader<R>,
+    validate_names: bool,
+    record_number: u64,
+}
+
+impl<R> InterleavedReader<R> {
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::io::InterleavedReader::new(io::empty());
+    /// let _inner = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::io::InterleavedReader::new(io::empty());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Returns the number of read pairs read.
+    pub fn record_number(&self) -> u64 {
+        self.record_number
+    }
+}
+
+impl<R> InterleavedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an interleaved paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let reader = fastq::io::InterleavedReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::from_reader(Reader::new(inner), true)
+    }
+
+    fn from_reader(inner: Reader<R>, validate_names: bool) -> Self {
+        Self {
+            inner,
+            validate_names,
+            record_number: 0,
+        }
+    }
+
+    /// Reads a read pair.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, the stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::io::InterleavedReader::new(&data[..]);
+    ///
+    /// let mut first = fastq::Record::default();
+    /// let mut second = fastq::Record::default();
+    /// reader.read_record_pair(&mut first, &mut second)?;
+    ///
+    /// assert_eq!(first.name(), "r0/1");
+    /// assert_eq!(second.name(), "r0/2");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record_pair(
+        &mut self,
+        first: &mut Record,
+        second: &mut Record,
+    ) -> io::Result<usize> {
+        let m = self.inner.read_record(first)?;
+
+        if m == 0 {
+            return Ok(0);
+        }
+
+        let n = self.inner.read_record(second)?;
+
+        self.record_number += 1;
+        validate_record_pair(
+            self.record_number,
+            self.validate_names,
+            (m, first),
+            (n, second),
+        )?;
+
+        Ok(m + n)
+    }
+
+    /// Returns an iterator over read pairs starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::io::InterleavedReader::new(&data[..]);
+    /// assert_eq!(reader.record_pairs().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn record_pairs(&mut self) -> impl Iterator<Item = io::Result<(Record, Record)>> + '_ {
+        let mut first = Record::default();
+        let mut second = Record::default();
+
+        std::iter::from_fn(
+            move || match self.read_record_pair(&mut first, &mut second) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok((first.clone(), second.clone()))),
+                Err(e) => Some(Err(e)),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::paired_reader::{Mate, PairError};
+
+    #[test]
+    fn test_read_record_pair() -> io::Result<()> {
+        let data = b"@r0/1\nA\n+\nN\n@r0/2\nC\n+\nD\n@r1/1\nG\n+\nL\n";
+        let mut reader = InterleavedReader::new(&data[..]);
+
+        let mut first = Record::default();
+        let mut second = Record::default();
+
+        reader.read_record_pair(&mut first, &mut second)?;
+        assert_eq!(first.name(), "r0/1");
+        assert_eq!(second.name(), "r0/2");
+
+        let e = reader
+            .read_record_pair(&mut first, &mut second)
+            .unwrap_err();
+
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            e.get_ref().and_then(|e| e.downcast_ref::<PairError>()),
+            Some(&PairError::MissingMate {
+                record_number: 2,
+                mate: Mate::Second
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(feature = "compression")]
+use std::num::NonZero;
+use std::{
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use super::InterleavedReader;
+use crate::io::reader;
+
+/// An interleaved paired-end FASTQ reader builder.
+///
+/// The stream can be uncompressed, gzip-compressed, or bgzip-compressed. See
+/// [`reader::Builder`].
+#[derive(Debug)]
+pub struct Builder {
+    #[cfg(feature = "compression")]
+    worker_count: NonZero<usize>,
+    validate_names: bool,
+}
+
+impl Builder {
+    /// Sets the worker count.
+    ///
+    /// By default, the worker count is 1. See [`reader::Builder::set_worker_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use noodles_fastq::io::interleaved_reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZero::new(4).unwrap());
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn set_worker_count(mut self, worker_count: NonZero<usize>) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets whether to check that the names of the mates match.
+    ///
+    /// By default, names are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::io::interleaved_reader::Builder;
+    /// let builder = Builder::default().set_name_validation(false);
+    /// ```
+    pub fn set_name_validation(mut self, validate_names: bool) -> Self {
+        self.validate_names = validate_names;
+        self
+    }
+
+    /// Builds an interleaved paired-end FASTQ reader from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fastq::io::interleaved_reader::Builder;
+    /// let reader = Builder::default().build_from_path("sample.fq.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(
+        self,
+        src: P,
+    ) -> io::Result<InterleavedReader<Box<dyn BufRead + Send>>>
+    where
+        P: AsRef<Path>,
+    {
+        self.reader_builder()
+            .build_from_path(src)
+            .map(|inner| InterleavedReader::from_reader(inner, self.validate_names))
+    }
+
+    /// Builds an interleaved paired-end FASTQ reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::interleaved_reader::Builder;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r1/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let mut reader = Builder::default()
+    ///     .set_name_validation(false)
+    ///     .build_from_reader(&data[..])?;
+    ///
+    /// assert_eq!(reader.record_pairs().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(
+        self,
+        reader: R,
+    ) -> io::Result<InterleavedReader<Box<dyn BufRead + Send>>>
+    where
+        R: Read + Send + 'static,
+    {
+        self.reader_builder()
+            .build_from_reader(reader)
+            .map(|inner| InterleavedReader::from_reader(inner, self.validate_names))
+    }
+
+    #[cfg(feature = "compression")]
+    fn reader_builder(&self) -> reader::Builder {
+        reader::Builder::default().set_worker_count(self.worker_count)
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn reader_builder(&self) -> reader::Builder {
+        reader::Builder::default()
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "compression")]
+            worker_count: NonZero::<usize>::MIN,
+            validate_names: true,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::io::{self, Write};
+
+use super::Writer;
+use crate::Record;
+
+/// An interleaved paired-end FASTQ writer.
+///
+/// This writes the second mate of each read pair immediately after the first mate.
+pub struct InterleavedWriter<W> {
+    inner: Writer<W>,
+}
+
+impl<W> InterleavedWriter<W> {
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::InterleavedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let mut writer = fastq::io::InterleavedWriter::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.get_mut()
+    }
+
+    /// Unwraps and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::InterleavedWriter::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
+
+impl<W> InterleavedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an interleaved paired-end FASTQ writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::InterleavedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(Writer::new(inner))
+    }
+
+    /// Writes a read pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let mut writer = fastq::io::InterleavedWriter::new(Vec::new());
+    ///
+    /// let first = fastq::Record::new(Definition::new("r0/1", ""), "ATCG", "NDLS");
+    /// let second = fastq::Record::new(Definition::new("r0/2", ""), "CGAT", "SLDN");
+    /// writer.write_record_pair(&first, &second)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record_pair(&mut self, first: &Record, second: &Record) -> io::Result<()> {
+        self.inner.write_record(first)?;
+        self.inner.write_record(second)
+    }
+}
+
+impl<W> From<Writer<W>> for InterleavedWriter<W> {
+    fn from(inner: Writer<W>) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::InterleavedReader;
+
+    #[test]
+    fn test_write_record_pair() -> io::Result<()> {
+        let r1 = b"@r0/1\nATCG\n+\nNDLS\n@r1/1\nGC\n+\nND\n";
+        let r2 = b"@r0/2\nCGAT\n+\nSLDN\n@r1/2\nCG\n+\nDN\n";
+
+        let mut reader = crate::io::PairedReader::new(&r1[..], &r2[..]);
+        let mut writer = InterleavedWriter::new(Vec::new());
+
+        for result in reader.record_pairs() {
+            let (first, second) = result?;
+            writer.write_record_pair(&first, &second)?;
+        }
+
+        let data = writer.into_inner();
+        let mut reader = InterleavedReader::new(&data[..]);
+        let names = reader
+            .record_pairs()
+            .map(|result| {
+                result.map(|(first, second)| (first.name().to_string(), second.name().to_string()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(
+            names,
+            [
+                (String::from("r0/1"), String::from("r0/2")),
+                (String::from("r1/1"), String::from("r1/2")),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+//! Paired-end FASTQ reader.
+
+mod builder;
+mod pair_error;
+
+use std::io::{self, BufRead};
+
+pub use self::{
+    builder::Builder,
+    pair_error::{Mate, PairError},
+};
+use super::Reader;
+use crate::Record;
+
+/// A paired-end FASTQ reader.
+///
+/// This reads read pairs from two parallel streams, e.g., `sample_R1.fq` and `sample_R2.fq`.
+///
+/// By default, the names of the mates are checked to match after removing a `/1` suffix from the
+/// first mate and a `/2` suffix from the second. Descriptions, e.g., Casava 1.8 comments
+/// (`1:N:0:ATCACG`), are not part of the name and do not need to match. A mismatch or a stream
+/// that ends early is reported as a [`PairError`] with the record number of the read pair.
+pub struct PairedReader<R1, R2> {
+    first: Reader<R1>,
+    second: Reader<R2>,
+    validate_names: bool,
+    record_number: u64,
+}
+
+impl<R1, R2> PairedReader<R1, R2> {
+    /// Returns references to the underlying readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::io::PairedReader::new(io::empty(), io::empty());
+    /// let (_first, _second) = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&R1, &R2) {
+        (self.first.get_ref(), self.second.get_ref())
+    }
+
+    /// Unwraps and returns the underlying readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::io::PairedReader::new(io::empty(), io::empty());
+    /// let (_first, _second) = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (R1, R2) {
+        (self.first.into_inner(), self.second.into_inner())
+    }
+
+    /// Returns the number of read pairs read.
+    pub fn record_number(&self) -> u64 {
+        self.record_number
+    }
+}
+
+impl<R1, R2> PairedReader<R1, R2>
+where
+    R1: BufRead,
+    R2: BufRead,
+{
+    /// Creates a paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let reader = fastq::io::PairedReader::new(&r1[..], &r2[..]);
+    /// ```
+    pub fn new(first: R1, second: R2) -> Self {
+        Self::from_readers(Reader::new(first), Reader::new(second), true)
+    }
+
+    fn from_readers(first: Reader<R1>, second: Reader<R2>, validate_names: bool) -> Self {
+        Self {
+            first,
+            second,
+            validate_names,
+            record_number: 0,
+        }
+    }
+
+    /// Reads a read pair.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, both streams reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::io::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut first = fastq::Record::default();
+    /// let mut second = fastq::Record::default();
+    /// reader.read_record_pair(&mut first, &mut second)?;
+    ///
+    /// assert_eq!(first.name(), "r0/1");
+    /// assert_eq!(second.name(), "r0/2");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record_pair(
+        &mut self,
+        first: &mut Record,
+        second: &mut Record,
+    ) -> io::Result<usize> {
+        let m = self.first.read_record(first)?;
+        let n = self.second.read_record(second)?;
+
+        if m == 0 && n == 0 {
+            return Ok(0);
+        }
+
+        self.record_number += 1;
+        validate_record_pair(
+            self.record_number,
+            self.validate_names,
+            (m, first),
+            (n, second),
+        )?;
+
+        Ok(m + n)
+    }
+
+    /// Returns an iterator over read pairs starting from the current stream positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n@r1/1\nGC\n+\nND\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::io::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut record_pairs = reader.record_pairs();
+    /// assert!(record_pairs.next().transpose()?.is_some());
+    ///
+    /// let e = record_pairs.next().transpose().unwrap_err();
+    /// assert_eq!(e.to_string(), "missing R2 at record 2");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn record_pairs(&mut self) -> impl Iterator<Item = io::Result<(Record, Record)>> + '_ {
+        let mut first = Record::default();
+        let mut second = Record::default();
+
+        std::iter::from_fn(
+            move || match self.read_record_pair(&mut first, &mut second) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok((first.clone(), second.clone()))),
+                Err(e) => Some(Err(e)),
+            },
+        )
+    }
+}
+
+pub(crate) fn validate_record_pair(
+    record_number: u64,
+    validate_names: bool,
+    (m, first): (usize, &Record),
+    (n, second): (usize, &Record),
+) -> io::Result<()> {
+    if m == 0 {
+        return Err(missing_mate_error(record_number, Mate::First));
+    } else if n == 0 {
+        return Err(missing_mate_error(record_number, Mate::Second));
+    }
+
+    if validate_names
+        && mate_name(first.name(), Mate::First) != mate_name(second.name(), Mate::Second)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            PairError::NameMismatch {
+                record_number,
+                first_name: first.name().into(),
+                second_name: second.name().into(),
+            },
+        ));
+    }
+
+    Ok(())
+}
+
+fn missing_mate_error(record_number: u64, mate: Mate) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        PairError::MissingMate {
+            record_number,
+            mate,
+        },
+    )
+}
+
+// Returns the name of a read without the description and the suffix of the given mate (`/1` or
+// `/2`), if any.
+fn mate_name(name: &[u8], mate: Mate) -> &[u8] {
+    let end = name
+        .iter()
+        .position(|b| b.is_ascii_whitespace())
+        .unwrap_or(name.len());
+
+    let name = &name[..end];
+
+    let suffix: &[u8] = match mate {
+        Mate::First => b"/1",
+        Mate::Second => b"/2",
+    };
+
+    name.strip_suffix(suffix).unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Definition;
+
+    #[test]
+    fn test_read_record_pair() -> io::Result<()> {
+        let r1 = b"@r0/1\nATCG\n+\nNDLS\n@r1 1:N:0:ATCACG\nGC\n+\nND\n";
+        let r2 = b"@r0/2\nCGAT\n+\nSLDN\n@r1 2:N:0:ATCACG\nCG\n+\nDN\n";
+
+        let mut reader = PairedReader::new(&r1[..], &r2[..]);
+
+        let actual = reader.record_pairs().collect::<io::Result<Vec<_>>>()?;
+
+        let expected = [
+            (
+                Record::new(Definition::new("r0/1", ""), "ATCG", "NDLS"),
+                Record::new(Definition::new("r0/2", ""), "CGAT", "SLDN"),
+            ),
+            (
+                Record::new(Definition::new("r1", "1:N:0:ATCACG"), "GC", "ND"),
+                Record::new(Definition::new("r1", "2:N:0:ATCACG"), "CG", "DN"),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(reader.record_number(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_pair_with_desynchronized_streams() {
+        fn read_error(r1: &[u8], r2: &[u8]) -> (io::ErrorKind, PairError) {
+            let mut reader = PairedReader::new(r1, r2);
+
+            let e = reader
+                .record_pairs()
+                .find_map(Result::err)
+                .expect("expected error");
+
+            let kind = e.kind();
+            let pair_error = e
+                .into_inner()
+                .and_then(|e| e.downcast::<PairError>().ok())
+                .expect("expected pair error");
+
+            (kind, *pair_error)
+        }
+
+        let r1 = b"@r0/1\nA\n+\nN\n@r1/1\nA\n+\nN\n";
+        let r2 = b"@r0/2\nA\n+\nN\n@r2/2\nA\n+\nN\n";
+        assert_eq!(
+            read_error(r1, r2),
+            (
+                io::ErrorKind::InvalidData,
+                PairError::NameMismatch {
+                    record_number: 2,
+                    first_name: "r1/1".into(),
+                    second_name: "r2/2".into(),
+                }
+            )
+        );
+
+        let r1 = b"@r0/1\nA\n+\nN\n";
+        let r2 = b"@r0/1\nA\n+\nN\n";
+        assert_eq!(
+            read_error(r1, r2),
+            (
+                io::ErrorKind::InvalidData,
+                PairError::NameMismatch {
+                    record_number: 1,
+                    first_name: "r0/1".into(),
+                    second_name: "r0/1".into(),
+                }
+            )
+        );
+
+        let r1 = b"@r0/2\nA\n+\nN\n";
+        let r2 = b"@r0/1\nA\n+\nN\n";
+        assert!(matches!(
+            read_error(r1, r2),
+            (io::ErrorKind::InvalidData, PairError::NameMismatch { .. })
+        ));
+
+        let r1 = b"@r0/1\nA\n+\nN\n";
+        let r2 = b"@r0/2\nA\n+\nN\n@r1/2\nA\n+\nN\n";
+        assert_eq!(
+            read_error(r1, r2),
+            (
+                io::ErrorKind::UnexpectedEof,
+                PairError::MissingMate {
+                    record_number: 2,
+                    mate: Mate::First,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_mate_name() {
+        assert_eq!(mate_name(b"r0", Mate::First), b"r0");
+        assert_eq!(mate_name(b"r0/1", Mate::First), b"r0");
+        assert_eq!(mate_name(b"r0/2", Mate::First), b"r0/2");
+        assert_eq!(mate_name(b"r0/2", Mate::Second), b"r0");
+        assert_eq!(mate_name(b"r0/1", Mate::Second), b"r0/1");
+        assert_eq!(mate_name(b"r0/3", Mate::First), b"r0/3");
+        assert_eq!(mate_name(b"r0/1 1:N:0:ATCACG", Mate::First), b"r0");
+    }
+}
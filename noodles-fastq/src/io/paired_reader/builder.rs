@@ -0,0 +1,138 @@
+#[cfg(feature = "compression")]
+use std::num::NonZero;
+use std::{
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use super::PairedReader;
+use crate::io::reader;
+
+/// A paired-end FASTQ reader builder.
+///
+/// Each stream can be uncompressed, gzip-compressed, or bgzip-compressed. See
+/// [`reader::Builder`].
+#[derive(Debug)]
+pub struct Builder {
+    #[cfg(feature = "compression")]
+    worker_count: NonZero<usize>,
+    validate_names: bool,
+}
+
+impl Builder {
+    /// Sets the worker count for each stream.
+    ///
+    /// By default, the worker count is 1. See [`reader::Builder::set_worker_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use noodles_fastq::io::paired_reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZero::new(4).unwrap());
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn set_worker_count(mut self, worker_count: NonZero<usize>) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets whether to check that the names of the mates match.
+    ///
+    /// By default, names are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::io::paired_reader::Builder;
+    /// let builder = Builder::default().set_name_validation(false);
+    /// ```
+    pub fn set_name_validation(mut self, validate_names: bool) -> Self {
+        self.validate_names = validate_names;
+        self
+    }
+
+    /// Builds a paired-end FASTQ reader from paths.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fastq::io::paired_reader::Builder;
+    /// let reader = Builder::default().build_from_paths("sample_R1.fq.gz", "sample_R2.fq.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_paths<P, Q>(
+        self,
+        first_src: P,
+        second_src: Q,
+    ) -> io::Result<PairedReader<Box<dyn BufRead + Send>, Box<dyn BufRead + Send>>>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let builder = self.reader_builder();
+        let first = builder.build_from_path(first_src)?;
+        let second = builder.build_from_path(second_src)?;
+
+        Ok(PairedReader::from_readers(
+            first,
+            second,
+            self.validate_names,
+        ))
+    }
+
+    /// Builds a paired-end FASTQ reader from readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::paired_reader::Builder;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    ///
+    /// let mut reader = Builder::default().build_from_readers(&r1[..], &r2[..])?;
+    /// assert_eq!(reader.record_pairs().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_readers<R1, R2>(
+        self,
+        first: R1,
+        second: R2,
+    ) -> io::Result<PairedReader<Box<dyn BufRead + Send>, Box<dyn BufRead + Send>>>
+    where
+        R1: Read + Send + 'static,
+        R2: Read + Send + 'static,
+    {
+        let builder = self.reader_builder();
+        let first = builder.build_from_reader(first)?;
+        let second = builder.build_from_reader(second)?;
+
+        Ok(PairedReader::from_readers(
+            first,
+            second,
+            self.validate_names,
+        ))
+    }
+
+    #[cfg(feature = "compression")]
+    fn reader_builder(&self) -> reader::Builder {
+        reader::Builder::default().set_worker_count(self.worker_count)
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn reader_builder(&self) -> reader::Builder {
+        reader::Builder::default()
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "compression")]
+            worker_count: NonZero::<usize>::MIN,
+            validate_names: true,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::{error, fmt};
+
+use bstr::BString;
+
+/// A mate of a read pair.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mate {
+    /// The first mate (R1).
+    First,
+    /// The second mate (R2).
+    Second,
+}
+
+impl fmt::Display for Mate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First => f.write_str("R1"),
+            Self::Second => f.write_str("R2"),
+        }
+    }
+}
+
+/// An error returned when a read pair is out of sync.
+///
+/// Record numbers are 1-based and count read pairs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PairError {
+    /// The mate names do not match.
+    NameMismatch {
+        /// The record number of the read pair.
+        record_number: u64,
+        /// The name of the first mate.
+        first_name: BString,
+        /// The name of the second mate.
+        second_name: BString,
+    },
+    /// A mate is missing because its stream ended early.
+    MissingMate {
+        /// The record number of the read pair.
+        record_number: u64,
+        /// The missing mate.
+        mate: Mate,
+    },
+}
+
+impl PairError {
+    /// Returns the record number of the read pair.
+    pub fn record_number(&self) -> u64 {
+        match self {
+            Self::NameMismatch { record_number, .. } => *record_number,
+            Self::MissingMate { record_number, .. } => *record_number,
+        }
+    }
+}
+
+impl error::Error for PairError {}
+
+impl fmt::Display for PairError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameMismatch {
+                record_number,
+                first_name,
+                second_name,
+            } => write!(
+                f,
+                "mate names do not match at record {record_number}: {first_name} != {second_name}"
+            ),
+            Self::MissingMate {
+                record_number,
+                mate,
+            } => write!(f, "missing {mate} at record {record_number}"),
+        }
+    }
+}
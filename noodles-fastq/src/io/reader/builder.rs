@@ -0,0 +1,237 @@
+#[cfg(feature = "compression")]
+mod threaded_reader;
+
+#[cfg(feature = "compression")]
+use std::num::NonZero;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+};
+
+#[cfg(feature = "compression")]
+use flate2::bufread::MultiGzDecoder;
+#[cfg(feature = "compression")]
+use noodles_bgzf as bgzf;
+
+#[cfg(feature = "compression")]
+use self::threaded_reader::ThreadedReader;
+use super::Reader;
+
+#[cfg(feature = "compression")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CompressionMethod {
+    Gzip,
+    Bgzf,
+}
+
+/// A FASTQ reader builder.
+///
+/// With the `compression` feature, the builder autodetects whether the input is uncompressed,
+/// gzip-compressed, or bgzip-compressed. Otherwise, the input is read as uncompressed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Builder {
+    #[cfg(feature = "compression")]
+    worker_count: Option<NonZero<usize>>,
+}
+
+impl Builder {
+    /// Sets the worker count.
+    ///
+    /// By default, the worker count is 1, i.e., the input is decompressed on the calling thread.
+    /// When greater than 1, BGZF blocks are inflated in parallel by the given number of workers,
+    /// and gzip streams, which cannot be inflated in parallel, are inflated on a separate
+    /// thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use noodles_fastq::io::reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZero::new(4).unwrap());
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn set_worker_count(mut self, worker_count: NonZero<usize>) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a FASTQ reader from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fastq::io::reader::Builder;
+    /// let reader = Builder::default().build_from_path("sample.fq.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead + Send>>>
+    where
+        P: AsRef<Path>,
+    {
+        File::open(src).and_then(|file| self.build_from_reader(file))
+    }
+
+    /// Builds a FASTQ reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, io::reader::Builder};
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let mut reader = Builder::default().build_from_reader(&data[..])?;
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record.name(), "r0");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<Box<dyn BufRead + Send>>>
+    where
+        R: Read + Send + 'static,
+    {
+        let reader = BufReader::new(reader);
+
+        #[cfg(feature = "compression")]
+        let inner = self.build_decoder(reader)?;
+
+        #[cfg(not(feature = "compression"))]
+        let inner: Box<dyn BufRead + Send> = Box::new(reader);
+
+        Ok(Reader::new(inner))
+    }
+
+    #[cfg(feature = "compression")]
+    fn build_decoder<R>(self, mut reader: BufReader<R>) -> io::Result<Box<dyn BufRead + Send>>
+    where
+        R: Read + Send + 'static,
+    {
+        let worker_count = self.worker_count.unwrap_or(NonZero::<usize>::MIN);
+
+        let inner: Box<dyn BufRead + Send> = match detect_compression_method(&mut reader)? {
+            None => Box::new(reader),
+            Some(CompressionMethod::Bgzf) => {
+                if worker_count.get() > 1 {
+                    Box::new(bgzf::io::MultithreadedReader::with_worker_count(
+                        worker_count,
+                        reader,
+                    ))
+                } else {
+                    Box::new(bgzf::io::Reader::new(reader))
+                }
+            }
+            Some(CompressionMethod::Gzip) => {
+                let decoder = MultiGzDecoder::new(reader);
+
+                if worker_count.get() > 1 {
+                    Box::new(ThreadedReader::new(decoder))
+                } else {
+                    Box::new(BufReader::new(decoder))
+                }
+            }
+        };
+
+        Ok(inner)
+    }
+}
+
+#[cfg(feature = "compression")]
+fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
+where
+    R: BufRead,
+{
+    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+    const FEXTRA: u8 = 0x04;
+    const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+    let src = reader.fill_buf()?;
+
+    if !src.starts_with(&GZIP_MAGIC_NUMBER) {
+        return Ok(None);
+    }
+
+    let is_bgzf = src.get(3).is_some_and(|&flags| flags & FEXTRA != 0)
+        && src.get(12..14) == Some(&BGZF_SUBFIELD_ID[..]);
+
+    if is_bgzf {
+        Ok(Some(CompressionMethod::Bgzf))
+    } else {
+        Ok(Some(CompressionMethod::Gzip))
+    }
+}
+
+#[cfg(all(test, feature = "compression"))]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+
+    use super::*;
+    use crate::Record;
+
+    static DATA: &[u8] = b"@r0\nATCG\n+\nNDLS\n@r1\nGCTA\n+\nSLDN\n";
+
+    fn read_names<R>(mut reader: Reader<R>) -> io::Result<Vec<String>>
+    where
+        R: BufRead,
+    {
+        reader
+            .records()
+            .map(|result| result.map(|record| record.name().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_compression_method() -> io::Result<()> {
+        assert_eq!(detect_compression_method(&mut &DATA[..])?, None);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        let data = encoder.finish()?;
+        assert_eq!(
+            detect_compression_method(&mut &data[..])?,
+            Some(CompressionMethod::Gzip)
+        );
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let data = writer.finish()?;
+        assert_eq!(
+            detect_compression_method(&mut &data[..])?,
+            Some(CompressionMethod::Bgzf)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader() -> io::Result<()> {
+        const WORKER_COUNT: NonZero<usize> = NonZero::new(2).unwrap();
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        let gzip_data = encoder.finish()?;
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let bgzf_data = writer.finish()?;
+
+        for data in [DATA.to_vec(), gzip_data, bgzf_data] {
+            for worker_count in [NonZero::<usize>::MIN, WORKER_COUNT] {
+                let reader = Builder::default()
+                    .set_worker_count(worker_count)
+                    .build_from_reader(io::Cursor::new(data.clone()))?;
+
+                assert_eq!(read_names(reader)?, ["r0", "r1"]);
+            }
+        }
+
+        let mut reader = Builder::default().build_from_reader(io::Cursor::new(DATA))?;
+        let mut record = Record::default();
+        assert!(reader.read_record(&mut record)? > 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    io::{self, BufRead, Read},
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+const CHUNK_SIZE: usize = 1 << 16;
+const CHANNEL_CAPACITY: usize = 4;
+
+/// A reader that reads from an inner reader on a separate thread.
+pub(super) struct ThreadedReader {
+    rx: Receiver<io::Result<Vec<u8>>>,
+    buf: Vec<u8>,
+    position: usize,
+    // The inner reader is not read after an error, so the error is returned for every
+    // subsequent read rather than an EOF.
+    error: Option<(io::ErrorKind, String)>,
+}
+
+impl ThreadedReader {
+    pub(super) fn new<R>(mut inner: R) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(CHANNEL_CAPACITY);
+
+        thread::spawn(move || {
+            loop {
+                let mut buf = vec![0; CHUNK_SIZE];
+
+                let result = match inner.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        buf.truncate(n);
+                        Ok(buf)
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => Err(e),
+                };
+
+                let is_err = result.is_err();
+
+                if tx.send(result).is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            rx,
+            buf: Vec::new(),
+            position: 0,
+            error: None,
+        }
+    }
+}
+
+impl Read for ThreadedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut src = self.fill_buf()?;
+        let amt = src.read(buf)?;
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl BufRead for ThreadedReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if let Some((kind, message)) = &self.error {
+            return Err(io::Error::new(*kind, message.clone()));
+        }
+
+        if self.position >= self.buf.len() {
+            match self.rx.recv() {
+                Ok(Ok(buf)) => {
+                    self.buf = buf;
+                    self.position = 0;
+                }
+                Ok(Err(e)) => {
+                    self.error = Some((e.kind(), e.to_string()));
+                    return Err(e);
+                }
+                // The sender is dropped when the inner reader reaches EOF.
+                Err(_) => {
+                    self.buf.clear();
+                    self.position = 0;
+                }
+            }
+        }
+
+        Ok(&self.buf[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = (self.position + amt).min(self.buf.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data: Vec<u8> = (0..=255).cycle().take(3 * CHUNK_SIZE + 8).collect();
+
+        let mut reader = ThreadedReader::new(io::Cursor::new(data.clone()));
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_inner_error() {
+        struct ErrReader;
+
+        impl Read for ErrReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::InvalidData))
+            }
+        }
+
+        let data = [0; 8];
+        let mut reader = ThreadedReader::new(io::Cursor::new(data).chain(ErrReader));
+
+        let mut buf = [0; 8];
+        assert!(reader.read_exact(&mut buf).is_ok());
+
+        for _ in 0..2 {
+            assert!(matches!(
+                reader.fill_buf(),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+    }
+}
@@ -43,6 +43,8 @@ pub mod r#async;
 pub mod fai;
 pub mod fs;
 pub mod io;
+#[cfg(feature = "qc")]
+pub mod qc;
 pub mod record;
 
 pub use self::record::Record;
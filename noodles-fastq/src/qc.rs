@@ -0,0 +1,36 @@
+//! FASTQ quality control.
+//!
+//! This includes quality score encoding detection and conversion ([`QualityEncoding`]) and
+//! streaming QC statistics ([`Statistics`]) that are summarized as a serializable [`Report`].
+//!
+//! # Examples
+//!
+//! ```
+//! use noodles_fastq::{self as fastq, qc::Statistics};
+//!
+//! let data = b"@r0\nACGT\n+\nNDLS\n@r1\nTTAG\n+\nII5#\n";
+//! let mut reader = fastq::io::Reader::new(&data[..]);
+//!
+//! let mut statistics = Statistics::default();
+//!
+//! for result in reader.records() {
+//!     let record = result?;
+//!     statistics.add(&record)?;
+//! }
+//!
+//! let report = statistics.report();
+//! assert_eq!(report.record_count(), 2);
+//! # Ok::<_, std::io::Error>(())
+//! ```
+
+mod adapter;
+mod quality_encoding;
+pub mod report;
+pub mod statistics;
+
+pub use self::{
+    adapter::{Adapter, default_adapters},
+    quality_encoding::{ConvertError, QualityEncoding, convert},
+    report::Report,
+    statistics::Statistics,
+};
@@ -0,0 +1,75 @@
+/// An adapter sequence to search for in reads.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Adapter {
+    name: String,
+    sequence: Vec<u8>,
+}
+
+impl Adapter {
+    /// Creates an adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Adapter;
+    /// let adapter = Adapter::new("Illumina Universal Adapter", "AGATCGGAAGAG");
+    /// ```
+    pub fn new<N, S>(name: N, sequence: S) -> Self
+    where
+        N: Into<String>,
+        S: Into<Vec<u8>>,
+    {
+        Self {
+            name: name.into(),
+            sequence: sequence.into(),
+        }
+    }
+
+    /// Returns the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Adapter;
+    /// let adapter = Adapter::new("Illumina Universal Adapter", "AGATCGGAAGAG");
+    /// assert_eq!(adapter.name(), "Illumina Universal Adapter");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Adapter;
+    /// let adapter = Adapter::new("Illumina Universal Adapter", "AGATCGGAAGAG");
+    /// assert_eq!(adapter.sequence(), b"AGATCGGAAGAG");
+    /// ```
+    pub fn sequence(&self) -> &[u8] {
+        &self.sequence
+    }
+}
+
+/// Returns the default list of adapters.
+///
+/// These are the first 12 bases of common Illumina adapters and poly-A and poly-G tails.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::qc;
+/// let adapters = qc::default_adapters();
+/// assert_eq!(adapters[0].sequence(), b"AGATCGGAAGAG");
+/// ```
+pub fn default_adapters() -> Vec<Adapter> {
+    vec![
+        Adapter::new("Illumina Universal Adapter", "AGATCGGAAGAG"),
+        Adapter::new("Illumina Small RNA 3' Adapter", "TGGAATTCTCGG"),
+        Adapter::new("Illumina Small RNA 5' Adapter", "GATCGTCGGACT"),
+        Adapter::new("Nextera Transposase Sequence", "CTGTCTCTTATA"),
+        Adapter::new("PolyA", "AAAAAAAAAAAA"),
+        Adapter::new("PolyG", "GGGGGGGGGGGG"),
+    ]
+}
@@ -0,0 +1,303 @@
+use std::{error, fmt};
+
+const MIN_SCORE_CHAR: u8 = b'!';
+const MAX_SCORE_CHAR: u8 = b'~';
+
+const PHRED_33_OFFSET: u8 = 33;
+const PHRED_64_OFFSET: u8 = 64;
+const SOLEXA_MIN_CHAR: u8 = b';';
+
+// Phred+33 scores above Q41 (`J`) are rare, so a sample with no characters below `@` and none
+// above `J` is more likely to be high quality Phred+33 than low quality Phred+64.
+const PHRED_33_TYPICAL_MAX_CHAR: u8 = b'J';
+
+/// A FASTQ quality score encoding.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityEncoding {
+    /// Phred quality scores offset by 33 (Sanger, Illumina 1.8+).
+    Phred33,
+    /// Phred quality scores offset by 64 (Illumina 1.3 to 1.7).
+    Phred64,
+    /// Solexa quality scores offset by 64 (Solexa, Illumina before 1.3).
+    Solexa,
+}
+
+impl QualityEncoding {
+    /// Detects the quality score encoding from a sample of quality score lines.
+    ///
+    /// This uses the range of characters in the sample. Characters below `;` are only used by
+    /// Phred+33; characters from `;` to `?` are only used by Solexa; and a sample with no
+    /// characters below `@` is Phred+64 unless all characters are at most `J`, in which case it is
+    /// assumed to be Phred+33.
+    ///
+    /// This returns `None` if the sample is empty or has characters outside of `!`..=`~`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::QualityEncoding;
+    ///
+    /// assert_eq!(
+    ///     QualityEncoding::detect([&b"II5+#"[..], b"IIII"]),
+    ///     Some(QualityEncoding::Phred33),
+    /// );
+    /// assert_eq!(QualityEncoding::detect([b"hhTJB"]), Some(QualityEncoding::Phred64));
+    /// assert_eq!(QualityEncoding::detect([b"hh;B"]), Some(QualityEncoding::Solexa));
+    /// assert!(QualityEncoding::detect::<_, &[u8]>([]).is_none());
+    /// ```
+    pub fn detect<I, Q>(quality_scores: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Q>,
+        Q: AsRef<[u8]>,
+    {
+        let mut range: Option<(u8, u8)> = None;
+
+        for buf in quality_scores {
+            for &c in buf.as_ref() {
+                range = Some(match range {
+                    Some((min, max)) => (min.min(c), max.max(c)),
+                    None => (c, c),
+                });
+            }
+        }
+
+        let (min, max) = range?;
+
+        if !(MIN_SCORE_CHAR..=MAX_SCORE_CHAR).contains(&min) || max > MAX_SCORE_CHAR {
+            None
+        } else if min < SOLEXA_MIN_CHAR {
+            Some(Self::Phred33)
+        } else if min < PHRED_64_OFFSET {
+            Some(Self::Solexa)
+        } else if max > PHRED_33_TYPICAL_MAX_CHAR {
+            Some(Self::Phred64)
+        } else {
+            Some(Self::Phred33)
+        }
+    }
+
+    /// Decodes a quality score character as a Phred quality score.
+    ///
+    /// Solexa scores are converted to Phred scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::QualityEncoding;
+    /// assert_eq!(QualityEncoding::Phred33.decode(b'I'), Some(40));
+    /// assert_eq!(QualityEncoding::Phred64.decode(b'h'), Some(40));
+    /// assert_eq!(QualityEncoding::Solexa.decode(b';'), Some(1));
+    /// assert!(QualityEncoding::Phred64.decode(b'#').is_none());
+    /// ```
+    pub fn decode(self, c: u8) -> Option<u8> {
+        if c > MAX_SCORE_CHAR {
+            return None;
+        }
+
+        match self {
+            Self::Phred33 => c.checked_sub(PHRED_33_OFFSET),
+            Self::Phred64 => c.checked_sub(PHRED_64_OFFSET),
+            Self::Solexa => {
+                if c < SOLEXA_MIN_CHAR {
+                    None
+                } else {
+                    let score = i32::from(c) - i32::from(PHRED_64_OFFSET);
+                    Some(solexa_to_phred(score))
+                }
+            }
+        }
+    }
+
+    /// Encodes a Phred quality score as a quality score character.
+    ///
+    /// For Solexa, the Phred score is converted to a Solexa score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::QualityEncoding;
+    /// assert_eq!(QualityEncoding::Phred33.encode(40), Some(b'I'));
+    /// assert_eq!(QualityEncoding::Phred64.encode(40), Some(b'h'));
+    /// assert_eq!(QualityEncoding::Solexa.encode(0), Some(b';'));
+    /// assert!(QualityEncoding::Phred64.encode(93).is_none());
+    /// ```
+    pub fn encode(self, score: u8) -> Option<u8> {
+        let c = match self {
+            Self::Phred33 => u32::from(score) + u32::from(PHRED_33_OFFSET),
+            Self::Phred64 => u32::from(score) + u32::from(PHRED_64_OFFSET),
+            Self::Solexa => {
+                let score = phred_to_solexa(score);
+                u32::try_from(score + i32::from(PHRED_64_OFFSET)).ok()?
+            }
+        };
+
+        u8::try_from(c)
+            .ok()
+            .filter(|c| (MIN_SCORE_CHAR..=MAX_SCORE_CHAR).contains(c))
+    }
+}
+
+impl fmt::Display for QualityEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Phred33 => f.write_str("Phred+33"),
+            Self::Phred64 => f.write_str("Phred+64"),
+            Self::Solexa => f.write_str("Solexa"),
+        }
+    }
+}
+
+/// An error returned when quality scores fail to convert.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConvertError {
+    /// A quality score character is invalid for the source encoding.
+    InvalidCharacter(u8),
+    /// A quality score cannot be represented in the destination encoding.
+    OutOfRange(u8),
+}
+
+impl error::Error for ConvertError {}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(c) => {
+                write!(f, "invalid quality score character: {:?}", char::from(*c))
+            }
+            Self::OutOfRange(score) => write!(f, "quality score out of range: {score}"),
+        }
+    }
+}
+
+/// Converts quality score characters from one encoding to another in place.
+///
+/// Conversions to and from Solexa are lossy for low quality scores.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::qc::{self, QualityEncoding};
+///
+/// let mut quality_scores = b"hhTJB".to_vec();
+/// qc::convert(QualityEncoding::Phred64, QualityEncoding::Phred33, &mut quality_scores)?;
+/// assert_eq!(quality_scores, b"II5+#");
+/// # Ok::<_, qc::ConvertError>(())
+/// ```
+pub fn convert(
+    src: QualityEncoding,
+    dst: QualityEncoding,
+    quality_scores: &mut [u8],
+) -> Result<(), ConvertError> {
+    if src == dst {
+        return Ok(());
+    }
+
+    for c in quality_scores {
+        let score = src.decode(*c).ok_or(ConvertError::InvalidCharacter(*c))?;
+        *c = dst.encode(score).ok_or(ConvertError::OutOfRange(score))?;
+    }
+
+    Ok(())
+}
+
+fn solexa_to_phred(score: i32) -> u8 {
+    let q = 10.0 * (10f64.powf(f64::from(score) / 10.0) + 1.0).log10();
+    q.round() as u8
+}
+
+fn phred_to_solexa(score: u8) -> i32 {
+    const MIN_SOLEXA_SCORE: i32 = -5;
+
+    if score == 0 {
+        return MIN_SOLEXA_SCORE;
+    }
+
+    let q = 10.0 * (10f64.powf(f64::from(score) / 10.0) - 1.0).log10();
+    (q.round() as i32).max(MIN_SOLEXA_SCORE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            QualityEncoding::detect([b"!!!"]),
+            Some(QualityEncoding::Phred33)
+        );
+        assert_eq!(
+            QualityEncoding::detect([b"@@JJ"]),
+            Some(QualityEncoding::Phred33)
+        );
+        assert_eq!(
+            QualityEncoding::detect([b"@@KK"]),
+            Some(QualityEncoding::Phred64)
+        );
+        assert_eq!(
+            QualityEncoding::detect([b"hh", b"=h"]),
+            Some(QualityEncoding::Solexa)
+        );
+        assert!(QualityEncoding::detect([b" "]).is_none());
+        assert!(QualityEncoding::detect([b"\x7f"]).is_none());
+    }
+
+    #[test]
+    fn test_solexa_to_phred() {
+        assert_eq!(solexa_to_phred(-5), 1);
+        assert_eq!(solexa_to_phred(0), 3);
+        assert_eq!(solexa_to_phred(10), 10);
+        assert_eq!(solexa_to_phred(40), 40);
+    }
+
+    #[test]
+    fn test_phred_to_solexa() {
+        assert_eq!(phred_to_solexa(0), -5);
+        assert_eq!(phred_to_solexa(1), -5);
+        assert_eq!(phred_to_solexa(3), 0);
+        assert_eq!(phred_to_solexa(10), 10);
+        assert_eq!(phred_to_solexa(40), 40);
+    }
+
+    #[test]
+    fn test_convert() {
+        let mut quality_scores = b"II5+#".to_vec();
+        convert(
+            QualityEncoding::Phred33,
+            QualityEncoding::Phred64,
+            &mut quality_scores,
+        )
+        .unwrap();
+        assert_eq!(quality_scores, b"hhTJB");
+
+        let mut quality_scores = b"hhTJ;".to_vec();
+        convert(
+            QualityEncoding::Solexa,
+            QualityEncoding::Phred33,
+            &mut quality_scores,
+        )
+        .unwrap();
+        assert_eq!(quality_scores, b"II5+\"");
+
+        let mut quality_scores = b"~".to_vec();
+        assert_eq!(
+            convert(
+                QualityEncoding::Phred33,
+                QualityEncoding::Phred64,
+                &mut quality_scores
+            ),
+            Err(ConvertError::OutOfRange(93))
+        );
+
+        let mut quality_scores = b"#".to_vec();
+        assert_eq!(
+            convert(
+                QualityEncoding::Phred64,
+                QualityEncoding::Phred33,
+                &mut quality_scores
+            ),
+            Err(ConvertError::InvalidCharacter(b'#'))
+        );
+    }
+}
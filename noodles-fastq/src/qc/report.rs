@@ -0,0 +1,325 @@
+//! FASTQ QC report.
+
+use std::{collections::BTreeMap, ops::Range};
+
+use serde::Serialize;
+
+use super::QualityEncoding;
+
+/// A FASTQ QC report.
+///
+/// This is a summary of [`super::Statistics`]. It is serializable, e.g., to JSON using
+/// `serde_json`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Report {
+    record_count: u64,
+    quality_encoding: QualityEncoding,
+    cycles: Vec<Range<usize>>,
+    per_cycle_quality: Vec<CycleQuality>,
+    base_composition: Vec<BaseComposition>,
+    gc_content: Vec<u64>,
+    mean_gc_content: f64,
+    length_distribution: BTreeMap<usize, u64>,
+    n_content: Vec<f64>,
+    overrepresented_sequences: Vec<OverrepresentedSequence>,
+    adapter_content: Vec<AdapterContent>,
+}
+
+impl Report {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        record_count: u64,
+        quality_encoding: QualityEncoding,
+        cycles: Vec<Range<usize>>,
+        per_cycle_quality: Vec<CycleQuality>,
+        base_composition: Vec<BaseComposition>,
+        gc_content: Vec<u64>,
+        mean_gc_content: f64,
+        length_distribution: BTreeMap<usize, u64>,
+        n_content: Vec<f64>,
+        overrepresented_sequences: Vec<OverrepresentedSequence>,
+        adapter_content: Vec<AdapterContent>,
+    ) -> Self {
+        Self {
+            record_count,
+            quality_encoding,
+            cycles,
+            per_cycle_quality,
+            base_composition,
+            gc_content,
+            mean_gc_content,
+            length_distribution,
+            n_content,
+            overrepresented_sequences,
+            adapter_content,
+        }
+    }
+
+    /// Returns the number of records.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the quality score encoding.
+    pub fn quality_encoding(&self) -> QualityEncoding {
+        self.quality_encoding
+    }
+
+    /// Returns the cycles (0-based base positions) of each per-cycle entry.
+    ///
+    /// The first 100 cycles are reported individually. Past that, cycles are grouped, and the
+    /// number of cycles in a group doubles every 100 groups. This bounds memory use for long
+    /// reads.
+    ///
+    /// These are the cycles of [`Self::per_cycle_quality`], [`Self::base_composition`],
+    /// [`Self::n_content`], and [`AdapterContent::percentages`].
+    pub fn cycles(&self) -> &[Range<usize>] {
+        &self.cycles
+    }
+
+    /// Returns the quality score distribution summary for each cycle (base position).
+    pub fn per_cycle_quality(&self) -> &[CycleQuality] {
+        &self.per_cycle_quality
+    }
+
+    /// Returns the base composition for each cycle (base position).
+    pub fn base_composition(&self) -> &[BaseComposition] {
+        &self.base_composition
+    }
+
+    /// Returns the GC content histogram.
+    ///
+    /// This has 101 bins, one for each GC percentage from 0 to 100, with the number of records in
+    /// each.
+    pub fn gc_content(&self) -> &[u64] {
+        &self.gc_content
+    }
+
+    /// Returns the mean GC content percentage of the records.
+    pub fn mean_gc_content(&self) -> f64 {
+        self.mean_gc_content
+    }
+
+    /// Returns the number of records for each sequence length.
+    pub fn length_distribution(&self) -> &BTreeMap<usize, u64> {
+        &self.length_distribution
+    }
+
+    /// Returns the percentage of `N` bases for each cycle (base position).
+    pub fn n_content(&self) -> &[f64] {
+        &self.n_content
+    }
+
+    /// Returns the overrepresented sequences, ordered by descending count.
+    pub fn overrepresented_sequences(&self) -> &[OverrepresentedSequence] {
+        &self.overrepresented_sequences
+    }
+
+    /// Returns the adapter content for each adapter.
+    pub fn adapter_content(&self) -> &[AdapterContent] {
+        &self.adapter_content
+    }
+}
+
+/// A quality score distribution summary for a cycle.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CycleQuality {
+    mean: f64,
+    median: u8,
+    lower_quartile: u8,
+    upper_quartile: u8,
+    percentile_10: u8,
+    percentile_90: u8,
+}
+
+impl CycleQuality {
+    pub(super) fn new(
+        mean: f64,
+        median: u8,
+        lower_quartile: u8,
+        upper_quartile: u8,
+        percentile_10: u8,
+        percentile_90: u8,
+    ) -> Self {
+        Self {
+            mean,
+            median,
+            lower_quartile,
+            upper_quartile,
+            percentile_10,
+            percentile_90,
+        }
+    }
+
+    /// Returns the mean Phred quality score.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the median Phred quality score.
+    pub fn median(&self) -> u8 {
+        self.median
+    }
+
+    /// Returns the 25th percentile Phred quality score.
+    pub fn lower_quartile(&self) -> u8 {
+        self.lower_quartile
+    }
+
+    /// Returns the 75th percentile Phred quality score.
+    pub fn upper_quartile(&self) -> u8 {
+        self.upper_quartile
+    }
+
+    /// Returns the 10th percentile Phred quality score.
+    pub fn percentile_10(&self) -> u8 {
+        self.percentile_10
+    }
+
+    /// Returns the 90th percentile Phred quality score.
+    pub fn percentile_90(&self) -> u8 {
+        self.percentile_90
+    }
+}
+
+/// The percentages of each base at a cycle.
+///
+/// Bases other than `A`, `C`, `G`, and `T` are counted as `N`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BaseComposition {
+    a: f64,
+    c: f64,
+    g: f64,
+    t: f64,
+    n: f64,
+}
+
+impl BaseComposition {
+    pub(super) fn new(a: f64, c: f64, g: f64, t: f64, n: f64) -> Self {
+        Self { a, c, g, t, n }
+    }
+
+    /// Returns the percentage of `A` bases.
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the percentage of `C` bases.
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    /// Returns the percentage of `G` bases.
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    /// Returns the percentage of `T` bases.
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Returns the percentage of `N` bases.
+    pub fn n(&self) -> f64 {
+        self.n
+    }
+}
+
+/// An overrepresented sequence.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OverrepresentedSequence {
+    #[serde(serialize_with = "serialize_sequence")]
+    sequence: Vec<u8>,
+    count: u64,
+    percentage: f64,
+}
+
+impl OverrepresentedSequence {
+    pub(super) fn new(sequence: Vec<u8>, count: u64, percentage: f64) -> Self {
+        Self {
+            sequence,
+            count,
+            percentage,
+        }
+    }
+
+    /// Returns the sequence.
+    ///
+    /// Sequences of records longer than 75 bases are truncated to the first 50 bases.
+    pub fn sequence(&self) -> &[u8] {
+        &self.sequence
+    }
+
+    /// Returns the number of records with this sequence.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the percentage of records with this sequence.
+    pub fn percentage(&self) -> f64 {
+        self.percentage
+    }
+}
+
+/// The adapter content of an adapter.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AdapterContent {
+    name: String,
+    percentages: Vec<f64>,
+}
+
+impl AdapterContent {
+    pub(super) fn new(name: String, percentages: Vec<f64>) -> Self {
+        Self { name, percentages }
+    }
+
+    /// Returns the adapter name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the cumulative percentage of records with the adapter at or before each cycle.
+    pub fn percentages(&self) -> &[f64] {
+        &self.percentages
+    }
+}
+
+fn serialize_sequence<S>(sequence: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&String::from_utf8_lossy(sequence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() -> Result<(), serde_json::Error> {
+        let report = Report::new(
+            1,
+            QualityEncoding::Phred33,
+            vec![Range { start: 0, end: 1 }],
+            vec![CycleQuality::new(40.0, 40, 40, 40, 40, 40)],
+            vec![BaseComposition::new(100.0, 0.0, 0.0, 0.0, 0.0)],
+            vec![1],
+            0.0,
+            [(1, 1)].into_iter().collect(),
+            vec![0.0],
+            vec![OverrepresentedSequence::new(b"A".to_vec(), 1, 100.0)],
+            vec![AdapterContent::new(String::from("PolyA"), vec![0.0])],
+        );
+
+        let actual = serde_json::to_value(&report)?;
+
+        assert_eq!(actual["quality_encoding"], "phred33");
+        assert_eq!(actual["cycles"][0]["start"], 0);
+        assert_eq!(actual["cycles"][0]["end"], 1);
+        assert_eq!(actual["length_distribution"]["1"], 1);
+        assert_eq!(actual["overrepresented_sequences"][0]["sequence"], "A");
+        assert_eq!(actual["adapter_content"][0]["name"], "PolyA");
+
+        Ok(())
+    }
+}
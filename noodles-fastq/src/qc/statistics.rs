@@ -0,0 +1,586 @@
+//! FASTQ QC statistics.
+
+mod builder;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+    ops::Range,
+};
+
+use memchr::memmem;
+
+pub use self::builder::Builder;
+use super::{
+    Adapter, QualityEncoding,
+    report::{AdapterContent, BaseComposition, CycleQuality, OverrepresentedSequence, Report},
+};
+use crate::Record;
+
+const MAX_QUALITY_SCORE: usize = 93;
+
+// These match the limits FastQC uses to bound memory when tracking duplicate sequences.
+const MAX_TRACKED_SEQUENCES: usize = 100_000;
+const TRUNCATE_SEQUENCE_THRESHOLD: usize = 75;
+const TRUNCATED_SEQUENCE_LENGTH: usize = 50;
+
+// Like FastQC, cycles are grouped past the start of a read so that per-cycle counts do not grow
+// with the length of long reads. The first 100 cycles are not grouped, and the width of a group
+// doubles every 100 groups.
+const CYCLE_GROUPS_PER_WIDTH: usize = 100;
+
+const A: usize = 0;
+const C: usize = 1;
+const G: usize = 2;
+const T: usize = 3;
+const N: usize = 4;
+
+type QualityScoreCounts = [u64; MAX_QUALITY_SCORE + 1];
+
+/// Streaming FASTQ QC statistics.
+///
+/// Records are added one at a time using [`Self::add`], and a summary is created using
+/// [`Self::report`].
+#[derive(Clone, Debug)]
+pub struct Statistics {
+    quality_encoding: QualityEncoding,
+    adapters: Vec<Adapter>,
+    overrepresented_threshold: f64,
+    record_count: u64,
+    // Per-cycle counts are indexed by cycle group (see `cycle_group`).
+    quality_score_counts: Vec<QualityScoreCounts>,
+    base_counts: Vec<[u64; 5]>,
+    gc_content_counts: [u64; 101],
+    length_counts: BTreeMap<usize, u64>,
+    sequence_counts: HashMap<Vec<u8>, u64>,
+    adapter_position_counts: Vec<Vec<u64>>,
+    scores: Vec<usize>,
+}
+
+impl Statistics {
+    /// Returns a builder to create statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Statistics;
+    /// let builder = Statistics::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    fn new(
+        quality_encoding: QualityEncoding,
+        adapters: Vec<Adapter>,
+        overrepresented_threshold: f64,
+    ) -> Self {
+        let adapter_position_counts = vec![Vec::new(); adapters.len()];
+
+        Self {
+            quality_encoding,
+            adapters,
+            overrepresented_threshold,
+            record_count: 0,
+            quality_score_counts: Vec::new(),
+            base_counts: Vec::new(),
+            gc_content_counts: [0; 101],
+            length_counts: BTreeMap::new(),
+            sequence_counts: HashMap::new(),
+            adapter_position_counts,
+            scores: Vec::new(),
+        }
+    }
+
+    /// Returns the number of records added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Statistics;
+    /// let statistics = Statistics::default();
+    /// assert_eq!(statistics.record_count(), 0);
+    /// ```
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Adds a record to the statistics.
+    ///
+    /// This returns an error if the sequence and quality scores lengths differ or a quality score
+    /// is invalid for the quality score encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, qc::Statistics};
+    ///
+    /// let mut statistics = Statistics::default();
+    ///
+    /// let record = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "NDLS");
+    /// statistics.add(&record)?;
+    ///
+    /// assert_eq!(statistics.record_count(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add(&mut self, record: &Record) -> io::Result<()> {
+        let sequence = record.sequence();
+        let quality_scores = record.quality_scores();
+
+        if sequence.len() != quality_scores.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "sequence and quality scores lengths mismatch",
+            ));
+        }
+
+        self.scores.clear();
+
+        for &c in quality_scores {
+            let score = self
+                .quality_encoding
+                .decode(c)
+                .map(usize::from)
+                .filter(|&n| n <= MAX_QUALITY_SCORE)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "invalid {} quality score: {:?}",
+                            self.quality_encoding,
+                            char::from(c)
+                        ),
+                    )
+                })?;
+
+            self.scores.push(score);
+        }
+
+        let len = sequence.len();
+        let group_count = len.checked_sub(1).map_or(0, |i| cycle_group(i) + 1);
+
+        if self.quality_score_counts.len() < group_count {
+            self.quality_score_counts
+                .resize(group_count, [0; MAX_QUALITY_SCORE + 1]);
+            self.base_counts.resize(group_count, [0; 5]);
+        }
+
+        for (i, &score) in self.scores.iter().enumerate() {
+            self.quality_score_counts[cycle_group(i)][score] += 1;
+        }
+
+        let mut gc_count: usize = 0;
+        let mut at_count = 0;
+
+        for (j, &b) in sequence.iter().enumerate() {
+            let i = base_index(b);
+
+            match i {
+                C | G => gc_count += 1,
+                A | T => at_count += 1,
+                _ => {}
+            }
+
+            self.base_counts[cycle_group(j)][i] += 1;
+        }
+
+        let base_count = gc_count + at_count;
+
+        if let Some(i) = (gc_count * 100 + base_count / 2).checked_div(base_count) {
+            self.gc_content_counts[i] += 1;
+        }
+
+        *self.length_counts.entry(len).or_insert(0) += 1;
+
+        self.count_sequence(sequence);
+
+        for (adapter, counts) in self.adapters.iter().zip(&mut self.adapter_position_counts) {
+            if let Some(i) = memmem::find(sequence, adapter.sequence()).map(cycle_group) {
+                if counts.len() <= i {
+                    counts.resize(i + 1, 0);
+                }
+
+                counts[i] += 1;
+            }
+        }
+
+        self.record_count += 1;
+
+        Ok(())
+    }
+
+    fn count_sequence(&mut self, sequence: &[u8]) {
+        let key = if sequence.len() > TRUNCATE_SEQUENCE_THRESHOLD {
+            &sequence[..TRUNCATED_SEQUENCE_LENGTH]
+        } else {
+            sequence
+        };
+
+        if let Some(count) = self.sequence_counts.get_mut(key) {
+            *count += 1;
+        } else if self.sequence_counts.len() < MAX_TRACKED_SEQUENCES {
+            self.sequence_counts.insert(key.to_vec(), 1);
+        }
+    }
+
+    /// Summarizes the statistics as a report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::Statistics;
+    /// let statistics = Statistics::default();
+    /// let report = statistics.report();
+    /// assert_eq!(report.record_count(), 0);
+    /// ```
+    pub fn report(&self) -> Report {
+        let cycles = (0..self.quality_score_counts.len())
+            .map(cycle_group_range)
+            .collect();
+
+        let per_cycle_quality = self
+            .quality_score_counts
+            .iter()
+            .map(summarize_quality_scores)
+            .collect();
+
+        let base_composition: Vec<_> = self.base_counts.iter().map(summarize_bases).collect();
+
+        let n_content = base_composition.iter().map(BaseComposition::n).collect();
+
+        let gc_content = self.gc_content_counts.to_vec();
+        let mean_gc_content = mean_gc_content(&self.gc_content_counts);
+
+        Report::new(
+            self.record_count,
+            self.quality_encoding,
+            cycles,
+            per_cycle_quality,
+            base_composition,
+            gc_content,
+            mean_gc_content,
+            self.length_counts.clone(),
+            n_content,
+            self.overrepresented_sequences(),
+            self.adapter_content(),
+        )
+    }
+
+    fn overrepresented_sequences(&self) -> Vec<OverrepresentedSequence> {
+        if self.record_count == 0 {
+            return Vec::new();
+        }
+
+        let record_count = self.record_count as f64;
+
+        let mut sequences: Vec<_> = self
+            .sequence_counts
+            .iter()
+            .filter(|&(_, &count)| count as f64 / record_count > self.overrepresented_threshold)
+            .filter(|&(_, &count)| count > 1)
+            .map(|(sequence, &count)| {
+                let percentage = percentage(count, self.record_count);
+                OverrepresentedSequence::new(sequence.clone(), count, percentage)
+            })
+            .collect();
+
+        sequences.sort_by(|a, b| {
+            b.count()
+                .cmp(&a.count())
+                .then_with(|| a.sequence().cmp(b.sequence()))
+        });
+
+        sequences
+    }
+
+    fn adapter_content(&self) -> Vec<AdapterContent> {
+        let len = self.quality_score_counts.len();
+
+        self.adapters
+            .iter()
+            .zip(&self.adapter_position_counts)
+            .map(|(adapter, counts)| {
+                let mut sum = 0;
+
+                let percentages = (0..len)
+                    .map(|i| {
+                        sum += counts.get(i).copied().unwrap_or_default();
+                        percentage(sum, self.record_count)
+                    })
+                    .collect();
+
+                AdapterContent::new(adapter.name().into(), percentages)
+            })
+            .collect()
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+fn cycle_group(position: usize) -> usize {
+    let tier = (position / CYCLE_GROUPS_PER_WIDTH + 1).ilog2() as usize;
+    let start = CYCLE_GROUPS_PER_WIDTH * ((1 << tier) - 1);
+    CYCLE_GROUPS_PER_WIDTH * tier + ((position - start) >> tier)
+}
+
+fn cycle_group_range(i: usize) -> Range<usize> {
+    let (tier, j) = (i / CYCLE_GROUPS_PER_WIDTH, i % CYCLE_GROUPS_PER_WIDTH);
+    let width = 1 << tier;
+    let start = CYCLE_GROUPS_PER_WIDTH * (width - 1) + j * width;
+    start..start + width
+}
+
+fn base_index(b: u8) -> usize {
+    match b {
+        b'A' | b'a' => A,
+        b'C' | b'c' => C,
+        b'G' | b'g' => G,
+        b'T' | b't' => T,
+        _ => N,
+    }
+}
+
+fn percentage(n: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (n as f64 / total as f64) * 100.0
+    }
+}
+
+fn summarize_quality_scores(counts: &QualityScoreCounts) -> CycleQuality {
+    let total: u64 = counts.iter().sum();
+
+    let sum: u64 = counts
+        .iter()
+        .enumerate()
+        .map(|(score, &count)| score as u64 * count)
+        .sum();
+
+    let mean = if total == 0 {
+        0.0
+    } else {
+        sum as f64 / total as f64
+    };
+
+    CycleQuality::new(
+        mean,
+        quantile(counts, total, 0.5),
+        quantile(counts, total, 0.25),
+        quantile(counts, total, 0.75),
+        quantile(counts, total, 0.1),
+        quantile(counts, total, 0.9),
+    )
+}
+
+fn quantile(counts: &QualityScoreCounts, total: u64, p: f64) -> u8 {
+    let rank = ((total as f64 * p).ceil() as u64).max(1);
+    let mut sum = 0;
+
+    for (score, &count) in counts.iter().enumerate() {
+        sum += count;
+
+        if sum >= rank {
+            return score as u8;
+        }
+    }
+
+    0
+}
+
+fn summarize_bases(counts: &[u64; 5]) -> BaseComposition {
+    let total = counts.iter().sum();
+
+    BaseComposition::new(
+        percentage(counts[A], total),
+        percentage(counts[C], total),
+        percentage(counts[G], total),
+        percentage(counts[T], total),
+        percentage(counts[N], total),
+    )
+}
+
+fn mean_gc_content(counts: &[u64; 101]) -> f64 {
+    let total: u64 = counts.iter().sum();
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    let sum: u64 = counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| i as u64 * count)
+        .sum();
+
+    sum as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Definition;
+
+    fn build_record(name: &str, sequence: &str, quality_scores: &str) -> Record {
+        Record::new(Definition::new(name, ""), sequence, quality_scores)
+    }
+
+    #[test]
+    fn test_add() -> io::Result<()> {
+        let mut statistics = Statistics::default();
+
+        statistics.add(&build_record("r0", "ACGT", "IIII"))?;
+        statistics.add(&build_record("r1", "GGCN", "!!I5"))?;
+
+        assert_eq!(statistics.record_count(), 2);
+        assert_eq!(statistics.quality_score_counts.len(), 4);
+        assert_eq!(statistics.quality_score_counts[0][40], 1);
+        assert_eq!(statistics.quality_score_counts[0][0], 1);
+        assert_eq!(statistics.base_counts[3], [0, 0, 0, 1, 1]);
+        assert_eq!(statistics.gc_content_counts[50], 1);
+        assert_eq!(statistics.gc_content_counts[100], 1);
+        assert_eq!(statistics.length_counts.get(&4), Some(&2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_with_invalid_record() {
+        let mut statistics = Statistics::default();
+
+        assert!(matches!(
+            statistics.add(&build_record("r0", "ACGT", "III")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut statistics = Statistics::builder()
+            .set_quality_encoding(QualityEncoding::Phred64)
+            .build();
+
+        assert!(matches!(
+            statistics.add(&build_record("r0", "ACGT", "II#I")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(statistics.record_count(), 0);
+        assert!(statistics.quality_score_counts.is_empty());
+    }
+
+    #[test]
+    fn test_report() -> io::Result<()> {
+        let mut statistics = Statistics::builder()
+            .set_adapters(vec![Adapter::new("adapter", "AGATC")])
+            .set_overrepresented_threshold(0.25)
+            .build();
+
+        statistics.add(&build_record("r0", "TTAGATCGG", "IIIIIIIII"))?;
+        statistics.add(&build_record("r1", "TTAGATCGG", "+++++++++"))?;
+        statistics.add(&build_record("r2", "CCCCCCCCC", "555555555"))?;
+        statistics.add(&build_record("r3", "AGATCNNNN", "#########"))?;
+
+        let report = statistics.report();
+
+        assert_eq!(report.record_count(), 4);
+        assert_eq!(report.quality_encoding(), QualityEncoding::Phred33);
+
+        let cycle_quality = &report.per_cycle_quality()[0];
+        assert_eq!(cycle_quality.mean(), 18.0);
+        assert_eq!(cycle_quality.median(), 10);
+        assert_eq!(cycle_quality.lower_quartile(), 2);
+        assert_eq!(cycle_quality.upper_quartile(), 20);
+        assert_eq!(cycle_quality.percentile_10(), 2);
+        assert_eq!(cycle_quality.percentile_90(), 40);
+
+        let base_composition = &report.base_composition()[8];
+        assert_eq!(base_composition.c(), 25.0);
+        assert_eq!(base_composition.g(), 50.0);
+        assert_eq!(base_composition.n(), 25.0);
+        assert_eq!(report.n_content()[8], 25.0);
+
+        assert_eq!(report.length_distribution().get(&9), Some(&4));
+
+        let overrepresented_sequences = report.overrepresented_sequences();
+        assert_eq!(overrepresented_sequences.len(), 1);
+        assert_eq!(overrepresented_sequences[0].sequence(), b"TTAGATCGG");
+        assert_eq!(overrepresented_sequences[0].count(), 2);
+        assert_eq!(overrepresented_sequences[0].percentage(), 50.0);
+
+        let adapter_content = report.adapter_content();
+        assert_eq!(adapter_content.len(), 1);
+        assert_eq!(adapter_content[0].name(), "adapter");
+        assert_eq!(
+            adapter_content[0].percentages(),
+            [25.0, 25.0, 75.0, 75.0, 75.0, 75.0, 75.0, 75.0, 75.0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_with_long_record() -> io::Result<()> {
+        const LEN: usize = 1 << 20;
+
+        let mut statistics = Statistics::default();
+
+        let sequence = vec![b'A'; LEN];
+        let quality_scores = vec![b'I'; LEN];
+        statistics.add(&Record::new(
+            Definition::new("r0", ""),
+            sequence,
+            quality_scores,
+        ))?;
+
+        assert!(statistics.quality_score_counts.len() < 1500);
+
+        let report = statistics.report();
+        let cycles = report.cycles();
+
+        assert_eq!(cycles.len(), report.per_cycle_quality().len());
+        assert_eq!(cycles.first(), Some(&(0..1)));
+        assert!(cycles.windows(2).all(|w| w[0].end == w[1].start));
+        assert!(
+            cycles
+                .last()
+                .is_some_and(|cycle| cycle.contains(&(LEN - 1)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle_group() {
+        assert_eq!(cycle_group(0), 0);
+        assert_eq!(cycle_group(99), 99);
+        assert_eq!(cycle_group(100), 100);
+        assert_eq!(cycle_group(101), 100);
+        assert_eq!(cycle_group(102), 101);
+        assert_eq!(cycle_group(299), 199);
+        assert_eq!(cycle_group(300), 200);
+        assert_eq!(cycle_group(303), 200);
+        assert_eq!(cycle_group(304), 201);
+
+        for i in 0..1000 {
+            let range = cycle_group_range(i);
+            assert!(range.clone().all(|position| cycle_group(position) == i));
+        }
+    }
+
+    #[test]
+    fn test_count_sequence_truncates_long_sequences() {
+        let mut statistics = Statistics::default();
+
+        let sequence = vec![b'A'; 80];
+        statistics.count_sequence(&sequence);
+
+        assert_eq!(
+            statistics
+                .sequence_counts
+                .get(&sequence[..TRUNCATED_SEQUENCE_LENGTH]),
+            Some(&1)
+        );
+    }
+}
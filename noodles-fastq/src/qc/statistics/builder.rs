@@ -0,0 +1,88 @@
+use super::Statistics;
+use crate::qc::{Adapter, QualityEncoding, default_adapters};
+
+const DEFAULT_OVERREPRESENTED_THRESHOLD: f64 = 0.001;
+
+/// A FASTQ QC statistics builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    quality_encoding: QualityEncoding,
+    adapters: Vec<Adapter>,
+    overrepresented_threshold: f64,
+}
+
+impl Builder {
+    /// Sets the quality score encoding.
+    ///
+    /// By default, this is [`QualityEncoding::Phred33`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::{statistics, QualityEncoding};
+    /// let builder = statistics::Builder::default().set_quality_encoding(QualityEncoding::Phred64);
+    /// ```
+    pub fn set_quality_encoding(mut self, quality_encoding: QualityEncoding) -> Self {
+        self.quality_encoding = quality_encoding;
+        self
+    }
+
+    /// Sets the adapters to search for.
+    ///
+    /// By default, this is [`crate::qc::default_adapters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::{statistics, Adapter};
+    ///
+    /// let builder = statistics::Builder::default()
+    ///     .set_adapters(vec![Adapter::new("Nextera Transposase Sequence", "CTGTCTCTTATA")]);
+    /// ```
+    pub fn set_adapters(mut self, adapters: Vec<Adapter>) -> Self {
+        self.adapters = adapters;
+        self
+    }
+
+    /// Sets the minimum fraction of records a sequence must make up to be reported as
+    /// overrepresented.
+    ///
+    /// By default, this is 0.001 (0.1%).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::statistics;
+    /// let builder = statistics::Builder::default().set_overrepresented_threshold(0.01);
+    /// ```
+    pub fn set_overrepresented_threshold(mut self, overrepresented_threshold: f64) -> Self {
+        self.overrepresented_threshold = overrepresented_threshold;
+        self
+    }
+
+    /// Builds FASTQ QC statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::statistics;
+    /// let statistics = statistics::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Statistics {
+        Statistics::new(
+            self.quality_encoding,
+            self.adapters,
+            self.overrepresented_threshold,
+        )
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            quality_encoding: QualityEncoding::Phred33,
+            adapters: default_adapters(),
+            overrepresented_threshold: DEFAULT_OVERREPRESENTED_THRESHOLD,
+        }
+    }
+}
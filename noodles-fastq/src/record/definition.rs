@@ -1,5 +1,13 @@
+//! FASTQ record definition and structured definition conventions.
+
+pub mod casava;
+pub mod ont;
+pub mod sra;
+
 use bstr::{BStr, BString};
 
+pub use self::{casava::Casava, ont::Ont, sra::Sra};
+
 /// A FASTQ record definition.
 ///
 /// A definition represents a definition line, i.e., a read name and, optionally, a description.
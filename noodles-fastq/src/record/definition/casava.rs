@@ -0,0 +1,396 @@
+//! Illumina Casava 1.8 FASTQ record definition.
+
+mod builder;
+
+use std::{error, fmt, str};
+
+use bstr::{BStr, BString, ByteSlice};
+
+pub use self::builder::Builder;
+use super::Definition;
+
+const DELIMITER: u8 = b':';
+
+/// An Illumina Casava 1.8 FASTQ record definition.
+///
+/// The definition has the form
+///
+/// ```text
+/// @<instrument>:<run number>:<flowcell ID>:<lane>:<tile>:<x>:<y>[:<UMI>] <read number>:<is filtered>:<control number>:<index>
+/// ```
+///
+/// e.g., `@EAS139:136:FC706VJ:2:2104:15343:197393 1:Y:18:ATCACG`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::record::{definition::Casava, Definition};
+///
+/// let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "1:Y:18:ATCACG");
+/// let casava = Casava::try_from(&definition)?;
+///
+/// assert_eq!(casava.instrument(), "EAS139");
+/// assert_eq!(casava.lane(), 2);
+/// assert_eq!((casava.tile(), casava.x(), casava.y()), (2104, 15343, 197393));
+/// assert_eq!(casava.read_number(), 1);
+/// assert!(casava.is_filtered());
+/// assert_eq!(casava.index(), "ATCACG");
+/// # Ok::<_, noodles_fastq::record::definition::casava::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Casava {
+    instrument: BString,
+    run_number: u32,
+    flowcell_id: BString,
+    lane: u32,
+    tile: u32,
+    x: u32,
+    y: u32,
+    umi: Option<BString>,
+    read_number: u8,
+    is_filtered: bool,
+    control_number: u16,
+    index: BString,
+}
+
+impl Casava {
+    /// Returns a builder to create an Illumina Casava 1.8 definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::record::definition::Casava;
+    /// let builder = Casava::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the instrument ID.
+    pub fn instrument(&self) -> &BStr {
+        self.instrument.as_ref()
+    }
+
+    /// Returns the run number.
+    pub fn run_number(&self) -> u32 {
+        self.run_number
+    }
+
+    /// Returns the flowcell ID.
+    pub fn flowcell_id(&self) -> &BStr {
+        self.flowcell_id.as_ref()
+    }
+
+    /// Returns the lane.
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    /// Returns the tile.
+    pub fn tile(&self) -> u32 {
+        self.tile
+    }
+
+    /// Returns the x-coordinate of the cluster in the tile.
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Returns the y-coordinate of the cluster in the tile.
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Returns the unique molecular identifier (UMI).
+    pub fn umi(&self) -> Option<&BStr> {
+        self.umi.as_ref().map(|umi| umi.as_ref())
+    }
+
+    /// Returns the read number, e.g., 1 or 2 for paired-end reads.
+    pub fn read_number(&self) -> u8 {
+        self.read_number
+    }
+
+    /// Returns whether the read was filtered, i.e., did not pass filter.
+    pub fn is_filtered(&self) -> bool {
+        self.is_filtered
+    }
+
+    /// Returns the control number.
+    ///
+    /// This is 0 when none of the control bits are set.
+    pub fn control_number(&self) -> u16 {
+        self.control_number
+    }
+
+    /// Returns the index sequence or sample number.
+    pub fn index(&self) -> &BStr {
+        self.index.as_ref()
+    }
+}
+
+/// A Casava 1.8 definition field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    /// The instrument ID.
+    Instrument,
+    /// The run number.
+    RunNumber,
+    /// The flowcell ID.
+    FlowcellId,
+    /// The lane.
+    Lane,
+    /// The tile.
+    Tile,
+    /// The x-coordinate.
+    X,
+    /// The y-coordinate.
+    Y,
+    /// The read number.
+    ReadNumber,
+    /// The filter flag.
+    IsFiltered,
+    /// The control number.
+    ControlNumber,
+    /// The index sequence or sample number.
+    Index,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Instrument => "instrument",
+            Self::RunNumber => "run number",
+            Self::FlowcellId => "flowcell ID",
+            Self::Lane => "lane",
+            Self::Tile => "tile",
+            Self::X => "x",
+            Self::Y => "y",
+            Self::ReadNumber => "read number",
+            Self::IsFiltered => "is filtered",
+            Self::ControlNumber => "control number",
+            Self::Index => "index",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// An error returned when a Casava 1.8 definition fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A field is missing.
+    MissingField(Field),
+    /// A field is invalid.
+    InvalidField(Field),
+    /// The name has unexpected fields.
+    UnexpectedNameFields,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "missing field: {field}"),
+            Self::InvalidField(field) => write!(f, "invalid field: {field}"),
+            Self::UnexpectedNameFields => f.write_str("unexpected name fields"),
+        }
+    }
+}
+
+impl TryFrom<&Definition> for Casava {
+    type Error = ParseError;
+
+    fn try_from(definition: &Definition) -> Result<Self, Self::Error> {
+        let mut fields = definition.name().split_str(&[DELIMITER]);
+
+        let instrument = parse_string(&mut fields, Field::Instrument)?;
+        let run_number = parse_int(&mut fields, Field::RunNumber)?;
+        let flowcell_id = parse_string(&mut fields, Field::FlowcellId)?;
+        let lane = parse_int(&mut fields, Field::Lane)?;
+        let tile = parse_int(&mut fields, Field::Tile)?;
+        let x = parse_int(&mut fields, Field::X)?;
+        let y = parse_int(&mut fields, Field::Y)?;
+        let umi = fields.next().map(BString::from);
+
+        if fields.next().is_some() {
+            return Err(ParseError::UnexpectedNameFields);
+        }
+
+        let mut fields = definition.description().splitn_str(4, &[DELIMITER]);
+
+        let read_number = parse_int(&mut fields, Field::ReadNumber)?;
+
+        let is_filtered = match next_field(&mut fields, Field::IsFiltered)? {
+            b"Y" => true,
+            b"N" => false,
+            _ => return Err(ParseError::InvalidField(Field::IsFiltered)),
+        };
+
+        let control_number = parse_int(&mut fields, Field::ControlNumber)?;
+
+        let index = fields
+            .next()
+            .map(|buf| buf.trim_ascii_end().into())
+            .ok_or(ParseError::MissingField(Field::Index))?;
+
+        Ok(Self {
+            instrument,
+            run_number,
+            flowcell_id,
+            lane,
+            tile,
+            x,
+            y,
+            umi,
+            read_number,
+            is_filtered,
+            control_number,
+            index,
+        })
+    }
+}
+
+impl From<&Casava> for Definition {
+    fn from(casava: &Casava) -> Self {
+        let mut name = format!(
+            "{}:{}:{}:{}:{}:{}:{}",
+            casava.instrument,
+            casava.run_number,
+            casava.flowcell_id,
+            casava.lane,
+            casava.tile,
+            casava.x,
+            casava.y,
+        );
+
+        if let Some(umi) = casava.umi() {
+            name.push(':');
+            name.push_str(&umi.to_str_lossy());
+        }
+
+        let description = format!(
+            "{}:{}:{}:{}",
+            casava.read_number,
+            if casava.is_filtered { 'Y' } else { 'N' },
+            casava.control_number,
+            casava.index,
+        );
+
+        Self::new(name, description)
+    }
+}
+
+fn next_field<'a, I>(fields: &mut I, field: Field) -> Result<&'a [u8], ParseError>
+where
+    I: Iterator<Item = &'a [u8]>,
+{
+    fields.next().ok_or(ParseError::MissingField(field))
+}
+
+fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<BString, ParseError>
+where
+    I: Iterator<Item = &'a [u8]>,
+{
+    match next_field(fields, field)? {
+        [] => Err(ParseError::InvalidField(field)),
+        buf => Ok(buf.into()),
+    }
+}
+
+fn parse_int<'a, I, N>(fields: &mut I, field: Field) -> Result<N, ParseError>
+where
+    I: Iterator<Item = &'a [u8]>,
+    N: str::FromStr,
+{
+    let buf = next_field(fields, field)?;
+
+    str::from_utf8(buf)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(ParseError::InvalidField(field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_casava() -> Casava {
+        Casava {
+            instrument: "EAS139".into(),
+            run_number: 136,
+            flowcell_id: "FC706VJ".into(),
+            lane: 2,
+            tile: 2104,
+            x: 15343,
+            y: 197393,
+            umi: None,
+            read_number: 1,
+            is_filtered: true,
+            control_number: 18,
+            index: "ATCACG".into(),
+        }
+    }
+
+    #[test]
+    fn test_try_from_definition_for_casava() {
+        let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "1:Y:18:ATCACG");
+        assert_eq!(Casava::try_from(&definition), Ok(build_casava()));
+
+        let definition = Definition::new(
+            "EAS139:136:FC706VJ:2:2104:15343:197393:GATCTACG",
+            "2:N:0:ATCACG+GTTTCG",
+        );
+        let actual = Casava::try_from(&definition).unwrap();
+        assert_eq!(actual.umi(), Some(BStr::new("GATCTACG")));
+        assert_eq!(actual.read_number(), 2);
+        assert!(!actual.is_filtered());
+        assert_eq!(actual.index(), "ATCACG+GTTTCG");
+
+        let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343", "1:Y:18:ATCACG");
+        assert_eq!(
+            Casava::try_from(&definition),
+            Err(ParseError::MissingField(Field::Y))
+        );
+
+        let definition = Definition::new("EAS139:136:FC706VJ:two:2104:15343:197393", "1:Y:18:1");
+        assert_eq!(
+            Casava::try_from(&definition),
+            Err(ParseError::InvalidField(Field::Lane))
+        );
+
+        let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393:U:V", "1:Y:18:1");
+        assert_eq!(
+            Casava::try_from(&definition),
+            Err(ParseError::UnexpectedNameFields)
+        );
+
+        let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "1:X:18:1");
+        assert_eq!(
+            Casava::try_from(&definition),
+            Err(ParseError::InvalidField(Field::IsFiltered))
+        );
+
+        let definition = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "");
+        assert_eq!(
+            Casava::try_from(&definition),
+            Err(ParseError::InvalidField(Field::ReadNumber))
+        );
+    }
+
+    #[test]
+    fn test_from_casava_for_definition() {
+        let casava = build_casava();
+        let actual = Definition::from(&casava);
+        let expected = Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "1:Y:18:ATCACG");
+        assert_eq!(actual, expected);
+
+        let casava = Casava {
+            umi: Some("GATCTACG".into()),
+            ..build_casava()
+        };
+        let definition = Definition::from(&casava);
+        assert_eq!(Casava::try_from(&definition), Ok(casava));
+    }
+}
@@ -0,0 +1,125 @@
+use bstr::BString;
+
+use super::Casava;
+
+/// An Illumina Casava 1.8 definition builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    inner: Casava,
+}
+
+impl Builder {
+    /// Sets the instrument ID.
+    pub fn set_instrument<S>(mut self, instrument: S) -> Self
+    where
+        S: Into<BString>,
+    {
+        self.inner.instrument = instrument.into();
+        self
+    }
+
+    /// Sets the run number.
+    pub fn set_run_number(mut self, run_number: u32) -> Self {
+        self.inner.run_number = run_number;
+        self
+    }
+
+    /// Sets the flowcell ID.
+    pub fn set_flowcell_id<S>(mut self, flowcell_id: S) -> Self
+    where
+        S: Into<BString>,
+    {
+        self.inner.flowcell_id = flowcell_id.into();
+        self
+    }
+
+    /// Sets the lane.
+    pub fn set_lane(mut self, lane: u32) -> Self {
+        self.inner.lane = lane;
+        self
+    }
+
+    /// Sets the tile.
+    pub fn set_tile(mut self, tile: u32) -> Self {
+        self.inner.tile = tile;
+        self
+    }
+
+    /// Sets the x-coordinate of the cluster in the tile.
+    pub fn set_x(mut self, x: u32) -> Self {
+        self.inner.x = x;
+        self
+    }
+
+    /// Sets the y-coordinate of the cluster in the tile.
+    pub fn set_y(mut self, y: u32) -> Self {
+        self.inner.y = y;
+        self
+    }
+
+    /// Sets the unique molecular identifier (UMI).
+    pub fn set_umi<S>(mut self, umi: S) -> Self
+    where
+        S: Into<BString>,
+    {
+        self.inner.umi = Some(umi.into());
+        self
+    }
+
+    /// Sets the read number.
+    pub fn set_read_number(mut self, read_number: u8) -> Self {
+        self.inner.read_number = read_number;
+        self
+    }
+
+    /// Sets whether the read was filtered.
+    pub fn set_is_filtered(mut self, is_filtered: bool) -> Self {
+        self.inner.is_filtered = is_filtered;
+        self
+    }
+
+    /// Sets the control number.
+    pub fn set_control_number(mut self, control_number: u16) -> Self {
+        self.inner.control_number = control_number;
+        self
+    }
+
+    /// Sets the index sequence or sample number.
+    pub fn set_index<S>(mut self, index: S) -> Self
+    where
+        S: Into<BString>,
+    {
+        self.inner.index = index.into();
+        self
+    }
+
+    /// Builds an Illumina Casava 1.8 definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::record::{definition::Casava, Definition};
+    ///
+    /// let casava = Casava::builder()
+    ///     .set_instrument("EAS139")
+    ///     .set_run_number(136)
+    ///     .set_flowcell_id("FC706VJ")
+    ///     .set_lane(2)
+    ///     .set_tile(2104)
+    ///     .set_x(15343)
+    ///     .set_y(197393)
+    ///     .set_read_number(1)
+    ///     .set_is_filtered(true)
+    ///     .set_control_number(18)
+    ///     .set_index("ATCACG")
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     Definition::from(&casava),
+    ///     Definition::new("EAS139:136:FC706VJ:2:2104:15343:197393", "1:Y:18:ATCACG"),
+    /// );
+    /// ```
+    pub fn build(self) -> Casava {
+        self.inner
+    }
+}
@@ -0,0 +1,288 @@
+//! Oxford Nanopore Technologies (ONT) FASTQ record definition.
+
+use std::{error, fmt, str};
+
+use bstr::{BStr, BString, ByteSlice};
+
+use super::Definition;
+
+const RUN_ID: &[u8] = b"runid";
+const READ_NUMBER: &[u8] = b"read";
+const CHANNEL: &[u8] = b"ch";
+const START_TIME: &[u8] = b"start_time";
+
+/// An Oxford Nanopore Technologies (ONT) FASTQ record definition.
+///
+/// The name is the read ID, and the description is a list of `key=value` fields separated by
+/// whitespace, e.g.,
+///
+/// ```text
+/// @a0b1c2d3-e4f5-a6b7-c8d9-e0f1a2b3c4d5 runid=8f2b0c5e read=21 ch=380 start_time=2021-06-01T12:00:00Z
+/// ```
+///
+/// Fields are kept in order. Well-known fields have typed accessors.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::record::{definition::Ont, Definition};
+///
+/// let definition = Definition::new(
+///     "a0b1c2d3-e4f5-a6b7-c8d9-e0f1a2b3c4d5",
+///     "runid=8f2b0c5e read=21 ch=380 start_time=2021-06-01T12:00:00Z",
+/// );
+///
+/// let ont = Ont::try_from(&definition)?;
+///
+/// assert_eq!(ont.read_id(), "a0b1c2d3-e4f5-a6b7-c8d9-e0f1a2b3c4d5");
+/// assert_eq!(ont.run_id(), Some("8f2b0c5e".into()));
+/// assert_eq!(ont.read_number(), Some(21));
+/// assert_eq!(ont.channel(), Some(380));
+/// assert_eq!(ont.start_time(), Some("2021-06-01T12:00:00Z".into()));
+/// # Ok::<_, noodles_fastq::record::definition::ont::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Ont {
+    read_id: BString,
+    fields: Vec<(BString, BString)>,
+}
+
+impl Ont {
+    /// Creates an ONT definition with no fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::record::{definition::Ont, Definition};
+    ///
+    /// let mut ont = Ont::new("a0b1c2d3");
+    /// ont.insert("read", "21");
+    /// ont.insert("ch", "380");
+    ///
+    /// assert_eq!(Definition::from(&ont), Definition::new("a0b1c2d3", "read=21 ch=380"));
+    /// ```
+    pub fn new<I>(read_id: I) -> Self
+    where
+        I: Into<BString>,
+    {
+        Self {
+            read_id: read_id.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the read ID.
+    pub fn read_id(&self) -> &BStr {
+        self.read_id.as_ref()
+    }
+
+    /// Returns the fields.
+    pub fn fields(&self) -> &[(BString, BString)] {
+        &self.fields
+    }
+
+    /// Returns the value of the field with the given key.
+    pub fn get<K>(&self, key: K) -> Option<&BStr>
+    where
+        K: AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_ref())
+    }
+
+    /// Sets the value of a field.
+    ///
+    /// If the key already exists, its value is replaced in place. Otherwise, the field is
+    /// appended.
+    pub fn insert<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<BString>,
+        V: Into<BString>,
+    {
+        let key = key.into();
+        let value = value.into();
+
+        match self.fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.fields.push((key, value)),
+        }
+    }
+
+    /// Returns the run ID (`runid`).
+    pub fn run_id(&self) -> Option<&BStr> {
+        self.get(RUN_ID)
+    }
+
+    /// Returns the read number (`read`).
+    ///
+    /// This returns `None` if the field is missing or is not an integer.
+    pub fn read_number(&self) -> Option<u64> {
+        self.get(READ_NUMBER).and_then(|buf| parse_int(buf))
+    }
+
+    /// Returns the channel (`ch`).
+    ///
+    /// This returns `None` if the field is missing or is not an integer.
+    pub fn channel(&self) -> Option<u32> {
+        self.get(CHANNEL).and_then(|buf| parse_int(buf))
+    }
+
+    /// Returns the start time (`start_time`).
+    pub fn start_time(&self) -> Option<&BStr> {
+        self.get(START_TIME)
+    }
+}
+
+/// An error returned when an ONT definition fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A field is not a `key=value` pair.
+    InvalidField(BString),
+    /// A key is duplicated.
+    DuplicateKey(BString),
+    /// The read number is invalid.
+    InvalidReadNumber,
+    /// The channel is invalid.
+    InvalidChannel,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidField(field) => write!(f, "invalid field: {field}"),
+            Self::DuplicateKey(key) => write!(f, "duplicate key: {key}"),
+            Self::InvalidReadNumber => f.write_str("invalid read number"),
+            Self::InvalidChannel => f.write_str("invalid channel"),
+        }
+    }
+}
+
+impl TryFrom<&Definition> for Ont {
+    type Error = ParseError;
+
+    fn try_from(definition: &Definition) -> Result<Self, Self::Error> {
+        let mut ont = Self::new(definition.name());
+
+        for raw_field in definition
+            .description()
+            .split(|b| b.is_ascii_whitespace())
+            .filter(|buf| !buf.is_empty())
+        {
+            let (key, value) = raw_field
+                .split_once_str("=")
+                .filter(|(key, _)| !key.is_empty())
+                .ok_or_else(|| ParseError::InvalidField(raw_field.into()))?;
+
+            if ont.get(key).is_some() {
+                return Err(ParseError::DuplicateKey(key.into()));
+            }
+
+            ont.fields.push((key.into(), value.into()));
+        }
+
+        if ont.get(READ_NUMBER).is_some() && ont.read_number().is_none() {
+            return Err(ParseError::InvalidReadNumber);
+        }
+
+        if ont.get(CHANNEL).is_some() && ont.channel().is_none() {
+            return Err(ParseError::InvalidChannel);
+        }
+
+        Ok(ont)
+    }
+}
+
+impl From<&Ont> for Definition {
+    fn from(ont: &Ont) -> Self {
+        let mut description = BString::default();
+
+        for (i, (key, value)) in ont.fields.iter().enumerate() {
+            if i > 0 {
+                description.push(b' ');
+            }
+
+            description.extend_from_slice(key);
+            description.push(b'=');
+            description.extend_from_slice(value);
+        }
+
+        Self::new(ont.read_id.clone(), description)
+    }
+}
+
+fn parse_int<N>(buf: &[u8]) -> Option<N>
+where
+    N: str::FromStr,
+{
+    str::from_utf8(buf).ok().and_then(|s| s.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_definition_for_ont() {
+        let definition = Definition::new(
+            "a0b1c2d3",
+            "runid=8f2b0c5e  read=21\tch=380 barcode=unclassified",
+        );
+
+        let ont = Ont::try_from(&definition).unwrap();
+        assert_eq!(ont.read_id(), "a0b1c2d3");
+        assert_eq!(ont.read_number(), Some(21));
+        assert_eq!(ont.channel(), Some(380));
+        assert!(ont.start_time().is_none());
+        assert_eq!(ont.get("barcode"), Some(BStr::new("unclassified")));
+        assert_eq!(ont.fields().len(), 4);
+
+        let definition = Definition::new("a0b1c2d3", "read=21 ch");
+        assert_eq!(
+            Ont::try_from(&definition),
+            Err(ParseError::InvalidField("ch".into()))
+        );
+
+        let definition = Definition::new("a0b1c2d3", "read=21 read=22");
+        assert_eq!(
+            Ont::try_from(&definition),
+            Err(ParseError::DuplicateKey("read".into()))
+        );
+
+        let definition = Definition::new("a0b1c2d3", "read=x");
+        assert_eq!(
+            Ont::try_from(&definition),
+            Err(ParseError::InvalidReadNumber)
+        );
+
+        let definition = Definition::new("a0b1c2d3", "ch=-1");
+        assert_eq!(Ont::try_from(&definition), Err(ParseError::InvalidChannel));
+    }
+
+    #[test]
+    fn test_from_ont_for_definition() {
+        let definition = Definition::new(
+            "a0b1c2d3",
+            "runid=8f2b0c5e read=21 ch=380 start_time=2021-06-01T12:00:00Z",
+        );
+
+        let mut ont = Ont::try_from(&definition).unwrap();
+        assert_eq!(Definition::from(&ont), definition);
+
+        ont.insert("read", "22");
+        ont.insert("barcode", "barcode01");
+
+        assert_eq!(
+            Definition::from(&ont),
+            Definition::new(
+                "a0b1c2d3",
+                "runid=8f2b0c5e read=22 ch=380 start_time=2021-06-01T12:00:00Z barcode=barcode01"
+            )
+        );
+    }
+}
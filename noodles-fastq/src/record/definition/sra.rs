@@ -0,0 +1,315 @@
+//! SRA FASTQ record definition.
+
+use std::{error, fmt, str};
+
+use bstr::{BStr, BString, ByteSlice};
+
+use super::Definition;
+
+const LENGTH_PREFIX: &[u8] = b"length=";
+
+/// An SRA FASTQ record definition.
+///
+/// This is the definition written by the SRA Toolkit (`fastq-dump` and `fasterq-dump`), which has
+/// the form
+///
+/// ```text
+/// @<accession>.<spot number>[<.|/><read number>] [<original name>] [length=<length>]
+/// ```
+///
+/// e.g., `@SRR001666.1 071112_SLXA-EAS1_s_7:5:1:817:345 length=36`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::record::{definition::Sra, Definition};
+///
+/// let definition = Definition::new("SRR001666.1", "071112_SLXA-EAS1_s_7:5:1:817:345 length=36");
+/// let sra = Sra::try_from(&definition)?;
+///
+/// assert_eq!(sra.accession(), "SRR001666");
+/// assert_eq!(sra.spot_number(), 1);
+/// assert!(sra.read_number().is_none());
+/// assert_eq!(sra.original_name(), Some("071112_SLXA-EAS1_s_7:5:1:817:345".into()));
+/// assert_eq!(sra.length(), Some(36));
+/// # Ok::<_, noodles_fastq::record::definition::sra::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sra {
+    accession: BString,
+    spot_number: u64,
+    read_number: Option<u8>,
+    original_name: Option<BString>,
+    length: Option<usize>,
+}
+
+impl Sra {
+    /// Creates an SRA definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::record::{definition::Sra, Definition};
+    ///
+    /// let mut sra = Sra::new("SRR001666", 1);
+    /// *sra.read_number_mut() = Some(2);
+    /// *sra.length_mut() = Some(36);
+    ///
+    /// assert_eq!(Definition::from(&sra), Definition::new("SRR001666.1.2", "length=36"));
+    /// ```
+    pub fn new<A>(accession: A, spot_number: u64) -> Self
+    where
+        A: Into<BString>,
+    {
+        Self {
+            accession: accession.into(),
+            spot_number,
+            read_number: None,
+            original_name: None,
+            length: None,
+        }
+    }
+
+    /// Returns the run accession, e.g., `SRR001666`.
+    pub fn accession(&self) -> &BStr {
+        self.accession.as_ref()
+    }
+
+    /// Returns the spot number.
+    pub fn spot_number(&self) -> u64 {
+        self.spot_number
+    }
+
+    /// Returns the read number of the spot.
+    pub fn read_number(&self) -> Option<u8> {
+        self.read_number
+    }
+
+    /// Returns a mutable reference to the read number of the spot.
+    pub fn read_number_mut(&mut self) -> &mut Option<u8> {
+        &mut self.read_number
+    }
+
+    /// Returns the original read name.
+    pub fn original_name(&self) -> Option<&BStr> {
+        self.original_name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns a mutable reference to the original read name.
+    pub fn original_name_mut(&mut self) -> &mut Option<BString> {
+        &mut self.original_name
+    }
+
+    /// Returns the read length.
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Returns a mutable reference to the read length.
+    pub fn length_mut(&mut self) -> &mut Option<usize> {
+        &mut self.length
+    }
+}
+
+/// An error returned when an SRA definition fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The accession is invalid.
+    InvalidAccession,
+    /// The spot number is missing.
+    MissingSpotNumber,
+    /// The spot number is invalid.
+    InvalidSpotNumber,
+    /// The read number is invalid.
+    InvalidReadNumber,
+    /// The length is invalid.
+    InvalidLength,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAccession => f.write_str("invalid accession"),
+            Self::MissingSpotNumber => f.write_str("missing spot number"),
+            Self::InvalidSpotNumber => f.write_str("invalid spot number"),
+            Self::InvalidReadNumber => f.write_str("invalid read number"),
+            Self::InvalidLength => f.write_str("invalid length"),
+        }
+    }
+}
+
+impl TryFrom<&Definition> for Sra {
+    type Error = ParseError;
+
+    fn try_from(definition: &Definition) -> Result<Self, Self::Error> {
+        let name = definition.name();
+
+        let (accession, rest) = name
+            .split_once_str(".")
+            .ok_or(ParseError::MissingSpotNumber)?;
+
+        if !is_valid_accession(accession) {
+            return Err(ParseError::InvalidAccession);
+        }
+
+        let (raw_spot_number, raw_read_number) = match rest.find_byteset(b"./") {
+            Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+            None => (rest, None),
+        };
+
+        let spot_number = parse_int(raw_spot_number).ok_or(ParseError::InvalidSpotNumber)?;
+
+        let read_number = raw_read_number
+            .map(|buf| parse_int(buf).ok_or(ParseError::InvalidReadNumber))
+            .transpose()?;
+
+        let description = definition.description().trim_ascii();
+
+        let (raw_original_name, raw_length) = match description.rsplit_once_str(" ") {
+            Some((rest, last)) if last.starts_with(LENGTH_PREFIX) => (rest, Some(last)),
+            _ if description.starts_with(LENGTH_PREFIX) => (&b""[..], Some(description)),
+            _ => (description, None),
+        };
+
+        let length = raw_length
+            .map(|buf| parse_int(&buf[LENGTH_PREFIX.len()..]).ok_or(ParseError::InvalidLength))
+            .transpose()?;
+
+        let raw_original_name = raw_original_name.trim_ascii();
+        let original_name = (!raw_original_name.is_empty()).then(|| raw_original_name.into());
+
+        Ok(Self {
+            accession: accession.into(),
+            spot_number,
+            read_number,
+            original_name,
+            length,
+        })
+    }
+}
+
+impl From<&Sra> for Definition {
+    fn from(sra: &Sra) -> Self {
+        let mut name = format!("{}.{}", sra.accession, sra.spot_number);
+
+        if let Some(read_number) = sra.read_number {
+            name.push_str(&format!(".{read_number}"));
+        }
+
+        let mut description = BString::default();
+
+        if let Some(original_name) = sra.original_name() {
+            description.extend_from_slice(original_name);
+        }
+
+        if let Some(length) = sra.length {
+            if !description.is_empty() {
+                description.push(b' ');
+            }
+
+            description.extend_from_slice(LENGTH_PREFIX);
+            description.extend_from_slice(length.to_string().as_bytes());
+        }
+
+        Self::new(name, description)
+    }
+}
+
+fn is_valid_accession(buf: &[u8]) -> bool {
+    let i = buf
+        .iter()
+        .position(|b| !b.is_ascii_uppercase())
+        .unwrap_or(buf.len());
+
+    let (prefix, digits) = buf.split_at(i);
+
+    !prefix.is_empty() && !digits.is_empty() && digits.iter().all(u8::is_ascii_digit)
+}
+
+fn parse_int<N>(buf: &[u8]) -> Option<N>
+where
+    N: str::FromStr,
+{
+    str::from_utf8(buf).ok().and_then(|s| s.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_definition_for_sra() {
+        let definition = Definition::new("SRR001666.1.2", "length=36");
+        let mut expected = Sra::new("SRR001666", 1);
+        expected.read_number = Some(2);
+        expected.length = Some(36);
+        assert_eq!(Sra::try_from(&definition), Ok(expected));
+
+        let definition = Definition::new("ERR000001.8/1", "");
+        let mut expected = Sra::new("ERR000001", 8);
+        expected.read_number = Some(1);
+        assert_eq!(Sra::try_from(&definition), Ok(expected));
+
+        let definition = Definition::new("SRR001666.1", "071112_SLXA-EAS1_s_7:5:1:817:345");
+        let mut expected = Sra::new("SRR001666", 1);
+        expected.original_name = Some("071112_SLXA-EAS1_s_7:5:1:817:345".into());
+        assert_eq!(Sra::try_from(&definition), Ok(expected));
+
+        let definition = Definition::new("SRR001666", "");
+        assert_eq!(
+            Sra::try_from(&definition),
+            Err(ParseError::MissingSpotNumber)
+        );
+
+        let definition = Definition::new("r0.1", "");
+        assert_eq!(
+            Sra::try_from(&definition),
+            Err(ParseError::InvalidAccession)
+        );
+
+        let definition = Definition::new("SRR001666.one", "");
+        assert_eq!(
+            Sra::try_from(&definition),
+            Err(ParseError::InvalidSpotNumber)
+        );
+
+        let definition = Definition::new("SRR001666.1.x", "");
+        assert_eq!(
+            Sra::try_from(&definition),
+            Err(ParseError::InvalidReadNumber)
+        );
+
+        let definition = Definition::new("SRR001666.1", "length=n");
+        assert_eq!(Sra::try_from(&definition), Err(ParseError::InvalidLength));
+    }
+
+    #[test]
+    fn test_from_sra_for_definition() {
+        let mut sra = Sra::new("SRR001666", 1);
+        assert_eq!(Definition::from(&sra), Definition::new("SRR001666.1", ""));
+
+        sra.original_name = Some("071112_SLXA-EAS1_s_7:5:1:817:345".into());
+        sra.length = Some(36);
+
+        let definition = Definition::from(&sra);
+
+        assert_eq!(
+            definition,
+            Definition::new("SRR001666.1", "071112_SLXA-EAS1_s_7:5:1:817:345 length=36")
+        );
+
+        assert_eq!(Sra::try_from(&definition), Ok(sra));
+    }
+
+    #[test]
+    fn test_is_valid_accession() {
+        assert!(is_valid_accession(b"SRR001666"));
+        assert!(is_valid_accession(b"DRR000001"));
+        assert!(!is_valid_accession(b""));
+        assert!(!is_valid_accession(b"SRR"));
+        assert!(!is_valid_accession(b"001666"));
+        assert!(!is_valid_accession(b"SRR001666a"));
+    }
+}
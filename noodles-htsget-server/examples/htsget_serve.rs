@@ -0,0 +1,25 @@
+//! Serves indexed files in a directory using the htsget protocol.
+//!
+//! The base URL is `http://<addr>/`, e.g., `http://127.0.0.1:8080/reads/sample` serves tickets
+//! for `<root>/sample.bam`.
+
+use std::{env, net::TcpListener};
+
+use noodles_htsget_server::Server;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let root = args.next().expect("missing root");
+    let addr = args
+        .next()
+        .unwrap_or_else(|| String::from("127.0.0.1:8080"));
+
+    let listener = TcpListener::bind(&addr)?;
+    let base_url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+    let server = Server::new(root, base_url);
+    server.serve(&listener)?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::response::{self, error::Kind};
+
+/// A data format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Format {
+    /// BAM.
+    Bam,
+    /// CRAM.
+    Cram,
+    /// VCF.
+    Vcf,
+    /// BCF.
+    Bcf,
+}
+
+impl Format {
+    /// Returns the extension of files with this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::Format;
+    /// assert_eq!(Format::Bam.file_extension(), "bam");
+    /// assert_eq!(Format::Vcf.file_extension(), "vcf.gz");
+    /// ```
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Bam => "bam",
+            Self::Cram => "cram",
+            Self::Vcf => "vcf.gz",
+            Self::Bcf => "bcf",
+        }
+    }
+
+    /// Returns the extensions of the supported index files of this format, in order of
+    /// preference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::Format;
+    /// assert_eq!(Format::Bam.index_file_extensions(), ["bai", "csi"]);
+    /// ```
+    pub fn index_file_extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Bam => &["bai", "csi"],
+            Self::Cram => &["crai"],
+            Self::Vcf => &["tbi", "csi"],
+            Self::Bcf => &["csi"],
+        }
+    }
+
+    pub(crate) fn media_type(self) -> &'static str {
+        match self {
+            Self::Bam => "application/vnd.ga4gh.bam",
+            Self::Cram => "application/vnd.ga4gh.cram",
+            Self::Vcf => "application/vnd.ga4gh.vcf",
+            Self::Bcf => "application/vnd.ga4gh.bcf",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = response::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BAM" => Ok(Self::Bam),
+            "CRAM" => Ok(Self::Cram),
+            "VCF" => Ok(Self::Vcf),
+            "BCF" => Ok(Self::Bcf),
+            _ => Err(response::Error::new(
+                Kind::UnsupportedFormat,
+                format!("unsupported format: {s}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("BAM".parse::<Format>(), Ok(Format::Bam));
+        assert_eq!("CRAM".parse::<Format>(), Ok(Format::Cram));
+        assert_eq!("VCF".parse::<Format>(), Ok(Format::Vcf));
+        assert_eq!("BCF".parse::<Format>(), Ok(Format::Bcf));
+
+        assert!(matches!(
+            "bam".parse::<Format>(),
+            Err(e) if e.kind() == Kind::UnsupportedFormat
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+//! **noodles-htsget-server** is an htsget 1.3 server backed by local indexed files.
+//!
+//! A [`Server`] resolves reads (BAM, CRAM) and variants (VCF, BCF) requests against files in a
+//! root directory and their associated indices (BAI, CSI, CRAI, tabix). It returns tickets of
+//! byte ranges and inline data block URIs and serves the byte ranges it references.
+
+mod format;
+pub mod request;
+pub mod response;
+mod server;
+
+pub use noodles_http::Body;
+
+pub use self::{format::Format, request::Request, response::Ticket, server::Server};
+
+use std::{error, fmt, io};
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// An error returned when a request fails to process.
+#[derive(Debug)]
+pub enum Error {
+    /// The request cannot be fulfilled.
+    ///
+    /// This is reported to the client as an htsget error response.
+    Response(response::Error),
+    /// An I/O error.
+    Io(io::Error),
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Response(e) => Some(e),
+            Self::Io(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Response(_) => f.write_str("response error"),
+            Self::Io(_) => f.write_str("I/O error"),
+        }
+    }
+}
+
+impl From<response::Error> for Error {
+    fn from(e: response::Error) -> Self {
+        Self::Response(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -0,0 +1,527 @@
+//! htsget request.
+
+mod kind;
+mod payload;
+
+pub use self::kind::Kind;
+
+use noodles_core::{Position, Region};
+
+use self::payload::Payload;
+use crate::{
+    Format,
+    response::{self, error::Kind as ErrorKind},
+};
+
+// _Htsget retrieval API spec v1.3.0_ § "Query parameters": `fields`.
+const READS_FIELDS: [&str; 11] = [
+    "QNAME", "FLAG", "RNAME", "POS", "MAPQ", "CIGAR", "RNEXT", "PNEXT", "TLEN", "SEQ", "QUAL",
+];
+
+// The reference name of unplaced, unmapped reads.
+pub(crate) const UNMAPPED_REFERENCE_NAME: &str = "*";
+
+/// The class of data of a request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Class {
+    /// Header data only.
+    Header,
+}
+
+/// An htsget request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Request {
+    kind: Kind,
+    id: String,
+    format: Format,
+    class: Option<Class>,
+    regions: Option<Vec<Region>>,
+    fields: Option<Vec<String>>,
+    tags: Option<Vec<String>>,
+    notags: Option<Vec<String>>,
+}
+
+impl Request {
+    /// Creates a request for all data of a resource.
+    ///
+    /// The format is the default format of the endpoint, i.e., BAM for reads and VCF for
+    /// variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::{request::Kind, Format, Request};
+    /// let request = Request::new(Kind::Reads, "sample");
+    /// assert_eq!(request.format(), Format::Bam);
+    /// ```
+    pub fn new<I>(kind: Kind, id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        Self {
+            kind,
+            id: id.into(),
+            format: kind.default_format(),
+            class: None,
+            regions: None,
+            fields: None,
+            tags: None,
+            notags: None,
+        }
+    }
+
+    /// Parses a request from the query string of a `GET` request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::{request::Kind, Request};
+    /// let request = Request::from_query(Kind::Reads, "sample", "referenceName=sq0&start=7&end=13")?;
+    /// assert_eq!(request.regions().map(|regions| regions.len()), Some(1));
+    /// # Ok::<_, noodles_htsget_server::response::Error>(())
+    /// ```
+    pub fn from_query<I>(kind: Kind, id: I, query: &str) -> Result<Self, response::Error>
+    where
+        I: Into<String>,
+    {
+        let mut request = Self::new(kind, id);
+
+        let mut reference_name = None;
+        let mut start = None;
+        let mut end = None;
+
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "format" => request.format = value.parse()?,
+                "class" => request.class = Some(parse_class(&value)?),
+                "referenceName" => reference_name = Some(value.into_owned()),
+                "start" => start = Some(parse_position("start", &value)?),
+                "end" => end = Some(parse_position("end", &value)?),
+                "fields" => request.fields = Some(split_list(&value)),
+                "tags" => request.tags = Some(split_list(&value)),
+                "notags" => request.notags = Some(split_list(&value)),
+                _ => {}
+            }
+        }
+
+        match reference_name {
+            Some(name) => {
+                request.regions = Some(build_region(&name, start, end)?.into_iter().collect());
+            }
+            None if start.is_some() || end.is_some() => {
+                return Err(invalid_input("start and end require referenceName"));
+            }
+            None => {}
+        }
+
+        request.validate()?;
+
+        Ok(request)
+    }
+
+    /// Parses a request from the JSON body of a `POST` request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::{request::Kind, Request};
+    ///
+    /// let body = br#"{"format":"BAM","regions":[{"referenceName":"sq0","start":7,"end":13}]}"#;
+    /// let request = Request::from_json(Kind::Reads, "sample", body)?;
+    ///
+    /// assert_eq!(request.regions().map(|regions| regions.len()), Some(1));
+    /// # Ok::<_, noodles_htsget_server::response::Error>(())
+    /// ```
+    pub fn from_json<I>(kind: Kind, id: I, body: &[u8]) -> Result<Self, response::Error>
+    where
+        I: Into<String>,
+    {
+        let mut request = Self::new(kind, id);
+
+        // An empty body is equivalent to an empty object.
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(request);
+        }
+
+        let payload: Payload = serde_json::from_slice(body)
+            .map_err(|e| invalid_input(format!("invalid JSON body: {e}")))?;
+
+        if let Some(format) = payload.format {
+            request.format = format.parse()?;
+        }
+
+        if let Some(class) = payload.class {
+            request.class = Some(parse_class(&class)?);
+        }
+
+        if let Some(regions) = payload.regions {
+            let mut rs = Vec::with_capacity(regions.len());
+
+            for region in regions {
+                rs.extend(build_region(
+                    &region.reference_name,
+                    region.start,
+                    region.end,
+                )?);
+            }
+
+            request.regions = Some(rs);
+        }
+
+        request.fields = payload.fields;
+        request.tags = payload.tags;
+        request.notags = payload.notags;
+
+        request.validate()?;
+
+        Ok(request)
+    }
+
+    /// Returns the endpoint kind.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the resource ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the data format.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Sets the data format.
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Returns the class of data.
+    pub fn class(&self) -> Option<Class> {
+        self.class
+    }
+
+    /// Sets the class of data.
+    pub fn set_class(mut self, class: Class) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Returns the regions to query.
+    ///
+    /// This is `None` if the request is for all records. Otherwise, it is the list of nonempty
+    /// regions, which may be empty.
+    pub fn regions(&self) -> Option<&[Region]> {
+        self.regions.as_deref()
+    }
+
+    /// Adds a region to query.
+    ///
+    /// The reference sequence name `*` selects unplaced, unmapped reads.
+    pub fn add_region(mut self, region: Region) -> Self {
+        self.regions.get_or_insert_with(Vec::new).push(region);
+        self
+    }
+
+    /// Returns the requested fields.
+    ///
+    /// Byte ranges cannot be filtered by field, so a server may return all fields.
+    pub fn fields(&self) -> Option<&[String]> {
+        self.fields.as_deref()
+    }
+
+    /// Returns the requested tags.
+    pub fn tags(&self) -> Option<&[String]> {
+        self.tags.as_deref()
+    }
+
+    /// Returns the excluded tags.
+    pub fn notags(&self) -> Option<&[String]> {
+        self.notags.as_deref()
+    }
+
+    /// Validates the request.
+    ///
+    /// This checks that the format is supported by the endpoint and that the parameters are
+    /// consistent.
+    pub fn validate(&self) -> Result<(), response::Error> {
+        if !self.kind.formats().contains(&self.format) {
+            return Err(response::Error::new(
+                ErrorKind::UnsupportedFormat,
+                format!("unsupported format for endpoint: {:?}", self.format),
+            ));
+        }
+
+        let has_filters = self.fields.is_some() || self.tags.is_some() || self.notags.is_some();
+
+        if self.class == Some(Class::Header) && (self.regions.is_some() || has_filters) {
+            return Err(invalid_input(
+                "class=header cannot be combined with other parameters",
+            ));
+        }
+
+        match self.kind {
+            Kind::Reads => {
+                if let Some(field) = self
+                    .fields
+                    .iter()
+                    .flatten()
+                    .find(|field| !READS_FIELDS.contains(&field.as_str()))
+                {
+                    return Err(invalid_input(format!("invalid field: {field}")));
+                }
+
+                if let (Some(tags), Some(notags)) = (&self.tags, &self.notags) {
+                    if let Some(tag) = tags.iter().find(|tag| notags.contains(tag)) {
+                        return Err(invalid_input(format!("tag in both tags and notags: {tag}")));
+                    }
+                }
+            }
+            Kind::Variants => {
+                if has_filters {
+                    return Err(invalid_input(
+                        "fields, tags, and notags are not supported for variants",
+                    ));
+                }
+
+                if self
+                    .regions
+                    .iter()
+                    .flatten()
+                    .any(|region| region.name() == UNMAPPED_REFERENCE_NAME)
+                {
+                    return Err(invalid_input("invalid reference name: *"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_input<M>(message: M) -> response::Error
+where
+    M: Into<String>,
+{
+    response::Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn parse_class(s: &str) -> Result<Class, response::Error> {
+    match s {
+        "header" => Ok(Class::Header),
+        _ => Err(invalid_input(format!("invalid class: {s}"))),
+    }
+}
+
+fn parse_position(key: &str, s: &str) -> Result<u32, response::Error> {
+    s.parse()
+        .map_err(|_| invalid_input(format!("invalid {key}: {s}")))
+}
+
+fn split_list(s: &str) -> Vec<String> {
+    s.split(',').map(String::from).collect()
+}
+
+// Converts an htsget range (0-based, half-open) to a region.
+//
+// This returns `None` if the range is empty.
+fn build_region(
+    reference_name: &str,
+    start: Option<u32>,
+    end: Option<u32>,
+) -> Result<Option<Region>, response::Error> {
+    if reference_name == UNMAPPED_REFERENCE_NAME {
+        if start.is_some() || end.is_some() {
+            return Err(invalid_input(
+                "start and end cannot be used with referenceName=*",
+            ));
+        }
+
+        return Ok(Some(Region::new(reference_name, ..)));
+    }
+
+    if let (Some(start), Some(end)) = (start, end) {
+        if start > end {
+            return Err(response::Error::new(
+                ErrorKind::InvalidRange,
+                format!("start is greater than end: {start} > {end}"),
+            ));
+        } else if start == end {
+            return Ok(None);
+        }
+    }
+
+    let start = start.and_then(|n| Position::new(n as usize + 1));
+    let end = end.and_then(|n| Position::new(n as usize));
+
+    let region = match (start, end) {
+        (Some(start), Some(end)) => Region::new(reference_name, start..=end),
+        (Some(start), None) => Region::new(reference_name, start..),
+        (None, Some(end)) => Region::new(reference_name, ..=end),
+        (None, None) => Region::new(reference_name, ..),
+    };
+
+    Ok(Some(region))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_query() -> Result<(), Box<dyn std::error::Error>> {
+        let request = Request::from_query(Kind::Reads, "sample", "")?;
+        assert_eq!(request, Request::new(Kind::Reads, "sample"));
+
+        let request = Request::from_query(
+            Kind::Reads,
+            "sample",
+            "format=CRAM&referenceName=sq0&start=7&end=13&fields=QNAME,FLAG&tags=NM&notags=MD",
+        )?;
+
+        assert_eq!(request.format(), Format::Cram);
+        assert_eq!(
+            request.regions(),
+            Some(
+                &[Region::new(
+                    "sq0",
+                    Position::try_from(8)?..=Position::try_from(13)?
+                )][..]
+            )
+        );
+        assert_eq!(
+            request.fields(),
+            Some(&[String::from("QNAME"), String::from("FLAG")][..])
+        );
+        assert_eq!(request.tags(), Some(&[String::from("NM")][..]));
+        assert_eq!(request.notags(), Some(&[String::from("MD")][..]));
+
+        let request =
+            Request::from_query(Kind::Reads, "sample", "referenceName=sq0&start=5&end=5")?;
+        assert_eq!(request.regions(), Some(&[][..]));
+
+        let request = Request::from_query(Kind::Reads, "sample", "referenceName=*")?;
+        assert_eq!(request.regions(), Some(&[Region::new("*", ..)][..]));
+
+        let request = Request::from_query(Kind::Variants, "sample", "class=header")?;
+        assert_eq!(request.format(), Format::Vcf);
+        assert_eq!(request.class(), Some(Class::Header));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_query_with_invalid_parameters() {
+        fn kind_of(kind: Kind, query: &str) -> Option<ErrorKind> {
+            Request::from_query(kind, "sample", query)
+                .err()
+                .map(|e| e.kind())
+        }
+
+        assert_eq!(
+            kind_of(Kind::Reads, "format=VCF"),
+            Some(ErrorKind::UnsupportedFormat)
+        );
+        assert_eq!(
+            kind_of(Kind::Variants, "format=BAM"),
+            Some(ErrorKind::UnsupportedFormat)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "format=SAM"),
+            Some(ErrorKind::UnsupportedFormat)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "class=body"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "start=8"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "referenceName=sq0&start=a"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "referenceName=sq0&start=13&end=8"),
+            Some(ErrorKind::InvalidRange)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "referenceName=*&start=8"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "class=header&referenceName=sq0"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "fields=QNAME,NDLS"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Reads, "tags=NM,MD&notags=MD"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Variants, "referenceName=*"),
+            Some(ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            kind_of(Kind::Variants, "fields=QNAME"),
+            Some(ErrorKind::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_from_json() -> Result<(), Box<dyn std::error::Error>> {
+        let request = Request::from_json(Kind::Reads, "sample", b"")?;
+        assert_eq!(request, Request::new(Kind::Reads, "sample"));
+
+        let request = Request::from_json(
+            Kind::Reads,
+            "sample",
+            br#"{
+                "format": "BAM",
+                "regions": [
+                    { "referenceName": "sq0", "start": 7, "end": 13 },
+                    { "referenceName": "sq1" },
+                    { "referenceName": "*" }
+                ],
+                "tags": ["NM"]
+            }"#,
+        )?;
+
+        assert_eq!(
+            request.regions(),
+            Some(
+                &[
+                    Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?),
+                    Region::new("sq1", ..),
+                    Region::new("*", ..),
+                ][..]
+            )
+        );
+        assert_eq!(request.tags(), Some(&[String::from("NM")][..]));
+
+        let request = Request::from_json(Kind::Variants, "sample", br#"{"class":"header"}"#)?;
+        assert_eq!(request.class(), Some(Class::Header));
+
+        assert!(matches!(
+            Request::from_json(Kind::Reads, "sample", b"{"),
+            Err(e) if e.kind() == ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Request::from_json(
+                Kind::Reads,
+                "sample",
+                br#"{"regions":[{"referenceName":"sq0","start":13,"end":8}]}"#
+            ),
+            Err(e) if e.kind() == ErrorKind::InvalidRange
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Format;
+
+/// An htsget endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The reads endpoint (`/reads/<id>`).
+    Reads,
+    /// The variants endpoint (`/variants/<id>`).
+    Variants,
+}
+
+impl Kind {
+    pub(crate) fn default_format(self) -> Format {
+        match self {
+            Self::Reads => Format::Bam,
+            Self::Variants => Format::Vcf,
+        }
+    }
+
+    pub(crate) fn formats(self) -> &'static [Format] {
+        match self {
+            Self::Reads => &[Format::Bam, Format::Cram],
+            Self::Variants => &[Format::Vcf, Format::Bcf],
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+// The JSON body of a `POST` request.
+#[derive(Debug, Default, Deserialize)]
+pub(super) struct Payload {
+    pub(super) format: Option<String>,
+    pub(super) class: Option<String>,
+    pub(super) fields: Option<Vec<String>>,
+    pub(super) tags: Option<Vec<String>>,
+    pub(super) notags: Option<Vec<String>>,
+    pub(super) regions: Option<Vec<PayloadRegion>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayloadRegion {
+    pub(super) reference_name: String,
+    pub(super) start: Option<u32>,
+    pub(super) end: Option<u32>,
+}
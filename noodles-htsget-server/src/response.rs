@@ -0,0 +1,24 @@
+//! htsget response.
+
+pub mod error;
+pub mod ticket;
+
+pub use self::{error::Error, ticket::Ticket};
+
+use serde::Serialize;
+
+// The media type of htsget ticket and error responses.
+pub(crate) const CONTENT_TYPE: &str = "application/vnd.ga4gh.htsget.v1.3.0+json; charset=utf-8";
+
+// _Htsget retrieval API spec v1.3.0_ § "Response JSON fields": responses are wrapped in an
+// `htsget` object.
+#[derive(Serialize)]
+pub(crate) struct Envelope<'a, T> {
+    htsget: &'a T,
+}
+
+impl<'a, T> Envelope<'a, T> {
+    pub(crate) fn new(htsget: &'a T) -> Self {
+        Self { htsget }
+    }
+}
@@ -0,0 +1,101 @@
+//! htsget error response.
+
+use std::{error, fmt};
+
+use serde::Serialize;
+
+/// An htsget error kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum Kind {
+    /// The authorization provided is invalid.
+    InvalidAuthentication,
+    /// Authorization is required to access the resource.
+    PermissionDenied,
+    /// The resource was not found.
+    NotFound,
+    /// The request size is too large.
+    PayloadTooLarge,
+    /// The requested file format is not supported by the server.
+    UnsupportedFormat,
+    /// The request parameters are invalid.
+    InvalidInput,
+    /// The request range is invalid.
+    InvalidRange,
+}
+
+impl Kind {
+    /// Returns the HTTP status code of the error kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::response::error::Kind;
+    /// assert_eq!(Kind::NotFound.status_code(), 404);
+    /// ```
+    pub fn status_code(self) -> u16 {
+        match self {
+            Self::InvalidAuthentication => 401,
+            Self::PermissionDenied => 403,
+            Self::NotFound => 404,
+            Self::PayloadTooLarge => 413,
+            Self::UnsupportedFormat | Self::InvalidInput | Self::InvalidRange => 400,
+        }
+    }
+}
+
+/// An htsget error response.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Error {
+    #[serde(rename = "error")]
+    kind: Kind,
+    message: String,
+}
+
+impl Error {
+    /// Creates an htsget error response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::response::{error::Kind, Error};
+    /// let error = Error::new(Kind::NotFound, "ID not found");
+    /// ```
+    pub fn new<M>(kind: Kind, message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the error kind.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let error = Error::new(Kind::NotFound, "ID not found");
+        assert_eq!(error.to_string(), "NotFound: ID not found");
+    }
+}
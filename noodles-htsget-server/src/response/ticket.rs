@@ -0,0 +1,87 @@
+//! htsget ticket.
+
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Serializer};
+
+use crate::Format;
+
+/// The class of data of a ticket URL.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Class {
+    /// Header data.
+    Header,
+    /// Body data.
+    Body,
+}
+
+/// A ticket URL.
+///
+/// This is either a URL to a byte range of a file or an inline data block URI.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct BlockUrl {
+    #[serde(serialize_with = "serialize_url")]
+    url: url::Url,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    class: Class,
+}
+
+impl BlockUrl {
+    pub(crate) fn new(url: url::Url, headers: BTreeMap<String, String>, class: Class) -> Self {
+        Self {
+            url,
+            headers,
+            class,
+        }
+    }
+
+    /// Returns the URL.
+    pub fn url(&self) -> &url::Url {
+        &self.url
+    }
+
+    /// Returns the HTTP headers to send with a request to the URL.
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.headers
+    }
+
+    /// Returns the class of data.
+    pub fn class(&self) -> Class {
+        self.class
+    }
+}
+
+/// An htsget ticket.
+///
+/// A client reconstructs the requested data by concatenating the data of the ticket URLs, in
+/// order.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Ticket {
+    format: Format,
+    urls: Vec<BlockUrl>,
+}
+
+impl Ticket {
+    pub(crate) fn new(format: Format, urls: Vec<BlockUrl>) -> Self {
+        Self { format, urls }
+    }
+
+    /// Returns the data format.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the ticket URLs.
+    pub fn urls(&self) -> &[BlockUrl] {
+        &self.urls
+    }
+}
+
+fn serialize_url<S>(url: &url::Url, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(url.as_str())
+}
@@ -0,0 +1,1003 @@
+//! htsget server.
+
+mod bam;
+mod bcf;
+mod bgzf;
+mod cram;
+mod urls;
+mod vcf;
+
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    net::TcpListener,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use http::{StatusCode, header};
+use noodles_http::Body;
+use percent_encoding::percent_decode_str;
+
+use self::urls::Urls;
+use crate::{
+    Format, Request, Ticket,
+    request::Kind,
+    response::{self, CONTENT_TYPE, Envelope, error::Kind as ErrorKind},
+};
+
+/// An htsget server backed by local indexed files.
+///
+/// Resources are files in a root directory named `<id>.<ext>`, where the extension is the file
+/// extension of the requested format (see [`Format::file_extension`]). Each file must have an
+/// associated index named `<id>.<ext>.<index-ext>` (see [`Format::index_file_extensions`]).
+///
+/// Tickets reference byte ranges of a data endpoint (`<base-url>/data/<id>.<ext>`), which the
+/// server also serves. Partial BGZF blocks are inlined as data block URIs, so the concatenated
+/// ticket data contains exactly the requested records.
+#[derive(Clone, Debug)]
+pub struct Server {
+    root: PathBuf,
+    base_url: url::Url,
+}
+
+impl Server {
+    /// Creates an htsget server.
+    ///
+    /// The base URL is the public URL of the server. It is used to route requests and to build
+    /// the URLs of the data endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget_server::Server;
+    /// let server = Server::new("data", "http://localhost:8080/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn new<P>(root: P, base_url: url::Url) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            root: root.into(),
+            base_url,
+        }
+    }
+
+    /// Returns the root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the base URL.
+    pub fn base_url(&self) -> &url::Url {
+        &self.base_url
+    }
+
+    /// Builds a ticket for the given request.
+    ///
+    /// The fields, tags, and notags parameters are validated but not applied, i.e., all fields
+    /// of the matching records are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_htsget_server::{request::Kind, Request, Server};
+    ///
+    /// let server = Server::new("data", "http://localhost:8080/".parse()?);
+    ///
+    /// let request = Request::from_query(Kind::Reads, "sample", "referenceName=sq0&start=7&end=13")?;
+    /// let ticket = server.ticket(&request)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ticket(&self, request: &Request) -> crate::Result<Ticket> {
+        request.validate()?;
+
+        let format = request.format();
+        let (src, index_src) = self.resolve(request.id(), format)?;
+
+        let data_url = self.build_data_url(request.id(), format)?;
+        let mut urls = Urls::new(format, data_url);
+
+        match format {
+            Format::Bam => bam::build(&src, &index_src, request, &mut urls)?,
+            Format::Cram => cram::build(&src, &index_src, request, &mut urls)?,
+            Format::Vcf => vcf::build(&src, &index_src, request, &mut urls)?,
+            Format::Bcf => bcf::build(&src, &index_src, request, &mut urls)?,
+        }
+
+        Ok(Ticket::new(format, urls.finish()?))
+    }
+
+    /// Opens a byte range of a data file.
+    ///
+    /// The file name is `<id>.<ext>`, as referenced by ticket URLs. If `range` is `None`, the
+    /// entire file is opened.
+    ///
+    /// The returned reader is positioned at the start of the range and limited to its length
+    /// ([`io::Take::limit`]), so the range can be streamed rather than read into memory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::io::Read;
+    /// use noodles_htsget_server::Server;
+    ///
+    /// let server = Server::new("data", "http://localhost:8080/".parse()?);
+    ///
+    /// let mut reader = server.open_data("sample.bam", Some(0..=1023))?;
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn open_data(
+        &self,
+        name: &str,
+        range: Option<RangeInclusive<u64>>,
+    ) -> crate::Result<io::Take<File>> {
+        let (id, format) = [Format::Bam, Format::Cram, Format::Vcf, Format::Bcf]
+            .into_iter()
+            .find_map(|format| {
+                name.strip_suffix(format.file_extension())
+                    .and_then(|s| s.strip_suffix('.'))
+                    .map(|id| (id, format))
+            })
+            .ok_or_else(|| not_found(format!("data not found: {name}")))?;
+
+        let (src, _) = self.resolve(id, format)?;
+        let mut file = File::open(src)?;
+
+        let len = file.seek(SeekFrom::End(0))?;
+
+        let (start, end) = match range {
+            // An end past the end of the file is truncated.
+            Some(range) => (*range.start(), (*range.end()).min(len.saturating_sub(1))),
+            None if len == 0 => return Ok(file.take(0)),
+            None => (0, len - 1),
+        };
+
+        if start > end || start >= len {
+            return Err(response::Error::new(
+                ErrorKind::InvalidRange,
+                format!("invalid range: {start}-{end}"),
+            )
+            .into());
+        }
+
+        file.seek(SeekFrom::Start(start))?;
+
+        Ok(file.take(end - start + 1))
+    }
+
+    /// Handles an HTTP request.
+    ///
+    /// This routes `GET` and `POST` requests to the reads and variants endpoints
+    /// (`<base-url>/reads/<id>` and `<base-url>/variants/<id>`) and `GET` requests to the data
+    /// endpoint (`<base-url>/data/<id>.<ext>`). htsget errors are returned as JSON error
+    /// responses.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_htsget_server::Server;
+    ///
+    /// let server = Server::new("data", "http://localhost:8080/".parse()?);
+    ///
+    /// let request = http::Request::get("/reads/sample?referenceName=sq0").body(Vec::new())?;
+    /// let response = server.handle(&request);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn handle<B>(&self, request: &http::Request<B>) -> http::Response<Body>
+    where
+        B: AsRef<[u8]>,
+    {
+        match self.route(request) {
+            Ok(response) => response,
+            Err(crate::Error::Response(e)) => error_response(&e),
+            Err(crate::Error::Io(_)) => {
+                build_response(StatusCode::INTERNAL_SERVER_ERROR, "text/plain", Vec::new())
+            }
+        }
+    }
+
+    /// Serves HTTP/1.1 requests from the given listener.
+    ///
+    /// Each connection is handled on its own thread, one request per connection, with a bound on
+    /// the number of connections handled at the same time. Request lines, headers, and bodies are
+    /// size-limited, and reads and writes time out. Data ranges are streamed from the data files.
+    ///
+    /// This is intended for local use and testing, e.g., to run a server in-process for an htsget
+    /// client. Failures of individual connections are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::net::TcpListener;
+    /// use noodles_htsget_server::Server;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080")?;
+    /// let server = Server::new("data", "http://127.0.0.1:8080/".parse()?);
+    /// server.serve(&listener)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn serve(&self, listener: &TcpListener) -> io::Result<()> {
+        noodles_http::serve(listener, |request| self.handle(request))
+    }
+
+    fn route<B>(&self, request: &http::Request<B>) -> crate::Result<http::Response<Body>>
+    where
+        B: AsRef<[u8]>,
+    {
+        let base_path = self.base_url.path().trim_end_matches('/');
+
+        let (endpoint, id) = request
+            .uri()
+            .path()
+            .strip_prefix(base_path)
+            .and_then(|path| path.strip_prefix('/'))
+            .and_then(|path| path.split_once('/'))
+            .ok_or_else(|| not_found("endpoint not found"))?;
+
+        let id = percent_decode_str(id)
+            .decode_utf8()
+            .map_err(|_| not_found("ID not found"))?;
+
+        let method = request.method();
+
+        let kind = match endpoint {
+            "reads" => Kind::Reads,
+            "variants" => Kind::Variants,
+            "data" if method == http::Method::GET => {
+                let range = match request.headers().get(header::RANGE) {
+                    Some(value) => Some(parse_range(value)?),
+                    None => None,
+                };
+
+                let status = if range.is_some() {
+                    StatusCode::PARTIAL_CONTENT
+                } else {
+                    StatusCode::OK
+                };
+
+                let reader = self.open_data(&id, range)?;
+                let len = reader.limit();
+                let body = Body::Reader(Box::new(reader), len);
+
+                return Ok(build_response(status, "application/octet-stream", body));
+            }
+            _ => return Err(not_found("endpoint not found").into()),
+        };
+
+        let htsget_request = if method == http::Method::GET {
+            Request::from_query(kind, id, request.uri().query().unwrap_or_default())?
+        } else if method == http::Method::POST {
+            Request::from_json(kind, id, request.body().as_ref())?
+        } else {
+            return Err(not_found("endpoint not found").into());
+        };
+
+        let ticket = self.ticket(&htsget_request)?;
+
+        let body = serde_json::to_vec(&Envelope::new(&ticket))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(build_response(StatusCode::OK, CONTENT_TYPE, body))
+    }
+
+    fn resolve(&self, id: &str, format: Format) -> crate::Result<(PathBuf, PathBuf)> {
+        if !is_valid_id(id) {
+            return Err(not_found(format!("ID not found: {id}")).into());
+        }
+
+        let name = format!("{id}.{}", format.file_extension());
+        let src = self.root.join(&name);
+
+        if !src.is_file() {
+            return Err(not_found(format!("ID not found: {id}")).into());
+        }
+
+        let index_src = format
+            .index_file_extensions()
+            .iter()
+            .map(|ext| self.root.join(format!("{name}.{ext}")))
+            .find(|path| path.is_file())
+            .ok_or_else(|| not_found(format!("index not found: {id}")))?;
+
+        Ok((src, index_src))
+    }
+
+    fn build_data_url(&self, id: &str, format: Format) -> io::Result<url::Url> {
+        let mut url = self.base_url.clone();
+
+        url.path_segments_mut()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "invalid base URL"))?
+            .pop_if_empty()
+            .push("data")
+            .push(&format!("{id}.{}", format.file_extension()));
+
+        Ok(url)
+    }
+}
+
+// IDs are file name stems, so they cannot name other directories or hidden files.
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('.')
+        && !id.contains(['/', '\\'])
+        && !id.chars().any(char::is_control)
+}
+
+fn not_found<M>(message: M) -> response::Error
+where
+    M: Into<String>,
+{
+    response::Error::new(ErrorKind::NotFound, message)
+}
+
+pub(crate) fn reference_sequence_not_found(name: &[u8]) -> response::Error {
+    not_found(format!(
+        "reference sequence not found: {}",
+        String::from_utf8_lossy(name)
+    ))
+}
+
+// Parses a single byte range, e.g., `bytes=8-13` or `bytes=8-`.
+fn parse_range(value: &header::HeaderValue) -> crate::Result<RangeInclusive<u64>> {
+    fn invalid_range() -> response::Error {
+        response::Error::new(ErrorKind::InvalidRange, "invalid Range header")
+    }
+
+    let (start, end) = value
+        .to_str()
+        .ok()
+        .and_then(|s| s.strip_prefix("bytes="))
+        .and_then(|s| s.split_once('-'))
+        .ok_or_else(invalid_range)?;
+
+    let start = start.parse().map_err(|_| invalid_range())?;
+
+    let end = if end.is_empty() {
+        u64::MAX
+    } else {
+        end.parse().map_err(|_| invalid_range())?
+    };
+
+    Ok(start..=end)
+}
+
+fn error_response(e: &response::Error) -> http::Response<Body> {
+    let status =
+        StatusCode::from_u16(e.kind().status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    let body = serde_json::to_vec(&Envelope::new(e)).unwrap_or_default();
+
+    build_response(status, CONTENT_TYPE, body)
+}
+
+fn build_response<B>(
+    status: StatusCode,
+    content_type: &'static str,
+    body: B,
+) -> http::Response<Body>
+where
+    B: Into<Body>,
+{
+    let mut response = http::Response::new(body.into());
+    *response.status_mut() = status;
+
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(content_type),
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, num::NonZeroUsize, process};
+
+    use base64::prelude::{BASE64_STANDARD, Engine as _};
+    use bstr::BString;
+    use noodles_bam as bam;
+    use noodles_core::{Position, Region};
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            RecordBuf,
+            io::Write as _,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind as OpKind},
+            },
+            record_buf::{QualityScores, Sequence},
+        },
+        header::record::value::{
+            Map,
+            map::{
+                self, ReferenceSequence,
+                header::{sort_order::COORDINATE, tag::SORT_ORDER},
+            },
+        },
+    };
+    use noodles_vcf::{self as vcf, variant::io::Write as _};
+
+    use super::*;
+    use crate::request::Class;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> io::Result<Self> {
+            let path =
+                env::temp_dir().join(format!("noodles-htsget-server-{}-{name}", process::id()));
+
+            fs::create_dir_all(&path)?;
+
+            Ok(Self(path))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn read_body(response: http::Response<Body>) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        response.into_body().write_to(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn build_server(root: &Path) -> Result<Server, url::ParseError> {
+        Ok(Server::new(root, "http://localhost/htsget/".parse()?))
+    }
+
+    // Reconstructs the data of a ticket by resolving its URLs in-process.
+    fn read_ticket_data(
+        server: &Server,
+        ticket: &Ticket,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+
+        for block_url in ticket.urls() {
+            let url = block_url.url();
+
+            if url.scheme() == "data" {
+                let (_, data) = url
+                    .as_str()
+                    .split_once(";base64,")
+                    .ok_or("invalid data URL")?;
+
+                buf.extend(BASE64_STANDARD.decode(data)?);
+            } else {
+                assert_eq!(
+                    url.path().rsplit_once('/').map(|(p, _)| p),
+                    Some("/htsget/data")
+                );
+
+                let name = url
+                    .path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .ok_or("invalid URL")?;
+
+                let range = match block_url.headers().get("Range") {
+                    Some(value) => Some(parse_range(&header::HeaderValue::from_str(value)?)?),
+                    None => None,
+                };
+
+                server.open_data(name, range)?.read_to_end(&mut buf)?;
+            }
+        }
+
+        Ok(buf)
+    }
+
+    fn build_sam_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        let reference_sequence = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 20)?);
+
+        Ok(sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(SORT_ORDER, COORDINATE)
+                    .build()?,
+            )
+            .set_reference_sequences(
+                [
+                    (BString::from("sq0"), reference_sequence.clone()),
+                    (BString::from("sq1"), reference_sequence),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build())
+    }
+
+    fn build_alignment_records() -> Result<Vec<RecordBuf>, Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+
+        for reference_sequence_id in 0..2 {
+            for i in 0..3000 {
+                let record = RecordBuf::builder()
+                    .set_name(format!("r{reference_sequence_id}_{i}"))
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(i * 100 + 1)?)
+                    .set_cigar([Op::new(OpKind::Match, 50)].into_iter().collect())
+                    .set_sequence(Sequence::from(vec![b'A'; 50]))
+                    .set_quality_scores(QualityScores::from(vec![30; 50]))
+                    .build();
+
+                records.push(record);
+            }
+        }
+
+        for i in 0..5 {
+            let record = RecordBuf::builder()
+                .set_name(format!("u{i}"))
+                .set_flags(Flags::UNMAPPED)
+                .build();
+
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+
+    fn read_bam_records(
+        src: &[u8],
+    ) -> Result<(sam::Header, Vec<bam::Record>), Box<dyn std::error::Error>> {
+        let mut reader = bam::io::Reader::new(src);
+        let header = reader.read_header()?;
+        let records = reader.records().collect::<io::Result<_>>()?;
+        Ok((header, records))
+    }
+
+    fn write_bam(root: &Path) -> Result<(sam::Header, Vec<RecordBuf>), Box<dyn std::error::Error>> {
+        let header = build_sam_header()?;
+        let records = build_alignment_records()?;
+
+        let src = root.join("sample.bam");
+
+        let mut writer = File::create(&src).map(bam::io::Writer::new)?;
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish()?;
+
+        let index = bam::fs::index(&src)?;
+        bam::bai::fs::write(root.join("sample.bam.bai"), &index)?;
+
+        Ok((header, records))
+    }
+
+    #[test]
+    fn test_ticket_with_bam() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("bam")?;
+        let (header, records) = write_bam(&dir.0)?;
+        let server = build_server(&dir.0)?;
+
+        let names = |records: &[bam::Record]| -> Vec<BString> {
+            records
+                .iter()
+                .map(|record| record.name().map(BString::from).unwrap_or_default())
+                .collect()
+        };
+
+        // all records
+        let request = Request::new(Kind::Reads, "sample");
+        let ticket = server.ticket(&request)?;
+        assert_eq!(ticket.format(), Format::Bam);
+        assert!(ticket.urls().iter().any(|url| !url.headers().is_empty()));
+
+        let data = read_ticket_data(&server, &ticket)?;
+        let (actual_header, actual_records) = read_bam_records(&data)?;
+        assert_eq!(actual_header, header);
+
+        let expected: Vec<_> = records
+            .iter()
+            .map(|record| record.name().map(BString::from).unwrap_or_default())
+            .collect();
+        assert_eq!(names(&actual_records), expected);
+
+        // header
+        let request = Request::new(Kind::Reads, "sample").set_class(Class::Header);
+        let ticket = server.ticket(&request)?;
+        let data = read_ticket_data(&server, &ticket)?;
+        let (actual_header, actual_records) = read_bam_records(&data)?;
+        assert_eq!(actual_header, header);
+        assert!(actual_records.is_empty());
+
+        // regions
+        let request = Request::from_query(
+            Kind::Reads,
+            "sample",
+            "referenceName=sq0&start=150000&end=160000",
+        )?
+        .add_region(Region::new("sq1", ..=Position::try_from(1000)?))
+        .add_region(Region::new("*", ..));
+
+        let ticket = server.ticket(&request)?;
+        let data = read_ticket_data(&server, &ticket)?;
+        let (_, actual_records) = read_bam_records(&data)?;
+
+        let mut actual = names(&actual_records);
+        let len = actual.len();
+        actual.dedup();
+        assert_eq!(actual.len(), len, "duplicate records");
+
+        let intersects = |name: &BString| {
+            records
+                .iter()
+                .find(|record| record.name().map(|n| n == name.as_slice()) == Some(true))
+                .map(|record| {
+                    let id = record.reference_sequence_id();
+                    let (start, end) = (record.alignment_start(), record.alignment_end());
+
+                    match (id, start, end) {
+                        (Some(0), Some(start), Some(end)) => {
+                            usize::from(end) > 150000 && usize::from(start) <= 160000
+                        }
+                        (Some(1), Some(start), _) => usize::from(start) <= 1000,
+                        (None, ..) => true,
+                        _ => false,
+                    }
+                })
+                .unwrap_or(false)
+        };
+
+        let filtered: Vec<_> = actual.iter().filter(|name| intersects(name)).collect();
+        assert_eq!(filtered.len(), 100 + 10 + 5);
+        assert!(actual.len() < records.len());
+
+        // errors
+        assert!(matches!(
+            server.ticket(&Request::new(Kind::Reads, "ndls")),
+            Err(crate::Error::Response(e)) if e.kind() == ErrorKind::NotFound
+        ));
+
+        assert!(matches!(
+            server.ticket(&Request::new(Kind::Reads, "../sample")),
+            Err(crate::Error::Response(e)) if e.kind() == ErrorKind::NotFound
+        ));
+
+        assert!(matches!(
+            server.ticket(&Request::new(Kind::Reads, "sample").add_region(Region::new("sq2", ..))),
+            Err(crate::Error::Response(e)) if e.kind() == ErrorKind::NotFound
+        ));
+
+        assert!(matches!(
+            server.ticket(&Request::new(Kind::Reads, "sample").set_format(Format::Cram)),
+            Err(crate::Error::Response(e)) if e.kind() == ErrorKind::NotFound
+        ));
+
+        Ok(())
+    }
+
+    const VCF_HEADER: &str = "##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+##contig=<ID=sq2>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+    fn build_variant_records() -> Result<Vec<vcf::variant::RecordBuf>, Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+
+        for name in ["sq0", "sq1"] {
+            for i in 0..5000 {
+                let record = vcf::variant::RecordBuf::builder()
+                    .set_reference_sequence_name(name)
+                    .set_variant_start(Position::try_from(i * 10 + 1)?)
+                    .set_reference_bases("A")
+                    .build();
+
+                records.push(record);
+            }
+        }
+
+        Ok(records)
+    }
+
+    fn variant_positions(data: &[u8]) -> Result<Vec<(String, usize)>, Box<dyn std::error::Error>> {
+        let mut reader = vcf::io::Reader::new(noodles_bgzf::io::Reader::new(data));
+        let header = reader.read_header()?;
+
+        let mut positions = Vec::new();
+
+        for result in reader.record_bufs(&header) {
+            let record = result?;
+
+            positions.push((
+                record.reference_sequence_name().to_string(),
+                record.variant_start().map(usize::from).unwrap_or_default(),
+            ));
+        }
+
+        Ok(positions)
+    }
+
+    #[test]
+    fn test_ticket_with_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("vcf")?;
+
+        let header: vcf::Header = VCF_HEADER.parse()?;
+        let records = build_variant_records()?;
+
+        let src = dir.0.join("sample.vcf.gz");
+
+        let mut writer = File::create(&src)
+            .map(noodles_bgzf::io::Writer::new)
+            .map(vcf::io::Writer::new)?;
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_variant_record(&header, record)?;
+        }
+
+        writer.get_mut().try_finish()?;
+
+        let index = vcf::fs::index(&src)?;
+        noodles_tabix::fs::write(dir.0.join("sample.vcf.gz.tbi"), &index)?;
+
+        let server = build_server(&dir.0)?;
+
+        let ticket = server.ticket(&Request::new(Kind::Variants, "sample"))?;
+        let data = read_ticket_data(&server, &ticket)?;
+        assert_eq!(variant_positions(&data)?.len(), records.len());
+
+        let request = Request::from_query(
+            Kind::Variants,
+            "sample",
+            "referenceName=sq1&start=20000&end=20100",
+        )?
+        .add_region(Region::new("sq2", ..));
+
+        let ticket = server.ticket(&request)?;
+        let data = read_ticket_data(&server, &ticket)?;
+
+        let positions = variant_positions(&data)?;
+        let matches = positions
+            .iter()
+            .filter(|(name, position)| name == "sq1" && (20001..=20100).contains(position))
+            .count();
+
+        assert_eq!(matches, 10);
+        assert!(positions.len() < records.len());
+
+        assert!(matches!(
+            server.ticket(&Request::new(Kind::Variants, "sample").add_region(Region::new("sq3", ..))),
+            Err(crate::Error::Response(e)) if e.kind() == ErrorKind::NotFound
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ticket_with_bcf() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bcf as bcf;
+
+        let dir = TempDir::new("bcf")?;
+
+        let header: vcf::Header = VCF_HEADER.parse()?;
+        let records = build_variant_records()?;
+
+        let src = dir.0.join("sample.bcf");
+
+        let mut writer = File::create(&src).map(bcf::io::Writer::new)?;
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_variant_record(&header, record)?;
+        }
+
+        writer.try_finish()?;
+
+        let index = bcf::fs::index(&src)?;
+        noodles_csi::fs::write(dir.0.join("sample.bcf.csi"), &index)?;
+
+        let server = build_server(&dir.0)?;
+
+        let request = Request::new(Kind::Variants, "sample")
+            .set_format(Format::Bcf)
+            .add_region(Region::new(
+                "sq0",
+                Position::try_from(101)?..=Position::try_from(200)?,
+            ));
+
+        let ticket = server.ticket(&request)?;
+        let data = read_ticket_data(&server, &ticket)?;
+
+        let mut reader = bcf::io::Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        let mut positions = Vec::new();
+
+        for result in reader.records() {
+            let record = result?;
+            let name = record.reference_sequence_name(header.string_maps())?;
+            let position = record.variant_start().transpose()?.map(usize::from);
+            positions.push((name.to_string(), position));
+        }
+
+        let matches = positions
+            .iter()
+            .filter(|(name, position)| {
+                name == "sq0" && position.is_some_and(|n| (101..=200).contains(&n))
+            })
+            .count();
+
+        assert_eq!(matches, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ticket_with_cram() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_cram as cram;
+        use noodles_fasta as fasta;
+
+        let dir = TempDir::new("cram")?;
+
+        let reference_sequence_repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                fasta::record::Definition::new("sq0", None),
+                fasta::record::Sequence::from(vec![b'A'; 1 << 20]),
+            ),
+            fasta::Record::new(
+                fasta::record::Definition::new("sq1", None),
+                fasta::record::Sequence::from(vec![b'A'; 1 << 20]),
+            ),
+        ]);
+
+        let header = build_sam_header()?;
+        let records: Vec<_> = build_alignment_records()?
+            .into_iter()
+            .filter(|record| record.reference_sequence_id() == Some(0))
+            .take(100)
+            .collect();
+
+        let src = dir.0.join("sample.cram");
+
+        let mut writer = cram::io::indexed_writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository.clone())
+            .build_from_path(&src)?;
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.finish(&header)?;
+
+        let server = build_server(&dir.0)?;
+
+        let read_records = |data: &[u8]| -> io::Result<Vec<RecordBuf>> {
+            let mut reader = cram::io::reader::Builder::default()
+                .set_reference_sequence_repository(reference_sequence_repository.clone())
+                .build_from_reader(data);
+
+            let header = reader.read_header()?;
+            reader.records(&header).collect()
+        };
+
+        let request = Request::new(Kind::Reads, "sample")
+            .set_format(Format::Cram)
+            .add_region(Region::new("sq0", ..=Position::try_from(1000)?));
+
+        let ticket = server.ticket(&request)?;
+        assert!(ticket.urls().iter().all(|url| !url.headers().is_empty()));
+
+        let data = read_ticket_data(&server, &ticket)?;
+        assert_eq!(read_records(&data)?.len(), records.len());
+
+        let request = Request::new(Kind::Reads, "sample")
+            .set_format(Format::Cram)
+            .add_region(Region::new("sq1", ..));
+
+        let ticket = server.ticket(&request)?;
+        let data = read_ticket_data(&server, &ticket)?;
+        assert!(read_records(&data)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("handle")?;
+        write_bam(&dir.0)?;
+        let server = build_server(&dir.0)?;
+
+        let request = http::Request::get("/htsget/reads/sample?referenceName=sq0").body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE),
+            Some(&header::HeaderValue::from_static(CONTENT_TYPE))
+        );
+        assert!(read_body(response)?.starts_with(br#"{"htsget":{"format":"BAM","urls":["#));
+
+        let request = http::Request::post("/htsget/reads/sample")
+            .body(br#"{"format":"BAM","class":"header"}"#)?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = http::Request::get("/htsget/reads/sample?format=VCF").body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(read_body(response)?.starts_with(br#"{"htsget":{"error":"UnsupportedFormat","#));
+
+        let request = http::Request::get("/htsget/reads/ndls").body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let request = http::Request::get("/htsget/data/sample.bam")
+            .header(header::RANGE, "bytes=0-3")
+            .body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(read_body(response)?, [0x1f, 0x8b, 0x08, 0x04]);
+
+        let request = http::Request::get("/htsget/data/sample.bam.bai").body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let request = http::Request::get("/reads/sample").body(b"")?;
+        let response = server.handle(&request);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_serve_with_htsget_client() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::TryStreamExt;
+        use noodles_htsget as htsget;
+
+        let dir = TempDir::new("serve")?;
+        write_bam(&dir.0)?;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url: url::Url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        let server = Server::new(&dir.0, base_url.clone());
+
+        {
+            let server = server.clone();
+            std::thread::spawn(move || server.serve(&listener));
+        }
+
+        let client = htsget::Client::new(base_url);
+
+        let region = Region::new(
+            "sq0",
+            Position::try_from(150001)?..=Position::try_from(160000)?,
+        );
+
+        let response = client
+            .reads("sample")
+            .add_region(region.clone())
+            .send()
+            .await?;
+
+        let chunks: Vec<_> = response.chunks().try_collect().await?;
+        let actual: Vec<u8> = chunks.concat();
+
+        let request = Request::new(Kind::Reads, "sample").add_region(region);
+        let ticket = server.ticket(&request)?;
+        let expected = read_ticket_data(&server, &ticket)?;
+
+        assert_eq!(actual, expected);
+
+        let result = client.reads("ndls").send().await;
+        assert!(matches!(result, Err(htsget::Error::Response(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::{fs::File, io, path::Path};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_csi::{BinningIndex, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::{
+    bgzf::{Layout, push_urls, query, read_index},
+    reference_sequence_not_found,
+    urls::Urls,
+};
+use crate::{Request, request::UNMAPPED_REFERENCE_NAME};
+
+pub(super) fn build(
+    src: &Path,
+    index_src: &Path,
+    request: &Request,
+    urls: &mut Urls,
+) -> crate::Result<()> {
+    let mut reader = File::open(src).map(bam::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    let layout = Layout::new(src, reader.get_ref().virtual_position())?;
+
+    let chunks = match request.regions() {
+        Some(regions) => {
+            let index = read_index(index_src)?;
+
+            let mut intervals = Vec::new();
+            let mut query_unmapped = false;
+
+            for region in regions {
+                if region.name() == UNMAPPED_REFERENCE_NAME {
+                    query_unmapped = true;
+                    continue;
+                }
+
+                let reference_sequence_id = header
+                    .reference_sequences()
+                    .get_index_of(region.name())
+                    .ok_or_else(|| reference_sequence_not_found(region.name()))?;
+
+                intervals.push((reference_sequence_id, region.interval()));
+            }
+
+            let mut chunks = query(&index, intervals)?;
+
+            if query_unmapped {
+                if let Some(start) = find_unmapped_start(&mut reader, &index, &layout)? {
+                    chunks.push(Chunk::new(start, layout.data_end));
+                }
+            }
+
+            Some(chunks)
+        }
+        None => None,
+    };
+
+    push_urls(src, urls, request.class(), &layout, chunks)?;
+
+    Ok(())
+}
+
+// Finds the start position of the first unplaced, unmapped record.
+//
+// Unplaced, unmapped records are at the end of a coordinate-sorted file, so the search starts
+// from the last position known to the index.
+fn find_unmapped_start(
+    reader: &mut bam::io::Reader<bgzf::io::Reader<File>>,
+    index: &dyn BinningIndex,
+    layout: &Layout,
+) -> io::Result<Option<bgzf::VirtualPosition>> {
+    let pos = index
+        .last_first_record_start_position()
+        .unwrap_or(layout.header_end);
+
+    reader.get_mut().seek(pos)?;
+
+    let mut record = bam::Record::default();
+
+    loop {
+        let pos = reader.get_ref().virtual_position();
+
+        if reader.read_record(&mut record)? == 0 {
+            return Ok(None);
+        }
+
+        if record.reference_sequence_id().is_none() {
+            return Ok(Some(pos));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{fs::File, path::Path};
+
+use noodles_bcf as bcf;
+
+use super::{
+    bgzf::{Layout, push_urls, query, read_index},
+    reference_sequence_not_found,
+    urls::Urls,
+};
+use crate::Request;
+
+pub(super) fn build(
+    src: &Path,
+    index_src: &Path,
+    request: &Request,
+    urls: &mut Urls,
+) -> crate::Result<()> {
+    let mut reader = File::open(src).map(bcf::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    let layout = Layout::new(src, reader.get_ref().virtual_position())?;
+
+    let chunks = match request.regions() {
+        Some(regions) => {
+            let index = read_index(index_src)?;
+
+            let mut intervals = Vec::new();
+
+            for region in regions {
+                let name = region.name();
+
+                let reference_sequence_id = std::str::from_utf8(name)
+                    .ok()
+                    .and_then(|s| header.string_maps().contigs().get_index_of(s))
+                    .ok_or_else(|| reference_sequence_not_found(name))?;
+
+                intervals.push((reference_sequence_id, region.interval()));
+            }
+
+            Some(query(&index, intervals)?)
+        }
+        None => None,
+    };
+
+    push_urls(src, urls, request.class(), &layout, chunks)?;
+
+    Ok(())
+}
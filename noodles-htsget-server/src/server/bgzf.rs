@@ -0,0 +1,204 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::{Interval, RegionSet};
+use noodles_csi::{
+    self as csi, BinningIndex, binning_index::index::reference_sequence::bin::Chunk,
+};
+
+use super::urls::Urls;
+use crate::{
+    request,
+    response::{self, error::Kind, ticket::Class},
+};
+
+// The positions that delimit the sections of a BGZF-compressed file.
+pub(super) struct Layout {
+    // The end of the header, i.e., the start of the first record.
+    pub(super) header_end: bgzf::VirtualPosition,
+    // The end of the records, i.e., the start of the EOF marker, if any.
+    pub(super) data_end: bgzf::VirtualPosition,
+}
+
+impl Layout {
+    pub(super) fn new(src: &Path, header_end: bgzf::VirtualPosition) -> io::Result<Self> {
+        let eof = eof_block()?;
+
+        let mut file = File::open(src)?;
+        let len = file.seek(SeekFrom::End(0))?;
+
+        let mut data_end = len;
+
+        if let Some(pos) = len.checked_sub(eof.len() as u64) {
+            let mut buf = vec![0; eof.len()];
+            file.seek(SeekFrom::Start(pos))?;
+            file.read_exact(&mut buf)?;
+
+            if buf == eof {
+                data_end = pos;
+            }
+        }
+
+        let data_end = bgzf::VirtualPosition::new(data_end, 0).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid virtual position")
+        })?;
+
+        Ok(Self {
+            header_end,
+            data_end,
+        })
+    }
+}
+
+pub(super) fn read_index(src: &Path) -> io::Result<Box<dyn BinningIndex>> {
+    use noodles_bam::bai;
+    use noodles_tabix as tabix;
+
+    match src.extension().and_then(|ext| ext.to_str()) {
+        Some("bai") => bai::fs::read(src).map(|index| Box::new(index) as Box<dyn BinningIndex>),
+        Some("tbi") => tabix::fs::read(src).map(|index| Box::new(index) as Box<dyn BinningIndex>),
+        Some("csi") => csi::fs::read(src).map(|index| Box::new(index) as Box<dyn BinningIndex>),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "unsupported index format",
+        )),
+    }
+}
+
+// Queries the index for the chunks that intersect the given regions.
+pub(super) fn query(
+    index: &dyn BinningIndex,
+    regions: Vec<(usize, Interval)>,
+) -> crate::Result<Vec<Chunk>> {
+    let regions = RegionSet::new(regions);
+
+    csi::binning_index::query_regions(index, &regions).map_err(|e| {
+        if e.kind() == io::ErrorKind::InvalidInput {
+            response::Error::new(Kind::InvalidRange, e.to_string()).into()
+        } else {
+            e.into()
+        }
+    })
+}
+
+// Adds the URLs of a BGZF-compressed file.
+//
+// When `chunks` is `None`, all records are included.
+pub(super) fn push_urls(
+    src: &Path,
+    urls: &mut Urls,
+    class: Option<request::Class>,
+    layout: &Layout,
+    chunks: Option<Vec<Chunk>>,
+) -> io::Result<()> {
+    let mut reader = File::open(src).map(bgzf::io::Reader::new)?;
+
+    let header_chunk = Chunk::new(bgzf::VirtualPosition::MIN, layout.header_end);
+    push_chunk(&mut reader, urls, Class::Header, header_chunk)?;
+
+    let eof = eof_block()?;
+
+    if class == Some(request::Class::Header) {
+        urls.push_data(Class::Header, &eof);
+        return Ok(());
+    }
+
+    let chunks = chunks.unwrap_or_else(|| vec![Chunk::new(layout.header_end, layout.data_end)]);
+
+    for chunk in csi::binning_index::merge_chunks(&chunks) {
+        push_chunk(&mut reader, urls, Class::Body, chunk)?;
+    }
+
+    urls.push_data(Class::Body, &eof);
+
+    Ok(())
+}
+
+// Adds the URLs of the uncompressed data in the given chunk.
+//
+// Whole blocks are referenced by byte ranges. A partial block at the start or end of the chunk is
+// read, recompressed, and inlined, so the concatenated data contains exactly the chunk.
+fn push_chunk<R>(
+    reader: &mut bgzf::io::Reader<R>,
+    urls: &mut Urls,
+    class: Class,
+    chunk: Chunk,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    let (start, end) = (chunk.start(), chunk.end());
+
+    if start >= end {
+        return Ok(());
+    }
+
+    if start.compressed() == end.compressed() {
+        let len = usize::from(end.uncompressed() - start.uncompressed());
+        let buf = read_exact_at(reader, start, len)?;
+        urls.push_data(class, &compress(&buf)?);
+        return Ok(());
+    }
+
+    let mut range_start = start.compressed();
+
+    if start.uncompressed() > 0 {
+        reader.seek(start)?;
+
+        let buf = reader.fill_buf()?.to_vec();
+        reader.consume(buf.len());
+        urls.push_data(class, &compress(&buf)?);
+
+        range_start = reader.virtual_position().compressed();
+    }
+
+    urls.push_range(class, range_start..end.compressed());
+
+    if end.uncompressed() > 0 {
+        let block_start = bgzf::VirtualPosition::new(end.compressed(), 0).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid virtual position")
+        })?;
+
+        let buf = read_exact_at(reader, block_start, usize::from(end.uncompressed()))?;
+        urls.push_data(class, &compress(&buf)?);
+    }
+
+    Ok(())
+}
+
+fn read_exact_at<R>(
+    reader: &mut bgzf::io::Reader<R>,
+    pos: bgzf::VirtualPosition,
+    len: usize,
+) -> io::Result<Vec<u8>>
+where
+    R: Read + Seek,
+{
+    reader.seek(pos)?;
+    let mut buf = vec![0; len];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+// Compresses data as BGZF blocks without an EOF marker.
+fn compress(buf: &[u8]) -> io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let eof_len = eof_block()?.len();
+
+    let mut writer = bgzf::io::Writer::new(Vec::new());
+    writer.write_all(buf)?;
+    let mut dst = writer.finish()?;
+
+    dst.truncate(dst.len() - eof_len);
+
+    Ok(dst)
+}
+
+fn eof_block() -> io::Result<Vec<u8>> {
+    bgzf::io::Writer::new(Vec::new()).finish()
+}
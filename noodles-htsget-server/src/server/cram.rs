@@ -0,0 +1,127 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use noodles_core::region::{Interval, RegionSet};
+use noodles_cram::{self as cram, crai};
+
+use super::{reference_sequence_not_found, urls::Urls};
+use crate::{
+    Request,
+    request::{self, UNMAPPED_REFERENCE_NAME},
+    response::ticket::Class,
+};
+
+pub(super) fn build(
+    src: &Path,
+    index_src: &Path,
+    request: &Request,
+    urls: &mut Urls,
+) -> crate::Result<()> {
+    let mut reader = File::open(src).map(cram::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    let header_end = reader.position()?;
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    let index = crai::fs::read(index_src)?;
+
+    let mut offsets: Vec<_> = index.iter().map(|record| record.offset()).collect();
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    // The EOF container follows the last data container.
+    let eof_start = match offsets.last() {
+        Some(&offset) => container_end(&mut reader, offset)?,
+        None => header_end,
+    };
+
+    urls.push_range(Class::Header, 0..header_end);
+
+    if request.class() == Some(request::Class::Header) {
+        urls.push_range(Class::Header, eof_start..len);
+        return Ok(());
+    }
+
+    match request.regions() {
+        Some(regions) => {
+            let mut intervals = Vec::new();
+            let mut query_unmapped = false;
+
+            for region in regions {
+                if region.name() == UNMAPPED_REFERENCE_NAME {
+                    query_unmapped = true;
+                    continue;
+                }
+
+                let reference_sequence_id = header
+                    .reference_sequences()
+                    .get_index_of(region.name())
+                    .ok_or_else(|| reference_sequence_not_found(region.name()))?;
+
+                intervals.push((reference_sequence_id, region.interval()));
+            }
+
+            let regions = RegionSet::new(intervals).merged_intervals();
+
+            let mut offsets: Vec<_> = index
+                .iter()
+                .filter(|record| {
+                    if query_unmapped && record.reference_sequence_id().is_none() {
+                        true
+                    } else {
+                        intersects(record, &regions)
+                    }
+                })
+                .map(|record| record.offset())
+                .collect();
+
+            offsets.sort_unstable();
+            offsets.dedup();
+
+            for offset in offsets {
+                let end = container_end(&mut reader, offset)?;
+                urls.push_range(Class::Body, offset..end);
+            }
+        }
+        None => urls.push_range(Class::Body, header_end..eof_start),
+    }
+
+    urls.push_range(Class::Body, eof_start..len);
+
+    Ok(())
+}
+
+fn intersects(record: &crai::Record, regions: &[(usize, Interval)]) -> bool {
+    let (Some(reference_sequence_id), Some(start)) =
+        (record.reference_sequence_id(), record.alignment_start())
+    else {
+        return false;
+    };
+
+    let end = record
+        .alignment_span()
+        .checked_sub(1)
+        .and_then(|n| start.checked_add(n))
+        .unwrap_or(start);
+
+    let record_interval = (start..=end).into();
+
+    regions
+        .iter()
+        .any(|(id, interval)| *id == reference_sequence_id && interval.intersects(record_interval))
+}
+
+fn container_end<R>(reader: &mut cram::io::Reader<R>, offset: u64) -> io::Result<u64>
+where
+    R: Read + Seek,
+{
+    let mut container = cram::io::reader::Container::default();
+
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_container(&mut container)?;
+
+    reader.position()
+}
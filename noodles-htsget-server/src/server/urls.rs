@@ -0,0 +1,146 @@
+use std::{collections::BTreeMap, io, mem, ops::Range};
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+
+use crate::{
+    Format,
+    response::ticket::{BlockUrl, Class},
+};
+
+enum Part {
+    Data(Vec<u8>),
+    Range(Range<u64>),
+}
+
+// A list of ticket URLs under construction.
+//
+// Adjacent parts of the same class are coalesced, i.e., contiguous byte ranges are merged into a
+// single range, and consecutive inline data is concatenated.
+pub(super) struct Urls {
+    format: Format,
+    data_url: url::Url,
+    parts: Vec<(Class, Part)>,
+}
+
+impl Urls {
+    pub(super) fn new(format: Format, data_url: url::Url) -> Self {
+        Self {
+            format,
+            data_url,
+            parts: Vec::new(),
+        }
+    }
+
+    pub(super) fn push_data(&mut self, class: Class, buf: &[u8]) {
+        if buf.is_empty() {
+            return;
+        }
+
+        if let Some((last_class, Part::Data(data))) = self.parts.last_mut() {
+            if *last_class == class {
+                data.extend_from_slice(buf);
+                return;
+            }
+        }
+
+        self.parts.push((class, Part::Data(buf.to_vec())));
+    }
+
+    pub(super) fn push_range(&mut self, class: Class, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        if let Some((last_class, Part::Range(last_range))) = self.parts.last_mut() {
+            if *last_class == class && last_range.end == range.start {
+                last_range.end = range.end;
+                return;
+            }
+        }
+
+        self.parts.push((class, Part::Range(range)));
+    }
+
+    pub(super) fn finish(mut self) -> io::Result<Vec<BlockUrl>> {
+        let parts = mem::take(&mut self.parts);
+
+        parts
+            .into_iter()
+            .map(|(class, part)| match part {
+                Part::Data(buf) => self.build_data_url(class, &buf),
+                Part::Range(range) => Ok(self.build_range_url(class, range)),
+            })
+            .collect()
+    }
+
+    fn build_data_url(&self, class: Class, buf: &[u8]) -> io::Result<BlockUrl> {
+        // _Htsget retrieval API spec v1.3.0_ § "Inline data block URIs"
+        let s = format!(
+            "data:{};base64,{}",
+            self.format.media_type(),
+            BASE64_STANDARD.encode(buf)
+        );
+
+        let url = s
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(BlockUrl::new(url, BTreeMap::new(), class))
+    }
+
+    fn build_range_url(&self, class: Class, range: Range<u64>) -> BlockUrl {
+        let headers = [(
+            String::from("Range"),
+            format!("bytes={}-{}", range.start, range.end - 1),
+        )]
+        .into_iter()
+        .collect();
+
+        BlockUrl::new(self.data_url.clone(), headers, class)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let data_url: url::Url = "http://localhost/data/sample.bam".parse()?;
+        let mut urls = Urls::new(Format::Bam, data_url.clone());
+
+        urls.push_data(Class::Header, b"nd");
+        urls.push_data(Class::Header, b"ls");
+        urls.push_range(Class::Body, 8..13);
+        urls.push_range(Class::Body, 13..21);
+        urls.push_range(Class::Body, 34..34);
+        urls.push_range(Class::Body, 55..89);
+        urls.push_data(Class::Body, b"");
+
+        let actual = urls.finish()?;
+
+        let range_url = |range: &str| {
+            BlockUrl::new(
+                data_url.clone(),
+                [(String::from("Range"), String::from(range))]
+                    .into_iter()
+                    .collect(),
+                Class::Body,
+            )
+        };
+
+        let expected = [
+            BlockUrl::new(
+                "data:application/vnd.ga4gh.bam;base64,bmRscw==".parse()?,
+                BTreeMap::new(),
+                Class::Header,
+            ),
+            range_url("bytes=8-20"),
+            range_url("bytes=55-88"),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::{fs::File, path::Path};
+
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+
+use super::{
+    bgzf::{Layout, push_urls, query, read_index},
+    reference_sequence_not_found,
+    urls::Urls,
+};
+use crate::Request;
+
+pub(super) fn build(
+    src: &Path,
+    index_src: &Path,
+    request: &Request,
+    urls: &mut Urls,
+) -> crate::Result<()> {
+    let mut reader = File::open(src)
+        .map(bgzf::io::Reader::new)
+        .map(vcf::io::Reader::new)?;
+
+    let header = reader.read_header()?;
+
+    let layout = Layout::new(src, reader.get_ref().virtual_position())?;
+
+    let chunks = match request.regions() {
+        Some(regions) => {
+            let index = read_index(index_src)?;
+
+            let reference_sequence_names = index
+                .header()
+                .map(|index_header| index_header.reference_sequence_names());
+
+            let mut intervals = Vec::new();
+
+            for region in regions {
+                let name = region.name();
+
+                match reference_sequence_names.and_then(|names| names.get_index_of(name)) {
+                    Some(reference_sequence_id) => {
+                        intervals.push((reference_sequence_id, region.interval()));
+                    }
+                    // A contig without records is not listed in the index.
+                    None if header.contigs().keys().any(|key| key.as_bytes() == name) => {}
+                    None => return Err(reference_sequence_not_found(name).into()),
+                }
+            }
+
+            Some(query(&index, intervals)?)
+        }
+        None => None,
+    };
+
+    push_urls(src, urls, request.class(), &layout, chunks)?;
+
+    Ok(())
+}
@@ -1,19 +1,24 @@
+mod data_url;
+
 use std::pin::Pin;
 
-use bytes::Bytes;
-use futures::{Stream, TryStreamExt, stream};
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use reqwest::StatusCode;
 
 use super::{Client, Error, response::ticket::BlockUrl};
 
-pub(crate) fn chunks<'a>(
-    client: &'a Client,
-    urls: &'a [BlockUrl],
-) -> impl Stream<Item = crate::Result<Bytes>> + 'a {
+type DataStream = Pin<Box<dyn Stream<Item = crate::Result<Bytes>>>>;
+
+pub(crate) fn chunks(
+    client: Client,
+    urls: Vec<BlockUrl>,
+) -> impl Stream<Item = crate::Result<Bytes>> + 'static {
     Box::pin(
         stream::try_unfold((client, urls, 0), |(client, urls, i)| async move {
             match urls.get(i) {
                 Some(url) => {
-                    let st = resolve_data(client, url).await;
+                    let st = resolve_data(&client, url).await;
                     Ok(Some((st, (client, urls, i + 1))))
                 }
                 None => Ok(None),
@@ -23,37 +28,158 @@ pub(crate) fn chunks<'a>(
     )
 }
 
-async fn resolve_data(
-    client: &Client,
-    block_url: &BlockUrl,
-) -> Pin<Box<dyn Stream<Item = crate::Result<Bytes>>>> {
-    use base64::prelude::{BASE64_STANDARD, Engine as _};
+// Fetches up to `n` URLs concurrently. The data of each URL is buffered and yielded in ticket
+// order.
+pub(crate) fn buffered_chunks<'a>(
+    client: &'a Client,
+    urls: &'a [BlockUrl],
+    n: usize,
+) -> impl Stream<Item = crate::Result<Bytes>> + 'a {
+    stream::iter(urls)
+        .map(move |url| async move {
+            let buf = resolve_data(client, url)
+                .await
+                .try_fold(BytesMut::new(), |mut buf, chunk| async move {
+                    buf.extend_from_slice(&chunk);
+                    Ok(buf)
+                })
+                .await?;
 
-    const DELIMITER: &str = ";base64,";
+            Ok(buf.freeze())
+        })
+        .buffered(n)
+}
 
+async fn resolve_data(client: &Client, block_url: &BlockUrl) -> DataStream {
     let url = block_url.url();
 
     if url.scheme() == "data" {
-        // _Htsget retrieval API spec v1.3.0_ § "Inline data block URIs": "client should ignore the
-        // media type (if any), treating the payload as a partial blob."
-        if let Some((_, encoded_data)) = url.as_str().split_once(DELIMITER) {
-            match BASE64_STANDARD.decode(encoded_data) {
-                Ok(data) => Box::pin(stream::once(async { Ok(Bytes::from(data)) })),
-                Err(e) => Box::pin(stream::once(async { Err(Error::Decode(e)) })),
-            }
-        } else {
-            Box::pin(stream::once(async { Err(Error::InvalidDataUrl) }))
-        }
+        let result = data_url::decode(url).map(Bytes::from);
+        Box::pin(stream::once(async { result }))
     } else {
-        let mut request = client.http_client().get(url.clone());
-
-        for (key, value) in block_url.headers() {
-            request = request.header(key, value);
+        match fetch(client, block_url).await {
+            Ok(st) => st,
+            Err(e) => Box::pin(stream::once(async { Err(e) })),
         }
+    }
+}
 
-        match request.send().await.map_err(Error::Request) {
-            Ok(response) => Box::pin(response.bytes_stream().map_err(Error::Request)),
-            Err(e) => Box::pin(stream::once(async { Err(e) })),
+async fn fetch(client: &Client, block_url: &BlockUrl) -> crate::Result<DataStream> {
+    let mut request = client.http_client().get(block_url.url().clone());
+
+    for (key, value) in block_url.headers() {
+        request = request.header(key, value);
+    }
+
+    let response = request
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(Error::Request)?;
+
+    // A server that does not support range requests responds with the entire resource.
+    if response.status() == StatusCode::OK {
+        if let Some((start, end)) = requested_range(block_url) {
+            let data = response.bytes().await.map_err(Error::Request)?;
+            let data = slice_range(data, start, end);
+            return Ok(Box::pin(stream::once(async { Ok(data) })));
         }
     }
+
+    Ok(Box::pin(response.bytes_stream().map_err(Error::Request)))
+}
+
+// Parses the `Range` header of a URL, if any, as a single byte range.
+fn requested_range(block_url: &BlockUrl) -> Option<(u64, Option<u64>)> {
+    let value = block_url
+        .headers()
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("range"))
+        .map(|(_, value)| value)?;
+
+    let (start, end) = value.trim().strip_prefix("bytes=")?.split_once('-')?;
+
+    let start = start.trim().parse().ok()?;
+
+    let end = match end.trim() {
+        "" => None,
+        s => Some(s.parse().ok()?),
+    };
+
+    Some((start, end))
+}
+
+fn slice_range(data: Bytes, start: u64, end: Option<u64>) -> Bytes {
+    let len = data.len() as u64;
+
+    let start = start.min(len);
+    let end = end.map(|n| n.saturating_add(1).min(len)).unwrap_or(len);
+
+    if start >= end {
+        Bytes::new()
+    } else {
+        data.slice(start as usize..end as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_block_url(headers: &str) -> BlockUrl {
+        let json = format!(r#"{{"url":"https://localhost/data","headers":{headers}}}"#);
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_requested_range() {
+        assert_eq!(requested_range(&build_block_url("{}")), None);
+
+        assert_eq!(
+            requested_range(&build_block_url(r#"{"Range":"bytes=8-13"}"#)),
+            Some((8, Some(13)))
+        );
+
+        assert_eq!(
+            requested_range(&build_block_url(r#"{"range":"bytes=8-"}"#)),
+            Some((8, None))
+        );
+
+        assert_eq!(
+            requested_range(&build_block_url(r#"{"Range":"bytes=8-13,21-34"}"#)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_slice_range() {
+        let data = Bytes::from_static(b"noodles");
+
+        assert_eq!(slice_range(data.clone(), 2, Some(4)), &b"odl"[..]);
+        assert_eq!(slice_range(data.clone(), 2, None), &b"odles"[..]);
+        assert_eq!(slice_range(data.clone(), 5, Some(13)), &b"es"[..]);
+        assert_eq!(slice_range(data, 8, Some(13)), &b""[..]);
+    }
+
+    #[tokio::test]
+    async fn test_buffered_chunks() -> crate::Result<()> {
+        let client = Client::new("https://localhost/".parse().map_err(Error::Url)?);
+
+        let urls: Vec<BlockUrl> = serde_json::from_str(
+            r#"[
+                {"url":"data:;base64,bm9v"},
+                {"url":"data:,dl"},
+                {"url":"data:application/octet-stream;base64,ZXM="}
+            ]"#,
+        )
+        .unwrap();
+
+        let actual: Vec<_> = buffered_chunks(&client, &urls, 2).try_collect().await?;
+        assert_eq!(actual, [&b"noo"[..], &b"dl"[..], &b"es"[..]]);
+
+        let actual: Vec<_> = chunks(client, urls).try_collect().await?;
+        assert_eq!(actual.concat(), b"noodles");
+
+        Ok(())
+    }
 }
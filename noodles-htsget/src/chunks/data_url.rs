@@ -0,0 +1,71 @@
+use base64::{
+    Engine as _, alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::Error;
+
+// A base64 engine that does not require padding.
+const ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+// Decodes the data of a data URL (RFC 2397).
+//
+// _Htsget retrieval API spec v1.3.0_ § "Inline data block URIs": "client should ignore the media
+// type (if any), treating the payload as a partial blob." The payload is percent-decoded and, if
+// the URL has a `base64` parameter, base64-decoded, ignoring ASCII whitespace.
+pub(super) fn decode(url: &Url) -> crate::Result<Vec<u8>> {
+    let s = &url[url::Position::BeforePath..url::Position::AfterQuery];
+
+    let (metadata, payload) = s.split_once(',').ok_or(Error::InvalidDataUrl)?;
+
+    let data: Vec<u8> = percent_decode_str(payload).collect();
+
+    let is_base64 = metadata
+        .rsplit(';')
+        .next()
+        .is_some_and(|parameter| parameter.trim().eq_ignore_ascii_case("base64"));
+
+    if is_base64 {
+        let data: Vec<u8> = data
+            .into_iter()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+
+        ENGINE.decode(data).map_err(Error::Decode)
+    } else {
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(s: &str) -> crate::Result<Vec<u8>> {
+            let url: Url = s.parse().map_err(Error::Url)?;
+            decode(&url)
+        }
+
+        assert_eq!(
+            t("data:application/vnd.ga4gh.bam;base64,bmRscw==")?,
+            b"ndls"
+        );
+        assert_eq!(t("data:;base64,bmRscw")?, b"ndls");
+        assert_eq!(t("data:;BASE64,bmRs%0Acw%3D%3D")?, b"ndls");
+        assert_eq!(t("data:text/plain;charset=US-ASCII,nd%20ls")?, b"nd ls");
+        assert_eq!(t("data:,ndls#fragment")?, b"ndls");
+        assert_eq!(t("data:,")?, b"");
+
+        assert!(matches!(t("data:ndls"), Err(Error::InvalidDataUrl)));
+        assert!(matches!(t("data:;base64,!"), Err(Error::Decode(_))));
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use noodles_core::Region;
 
-use super::Format;
+use super::{Field, Format};
 use crate::{
     Response,
     request::{self, Class},
@@ -36,6 +36,74 @@ impl Builder {
         self
     }
 
+    /// Sets the fields to include.
+    ///
+    /// By default, all fields are included. Excluded fields may be returned as missing values
+    /// (e.g., `*` for a sequence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::{self as htsget, reads::Field};
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let reads = client.reads("NDLS0001").set_fields([Field::Qname, Field::Flag]);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = Field>,
+    {
+        self.inner = self.inner.set_fields(fields.into_iter().collect());
+        self
+    }
+
+    /// Sets the data field tags to include.
+    ///
+    /// By default, all tags are included. An empty list excludes all tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let reads = client.reads("NDLS0001").set_tags(["NM", "MD"]);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_tags<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.inner = self
+            .inner
+            .set_tags(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the data field tags to exclude.
+    ///
+    /// A tag cannot be both included and excluded. Sending a request with a tag in both lists
+    /// fails with [`crate::Error::Input`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let reads = client.reads("NDLS0001").set_notags(["OQ"]);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_notags<I, T>(mut self, notags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.inner = self
+            .inner
+            .set_notags(notags.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Sends the request.
     pub async fn send(self) -> crate::Result<Response> {
         self.inner.send().await
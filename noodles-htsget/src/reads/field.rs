@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+/// A reads field.
+///
+/// These are the SAM fields that can be requested using the `fields` parameter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Field {
+    /// Read name (`QNAME`).
+    Qname,
+    /// Flags (`FLAG`).
+    Flag,
+    /// Reference sequence name (`RNAME`).
+    Rname,
+    /// Alignment start (`POS`).
+    Pos,
+    /// Mapping quality (`MAPQ`).
+    Mapq,
+    /// CIGAR operations (`CIGAR`).
+    Cigar,
+    /// Mate reference sequence name (`RNEXT`).
+    Rnext,
+    /// Mate alignment start (`PNEXT`).
+    Pnext,
+    /// Template length (`TLEN`).
+    Tlen,
+    /// Sequence (`SEQ`).
+    Seq,
+    /// Quality scores (`QUAL`).
+    Qual,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{Token, assert_ser_tokens};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        fn t(field: Field, variant: &'static str) {
+            assert_ser_tokens(
+                &field,
+                &[Token::UnitVariant {
+                    name: "Field",
+                    variant,
+                }],
+            );
+        }
+
+        t(Field::Qname, "QNAME");
+        t(Field::Flag, "FLAG");
+        t(Field::Rname, "RNAME");
+        t(Field::Pos, "POS");
+        t(Field::Mapq, "MAPQ");
+        t(Field::Cigar, "CIGAR");
+        t(Field::Rnext, "RNEXT");
+        t(Field::Pnext, "PNEXT");
+        t(Field::Tlen, "TLEN");
+        t(Field::Seq, "SEQ");
+        t(Field::Qual, "QUAL");
+    }
+}
@@ -3,7 +3,7 @@ use serde::Deserialize;
 use url::Url;
 
 use super::{Class, Kind, Payload};
-use crate::{Client, Error, Format, Response, response::Ticket};
+use crate::{Client, Error, Format, Response, reads::Field, response::Ticket};
 
 /// A request builder.
 pub struct Builder {
@@ -41,13 +41,31 @@ impl Builder {
         self
     }
 
+    pub fn set_fields(mut self, fields: Vec<Field>) -> Self {
+        *self.payload.fields_mut() = Some(fields);
+        self
+    }
+
+    pub fn set_tags(mut self, tags: Vec<String>) -> Self {
+        *self.payload.tags_mut() = Some(tags);
+        self
+    }
+
+    pub fn set_notags(mut self, notags: Vec<String>) -> Self {
+        *self.payload.notags_mut() = Some(notags);
+        self
+    }
+
     pub async fn send(self) -> crate::Result<Response> {
+        validate_tags(&self.payload)?;
+
         let endpoint = build_endpoint(self.client.base_url(), self.kind, &self.id)?;
         let mut request = self.client.http_client().post(endpoint);
 
         request = match self.payload.class() {
             Some(Class::Header) => {
                 let mut payload = Payload::from(self.kind);
+                *payload.format_mut() = self.payload.format();
                 *payload.class_mut() = Some(Class::Header);
                 request.json(&payload)
             }
@@ -76,6 +94,18 @@ pub struct ErrorResponse {
     htsget: crate::response::Error,
 }
 
+// _Htsget retrieval API spec v1.3.0_ § "Query parameters": "`tags` and `notags` ... MUST NOT
+// contain the same tag".
+fn validate_tags(payload: &Payload) -> crate::Result<()> {
+    if let (Some(tags), Some(notags)) = (payload.tags(), payload.notags()) {
+        if tags.iter().any(|tag| notags.contains(tag)) {
+            return Err(Error::Input);
+        }
+    }
+
+    Ok(())
+}
+
 fn build_endpoint(base_url: &Url, kind: Kind, id: &str) -> crate::Result<Url> {
     let k = match kind {
         Kind::Reads => "reads",
@@ -84,3 +114,21 @@ fn build_endpoint(base_url: &Url, kind: Kind, id: &str) -> crate::Result<Url> {
 
     base_url.join(&format!("{k}/{id}")).map_err(Error::Url)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_tags() {
+        let mut payload = Payload::from(Kind::Reads);
+        assert!(validate_tags(&payload).is_ok());
+
+        *payload.tags_mut() = Some(vec![String::from("NM"), String::from("MD")]);
+        *payload.notags_mut() = Some(vec![String::from("RG")]);
+        assert!(validate_tags(&payload).is_ok());
+
+        *payload.notags_mut() = Some(vec![String::from("MD")]);
+        assert!(matches!(validate_tags(&payload), Err(Error::Input)));
+    }
+}
@@ -5,7 +5,7 @@ use serde::Serialize;
 
 use self::regions::Regions;
 use super::{Class, Kind};
-use crate::Format;
+use crate::{Format, reads::Field};
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct Payload {
@@ -16,9 +16,22 @@ pub struct Payload {
 
     #[serde(skip_serializing_if = "Regions::is_empty")]
     regions: Regions,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<Vec<Field>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notags: Option<Vec<String>>,
 }
 
 impl Payload {
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
     pub fn format_mut(&mut self) -> &mut Format {
         &mut self.format
     }
@@ -34,6 +47,26 @@ impl Payload {
     pub fn regions_mut(&mut self) -> &mut Vec<Region> {
         &mut self.regions.0
     }
+
+    pub fn fields_mut(&mut self) -> &mut Option<Vec<Field>> {
+        &mut self.fields
+    }
+
+    pub fn tags(&self) -> Option<&[String]> {
+        self.tags.as_deref()
+    }
+
+    pub fn tags_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.tags
+    }
+
+    pub fn notags(&self) -> Option<&[String]> {
+        self.notags.as_deref()
+    }
+
+    pub fn notags_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.notags
+    }
 }
 
 impl From<Kind> for Payload {
@@ -47,6 +80,9 @@ impl From<Kind> for Payload {
             format,
             class: None,
             regions: Regions::default(),
+            fields: None,
+            tags: None,
+            notags: None,
         }
     }
 }
@@ -127,5 +163,47 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+
+        let mut payload = Payload::from(Kind::Reads);
+        *payload.fields_mut() = Some(vec![Field::Qname, Field::Flag]);
+        *payload.tags_mut() = Some(vec![String::from("NM")]);
+        *payload.notags_mut() = Some(Vec::new());
+
+        assert_ser_tokens(
+            &payload,
+            &[
+                Token::Struct {
+                    name: "Payload",
+                    len: 4,
+                },
+                Token::Str("format"),
+                Token::UnitVariant {
+                    name: "Format",
+                    variant: "BAM",
+                },
+                Token::Str("fields"),
+                Token::Some,
+                Token::Seq { len: Some(2) },
+                Token::UnitVariant {
+                    name: "Field",
+                    variant: "QNAME",
+                },
+                Token::UnitVariant {
+                    name: "Field",
+                    variant: "FLAG",
+                },
+                Token::SeqEnd,
+                Token::Str("tags"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Str("NM"),
+                Token::SeqEnd,
+                Token::Str("notags"),
+                Token::Some,
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
     }
 }
@@ -6,10 +6,13 @@ pub(crate) mod ticket;
 pub use self::error::Error;
 pub(crate) use self::ticket::Ticket;
 
+use std::num::NonZeroUsize;
+
 use bytes::Bytes;
-use futures::Stream;
+use futures::{Stream, TryStreamExt};
+use tokio::io::AsyncBufRead;
 
-use super::Client;
+use super::{Client, Format};
 
 /// An htsget response.
 #[derive(Debug)]
@@ -29,9 +32,300 @@ impl Response {
         &self.id
     }
 
+    /// Returns the data format.
+    pub fn format(&self) -> Format {
+        self.ticket.format()
+    }
+
     /// Returns the data from the ticket URLs.
+    ///
+    /// The URLs are fetched sequentially, and the data of each URL is streamed as it is
+    /// received.
     pub fn chunks(&self) -> impl Stream<Item = crate::Result<Bytes>> + '_ {
         use super::chunks::chunks;
-        chunks(&self.client, self.ticket.urls())
+        chunks(self.client.clone(), self.ticket.urls().to_vec())
+    }
+
+    /// Returns the data from the ticket URLs, fetching up to `n` URLs concurrently.
+    ///
+    /// The data of each URL is buffered and yielded as a single chunk. Chunks are yielded in
+    /// ticket order, so their concatenation is the same as the data from [`Self::chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use futures::TryStreamExt;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.reads("NDLS0001").send().await?;
+    ///
+    /// let mut chunks = response.buffered_chunks(NonZeroUsize::try_from(4)?);
+    ///
+    /// while let Some(chunk) = chunks.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn buffered_chunks(
+        &self,
+        n: NonZeroUsize,
+    ) -> impl Stream<Item = crate::Result<Bytes>> + '_ {
+        use super::chunks::buffered_chunks;
+        buffered_chunks(&self.client, self.ticket.urls(), n.get())
+    }
+
+    /// Converts the response into an async reader of the data from the ticket URLs.
+    ///
+    /// This allows the data to be decoded directly, e.g., by an async format reader, without
+    /// writing it to a file first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_htsget as htsget;
+    /// use tokio::io;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.reads("NDLS0001").send().await?;
+    ///
+    /// let mut reader = response.into_reader();
+    /// io::copy_buf(&mut reader, &mut io::sink()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_reader(self) -> impl AsyncBufRead + Unpin + 'static {
+        use super::chunks::chunks;
+
+        let urls = self.ticket.into_urls();
+        let stream = chunks(self.client, urls).map_err(std::io::Error::other);
+
+        tokio_util::io::StreamReader::new(stream)
+    }
+
+    /// Converts the response into an alignment reader.
+    ///
+    /// The format is the format of the ticket. A reference sequence repository is only used to
+    /// decode CRAM records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fasta as fasta;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.reads("NDLS0001").send().await?;
+    ///
+    /// let mut reader = response
+    ///     .into_alignment_reader(fasta::Repository::default())
+    ///     .await?;
+    ///
+    /// let header = reader.read_header().await?;
+    /// let mut records = reader.records(&header);
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alignment")]
+    pub async fn into_alignment_reader(
+        self,
+        reference_sequence_repository: noodles_fasta::Repository,
+    ) -> std::io::Result<noodles_util::alignment::r#async::io::Reader<Box<dyn AsyncBufRead + Unpin>>>
+    {
+        use noodles_util::alignment::{
+            self,
+            io::{CompressionMethod, Format as AlignmentFormat},
+        };
+
+        let (format, compression_method) = match self.format() {
+            Format::Bam => (AlignmentFormat::Bam, Some(CompressionMethod::Bgzf)),
+            Format::Cram => (AlignmentFormat::Cram, None),
+            Format::Vcf | Format::Bcf => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "invalid alignment format",
+                ));
+            }
+        };
+
+        alignment::r#async::io::reader::Builder::default()
+            .set_format(format)
+            .set_compression_method(compression_method)
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build_from_reader(self.into_reader())
+            .await
+    }
+
+    /// Converts the response into a variant reader.
+    ///
+    /// The format is the format of the ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.variants("NDLS0001").send().await?;
+    ///
+    /// let mut reader = response.into_variant_reader().await?;
+    /// let header = reader.read_header().await?;
+    /// let mut records = reader.records();
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "variant")]
+    pub async fn into_variant_reader(
+        self,
+    ) -> std::io::Result<noodles_util::variant::r#async::io::Reader<Box<dyn AsyncBufRead + Unpin>>>
+    {
+        use noodles_util::variant::{
+            self,
+            io::{CompressionMethod, Format as VariantFormat},
+        };
+
+        let format = match self.format() {
+            Format::Vcf => VariantFormat::Vcf,
+            Format::Bcf => VariantFormat::Bcf,
+            Format::Bam | Format::Cram => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "invalid variant format",
+                ));
+            }
+        };
+
+        variant::r#async::io::reader::Builder::default()
+            .set_format(format)
+            .set_compression_method(Some(CompressionMethod::Bgzf))
+            .build_from_reader(self.into_reader())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::prelude::{BASE64_STANDARD, Engine};
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+    use crate::Error;
+
+    fn build_response(format: &str, data: &[&[u8]]) -> crate::Result<Response> {
+        let client = Client::new("https://localhost/".parse().map_err(Error::Url)?);
+
+        let urls: Vec<_> = data
+            .iter()
+            .map(|buf| {
+                format!(
+                    r#"{{"url":"data:application/octet-stream;base64,{}"}}"#,
+                    BASE64_STANDARD.encode(buf)
+                )
+            })
+            .collect();
+
+        let json = format!(r#"{{"format":"{format}","urls":[{}]}}"#, urls.join(","));
+        let ticket = serde_json::from_str(&json).unwrap();
+
+        Ok(Response::new(client, String::from("NDLS0001"), ticket))
+    }
+
+    #[tokio::test]
+    async fn test_into_reader() -> Result<(), Box<dyn std::error::Error>> {
+        let response = build_response("BAM", &[b"noo", b"dles"])?;
+        assert_eq!(response.format(), Format::Bam);
+
+        let mut reader = response.into_reader();
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alignment")]
+    #[tokio::test]
+    async fn test_into_alignment_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::TryStreamExt;
+        use noodles_fasta as fasta;
+        use noodles_sam::{
+            self as sam,
+            alignment::{RecordBuf, io::Write},
+            header::record::value::{Map, map::ReferenceSequence},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = noodles_bam::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for name in ["r0", "r1"] {
+            let record = RecordBuf::builder().set_name(name).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+
+        // Split the data across URLs at an arbitrary position.
+        let (a, b) = data.split_at(data.len() / 2);
+        let response = build_response("BAM", &[a, b])?;
+
+        let mut reader = response
+            .into_alignment_reader(fasta::Repository::default())
+            .await?;
+
+        let actual_header = reader.read_header().await?;
+        assert_eq!(actual_header.reference_sequences().len(), 1);
+
+        let names: Vec<_> = reader
+            .records(&actual_header)
+            .map_ok(|record| record.name().map(|name| name.to_vec()))
+            .try_collect()
+            .await?;
+
+        assert_eq!(names, [Some(b"r0".to_vec()), Some(b"r1".to_vec())]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "variant")]
+    #[tokio::test]
+    async fn test_into_variant_reader_with_invalid_format() -> crate::Result<()> {
+        let response = build_response("BAM", &[])?;
+
+        assert!(matches!(
+            response.into_variant_reader().await,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
     }
 }
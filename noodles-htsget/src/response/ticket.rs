@@ -38,7 +38,15 @@ pub(crate) struct Ticket {
 }
 
 impl Ticket {
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
     pub fn urls(&self) -> &[BlockUrl] {
         &self.urls
     }
+
+    pub fn into_urls(self) -> Vec<BlockUrl> {
+        self.urls
+    }
 }
@@ -0,0 +1,91 @@
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+/// An HTTP response body.
+pub enum Body {
+    /// A body held in memory.
+    Bytes(Vec<u8>),
+    /// A body streamed from a reader with a known length.
+    Reader(Box<dyn Read + Send>, u64),
+}
+
+impl Body {
+    /// Returns the length of the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_http::Body;
+    /// assert_eq!(Body::from(b"ndls".to_vec()).len(), 4);
+    /// ```
+    pub fn len(&self) -> u64 {
+        match self {
+            Self::Bytes(buf) => buf.len() as u64,
+            Self::Reader(_, len) => *len,
+        }
+    }
+
+    /// Returns whether the body is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_http::Body;
+    /// assert!(Body::from(Vec::new()).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the body to a writer.
+    ///
+    /// A reader body is copied in chunks rather than read into memory. This returns an error if a
+    /// reader ends before its length is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_http::Body;
+    ///
+    /// let body = Body::Reader(Box::new(&b"ndls"[..]), 4);
+    ///
+    /// let mut buf = Vec::new();
+    /// body.write_to(&mut buf)?;
+    /// assert_eq!(buf, b"ndls");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_to<W>(self, writer: &mut W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        match self {
+            Self::Bytes(buf) => writer.write_all(&buf),
+            Self::Reader(reader, len) => {
+                let n = io::copy(&mut reader.take(len), writer)?;
+
+                if n == len {
+                    Ok(())
+                } else {
+                    Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(buf) => f.debug_tuple("Bytes").field(buf).finish(),
+            Self::Reader(_, len) => f.debug_tuple("Reader").field(len).finish(),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(buf: Vec<u8>) -> Self {
+        Self::Bytes(buf)
+    }
+}
@@ -0,0 +1,327 @@
+use std::{
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Condvar, Mutex},
+    thread,
+    time::Duration,
+};
+
+use crate::Body;
+
+// The max size of a request line or header line, including the line ending.
+const MAX_LINE_LEN: usize = 8 * 1024;
+
+// The max number of request headers.
+const MAX_HEADER_COUNT: usize = 100;
+
+// The max size of a request body.
+const MAX_BODY_SIZE: usize = 1 << 20;
+
+// The max number of connections handled at the same time.
+const MAX_CONNECTION_COUNT: usize = 64;
+
+// The max time to wait on a read from or write to a client.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// The time to wait before accepting another connection after accepting a connection fails.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Serves HTTP/1.1 requests from the given listener.
+///
+/// Each connection is handled on its own thread, one request per connection. At most 64
+/// connections are handled at the same time; further connections wait to be accepted. Request
+/// lines and header lines are limited to 8 KiB, requests to 100 headers, and request bodies to
+/// 1 MiB. Reads from and writes to clients time out after 30 seconds.
+///
+/// Failures of individual connections are ignored. Failures to accept a connection, e.g., when the
+/// process runs out of file descriptors, are written to stderr, and the server continues after a
+/// short delay.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::net::TcpListener;
+///
+/// let listener = TcpListener::bind("127.0.0.1:8080")?;
+///
+/// noodles_http::serve(&listener, |_| {
+///     http::Response::new(noodles_http::Body::from(b"noodles".to_vec()))
+/// })?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn serve<F>(listener: &TcpListener, handler: F) -> io::Result<()>
+where
+    F: Fn(&http::Request<Vec<u8>>) -> http::Response<Body> + Sync,
+{
+    let limiter = Limiter::new(MAX_CONNECTION_COUNT);
+
+    thread::scope(|scope| {
+        for result in listener.incoming() {
+            let stream = match result {
+                Ok(stream) => stream,
+                Err(e) => {
+                    eprintln!("noodles-http: failed to accept connection: {e}");
+                    thread::sleep(ACCEPT_RETRY_DELAY);
+                    continue;
+                }
+            };
+
+            let permit = limiter.acquire();
+            let handler = &handler;
+
+            scope.spawn(move || {
+                let _permit = permit;
+                let _ = handle(stream, handler);
+            });
+        }
+
+        Ok(())
+    })
+}
+
+fn handle<F>(stream: TcpStream, handler: &F) -> io::Result<()>
+where
+    F: Fn(&http::Request<Vec<u8>>) -> http::Response<Body>,
+{
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let request = read_request(&mut reader)?;
+
+    let response = handler(&request);
+
+    let mut writer = BufWriter::new(stream);
+    write_response(&mut writer, response)?;
+    writer.flush()
+}
+
+fn read_request<R>(reader: &mut R) -> io::Result<http::Request<Vec<u8>>>
+where
+    R: BufRead,
+{
+    let mut buf = Vec::new();
+
+    let line = read_line(reader, &mut buf)?;
+    let mut components = line.split_whitespace();
+
+    let (Some(method), Some(target)) = (components.next(), components.next()) else {
+        return Err(invalid_data("invalid request line"));
+    };
+
+    let mut builder = http::Request::builder().method(method).uri(target);
+    let mut content_length = 0;
+    let mut header_count = 0;
+
+    loop {
+        let line = read_line(reader, &mut buf)?;
+
+        if line.is_empty() {
+            break;
+        }
+
+        header_count += 1;
+
+        if header_count > MAX_HEADER_COUNT {
+            return Err(invalid_data("too many headers"));
+        }
+
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| invalid_data("invalid header"))?;
+
+        let value = value.trim();
+
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .parse()
+                .map_err(|_| invalid_data("invalid content length"))?;
+        }
+
+        builder = builder.header(name, value);
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(invalid_data("request body is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    builder.body(body).map_err(invalid_data)
+}
+
+// Reads a line of at most `MAX_LINE_LEN` bytes and returns it without the line ending.
+fn read_line<'a, R>(reader: &mut R, buf: &'a mut Vec<u8>) -> io::Result<&'a str>
+where
+    R: BufRead,
+{
+    buf.clear();
+
+    let n = reader
+        .by_ref()
+        .take(MAX_LINE_LEN as u64)
+        .read_until(b'\n', buf)?;
+
+    if !buf.ends_with(b"\n") {
+        return if n == MAX_LINE_LEN {
+            Err(invalid_data("line is too long"))
+        } else {
+            Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+        };
+    }
+
+    let line = buf
+        .strip_suffix(b"\r\n")
+        .or_else(|| buf.strip_suffix(b"\n"))
+        .unwrap_or_default();
+
+    std::str::from_utf8(line).map_err(invalid_data)
+}
+
+fn write_response<W>(writer: &mut W, response: http::Response<Body>) -> io::Result<()>
+where
+    W: Write,
+{
+    let status = response.status();
+
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\n",
+        status.as_str(),
+        status.canonical_reason().unwrap_or_default()
+    )?;
+
+    for (name, value) in response.headers() {
+        write!(writer, "{name}: ")?;
+        writer.write_all(value.as_bytes())?;
+        writer.write_all(b"\r\n")?;
+    }
+
+    let body = response.into_body();
+
+    write!(writer, "Content-Length: {}\r\n", body.len())?;
+    writer.write_all(b"Connection: close\r\n\r\n")?;
+
+    body.write_to(writer)
+}
+
+fn invalid_data<E>(e: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+// A counting semaphore that bounds the number of connections handled at the same time.
+struct Limiter {
+    count: Mutex<usize>,
+    is_available: Condvar,
+    max_count: usize,
+}
+
+impl Limiter {
+    fn new(max_count: usize) -> Self {
+        Self {
+            count: Mutex::new(0),
+            is_available: Condvar::new(),
+            max_count,
+        }
+    }
+
+    fn acquire(&self) -> Permit<'_> {
+        let mut count = self.count.lock().unwrap_or_else(|e| e.into_inner());
+
+        while *count >= self.max_count {
+            count = self
+                .is_available
+                .wait(count)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+
+        *count += 1;
+
+        Permit(self)
+    }
+}
+
+// A connection slot that is released when dropped.
+struct Permit<'a>(&'a Limiter);
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let mut count = self.0.count.lock().unwrap_or_else(|e| e.into_inner());
+        *count -= 1;
+        self.0.is_available.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() -> io::Result<()> {
+        let data = b"POST /reads/sample HTTP/1.1\r\nHost: localhost\r\nContent-Length: 2\r\n\r\n{}";
+        let mut reader = &data[..];
+
+        let request = read_request(&mut reader)?;
+
+        assert_eq!(request.method(), http::Method::POST);
+        assert_eq!(request.uri().path(), "/reads/sample");
+        assert_eq!(
+            request.headers().get("host").map(|v| v.as_bytes()),
+            Some(&b"localhost"[..])
+        );
+        assert_eq!(request.body(), b"{}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_request_with_limits() {
+        let mut data = b"GET /".to_vec();
+        data.resize(MAX_LINE_LEN * 2, b'a');
+        data.extend(b" HTTP/1.1\r\n\r\n");
+
+        assert!(matches!(
+            read_request(&mut &data[..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut data = b"GET / HTTP/1.1\r\n".to_vec();
+
+        for i in 0..=MAX_HEADER_COUNT {
+            data.extend(format!("X-{i}: 0\r\n").as_bytes());
+        }
+
+        data.extend(b"\r\n");
+
+        assert!(matches!(
+            read_request(&mut &data[..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"GET / HTTP/1.1\r\nHost: local";
+
+        assert!(matches!(
+            read_request(&mut &data[..]),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn test_write_response() -> io::Result<()> {
+        let mut response = http::Response::new(Body::Reader(Box::new(&b"ndls"[..]), 4));
+        *response.status_mut() = http::StatusCode::PARTIAL_CONTENT;
+
+        let mut buf = Vec::new();
+        write_response(&mut buf, response)?;
+
+        let expected =
+            b"HTTP/1.1 206 Partial Content\r\nContent-Length: 4\r\nConnection: close\r\n\r\nndls";
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! **noodles-http** is a minimal HTTP/1.1 server shared by the noodles htsget and refget servers.
+//!
+//! [`serve`] accepts connections from a listener and handles one request per connection using a
+//! handler function. Responses have a [`Body`] that is either held in memory or streamed from a
+//! reader.
+
+mod body;
+mod connection;
+
+pub use self::{body::Body, connection::serve};
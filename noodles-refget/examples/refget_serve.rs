@@ -0,0 +1,25 @@
+//! Serves the sequences of an indexed FASTA using the refget protocol.
+//!
+//! The FASTA must have an associated index (`<src>.fai`). The digest of the sequence collection
+//! is printed to stderr, e.g., for requests to `http://<addr>/collection/<digest>`.
+
+use std::{env, net::TcpListener};
+
+use noodles_refget::server;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let addr = args
+        .next()
+        .unwrap_or_else(|| String::from("127.0.0.1:8080"));
+
+    let server = server::Builder::default().build_from_path(src)?;
+    eprintln!("collection: {}", server.digest());
+
+    let listener = TcpListener::bind(&addr)?;
+    server.serve(&listener)?;
+
+    Ok(())
+}
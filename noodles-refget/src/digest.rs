@@ -0,0 +1,64 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha512};
+
+// The prefix of a GA4GH sequence digest.
+pub(crate) const SQ_PREFIX: &str = "SQ.";
+
+// Returns the hex-encoded MD5 checksum of the data.
+#[cfg(any(feature = "repository", feature = "server"))]
+pub(crate) fn md5(data: &[u8]) -> String {
+    use md5::Md5;
+
+    let digest = Md5::digest(data);
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// Returns the GA4GH truncated SHA-512 digest (sha512t24u) of the data.
+//
+// This is the first 24 bytes of the SHA-512 digest, base64url-encoded without padding.
+pub(crate) fn sha512t24u(data: &[u8]) -> String {
+    let digest = Sha512::digest(data);
+    URL_SAFE_NO_PAD.encode(&digest[..24])
+}
+
+// Returns the GA4GH sequence digest (`SQ.<sha512t24u>`) of a normalized sequence.
+pub(crate) fn sequence_digest(sequence: &[u8]) -> String {
+    format!("{SQ_PREFIX}{}", sha512t24u(sequence))
+}
+
+// Normalizes a sequence for digest computation.
+//
+// Sequences are uppercased, and non-alphabetic characters are removed.
+#[cfg(feature = "seqcol")]
+pub(crate) fn normalize(sequence: &[u8]) -> Vec<u8> {
+    sequence
+        .iter()
+        .filter(|b| b.is_ascii_alphabetic())
+        .map(|b| b.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "repository", feature = "server"))]
+    #[test]
+    fn test_md5() {
+        assert_eq!(md5(b"ACGT"), "f1f8f4bf413b16ad135722aa4591043e");
+    }
+
+    #[test]
+    fn test_sequence_digest() {
+        assert_eq!(
+            sequence_digest(b"ACGT"),
+            "SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"
+        );
+    }
+
+    #[cfg(feature = "seqcol")]
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(b"acgT*-N\n"), b"ACGTN");
+    }
+}
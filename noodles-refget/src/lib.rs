@@ -1,7 +1,17 @@
-//! **noodles-refget** is a refget 2.0 client.
+//! **noodles-refget** is a refget 2.0 client. It also includes a server and GA4GH sequence
+//! collections (seqcol) support, which are available with the `server` and `seqcol` features,
+//! respectively.
 
 mod client;
+#[cfg(any(feature = "repository", feature = "seqcol"))]
+mod digest;
+#[cfg(feature = "repository")]
+pub mod repository;
+#[cfg(feature = "seqcol")]
+pub mod seqcol;
 pub mod sequence;
+#[cfg(feature = "server")]
+pub mod server;
 
 pub use self::{client::Client, sequence::Sequence};
 
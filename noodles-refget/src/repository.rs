@@ -0,0 +1,11 @@
+//! refget-backed sequence repository.
+//!
+//! [`Adapter`] is a [`noodles_fasta::repository::Adapter`] that resolves reference sequences by
+//! their refget identifiers, e.g., the MD5 checksums in `@SQ M5` fields. Sequences can be
+//! persisted in a local [`Cache`], which can be shared with htslib via `REF_CACHE`.
+
+pub mod adapter;
+pub mod cache;
+mod id;
+
+pub use self::{adapter::Adapter, cache::Cache};
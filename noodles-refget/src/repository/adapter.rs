@@ -0,0 +1,249 @@
+//! refget sequence repository adapter.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{collections::HashMap, io, str, thread};
+
+use noodles_fasta::{
+    self as fasta,
+    record::{Definition, Sequence},
+};
+use reqwest::StatusCode;
+use tokio::runtime::Runtime;
+
+use super::{Cache, id::Id};
+use crate::{Client, Error};
+
+/// A refget sequence repository adapter.
+///
+/// A reference sequence name is resolved to a refget identifier using the identifiers added to
+/// the adapter, e.g., from the `@SQ M5` fields of a SAM header. A name that is itself an MD5
+/// checksum or GA4GH sequence digest (`[ga4gh:]SQ.<sha512t24u>`) is used as is; other names are
+/// not found.
+///
+/// Fetched sequences are verified against digest identifiers and, if a [`Cache`] is set, stored
+/// in the cache. Subsequent lookups are read from the cache without querying the server.
+///
+/// The [`fasta::repository::Adapter`] interface is synchronous. Requests are sent on a runtime
+/// owned by the adapter from a separate thread, so the adapter can be used both in and outside of
+/// an async context.
+pub struct Adapter {
+    client: Client,
+    cache: Option<Cache>,
+    ids: HashMap<Vec<u8>, Id>,
+    runtime: Option<Runtime>,
+}
+
+impl Adapter {
+    /// Returns a builder to create an adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::{self as refget, repository::Adapter};
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = Adapter::builder(client);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn builder(client: Client) -> Builder {
+        Builder::new(client)
+    }
+
+    /// Returns the local sequence cache.
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    fn resolve(&self, name: &[u8]) -> Option<Id> {
+        self.ids
+            .get(name)
+            .cloned()
+            .or_else(|| str::from_utf8(name).ok().and_then(Id::parse_digest))
+    }
+
+    fn fetch(&self, id: &Id) -> io::Result<Vec<u8>> {
+        let runtime = self.runtime.as_ref().expect("missing runtime");
+        let request = self.client.sequence(id.as_str());
+
+        let result = thread::scope(|scope| {
+            scope
+                .spawn(|| runtime.block_on(request.send()))
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))
+        });
+
+        match result {
+            Ok(sequence) => Ok(sequence.sequence().to_vec()),
+            Err(Error::Response(e)) if e.status() == Some(StatusCode::NOT_FOUND) => {
+                Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("sequence not found: {}", id.as_str()),
+                ))
+            }
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+
+    fn get_sequence(&self, id: &Id) -> io::Result<Vec<u8>> {
+        let cache = self.cache.as_ref().filter(|_| id.is_digest());
+
+        if let Some(cache) = cache {
+            if let Some(sequence) = cache.get(id.as_str())? {
+                return Ok(sequence);
+            }
+        }
+
+        let sequence = self.fetch(id)?;
+
+        if !id.verify(&sequence) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("sequence digest mismatch: {}", id.as_str()),
+            ));
+        }
+
+        if let Some(cache) = cache {
+            cache.insert(id.as_str(), &sequence)?;
+        }
+
+        Ok(sequence)
+    }
+}
+
+impl fasta::repository::Adapter for Adapter {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<fasta::Record>> {
+        let id = self.resolve(name)?;
+
+        Some(self.get_sequence(&id).map(|sequence| {
+            let definition = Definition::new(name, None);
+            fasta::Record::new(definition, Sequence::from(sequence))
+        }))
+    }
+}
+
+impl Drop for Adapter {
+    fn drop(&mut self) {
+        // Dropping a runtime blocks, which is not allowed in an async context.
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process,
+    };
+
+    use fasta::repository::Adapter as _;
+
+    use super::*;
+
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            Self(env::temp_dir().join(format!("noodles-refget-adapter-{}-{name}", process::id())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // Serves each given response body to one request and returns the request paths.
+    fn serve(listener: TcpListener, bodies: Vec<&'static [u8]>) -> thread::JoinHandle<Vec<String>> {
+        thread::spawn(move || {
+            let mut paths = Vec::new();
+
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                paths.push(line.split(' ').nth(1).unwrap().to_string());
+
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                )
+                .unwrap();
+                stream.write_all(body).unwrap();
+            }
+
+            paths
+        })
+    }
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        const MD5: &str = "f1f8f4bf413b16ad135722aa4591043e";
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}/", listener.local_addr()?).parse()?;
+        let server = serve(listener, vec![b"ACGT", b"ACGA"]);
+
+        let dir = TempDir::new("get");
+        let cache = Cache::new(&dir.0);
+
+        let mut adapter = Adapter::builder(Client::new(base_url))
+            .set_cache(cache.clone())
+            .add_sequence("sq0", MD5)
+            .add_sequence("sq1", "SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2")
+            .build()?;
+
+        let record = adapter.get(b"sq0").transpose()?;
+        assert_eq!(record.as_ref().map(|r| r.name()), Some(&b"sq0"[..]));
+        assert_eq!(
+            record.as_ref().map(|r| r.sequence().as_ref()),
+            Some(&b"ACGT"[..])
+        );
+        assert_eq!(cache.get(MD5)?, Some(b"ACGT".to_vec()));
+
+        // The cached sequence is read without a request.
+        let record = adapter.get(MD5.as_bytes()).transpose()?;
+        assert_eq!(
+            record.as_ref().map(|r| r.sequence().as_ref()),
+            Some(&b"ACGT"[..])
+        );
+
+        // The server responds with a sequence that does not match its digest.
+        assert!(matches!(
+            adapter.get(b"sq1"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(cache.get("SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2")?.is_none());
+
+        assert!(adapter.get(b"sq2").is_none());
+
+        let paths = server.join().unwrap();
+        assert_eq!(
+            paths,
+            [
+                format!("/sequence/{MD5}"),
+                String::from("/sequence/SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, io};
+
+use noodles_sam::{self as sam, header::record::value::map::reference_sequence::tag};
+
+use super::Adapter;
+use crate::{
+    Client,
+    repository::{Cache, id::Id},
+};
+
+/// A refget sequence repository adapter builder.
+pub struct Builder {
+    client: Client,
+    cache: Option<Cache>,
+    ids: HashMap<Vec<u8>, Id>,
+}
+
+impl Builder {
+    pub(super) fn new(client: Client) -> Self {
+        Self {
+            client,
+            cache: None,
+            ids: HashMap::new(),
+        }
+    }
+
+    /// Sets the local sequence cache.
+    ///
+    /// By default, sequences are not cached on disk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::{self as refget, repository::{Adapter, Cache}};
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = Adapter::builder(client).set_cache(Cache::new("cache"));
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Adds the refget identifier of a reference sequence.
+    ///
+    /// The identifier can be an MD5 checksum, a GA4GH sequence digest, or any other identifier
+    /// supported by the server. Only sequences with digest identifiers are verified and cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::{self as refget, repository::Adapter};
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = Adapter::builder(client)
+    ///     .add_sequence("sq0", "d7eba311421bbc9d3ada44709dd61534");
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn add_sequence<N, I>(mut self, name: N, id: I) -> Self
+    where
+        N: Into<Vec<u8>>,
+        I: Into<String>,
+    {
+        self.ids.insert(name.into(), Id::from(id.into()));
+        self
+    }
+
+    /// Adds the MD5 checksums (`M5`) of the reference sequences in a SAM header.
+    ///
+    /// Reference sequences without an MD5 checksum are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_refget::{self as refget, repository::Adapter};
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{
+    ///         map::{reference_sequence::tag, ReferenceSequence},
+    ///         Map,
+    ///     },
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::builder()
+    ///             .set_length(NonZeroUsize::try_from(4)?)
+    ///             .insert(tag::MD5_CHECKSUM, "f1f8f4bf413b16ad135722aa4591043e")
+    ///             .build()?,
+    ///     )
+    ///     .build();
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = Adapter::builder(client).add_reference_sequences(&header);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_reference_sequences(mut self, header: &sam::Header) -> Self {
+        for (name, reference_sequence) in header.reference_sequences() {
+            if let Some(md5_checksum) = reference_sequence.other_fields().get(&tag::MD5_CHECKSUM) {
+                let id = Id::from(md5_checksum.to_string());
+                self.ids.insert(name.to_vec(), id);
+            }
+        }
+
+        self
+    }
+
+    /// Builds a refget sequence repository adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_refget::{self as refget, repository::Adapter};
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let adapter = Adapter::builder(client).build()?;
+    /// let repository = fasta::Repository::new(adapter);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self) -> io::Result<Adapter> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Adapter {
+            client: self.client,
+            cache: self.cache,
+            ids: self.ids,
+            runtime: Some(runtime),
+        })
+    }
+}
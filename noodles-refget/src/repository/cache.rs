@@ -0,0 +1,219 @@
+//! Local sequence cache.
+
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+const REF_CACHE: &str = "REF_CACHE";
+const DEFAULT_TEMPLATE: &str = "%2s/%2s/%s";
+
+/// A local sequence cache.
+///
+/// Sequences are stored as raw files, one per sequence identifier, at paths built from a path
+/// template. This uses the same layout as the htslib `REF_CACHE`: in a template, `%<n>s` is
+/// replaced by the next `n` characters of the identifier, and `%s`, by the remaining characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cache {
+    template: String,
+}
+
+impl Cache {
+    /// Creates a cache in the given directory.
+    ///
+    /// This uses the path template `<dst>/%2s/%2s/%s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use noodles_refget::repository::Cache;
+    ///
+    /// let cache = Cache::new("cache");
+    ///
+    /// assert_eq!(
+    ///     cache.path("d7eba311421bbc9d3ada44709dd61534"),
+    ///     Path::new("cache/d7/eb/a311421bbc9d3ada44709dd61534"),
+    /// );
+    /// ```
+    pub fn new<P>(dst: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let template = dst.as_ref().join(DEFAULT_TEMPLATE);
+        Self::from_template(template.to_string_lossy())
+    }
+
+    /// Creates a cache from a path template.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use noodles_refget::repository::Cache;
+    ///
+    /// let cache = Cache::from_template("cache/%4s/%s.seq");
+    ///
+    /// assert_eq!(
+    ///     cache.path("d7eba311421bbc9d3ada44709dd61534"),
+    ///     Path::new("cache/d7eb/a311421bbc9d3ada44709dd61534.seq"),
+    /// );
+    /// ```
+    pub fn from_template<S>(template: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            template: template.into(),
+        }
+    }
+
+    /// Creates a cache from the `REF_CACHE` environment variable.
+    ///
+    /// This returns `None` if `REF_CACHE` is unset or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::repository::Cache;
+    /// let cache = Cache::from_env();
+    /// ```
+    pub fn from_env() -> Option<Self> {
+        env::var(REF_CACHE)
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(Self::from_template)
+    }
+
+    /// Returns the path template.
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Returns the path of the sequence with the given identifier.
+    pub fn path(&self, id: &str) -> PathBuf {
+        PathBuf::from(expand_template(&self.template, id))
+    }
+
+    /// Returns the cached sequence with the given identifier.
+    ///
+    /// This returns `None` if the sequence is not in the cache.
+    pub fn get(&self, id: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.path(id)) {
+            Ok(buf) => Ok(Some(buf)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Adds a sequence to the cache.
+    ///
+    /// The sequence is first written to a temporary file and then moved into place, so readers
+    /// never observe a partially written sequence.
+    pub fn insert(&self, id: &str, sequence: &[u8]) -> io::Result<()> {
+        let dst = self.path(id);
+
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut tmp = dst.clone().into_os_string();
+        tmp.push(format!(".tmp.{}", process::id()));
+        let tmp = PathBuf::from(tmp);
+
+        let result = fs::File::create(&tmp)
+            .and_then(|mut file| file.write_all(sequence))
+            .and_then(|_| fs::rename(&tmp, &dst));
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+
+        result
+    }
+}
+
+fn expand_template(template: &str, id: &str) -> String {
+    let mut dst = String::with_capacity(template.len() + id.len());
+    let mut rest = id;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            dst.push(c);
+            continue;
+        }
+
+        let mut n = String::new();
+
+        while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+            n.push(d);
+        }
+
+        if chars.next_if_eq(&'s').is_some() {
+            let len = if n.is_empty() {
+                rest.len()
+            } else {
+                n.parse().unwrap_or(usize::MAX)
+            };
+
+            let i = rest
+                .char_indices()
+                .nth(len)
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len());
+
+            let (head, tail) = rest.split_at(i);
+            dst.push_str(head);
+            rest = tail;
+        } else {
+            dst.push(c);
+            dst.push_str(&n);
+        }
+    }
+
+    dst
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_template() {
+        const ID: &str = "d7eba311421bbc9d3ada44709dd61534";
+
+        assert_eq!(
+            expand_template("%2s/%2s/%s", ID),
+            "d7/eb/a311421bbc9d3ada44709dd61534"
+        );
+        assert_eq!(expand_template("/tmp/%s", ID), format!("/tmp/{ID}"));
+        assert_eq!(expand_template("%4s/%4s", ID), "d7eb/a311");
+        assert_eq!(expand_template("%2s/%s/%s", "d7eb"), "d7/eb/");
+        assert_eq!(expand_template("100%/%d/%s", "d7"), "100%/%d/d7");
+    }
+
+    #[test]
+    fn test_get_and_insert() -> io::Result<()> {
+        let dir = env::temp_dir().join(format!("noodles-refget-cache-{}", process::id()));
+        let cache = Cache::new(&dir);
+
+        const ID: &str = "f1f8f4bf413b16ad135722aa4591043e";
+
+        let result = (|| {
+            assert!(cache.get(ID)?.is_none());
+
+            cache.insert(ID, b"ACGT")?;
+            assert!(dir.join("f1/f8/f4bf413b16ad135722aa4591043e").exists());
+            assert_eq!(cache.get(ID)?, Some(b"ACGT".to_vec()));
+
+            Ok(())
+        })();
+
+        fs::remove_dir_all(&dir)?;
+
+        result
+    }
+}
@@ -0,0 +1,123 @@
+use crate::digest::{self, SQ_PREFIX};
+
+const MD5_PREFIX: &str = "md5:";
+const GA4GH_PREFIX: &str = "ga4gh:";
+
+const MD5_LEN: usize = 32;
+const SHA512T24U_LEN: usize = 32;
+
+// A refget sequence identifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Id {
+    // A lowercase hex-encoded MD5 checksum.
+    Md5(String),
+    // A GA4GH sequence digest (`SQ.<sha512t24u>`).
+    Sha512t24u(String),
+    // Any other identifier, e.g., an alias supported by the server.
+    Other(String),
+}
+
+impl Id {
+    // Parses an identifier that is a sequence digest.
+    pub fn parse_digest(s: &str) -> Option<Self> {
+        let t = s.strip_prefix(MD5_PREFIX).unwrap_or(s);
+
+        if t.len() == MD5_LEN && t.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Some(Self::Md5(t.to_ascii_lowercase()));
+        }
+
+        let t = s.strip_prefix(GA4GH_PREFIX).unwrap_or(s);
+
+        if let Some(digest) = t.strip_prefix(SQ_PREFIX) {
+            if digest.len() == SHA512T24U_LEN
+                && digest
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                return Some(Self::Sha512t24u(t.into()));
+            }
+        }
+
+        None
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Md5(s) | Self::Sha512t24u(s) | Self::Other(s) => s,
+        }
+    }
+
+    // Returns whether the identifier is a digest of its sequence.
+    pub fn is_digest(&self) -> bool {
+        !matches!(self, Self::Other(_))
+    }
+
+    // Returns whether the given sequence matches the identifier. Identifiers that are not
+    // digests always match.
+    pub fn verify(&self, sequence: &[u8]) -> bool {
+        match self {
+            Self::Md5(s) => *s == digest::md5(sequence),
+            Self::Sha512t24u(s) => *s == digest::sequence_digest(sequence),
+            Self::Other(_) => true,
+        }
+    }
+}
+
+impl From<String> for Id {
+    fn from(s: String) -> Self {
+        Self::parse_digest(&s).unwrap_or(Self::Other(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digest() {
+        assert_eq!(
+            Id::parse_digest("d7eba311421bbc9d3ada44709dd61534"),
+            Some(Id::Md5(String::from("d7eba311421bbc9d3ada44709dd61534")))
+        );
+
+        assert_eq!(
+            Id::parse_digest("md5:D7EBA311421BBC9D3ADA44709DD61534"),
+            Some(Id::Md5(String::from("d7eba311421bbc9d3ada44709dd61534")))
+        );
+
+        assert_eq!(
+            Id::parse_digest("ga4gh:SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"),
+            Some(Id::Sha512t24u(String::from(
+                "SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"
+            )))
+        );
+
+        assert_eq!(
+            Id::parse_digest("SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"),
+            Some(Id::Sha512t24u(String::from(
+                "SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"
+            )))
+        );
+
+        assert!(Id::parse_digest("sq0").is_none());
+        assert!(Id::parse_digest("d7eba311421bbc9d3ada44709dd6153").is_none());
+        assert!(Id::parse_digest("SQ.aKF498dAxcJAqme6QYQ7EZ07+fiw8Kw2").is_none());
+    }
+
+    #[test]
+    fn test_verify() {
+        let sequence = b"ACGT";
+
+        let id = Id::from(String::from("f1f8f4bf413b16ad135722aa4591043e"));
+        assert!(id.verify(sequence));
+        assert!(!id.verify(b"ACGA"));
+
+        let id = Id::from(String::from("SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2"));
+        assert!(id.verify(sequence));
+        assert!(!id.verify(b"ACGA"));
+
+        let id = Id::from(String::from("insdc:NC_000001.11"));
+        assert!(!id.is_digest());
+        assert!(id.verify(sequence));
+    }
+}
@@ -0,0 +1,469 @@
+//! GA4GH sequence collections (seqcol).
+//!
+//! A sequence collection is a set of named sequences, e.g., the reference sequences of a genome
+//! assembly. Collections are identified by digests computed from their attributes, which allows
+//! checking whether two collections, such as the reference sequences in the header of an
+//! alignment file and an assembly served by a refget server, are the same or compatible.
+
+mod comparison;
+
+pub use self::comparison::Comparison;
+
+use std::io::{self, BufRead};
+
+use noodles_fasta::{self as fasta, fai};
+use noodles_sam as sam;
+use serde::{Deserialize, Serialize};
+
+use crate::digest;
+
+/// A sequence collection.
+///
+/// This is the level 2 representation of a collection, i.e., the arrays of its attributes:
+/// sequence names, lengths, and, if known, GA4GH sequence digests. Digests are not available
+/// from, e.g., a SAM header or a FASTA index, so collections built from those only have names and
+/// lengths.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "RawSequenceCollection")]
+pub struct SequenceCollection {
+    names: Vec<String>,
+    lengths: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sequences: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct RawSequenceCollection {
+    names: Vec<String>,
+    lengths: Vec<u64>,
+    #[serde(default)]
+    sequences: Option<Vec<String>>,
+}
+
+impl TryFrom<RawSequenceCollection> for SequenceCollection {
+    type Error = io::Error;
+
+    fn try_from(raw: RawSequenceCollection) -> Result<Self, Self::Error> {
+        Self::new(raw.names, raw.lengths, raw.sequences)
+    }
+}
+
+/// The level 1 representation of a sequence collection.
+///
+/// This holds the digest of each attribute array.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Level1 {
+    names: String,
+    lengths: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sequences: Option<String>,
+    sorted_name_length_pairs: String,
+}
+
+impl Level1 {
+    /// Returns the digest of the names array.
+    pub fn names(&self) -> &str {
+        &self.names
+    }
+
+    /// Returns the digest of the lengths array.
+    pub fn lengths(&self) -> &str {
+        &self.lengths
+    }
+
+    /// Returns the digest of the sequences array.
+    pub fn sequences(&self) -> Option<&str> {
+        self.sequences.as_deref()
+    }
+
+    /// Returns the digest of the sorted name-length pairs.
+    ///
+    /// Collections with the same names and lengths, in any order, have the same digest, i.e.,
+    /// they share a coordinate system.
+    pub fn sorted_name_length_pairs(&self) -> &str {
+        &self.sorted_name_length_pairs
+    }
+}
+
+impl SequenceCollection {
+    /// Creates a sequence collection.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the attribute arrays do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::seqcol::SequenceCollection;
+    ///
+    /// let collection = SequenceCollection::new(
+    ///     vec![String::from("sq0"), String::from("sq1")],
+    ///     vec![8, 13],
+    ///     None,
+    /// )?;
+    ///
+    /// assert_eq!(collection.len(), 2);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(
+        names: Vec<String>,
+        lengths: Vec<u64>,
+        sequences: Option<Vec<String>>,
+    ) -> io::Result<Self> {
+        let is_valid = names.len() == lengths.len()
+            && sequences
+                .as_ref()
+                .map(|sequences| sequences.len() == names.len())
+                .unwrap_or(true);
+
+        if is_valid {
+            Ok(Self {
+                names,
+                lengths,
+                sequences,
+            })
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "attribute length mismatch",
+            ))
+        }
+    }
+
+    /// Reads a sequence collection from a FASTA reader.
+    ///
+    /// The sequence digests are computed from the normalized sequences, i.e., uppercased with
+    /// non-alphabetic characters removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_refget::seqcol::SequenceCollection;
+    ///
+    /// let data = b">chrX\nTTGGGGAA\n>chr1\nGGAA\n>chr2\nGCGC\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    /// let collection = SequenceCollection::read(&mut reader)?;
+    ///
+    /// assert_eq!(
+    ///     collection.digest().as_deref(),
+    ///     Some("XZlrcEGi6mlopZ2uD8ObHkQB1d0oDwKk")
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read<R>(reader: &mut fasta::io::Reader<R>) -> io::Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut collection = Self {
+            sequences: Some(Vec::new()),
+            ..Default::default()
+        };
+
+        for result in reader.records() {
+            let record = result?;
+            let sequence = digest::normalize(record.sequence().as_ref());
+
+            collection
+                .names
+                .push(String::from_utf8_lossy(record.name()).into_owned());
+            collection.lengths.push(sequence.len() as u64);
+
+            if let Some(sequences) = collection.sequences.as_mut() {
+                sequences.push(digest::sequence_digest(&sequence));
+            }
+        }
+
+        Ok(collection)
+    }
+
+    /// Returns the number of sequences in the collection.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether the collection has no sequences.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns the sequence names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the sequence lengths.
+    pub fn lengths(&self) -> &[u64] {
+        &self.lengths
+    }
+
+    /// Returns the GA4GH sequence digests.
+    pub fn sequences(&self) -> Option<&[String]> {
+        self.sequences.as_deref()
+    }
+
+    /// Returns the level 0 digest of the collection.
+    ///
+    /// This is computed from the inherent attributes, i.e., the names and sequences. If the
+    /// sequence digests are not known, this returns `None`.
+    pub fn digest(&self) -> Option<String> {
+        let level1 = self.level1();
+        let sequences = level1.sequences?;
+
+        let json = serde_json::json!({
+            "names": level1.names,
+            "sequences": sequences,
+        });
+
+        Some(digest_json(&json))
+    }
+
+    /// Returns the level 1 representation of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::seqcol::SequenceCollection;
+    ///
+    /// let a = SequenceCollection::new(
+    ///     vec![String::from("sq0"), String::from("sq1")],
+    ///     vec![8, 13],
+    ///     None,
+    /// )?;
+    ///
+    /// let b = SequenceCollection::new(
+    ///     vec![String::from("sq1"), String::from("sq0")],
+    ///     vec![13, 8],
+    ///     None,
+    /// )?;
+    ///
+    /// assert_ne!(a.level1().names(), b.level1().names());
+    /// assert_eq!(
+    ///     a.level1().sorted_name_length_pairs(),
+    ///     b.level1().sorted_name_length_pairs()
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn level1(&self) -> Level1 {
+        let mut pair_digests: Vec<_> = self
+            .names
+            .iter()
+            .zip(&self.lengths)
+            .map(|(name, length)| {
+                digest_json(&serde_json::json!({ "length": length, "name": name }))
+            })
+            .collect();
+
+        pair_digests.sort_unstable();
+
+        Level1 {
+            names: digest_json(&self.names),
+            lengths: digest_json(&self.lengths),
+            sequences: self.sequences.as_ref().map(digest_json),
+            sorted_name_length_pairs: digest_json(&pair_digests),
+        }
+    }
+
+    /// Compares this collection (`a`) with another collection (`b`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::seqcol::SequenceCollection;
+    ///
+    /// let a = SequenceCollection::new(
+    ///     vec![String::from("sq0"), String::from("sq1")],
+    ///     vec![8, 13],
+    ///     None,
+    /// )?;
+    ///
+    /// let b = SequenceCollection::new(vec![String::from("sq0")], vec![8], None)?;
+    ///
+    /// let comparison = a.compare(&b);
+    /// assert_eq!(comparison.a_and_b_count("names"), Some(1));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn compare(&self, other: &Self) -> Comparison {
+        Comparison::new(self, other)
+    }
+}
+
+impl From<&fai::Index> for SequenceCollection {
+    fn from(index: &fai::Index) -> Self {
+        let records = index.as_ref();
+
+        Self {
+            names: records
+                .iter()
+                .map(|record| record.name().to_string())
+                .collect(),
+            lengths: records.iter().map(|record| record.length()).collect(),
+            sequences: None,
+        }
+    }
+}
+
+impl From<&sam::Header> for SequenceCollection {
+    fn from(header: &sam::Header) -> Self {
+        let reference_sequences = header.reference_sequences();
+
+        Self {
+            names: reference_sequences
+                .keys()
+                .map(|name| name.to_string())
+                .collect(),
+            lengths: reference_sequences
+                .values()
+                .map(|reference_sequence| usize::from(reference_sequence.length()) as u64)
+                .collect(),
+            sequences: None,
+        }
+    }
+}
+
+// Returns the sha512t24u digest of the canonical JSON serialization (RFC 8785) of a value.
+//
+// serde_json serializes compactly, which is canonical for the strings and integers used here.
+// Object keys must be given in sorted order.
+fn digest_json<T>(value: &T) -> String
+where
+    T: Serialize + ?Sized,
+{
+    let buf = serde_json::to_vec(value).expect("invalid JSON value");
+    digest::sha512t24u(&buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{Map, map::ReferenceSequence};
+
+    use super::*;
+
+    // The `base.fa` example from the seqcol specification.
+    fn build_collection() -> io::Result<SequenceCollection> {
+        let data = b">chrX\nTTGGGGAA\n>chr1\nGGAA\n>chr2\nGCGC\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+        SequenceCollection::read(&mut reader)
+    }
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let collection = build_collection()?;
+
+        assert_eq!(collection.names(), ["chrX", "chr1", "chr2"]);
+        assert_eq!(collection.lengths(), [8, 4, 4]);
+        assert_eq!(
+            collection.sequences(),
+            Some(
+                &[
+                    String::from("SQ.iYtREV555dUFKg2_agSJW6suquUyPpMw"),
+                    String::from("SQ.YBbVX0dLKG1ieEDCiMmkrTZFt_Z5Vdaj"),
+                    String::from("SQ.AcLxtBuKEPk_7PGE_H4dGElwZHCujwH6"),
+                ][..]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_digest() -> io::Result<()> {
+        let collection = build_collection()?;
+
+        assert_eq!(
+            collection.digest().as_deref(),
+            Some("XZlrcEGi6mlopZ2uD8ObHkQB1d0oDwKk")
+        );
+
+        let level1 = collection.level1();
+        assert_eq!(level1.names(), "Fw1r9eRxfOZD98KKrhlYQNEdSRHoVxAG");
+        assert_eq!(level1.lengths(), "cGRMZIb3AVgkcAfNv39RN7hnT5Chk7RX");
+        assert_eq!(level1.sequences(), Some("0uDQVLuHaOZi1u76LjV__yrVUIz9Bwhr"));
+
+        let collection = SequenceCollection::new(
+            collection.names().to_vec(),
+            collection.lengths().to_vec(),
+            None,
+        )?;
+
+        assert!(collection.digest().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_mismatched_attributes() {
+        assert!(matches!(
+            SequenceCollection::new(vec![String::from("sq0")], Vec::new(), None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            SequenceCollection::new(vec![String::from("sq0")], vec![8], Some(Vec::new())),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_from_fai_index() -> io::Result<()> {
+        let index = fai::Index::from(vec![
+            fai::Record::new("chrX", 8, 6, 8, 9),
+            fai::Record::new("chr1", 4, 21, 4, 5),
+            fai::Record::new("chr2", 4, 32, 4, 5),
+        ]);
+
+        let actual = SequenceCollection::from(&index);
+        let expected = build_collection()?;
+
+        assert_eq!(actual.names(), expected.names());
+        assert_eq!(actual.lengths(), expected.lengths());
+        assert!(actual.sequences().is_none());
+        assert_eq!(
+            actual.level1().sorted_name_length_pairs(),
+            expected.level1().sorted_name_length_pairs()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_sam_header() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "chr1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?),
+            )
+            .add_reference_sequence(
+                "chrX",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let actual = SequenceCollection::from(&header);
+        assert_eq!(actual.names(), ["chr1", "chrX"]);
+        assert_eq!(actual.lengths(), [4, 8]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde() -> Result<(), Box<dyn std::error::Error>> {
+        let collection = SequenceCollection::new(vec![String::from("sq0")], vec![8], None)?;
+
+        let json = serde_json::to_string(&collection)?;
+        assert_eq!(json, r#"{"names":["sq0"],"lengths":[8]}"#);
+
+        let actual: SequenceCollection = serde_json::from_str(&json)?;
+        assert_eq!(actual, collection);
+
+        assert!(
+            serde_json::from_str::<SequenceCollection>(r#"{"names":["sq0"],"lengths":[]}"#)
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
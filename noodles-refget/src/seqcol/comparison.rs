@@ -0,0 +1,290 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    hash::Hash,
+};
+
+use serde::Serialize;
+
+use super::SequenceCollection;
+
+const NAMES: &str = "names";
+const LENGTHS: &str = "lengths";
+const SEQUENCES: &str = "sequences";
+
+/// A comparison of two sequence collections.
+///
+/// This is the result of the seqcol comparison function. It describes which attributes the
+/// collections have in common and, for each shared attribute, how many array elements are shared
+/// and whether shared elements are in the same order.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Comparison {
+    digests: Digests,
+    attributes: Attributes,
+    array_elements: ArrayElements,
+    #[serde(skip)]
+    is_compatible: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+struct Digests {
+    a: Option<String>,
+    b: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+struct Attributes {
+    a_only: Vec<String>,
+    b_only: Vec<String>,
+    a_and_b: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+struct ArrayElements {
+    a_count: BTreeMap<String, usize>,
+    b_count: BTreeMap<String, usize>,
+    a_and_b_count: BTreeMap<String, usize>,
+    a_and_b_same_order: BTreeMap<String, Option<bool>>,
+}
+
+impl Comparison {
+    pub(super) fn new(a: &SequenceCollection, b: &SequenceCollection) -> Self {
+        let a_attributes = attributes(a);
+        let b_attributes = attributes(b);
+
+        let mut array_elements = ArrayElements::default();
+
+        for (name, count) in &a_attributes {
+            array_elements.a_count.insert((*name).into(), *count);
+        }
+
+        for (name, count) in &b_attributes {
+            array_elements.b_count.insert((*name).into(), *count);
+        }
+
+        let mut shared = |name: &str, (count, same_order): (usize, Option<bool>)| {
+            array_elements.a_and_b_count.insert(name.into(), count);
+            array_elements
+                .a_and_b_same_order
+                .insert(name.into(), same_order);
+        };
+
+        shared(NAMES, compare_elements(&a.names, &b.names));
+        shared(LENGTHS, compare_elements(&a.lengths, &b.lengths));
+
+        if let (Some(a_sequences), Some(b_sequences)) = (&a.sequences, &b.sequences) {
+            shared(SEQUENCES, compare_elements(a_sequences, b_sequences));
+        }
+
+        let names = |attributes: &[(&str, usize)], others: &[(&str, usize)], shared: bool| {
+            attributes
+                .iter()
+                .filter(|(name, _)| others.iter().any(|(n, _)| n == name) == shared)
+                .map(|(name, _)| String::from(*name))
+                .collect()
+        };
+
+        Self {
+            digests: Digests {
+                a: a.digest(),
+                b: b.digest(),
+            },
+            attributes: Attributes {
+                a_only: names(&a_attributes, &b_attributes, false),
+                b_only: names(&b_attributes, &a_attributes, false),
+                a_and_b: names(&a_attributes, &b_attributes, true),
+            },
+            array_elements,
+            is_compatible: sorted_name_length_pairs(a) == sorted_name_length_pairs(b),
+        }
+    }
+
+    /// Returns the level 0 digest of collection `a`, if known.
+    pub fn a_digest(&self) -> Option<&str> {
+        self.digests.a.as_deref()
+    }
+
+    /// Returns the level 0 digest of collection `b`, if known.
+    pub fn b_digest(&self) -> Option<&str> {
+        self.digests.b.as_deref()
+    }
+
+    /// Returns the attributes only in collection `a`.
+    pub fn a_only(&self) -> &[String] {
+        &self.attributes.a_only
+    }
+
+    /// Returns the attributes only in collection `b`.
+    pub fn b_only(&self) -> &[String] {
+        &self.attributes.b_only
+    }
+
+    /// Returns the attributes in both collections.
+    pub fn a_and_b(&self) -> &[String] {
+        &self.attributes.a_and_b
+    }
+
+    /// Returns the number of elements of an attribute in collection `a`.
+    pub fn a_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.a_count.get(attribute).copied()
+    }
+
+    /// Returns the number of elements of an attribute in collection `b`.
+    pub fn b_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.b_count.get(attribute).copied()
+    }
+
+    /// Returns the number of elements of an attribute that are in both collections.
+    pub fn a_and_b_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.a_and_b_count.get(attribute).copied()
+    }
+
+    /// Returns whether the shared elements of an attribute are in the same order.
+    ///
+    /// The order is undefined, i.e., `None`, if no elements are shared or if shared elements are
+    /// unevenly duplicated.
+    pub fn a_and_b_same_order(&self, attribute: &str) -> Option<bool> {
+        self.array_elements
+            .a_and_b_same_order
+            .get(attribute)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns whether the collections have the same coordinate system.
+    ///
+    /// This is true when both collections have the same name-length pairs, in any order.
+    pub fn is_compatible(&self) -> bool {
+        self.is_compatible
+    }
+}
+
+fn attributes(collection: &SequenceCollection) -> Vec<(&'static str, usize)> {
+    let mut attributes = vec![
+        (NAMES, collection.names.len()),
+        (LENGTHS, collection.lengths.len()),
+    ];
+
+    if let Some(sequences) = &collection.sequences {
+        attributes.push((SEQUENCES, sequences.len()));
+    }
+
+    attributes
+}
+
+fn sorted_name_length_pairs(collection: &SequenceCollection) -> Vec<(&str, u64)> {
+    let mut pairs: Vec<_> = collection
+        .names
+        .iter()
+        .map(String::as_str)
+        .zip(collection.lengths.iter().copied())
+        .collect();
+
+    pairs.sort_unstable();
+
+    pairs
+}
+
+// Returns the number of shared elements and whether the shared elements are in the same order.
+fn compare_elements<T>(a: &[T], b: &[T]) -> (usize, Option<bool>)
+where
+    T: Eq + Hash,
+{
+    let a_set: HashSet<_> = a.iter().collect();
+    let b_set: HashSet<_> = b.iter().collect();
+
+    let a_filtered: Vec<_> = a.iter().filter(|t| b_set.contains(t)).collect();
+    let b_filtered: Vec<_> = b.iter().filter(|t| a_set.contains(t)).collect();
+
+    let count = a_filtered.len().min(b_filtered.len());
+
+    let same_order = if count == 0 || a_filtered.len() != b_filtered.len() {
+        None
+    } else {
+        Some(a_filtered == b_filtered)
+    };
+
+    (count, same_order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_collection(
+        names: &[&str],
+        lengths: &[u64],
+        sequences: Option<&[&str]>,
+    ) -> SequenceCollection {
+        SequenceCollection::new(
+            names.iter().map(|s| String::from(*s)).collect(),
+            lengths.to_vec(),
+            sequences.map(|sequences| sequences.iter().map(|s| String::from(*s)).collect()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let a = build_collection(
+            &["chrX", "chr1", "chr2"],
+            &[8, 4, 4],
+            Some(&["SQ.a", "SQ.b", "SQ.c"]),
+        );
+        let b = build_collection(&["chr1", "chrX", "chr3"], &[4, 8, 13], None);
+
+        let comparison = a.compare(&b);
+
+        assert!(comparison.a_digest().is_some());
+        assert!(comparison.b_digest().is_none());
+
+        assert_eq!(comparison.a_only(), ["sequences"]);
+        assert!(comparison.b_only().is_empty());
+        assert_eq!(comparison.a_and_b(), ["names", "lengths"]);
+
+        assert_eq!(comparison.a_count("names"), Some(3));
+        assert_eq!(comparison.b_count("sequences"), None);
+        assert_eq!(comparison.a_and_b_count("names"), Some(2));
+        assert_eq!(comparison.a_and_b_same_order("names"), Some(false));
+        assert_eq!(comparison.a_and_b_count("lengths"), Some(2));
+        assert_eq!(comparison.a_and_b_same_order("lengths"), None);
+        assert_eq!(comparison.a_and_b_count("sequences"), None);
+
+        assert!(!comparison.is_compatible());
+
+        let c = build_collection(&["chr2", "chr1", "chrX"], &[4, 4, 8], None);
+        assert!(a.compare(&c).is_compatible());
+    }
+
+    #[test]
+    fn test_compare_elements() {
+        assert_eq!(compare_elements(&[1, 2, 3], &[1, 2, 3]), (3, Some(true)));
+        assert_eq!(compare_elements(&[1, 2, 3], &[3, 2]), (2, Some(false)));
+        assert_eq!(compare_elements(&[1, 2], &[3, 4]), (0, None));
+        assert_eq!(compare_elements(&[1, 1, 2], &[1, 2]), (2, None));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let a = build_collection(&["sq0"], &[8], None);
+        let comparison = a.compare(&a);
+
+        let actual = serde_json::to_value(&comparison).unwrap();
+
+        let expected = serde_json::json!({
+            "digests": { "a": null, "b": null },
+            "attributes": {
+                "a_only": [],
+                "b_only": [],
+                "a_and_b": ["names", "lengths"],
+            },
+            "array_elements": {
+                "a_count": { "lengths": 1, "names": 1 },
+                "b_count": { "lengths": 1, "names": 1 },
+                "a_and_b_count": { "lengths": 1, "names": 1 },
+                "a_and_b_same_order": { "lengths": true, "names": true },
+            },
+        });
+
+        assert_eq!(actual, expected);
+    }
+}
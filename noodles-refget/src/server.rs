@@ -0,0 +1,768 @@
+//! refget server.
+//!
+//! [`Server`] serves the sequences of a local indexed FASTA using the refget 2.0 protocol and the
+//! sequence collection of the FASTA using the GA4GH sequence collections (seqcol) API.
+
+mod builder;
+
+pub use noodles_http::Body;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use http::{Method, StatusCode, header};
+use noodles_core::{Position, Region};
+use noodles_fasta as fasta;
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+
+use crate::seqcol::SequenceCollection;
+
+const SEQUENCE_CONTENT_TYPE: &str = "text/vnd.ga4gh.refget.v2.0.0+plain; charset=us-ascii";
+const METADATA_CONTENT_TYPE: &str = "application/vnd.ga4gh.refget.v2.0.0+json";
+const JSON_CONTENT_TYPE: &str = "application/json";
+const TEXT_CONTENT_TYPE: &str = "text/plain";
+
+const MD5_PREFIX: &str = "md5:";
+const GA4GH_PREFIX: &str = "ga4gh:";
+
+struct Sequence {
+    name: String,
+    length: u64,
+    md5: String,
+    ga4gh: String,
+    is_circular: bool,
+}
+
+/// A refget server backed by a local indexed FASTA.
+///
+/// Sequences are identified by their MD5 checksums (optionally prefixed with `md5:`) or GA4GH
+/// sequence digests (`SQ.<sha512t24u>`, optionally prefixed with `ga4gh:`). The server routes
+/// the following endpoints:
+///
+///   * `GET /sequence/service-info`,
+///   * `GET /sequence/<id>`, with optional `start` and `end` query parameters or a `Range`
+///     header,
+///   * `GET /sequence/<id>/metadata`,
+///   * `GET /collection/<digest>`, with an optional `level` (1 or 2) query parameter,
+///   * `GET /comparison/<digest>/<digest>`,
+///   * `POST /comparison/<digest>`, with a level 2 collection as the body, and
+///   * `GET /list/collection`.
+pub struct Server {
+    src: PathBuf,
+    reader: Mutex<IndexedReader>,
+    sequences: Vec<Sequence>,
+    ids: HashMap<String, usize>,
+    collection: SequenceCollection,
+    digest: String,
+}
+
+type IndexedReader = fasta::io::IndexedReader<fasta::io::BufReader<File>>;
+
+impl Server {
+    fn new(
+        src: PathBuf,
+        reader: IndexedReader,
+        sequences: Vec<Sequence>,
+        collection: SequenceCollection,
+    ) -> Self {
+        let ids = sequences
+            .iter()
+            .enumerate()
+            .flat_map(|(i, sequence)| [(sequence.md5.clone(), i), (sequence.ga4gh.clone(), i)])
+            .collect();
+
+        // The collection always has sequence digests.
+        let digest = collection.digest().unwrap_or_default();
+
+        Self {
+            src,
+            reader: Mutex::new(reader),
+            sequences,
+            ids,
+            collection,
+            digest,
+        }
+    }
+
+    /// Returns the path to the FASTA.
+    pub fn src(&self) -> &Path {
+        &self.src
+    }
+
+    /// Returns the sequence collection of the FASTA.
+    pub fn collection(&self) -> &SequenceCollection {
+        &self.collection
+    }
+
+    /// Returns the level 0 digest of the sequence collection.
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// Handles an HTTP request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_refget::server;
+    ///
+    /// let server = server::Builder::default().build_from_path("reference.fa")?;
+    ///
+    /// let request = http::Request::get("/sequence/service-info").body(Vec::new())?;
+    /// let response = server.handle(&request);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn handle<B>(&self, request: &http::Request<B>) -> http::Response<Body>
+    where
+        B: AsRef<[u8]>,
+    {
+        match self.route(request) {
+            Ok(response) => response,
+            Err(e) => build_response(e.status, TEXT_CONTENT_TYPE, e.message.into_bytes()),
+        }
+    }
+
+    /// Serves HTTP/1.1 requests from the given listener.
+    ///
+    /// Connections are handled concurrently, one request per connection (see
+    /// [`noodles_http::serve`]). This is intended for local use and testing. Failures of
+    /// individual connections are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::net::TcpListener;
+    /// use noodles_refget::server;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080")?;
+    /// let server = server::Builder::default().build_from_path("reference.fa")?;
+    /// server.serve(&listener)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn serve(&self, listener: &TcpListener) -> io::Result<()> {
+        noodles_http::serve(listener, |request| self.handle(request))
+    }
+
+    fn route<B>(&self, request: &http::Request<B>) -> Result<http::Response<Body>, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        let segments: Vec<_> = request
+            .uri()
+            .path()
+            .trim_matches('/')
+            .split('/')
+            .map(|s| percent_decode_str(s).decode_utf8_lossy())
+            .collect();
+
+        let segments: Vec<_> = segments.iter().map(|s| s.as_ref()).collect();
+        let method = request.method();
+        let query = request.uri().query();
+
+        match (method, &segments[..]) {
+            (&Method::GET, ["sequence", "service-info"]) => {
+                json_response(StatusCode::OK, METADATA_CONTENT_TYPE, &service_info())
+            }
+            (&Method::GET, ["sequence", id]) => {
+                let i = self.resolve(id)?;
+                let range = request.headers().get(header::RANGE);
+                self.get_sequence(i, query, range)
+            }
+            (&Method::GET, ["sequence", id, "metadata"]) => {
+                let i = self.resolve(id)?;
+                let metadata = self.metadata(i);
+                json_response(StatusCode::OK, METADATA_CONTENT_TYPE, &metadata)
+            }
+            (&Method::GET, ["collection", digest]) => {
+                self.resolve_collection(digest)?;
+
+                match parse_level(query)? {
+                    1 => {
+                        json_response(StatusCode::OK, JSON_CONTENT_TYPE, &self.collection.level1())
+                    }
+                    _ => json_response(StatusCode::OK, JSON_CONTENT_TYPE, &self.collection),
+                }
+            }
+            (&Method::GET, ["comparison", a, b]) => {
+                let a = self.resolve_collection(a)?;
+                let b = self.resolve_collection(b)?;
+                let comparison = a.compare(b);
+                json_response(StatusCode::OK, JSON_CONTENT_TYPE, &comparison)
+            }
+            (&Method::POST, ["comparison", a]) => {
+                let a = self.resolve_collection(a)?;
+
+                let b: SequenceCollection = serde_json::from_slice(request.body().as_ref())
+                    .map_err(|e| Error::bad_request(format!("invalid collection: {e}")))?;
+
+                let comparison = a.compare(&b);
+                json_response(StatusCode::OK, JSON_CONTENT_TYPE, &comparison)
+            }
+            (&Method::GET, ["list", "collection"]) => {
+                let list = serde_json::json!({
+                    "results": [&self.digest],
+                    "pagination": { "page": 0, "page_size": 1, "total": 1 },
+                });
+
+                json_response(StatusCode::OK, JSON_CONTENT_TYPE, &list)
+            }
+            _ => Err(Error::not_found("endpoint not found")),
+        }
+    }
+
+    fn resolve(&self, id: &str) -> Result<usize, Error> {
+        let id = id.strip_prefix(MD5_PREFIX).unwrap_or(id);
+        let id = id.strip_prefix(GA4GH_PREFIX).unwrap_or(id);
+
+        self.ids
+            .get(id)
+            .or_else(|| self.ids.get(&id.to_ascii_lowercase()))
+            .copied()
+            .ok_or_else(|| Error::not_found(format!("sequence not found: {id}")))
+    }
+
+    // Only the collection of the FASTA is served.
+    fn resolve_collection(&self, digest: &str) -> Result<&SequenceCollection, Error> {
+        if digest == self.digest {
+            Ok(&self.collection)
+        } else {
+            Err(Error::not_found(format!("collection not found: {digest}")))
+        }
+    }
+
+    fn get_sequence(
+        &self,
+        i: usize,
+        query: Option<&str>,
+        range: Option<&header::HeaderValue>,
+    ) -> Result<http::Response<Body>, Error> {
+        let sequence = &self.sequences[i];
+        let length = sequence.length;
+
+        let (start, end) = parse_start_end(query)?;
+
+        if let Some(value) = range {
+            if start.is_some() || end.is_some() {
+                return Err(Error::bad_request(
+                    "Range header and start/end parameters are mutually exclusive",
+                ));
+            }
+
+            let (range_start, range_end) = parse_range(value)?;
+
+            if range_start >= length {
+                return Err(Error::range_not_satisfiable());
+            }
+
+            let buf = self.read(i, range_start, range_end.saturating_add(1).min(length))?;
+
+            return Ok(build_response(
+                StatusCode::PARTIAL_CONTENT,
+                SEQUENCE_CONTENT_TYPE,
+                buf,
+            ));
+        }
+
+        let start = start.unwrap_or(0);
+        let end = end.unwrap_or(length);
+
+        if (start >= length && length > 0) || end > length {
+            return Err(Error::range_not_satisfiable());
+        }
+
+        let buf = if start <= end {
+            self.read(i, start, end)?
+        } else if sequence.is_circular {
+            let mut buf = self.read(i, start, length)?;
+            buf.extend(self.read(i, 0, end)?);
+            buf
+        } else {
+            return Err(Error::range_not_satisfiable());
+        };
+
+        Ok(build_response(StatusCode::OK, SEQUENCE_CONTENT_TYPE, buf))
+    }
+
+    // Reads the subsequence in the 0-based, half-open interval [start, end).
+    fn read(&self, i: usize, start: u64, end: u64) -> io::Result<Vec<u8>> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        let to_position = |n: u64| {
+            usize::try_from(n)
+                .ok()
+                .and_then(Position::new)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))
+        };
+
+        let interval = to_position(start + 1)?..=to_position(end)?;
+        let region = Region::new(self.sequences[i].name.as_str(), interval);
+
+        let record = self
+            .reader
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .query(&region)?;
+
+        let mut buf: Vec<u8> = record.sequence().as_ref().to_vec();
+        buf.make_ascii_uppercase();
+
+        Ok(buf)
+    }
+
+    fn metadata(&self, i: usize) -> serde_json::Value {
+        let sequence = &self.sequences[i];
+
+        serde_json::json!({
+            "metadata": {
+                "md5": sequence.md5,
+                "ga4gh": sequence.ga4gh,
+                "length": sequence.length,
+                "aliases": [
+                    { "alias": sequence.name, "naming_authority": "unknown" },
+                ],
+            },
+        })
+    }
+}
+
+struct Error {
+    status: StatusCode,
+    message: String,
+}
+
+impl Error {
+    fn new<M>(status: StatusCode, message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn bad_request<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    fn not_found<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    fn range_not_satisfiable() -> Self {
+        Self::new(StatusCode::RANGE_NOT_SATISFIABLE, "range not satisfiable")
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+    }
+}
+
+fn service_info() -> serde_json::Value {
+    serde_json::json!({
+        "id": "noodles-refget",
+        "name": "noodles refget server",
+        "type": {
+            "group": "org.ga4gh",
+            "artifact": "refget",
+            "version": "2.0.0",
+        },
+        "version": env!("CARGO_PKG_VERSION"),
+        "refget": {
+            "circular_supported": true,
+            "algorithms": ["md5", "ga4gh"],
+            "identifier_types": [],
+            "subsequence_limit": null,
+        },
+    })
+}
+
+fn query_pairs(query: Option<&str>) -> impl Iterator<Item = (String, String)> + '_ {
+    url::form_urlencoded::parse(query.unwrap_or_default().as_bytes()).into_owned()
+}
+
+// Parses the 0-based `start` (inclusive) and `end` (exclusive) query parameters.
+fn parse_start_end(query: Option<&str>) -> Result<(Option<u64>, Option<u64>), Error> {
+    let mut start = None;
+    let mut end = None;
+
+    for (key, value) in query_pairs(query) {
+        let dst = match key.as_str() {
+            "start" => &mut start,
+            "end" => &mut end,
+            _ => continue,
+        };
+
+        let n = value
+            .parse()
+            .map_err(|_| Error::bad_request(format!("invalid {key}: {value}")))?;
+
+        *dst = Some(n);
+    }
+
+    Ok((start, end))
+}
+
+fn parse_level(query: Option<&str>) -> Result<u8, Error> {
+    match query_pairs(query).find(|(key, _)| key == "level") {
+        Some((_, value)) => match value.as_str() {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err(Error::bad_request(format!("invalid level: {value}"))),
+        },
+        None => Ok(2),
+    }
+}
+
+// Parses a single, 0-based, inclusive byte range, e.g., `bytes=8-13`.
+fn parse_range(value: &header::HeaderValue) -> Result<(u64, u64), Error> {
+    fn invalid_range() -> Error {
+        Error::bad_request("invalid Range header")
+    }
+
+    let (start, end) = value
+        .to_str()
+        .ok()
+        .and_then(|s| s.strip_prefix("bytes="))
+        .and_then(|s| s.split_once('-'))
+        .ok_or_else(invalid_range)?;
+
+    let start: u64 = start.parse().map_err(|_| invalid_range())?;
+    let end: u64 = end.parse().map_err(|_| invalid_range())?;
+
+    if start > end {
+        return Err(invalid_range());
+    }
+
+    Ok((start, end))
+}
+
+fn json_response<T>(
+    status: StatusCode,
+    content_type: &'static str,
+    value: &T,
+) -> Result<http::Response<Body>, Error>
+where
+    T: Serialize,
+{
+    let body =
+        serde_json::to_vec(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(build_response(status, content_type, body))
+}
+
+fn build_response(
+    status: StatusCode,
+    content_type: &'static str,
+    body: Vec<u8>,
+) -> http::Response<Body> {
+    let mut response = http::Response::new(Body::from(body));
+    *response.status_mut() = status;
+
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(content_type),
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process, thread};
+
+    use super::*;
+
+    // The `base.fa` example from the seqcol specification.
+    const DATA: &[u8] = b">chrX\nTTGGGGAA\n>chr1\nGGAA\n>chr2\nGCGC\n";
+    const COLLECTION_DIGEST: &str = "XZlrcEGi6mlopZ2uD8ObHkQB1d0oDwKk";
+    const CHRX_MD5: &str = "5f63cfaa3ef61f88c9635fb9d18ec945";
+    const CHRX_GA4GH: &str = "SQ.iYtREV555dUFKg2_agSJW6suquUyPpMw";
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> io::Result<Self> {
+            let path =
+                env::temp_dir().join(format!("noodles-refget-server-{}-{name}", process::id()));
+
+            fs::create_dir_all(&path)?;
+
+            Ok(Self(path))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn build_server(dir: &TempDir) -> io::Result<Server> {
+        let src = dir.0.join("base.fa");
+        fs::write(&src, DATA)?;
+
+        let index = fasta::fs::index(&src)?;
+        fasta::fai::fs::write(dir.0.join("base.fa.fai"), &index)?;
+
+        Builder::default()
+            .add_circular_sequence("chrX")
+            .build_from_path(src)
+    }
+
+    fn handle(server: &Server, request: &http::Request<Vec<u8>>) -> http::Response<Vec<u8>> {
+        server.handle(request).map(|body| {
+            let mut buf = Vec::new();
+            body.write_to(&mut buf).unwrap();
+            buf
+        })
+    }
+
+    fn get(server: &Server, uri: &str) -> http::Response<Vec<u8>> {
+        let request = http::Request::get(uri).body(Vec::new()).unwrap();
+        handle(server, &request)
+    }
+
+    #[test]
+    fn test_build_from_path() -> io::Result<()> {
+        let dir = TempDir::new("build_from_path")?;
+        let server = build_server(&dir)?;
+
+        assert_eq!(server.digest(), COLLECTION_DIGEST);
+        assert_eq!(server.collection().names(), ["chrX", "chr1", "chr2"]);
+
+        assert_eq!(server.sequences[0].md5, CHRX_MD5);
+        assert_eq!(server.sequences[0].ga4gh, CHRX_GA4GH);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_sequence() -> io::Result<()> {
+        let dir = TempDir::new("handle_sequence")?;
+        let server = build_server(&dir)?;
+
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}"));
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE),
+            Some(&header::HeaderValue::from_static(SEQUENCE_CONTENT_TYPE))
+        );
+        assert_eq!(response.body(), b"TTGGGGAA");
+
+        for id in [
+            CHRX_GA4GH.to_string(),
+            format!("ga4gh:{CHRX_GA4GH}"),
+            format!("md5:{}", CHRX_MD5.to_ascii_uppercase()),
+        ] {
+            let response = get(&server, &format!("/sequence/{id}"));
+            assert_eq!(response.body(), b"TTGGGGAA");
+        }
+
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}?start=2&end=5"));
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"GGG");
+
+        // chrX is circular.
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}?start=6&end=2"));
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"AATT");
+
+        let chr1_md5 = &server.sequences[1].md5;
+        let response = get(&server, &format!("/sequence/{chr1_md5}?start=3&end=1"));
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}?start=8"));
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}?end=9"));
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+
+        let response = get(&server, &format!("/sequence/{CHRX_MD5}?start=a"));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let request = http::Request::get(format!("/sequence/{CHRX_MD5}"))
+            .header(header::RANGE, "bytes=2-13")
+            .body(Vec::new())
+            .unwrap();
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.body(), b"GGGGAA");
+
+        let request = http::Request::get(format!("/sequence/{CHRX_MD5}?start=2"))
+            .header(header::RANGE, "bytes=2-5")
+            .body(Vec::new())
+            .unwrap();
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let request = http::Request::get(format!("/sequence/{CHRX_MD5}"))
+            .header(header::RANGE, "bytes=8-13")
+            .body(Vec::new())
+            .unwrap();
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+
+        let response = get(&server, "/sequence/d7eba311421bbc9d3ada44709dd61534");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_metadata() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("handle_metadata")?;
+        let server = build_server(&dir)?;
+
+        let response = get(&server, &format!("/sequence/{CHRX_GA4GH}/metadata"));
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let actual: serde_json::Value = serde_json::from_slice(response.body())?;
+        let expected = serde_json::json!({
+            "metadata": {
+                "md5": CHRX_MD5,
+                "ga4gh": CHRX_GA4GH,
+                "length": 8,
+                "aliases": [{ "alias": "chrX", "naming_authority": "unknown" }],
+            },
+        });
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_collection() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("handle_collection")?;
+        let server = build_server(&dir)?;
+
+        let response = get(&server, &format!("/collection/{COLLECTION_DIGEST}"));
+        assert_eq!(response.status(), StatusCode::OK);
+        let actual: SequenceCollection = serde_json::from_slice(response.body())?;
+        assert_eq!(&actual, server.collection());
+
+        let response = get(&server, &format!("/collection/{COLLECTION_DIGEST}?level=1"));
+        assert_eq!(response.status(), StatusCode::OK);
+        let actual: serde_json::Value = serde_json::from_slice(response.body())?;
+        assert_eq!(actual["names"], "Fw1r9eRxfOZD98KKrhlYQNEdSRHoVxAG");
+        assert_eq!(actual["sequences"], "0uDQVLuHaOZi1u76LjV__yrVUIz9Bwhr");
+
+        let response = get(&server, &format!("/collection/{COLLECTION_DIGEST}?level=0"));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = get(&server, "/collection/0uDQVLuHaOZi1u76LjV__yrVUIz9Bwhr");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = get(&server, "/list/collection");
+        let actual: serde_json::Value = serde_json::from_slice(response.body())?;
+        assert_eq!(actual["results"], serde_json::json!([COLLECTION_DIGEST]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_comparison() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("handle_comparison")?;
+        let server = build_server(&dir)?;
+
+        let response = get(
+            &server,
+            &format!("/comparison/{COLLECTION_DIGEST}/{COLLECTION_DIGEST}"),
+        );
+        assert_eq!(response.status(), StatusCode::OK);
+        let actual: serde_json::Value = serde_json::from_slice(response.body())?;
+        assert_eq!(actual["digests"]["a"], COLLECTION_DIGEST);
+        assert_eq!(actual["digests"]["b"], COLLECTION_DIGEST);
+
+        const UNKNOWN_DIGEST: &str = "0uDQVLuHaOZi1u76LjV__yrVUIz9Bwhr";
+
+        for uri in [
+            format!("/comparison/{UNKNOWN_DIGEST}/{COLLECTION_DIGEST}"),
+            format!("/comparison/{COLLECTION_DIGEST}/{UNKNOWN_DIGEST}"),
+        ] {
+            let response = get(&server, &uri);
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        let request = http::Request::post(format!("/comparison/{UNKNOWN_DIGEST}"))
+            .body(br#"{"names":["chr1"],"lengths":[4]}"#.to_vec())?;
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // e.g., the reference sequences of an alignment header
+        let body = br#"{"names":["chr1","chr2","chrX"],"lengths":[4,4,8]}"#;
+        let request =
+            http::Request::post(format!("/comparison/{COLLECTION_DIGEST}")).body(body.to_vec())?;
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let actual: serde_json::Value = serde_json::from_slice(response.body())?;
+        assert_eq!(actual["digests"]["a"], COLLECTION_DIGEST);
+        assert_eq!(
+            actual["attributes"]["a_only"],
+            serde_json::json!(["sequences"])
+        );
+        assert_eq!(actual["array_elements"]["a_and_b_count"]["names"], 3);
+        assert_eq!(
+            actual["array_elements"]["a_and_b_same_order"]["names"],
+            false
+        );
+
+        let request = http::Request::post(format!("/comparison/{COLLECTION_DIGEST}"))
+            .body(br#"{"names":["chr1"],"lengths":[]}"#.to_vec())?;
+        let response = handle(&server, &request);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_serve() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        let dir = TempDir::new("serve")?;
+        let server = build_server(&dir)?;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        thread::spawn(move || server.serve(&listener));
+
+        let client = crate::Client::new(base_url);
+
+        let sequence = client
+            .sequence(CHRX_MD5)
+            .set_interval(Position::try_from(3)?..=Position::try_from(5)?)
+            .send()
+            .await?;
+        assert_eq!(sequence.sequence(), &b"GGG"[..]);
+
+        let metadata = sequence.metadata().send().await?;
+        assert_eq!(metadata.ga4gh(), Some(CHRX_GA4GH));
+        assert_eq!(metadata.length(), 8);
+
+        let service = client.service_info().send().await?;
+        assert!(service.circular_supported());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::{collections::HashSet, io, path::Path};
+
+use noodles_fasta as fasta;
+
+use super::{Sequence, Server};
+use crate::{digest, seqcol::SequenceCollection};
+
+/// A refget server builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    circular_sequence_names: HashSet<String>,
+}
+
+impl Builder {
+    /// Marks a sequence as circular.
+    ///
+    /// Subsequences of circular sequences can wrap around the origin, i.e., have a start greater
+    /// than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::server;
+    /// let builder = server::Builder::default().add_circular_sequence("chrM");
+    /// ```
+    pub fn add_circular_sequence<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.circular_sequence_names.insert(name.into());
+        self
+    }
+
+    /// Builds a refget server from a path to an indexed FASTA.
+    ///
+    /// The FASTA must have an associated index (`<src>.fai`). It can be bgzip-compressed. The
+    /// digests of all sequences are computed when the server is built.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_refget::server;
+    /// let server = server::Builder::default().build_from_path("reference.fa")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Server>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let mut reader = fasta::io::indexed_reader::Builder::default().build_from_path(src)?;
+        let index = reader.index().clone();
+
+        let mut sequences = Vec::with_capacity(index.as_ref().len());
+        let mut definition = String::new();
+        let mut buf = Vec::new();
+
+        for record in index.as_ref() {
+            definition.clear();
+
+            if reader.read_definition(&mut definition)? == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "missing FASTA record",
+                ));
+            }
+
+            buf.clear();
+            reader.read_sequence(&mut buf)?;
+
+            let sequence = digest::normalize(&buf);
+            let name = record.name().to_string();
+            let is_circular = self.circular_sequence_names.contains(&name);
+
+            sequences.push(Sequence {
+                name,
+                length: record.length(),
+                md5: digest::md5(&sequence),
+                ga4gh: digest::sequence_digest(&sequence),
+                is_circular,
+            });
+        }
+
+        let collection = SequenceCollection::new(
+            sequences.iter().map(|s| s.name.clone()).collect(),
+            sequences.iter().map(|s| s.length).collect(),
+            Some(sequences.iter().map(|s| s.ga4gh.clone()).collect()),
+        )?;
+
+        Ok(Server::new(src.into(), reader, sequences, collection))
+    }
+}
@@ -2,10 +2,13 @@
 
 mod builder;
 
-use std::io::{self, Read, Seek};
+use std::{
+    borrow::Cow,
+    io::{self, Read, Seek},
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 use noodles_csi::BinningIndex;
 
 pub use self::builder::Builder;
@@ -16,6 +19,7 @@ use crate::{Header, Record, alignment::RecordBuf};
 pub struct IndexedReader<R> {
     inner: Reader<bgzf::io::Reader<R>>,
     index: Box<dyn BinningIndex>,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R> {
@@ -47,6 +51,7 @@ where
         Self {
             inner: Reader::new(bgzf::io::Reader::new(inner)),
             index: Box::new(index),
+            aliases: Aliases::default(),
         }
     }
 
@@ -87,6 +92,16 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -99,7 +114,8 @@ where
         header: &'h Header,
         region: &Region,
     ) -> io::Result<impl Iterator<Item = io::Result<Record>> + use<'r, 'h, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = resolve_region(&self.aliases, header, region);
+        self.inner.query(header, &self.index, &region)
     }
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
@@ -109,3 +125,8 @@ where
         self.inner.query_unmapped(&self.index)
     }
 }
+
+fn resolve_region<'r>(aliases: &Aliases, header: &Header, region: &'r Region) -> Cow<'r, Region> {
+    let reference_sequences = header.reference_sequences();
+    aliases.resolve_region(region, |name| reference_sequences.contains_key(name))
+}
@@ -5,6 +5,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use noodles_core::region::Aliases;
 use noodles_csi::{self as csi, BinningIndex};
 
 use super::IndexedReader;
@@ -13,6 +14,7 @@ use super::IndexedReader;
 #[derive(Default)]
 pub struct Builder {
     index: Option<Box<dyn BinningIndex>>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -35,6 +37,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the header are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_sam::io::indexed_reader::Builder;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed SAM reader from a path.
     ///
     /// # Examples
@@ -81,7 +103,10 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        let mut reader = IndexedReader::new(reader, index);
+        *reader.aliases_mut() = self.aliases;
+
+        Ok(reader)
     }
 }
 
@@ -70,6 +70,74 @@ impl Indexer {
         self.indexer.add_record(alignment_context, chunk)
     }
 
+    /// Merges an indexer that indexed the records directly following the records of this indexer.
+    ///
+    /// The result is the same as adding all the records to a single indexer. The header of
+    /// `other` is discarded.
+    ///
+    /// This returns an error if the records of `other` are not sorted after those of this
+    /// indexer, i.e., if a reference sequence name reappears after a different one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{binning_index::index::reference_sequence::bin::Chunk, BinningIndex};
+    /// use noodles_tabix::index::Indexer;
+    ///
+    /// let mut indexer = Indexer::default();
+    /// indexer.add_record("sq0", Position::try_from(8)?, Position::try_from(13)?, Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// ))?;
+    ///
+    /// let mut other = Indexer::default();
+    /// other.add_record("sq1", Position::try_from(21)?, Position::try_from(34)?, Chunk::new(
+    ///     bgzf::VirtualPosition::from(233),
+    ///     bgzf::VirtualPosition::from(377),
+    /// ))?;
+    ///
+    /// indexer.merge(other)?;
+    ///
+    /// let index = indexer.build();
+    /// assert_eq!(index.header().map(|header| header.reference_sequence_names().len()), Some(2));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(&mut self, other: Self) -> io::Result<()> {
+        let mut names = other.reference_sequence_names.into_iter().peekable();
+
+        // The records of `other` can continue the last reference sequence of this indexer.
+        let is_continuation = match (self.reference_sequence_names.last(), names.peek()) {
+            (Some(last_name), Some(first_name)) => last_name == first_name,
+            _ => false,
+        };
+
+        let offset = if is_continuation {
+            names.next();
+            self.reference_sequence_names.len() - 1
+        } else {
+            self.reference_sequence_names.len()
+        };
+
+        let names: Vec<_> = names.collect();
+
+        if names
+            .iter()
+            .any(|name| self.reference_sequence_names.contains(name))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid reference sequence ID",
+            ));
+        }
+
+        self.indexer.merge_with_offset(other.indexer, offset)?;
+        self.reference_sequence_names.extend(names);
+
+        Ok(())
+    }
+
     /// Builds a tabix index.
     ///
     /// # Examples
@@ -88,3 +156,107 @@ impl Indexer {
             .build(reference_sequence_count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    fn build_records() -> Vec<(&'static str, Position, Position, Chunk)> {
+        [
+            ("sq0", 8),
+            ("sq0", 20000),
+            ("sq1", 13),
+            ("sq2", 21),
+            ("sq2", 55),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, start))| {
+            let start = Position::try_from(start).unwrap();
+            let end = start.checked_add(5).unwrap();
+            let position = i as u64 * 100;
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(position),
+                bgzf::VirtualPosition::from(position + 100),
+            );
+            (name, start, end, chunk)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let records = build_records();
+
+        for i in 0..=records.len() {
+            let mut actual = Indexer::default();
+
+            for &(name, start, end, chunk) in &records[..i] {
+                actual.add_record(name, start, end, chunk)?;
+            }
+
+            let mut other = Indexer::default();
+
+            for &(name, start, end, chunk) in &records[i..] {
+                other.add_record(name, start, end, chunk)?;
+            }
+
+            actual.merge(other)?;
+
+            let mut expected = Indexer::default();
+
+            for &(name, start, end, chunk) in &records {
+                expected.add_record(name, start, end, chunk)?;
+            }
+
+            assert_eq!(actual.build(), expected.build());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_unsorted_records() -> io::Result<()> {
+        let records = build_records();
+        let (name, start, end, chunk) = records[0];
+
+        let mut indexer = Indexer::default();
+        indexer.add_record(name, start, end, chunk)?;
+        indexer.add_record("sq1", start, end, chunk)?;
+
+        let mut other = Indexer::default();
+        other.add_record(name, start, end, chunk)?;
+
+        assert!(matches!(
+            indexer.merge(other),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_leaves_indexer_unchanged_on_error() -> io::Result<()> {
+        let records = build_records();
+        let (name, start, end, chunk) = records[0];
+
+        let mut indexer = Indexer::default();
+        indexer.add_record(name, start, end, chunk)?;
+        indexer.add_record("sq1", start, end, chunk)?;
+
+        let mut expected = Indexer::default();
+        expected.add_record(name, start, end, chunk)?;
+        expected.add_record("sq1", start, end, chunk)?;
+
+        let mut other = Indexer::default();
+        other.add_record("sq2", start, end, chunk)?;
+        other.add_record(name, start, end, chunk)?;
+
+        assert!(indexer.merge(other).is_err());
+        assert_eq!(indexer.build(), expected.build());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! Creates a reference sequence dictionary from a FASTA file.
+//!
+//! The result is similar to the output of `picard CreateSequenceDictionary --REFERENCE <src>
+//! --OUTPUT /dev/stdout`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_sam as sam;
+use noodles_util::reference::dictionary;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let assembly_id = args.next();
+
+    let mut builder = dictionary::Builder::default();
+
+    if let Some(assembly_id) = assembly_id {
+        builder = builder.set_assembly_id(assembly_id);
+    }
+
+    let dictionary = builder.build_from_path(src)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&dictionary)?;
+
+    Ok(())
+}
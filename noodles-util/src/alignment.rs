@@ -6,5 +6,6 @@ pub mod r#async;
 pub mod io;
 pub mod iter;
 mod record;
+pub mod transform;
 
 pub use self::record::Record;
@@ -5,6 +5,7 @@ use std::{
 };
 
 use noodles_bam as bam;
+use noodles_core::region::Aliases;
 use noodles_cram::{self as cram, crai};
 use noodles_csi::{
     self as csi, BinningIndex,
@@ -52,6 +53,7 @@ pub struct Builder {
     format: Option<Format>,
     reference_sequence_repository: fasta::Repository,
     index: Option<Index>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -121,6 +123,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the input are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_util::alignment;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = alignment::io::indexed_reader::Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed alignment reader from a path.
     ///
     /// The compression method and format will be autodetected, if not overridden. If no index is
@@ -157,7 +179,8 @@ impl Builder {
                 "source not bgzip-compressed",
             )),
             (Format::Sam, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = sam::io::indexed_reader::Builder::default();
+                let mut builder =
+                    sam::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(Index::Csi(index)) = self.index {
                     builder = builder.set_index(index);
@@ -166,7 +189,8 @@ impl Builder {
                 builder.build_from_path(src).map(IndexedReader::Sam)
             }
             (Format::Bam, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = bam::io::indexed_reader::Builder::default();
+                let mut builder =
+                    bam::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(Index::Csi(index)) = self.index {
                     builder = builder.set_index(index);
@@ -176,7 +200,8 @@ impl Builder {
             }
             (Format::Cram, None) => {
                 let mut builder = cram::io::indexed_reader::Builder::default()
-                    .set_reference_sequence_repository(self.reference_sequence_repository);
+                    .set_reference_sequence_repository(self.reference_sequence_repository)
+                    .set_aliases(self.aliases);
 
                 if let Some(Index::Crai(index)) = self.index {
                     builder = builder.set_index(index);
@@ -236,7 +261,8 @@ impl Builder {
                 "source not bgzip-compressed",
             )),
             (Format::Sam, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = sam::io::indexed_reader::Builder::default();
+                let mut builder =
+                    sam::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(Index::Csi(index)) = self.index {
                     builder = builder.set_index(index);
@@ -245,7 +271,8 @@ impl Builder {
                 builder.build_from_reader(reader).map(IndexedReader::Sam)
             }
             (Format::Bam, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = bam::io::indexed_reader::Builder::default();
+                let mut builder =
+                    bam::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(Index::Csi(index)) = self.index {
                     builder = builder.set_index(index);
@@ -255,7 +282,8 @@ impl Builder {
             }
             (Format::Cram, None) => {
                 let mut builder = cram::io::indexed_reader::Builder::default()
-                    .set_reference_sequence_repository(self.reference_sequence_repository);
+                    .set_reference_sequence_repository(self.reference_sequence_repository)
+                    .set_aliases(self.aliases);
 
                 if let Some(Index::Crai(index)) = self.index {
                     builder = builder.set_index(index);
@@ -0,0 +1,18 @@
+//! Lossy alignment record transforms.
+//!
+//! A [`Transformer`] applies optional lossy transforms to alignment records, e.g., quality score
+//! binning, replacing quality scores on bases that match the reference sequence, and stripping
+//! read names. Transforms are configured with [`Options`] and can be overridden per read group.
+//!
+//! A transform [`Writer`] applies a transformer in front of any alignment writer, e.g., a
+//! [`noodles_bam::io::Writer`] or [`noodles_cram::io::Writer`].
+
+mod options;
+pub mod quality_score_binner;
+pub mod transformer;
+pub mod writer;
+
+pub use self::{
+    options::Options, quality_score_binner::QualityScoreBinner, transformer::Transformer,
+    writer::Writer,
+};
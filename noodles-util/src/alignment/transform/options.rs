@@ -0,0 +1,91 @@
+use super::QualityScoreBinner;
+
+/// Lossy alignment record transform options.
+///
+/// By default, no transforms are applied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    quality_score_binner: Option<QualityScoreBinner>,
+    matching_base_quality_score: Option<u8>,
+    preserve_read_names: bool,
+}
+
+impl Options {
+    /// Sets the quality score binner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::{Options, QualityScoreBinner};
+    /// let options = Options::default()
+    ///     .set_quality_score_binner(QualityScoreBinner::illumina_8_level());
+    /// ```
+    pub fn set_quality_score_binner(mut self, quality_score_binner: QualityScoreBinner) -> Self {
+        self.quality_score_binner = Some(quality_score_binner);
+        self
+    }
+
+    /// Returns the quality score binner.
+    pub fn quality_score_binner(&self) -> Option<&QualityScoreBinner> {
+        self.quality_score_binner.as_ref()
+    }
+
+    /// Sets the quality score to use for read bases that match the reference sequence.
+    ///
+    /// This requires a reference sequence repository to be set on the transformer. Quality scores
+    /// of mismatched, inserted, and soft clipped bases are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::Options;
+    /// let options = Options::default().set_matching_base_quality_score(30);
+    /// ```
+    pub fn set_matching_base_quality_score(mut self, score: u8) -> Self {
+        self.matching_base_quality_score = Some(score);
+        self
+    }
+
+    /// Returns the quality score to use for read bases that match the reference sequence.
+    pub fn matching_base_quality_score(&self) -> Option<u8> {
+        self.matching_base_quality_score
+    }
+
+    /// Sets whether to preserve read names.
+    ///
+    /// If `false`, read names are discarded.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::Options;
+    /// let options = Options::default().preserve_read_names(false);
+    /// ```
+    pub fn preserve_read_names(mut self, value: bool) -> Self {
+        self.preserve_read_names = value;
+        self
+    }
+
+    /// Returns whether read names are preserved.
+    pub fn preserves_read_names(&self) -> bool {
+        self.preserve_read_names
+    }
+
+    pub(super) fn is_lossless(&self) -> bool {
+        self.quality_score_binner.is_none()
+            && self.matching_base_quality_score.is_none()
+            && self.preserve_read_names
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            quality_score_binner: None,
+            matching_base_quality_score: None,
+            preserve_read_names: true,
+        }
+    }
+}
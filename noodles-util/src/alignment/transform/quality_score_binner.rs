@@ -0,0 +1,130 @@
+//! Alignment record quality score binner.
+
+use std::ops::RangeInclusive;
+
+const ILLUMINA_8_LEVEL_BINS: [(RangeInclusive<u8>, u8); 7] = [
+    (2..=9, 6),
+    (10..=19, 15),
+    (20..=24, 22),
+    (25..=29, 27),
+    (30..=34, 33),
+    (35..=39, 37),
+    (40..=u8::MAX, 40),
+];
+
+/// A quality score binner.
+///
+/// This maps quality scores to a representative value of the bin they fall in. Quality scores
+/// that are not in any bin are left unchanged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QualityScoreBinner([u8; 256]);
+
+impl QualityScoreBinner {
+    /// Creates a quality score binner from a list of bins.
+    ///
+    /// Each bin is a range of quality scores and the value the scores are mapped to. If bins
+    /// overlap, later bins take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::QualityScoreBinner;
+    /// let binner = QualityScoreBinner::new([(0..=19, 10), (20..=u8::MAX, 30)]);
+    /// assert_eq!(binner.bin(8), 10);
+    /// assert_eq!(binner.bin(35), 30);
+    /// ```
+    pub fn new<I>(bins: I) -> Self
+    where
+        I: IntoIterator<Item = (RangeInclusive<u8>, u8)>,
+    {
+        let mut table = [0; 256];
+
+        for (score, value) in table.iter_mut().zip(u8::MIN..=u8::MAX) {
+            *score = value;
+        }
+
+        for (range, value) in bins {
+            for score in range {
+                table[usize::from(score)] = value;
+            }
+        }
+
+        Self(table)
+    }
+
+    /// Creates a quality score binner using the Illumina 8-level binning scheme.
+    ///
+    /// | score | value |
+    /// |-------|-------|
+    /// | 0–1   | 0–1   |
+    /// | 2–9   | 6     |
+    /// | 10–19 | 15    |
+    /// | 20–24 | 22    |
+    /// | 25–29 | 27    |
+    /// | 30–34 | 33    |
+    /// | 35–39 | 37    |
+    /// | ≥ 40  | 40    |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::QualityScoreBinner;
+    /// let binner = QualityScoreBinner::illumina_8_level();
+    /// assert_eq!(binner.bin(1), 1);
+    /// assert_eq!(binner.bin(13), 15);
+    /// assert_eq!(binner.bin(41), 40);
+    /// ```
+    pub fn illumina_8_level() -> Self {
+        Self::new(ILLUMINA_8_LEVEL_BINS)
+    }
+
+    /// Returns the binned value of a quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::QualityScoreBinner;
+    /// let binner = QualityScoreBinner::new([(0..=19, 10)]);
+    /// assert_eq!(binner.bin(5), 10);
+    /// assert_eq!(binner.bin(25), 25);
+    /// ```
+    pub fn bin(&self, score: u8) -> u8 {
+        self.0[usize::from(score)]
+    }
+
+    /// Bins a list of quality scores in place.
+    pub fn bin_all(&self, scores: &mut [u8]) {
+        for score in scores {
+            *score = self.bin(*score);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let binner = QualityScoreBinner::new([(0..=9, 5), (5..=14, 10)]);
+        assert_eq!(binner.bin(0), 5);
+        assert_eq!(binner.bin(4), 5);
+        assert_eq!(binner.bin(5), 10);
+        assert_eq!(binner.bin(14), 10);
+        assert_eq!(binner.bin(15), 15);
+        assert_eq!(binner.bin(u8::MAX), u8::MAX);
+    }
+
+    #[test]
+    fn test_illumina_8_level() {
+        let binner = QualityScoreBinner::illumina_8_level();
+
+        let mut scores = [0, 1, 2, 9, 10, 19, 20, 24, 25, 29, 30, 34, 35, 39, 40, 93];
+        binner.bin_all(&mut scores);
+
+        assert_eq!(
+            scores,
+            [0, 1, 6, 6, 15, 15, 22, 22, 27, 27, 33, 33, 37, 37, 40, 40]
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Alignment record transformer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{collections::HashMap, io};
+
+use bstr::BString;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        Record, RecordBuf,
+        record::{cigar::op::Kind, data::field::Tag},
+        record_buf::data::field::Value,
+    },
+};
+
+use super::Options;
+
+/// An alignment record transformer.
+///
+/// This applies lossy transforms to alignment records. Options can be set per read group, which
+/// is taken from the read group (`RG`) data field of each record. Records without a read group
+/// or with a read group without options use the default options.
+#[derive(Debug, Default)]
+pub struct Transformer {
+    reference_sequence_repository: fasta::Repository,
+    default_options: Options,
+    read_group_options: HashMap<BString, Options>,
+}
+
+impl Transformer {
+    /// Returns a builder to create a transformer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::Transformer;
+    /// let builder = Transformer::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the default options.
+    pub fn default_options(&self) -> &Options {
+        &self.default_options
+    }
+
+    /// Returns the options used for the given record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag, record_buf::data::field::Value, RecordBuf,
+    /// };
+    /// use noodles_util::alignment::transform::{Options, Transformer};
+    ///
+    /// let options = Options::default().preserve_read_names(false);
+    /// let transformer = Transformer::builder()
+    ///     .set_read_group_options("rg0", options.clone())
+    ///     .build();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_data([(Tag::READ_GROUP, Value::from("rg0"))].into_iter().collect())
+    ///     .build();
+    ///
+    /// assert_eq!(transformer.options(&record), &options);
+    /// ```
+    pub fn options(&self, record: &RecordBuf) -> &Options {
+        match record.data().get(&Tag::READ_GROUP) {
+            Some(Value::String(read_group_id)) => self
+                .read_group_options
+                .get(read_group_id)
+                .unwrap_or(&self.default_options),
+            _ => &self.default_options,
+        }
+    }
+
+    /// Returns whether no options apply any transforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::{Options, Transformer};
+    ///
+    /// assert!(Transformer::default().is_lossless());
+    ///
+    /// let transformer = Transformer::builder()
+    ///     .set_read_group_options("rg0", Options::default().preserve_read_names(false))
+    ///     .build();
+    ///
+    /// assert!(!transformer.is_lossless());
+    /// ```
+    pub fn is_lossless(&self) -> bool {
+        self.default_options.is_lossless()
+            && self.read_group_options.values().all(Options::is_lossless)
+    }
+
+    /// Transforms an alignment record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::transform::{Options, Transformer};
+    ///
+    /// let transformer = Transformer::builder()
+    ///     .set_default_options(Options::default().preserve_read_names(false))
+    ///     .build();
+    ///
+    /// let header = sam::Header::default();
+    /// let mut record = RecordBuf::builder().set_name("r0").build();
+    /// transformer.transform(&header, &mut record)?;
+    ///
+    /// assert!(record.name().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn transform(&self, header: &sam::Header, record: &mut RecordBuf) -> io::Result<()> {
+        let options = self.options(record);
+
+        if !options.preserves_read_names() {
+            *record.name_mut() = None;
+        }
+
+        if let Some(score) = options.matching_base_quality_score() {
+            replace_matching_base_quality_scores(
+                header,
+                &self.reference_sequence_repository,
+                record,
+                score,
+            )?;
+        }
+
+        if let Some(binner) = options.quality_score_binner() {
+            binner.bin_all(record.quality_scores_mut().as_mut());
+        }
+
+        Ok(())
+    }
+
+    /// Converts and transforms an alignment record.
+    ///
+    /// If the options for the record are lossless, the record is only converted.
+    pub fn transform_alignment_record<R>(
+        &self,
+        header: &sam::Header,
+        record: &R,
+    ) -> io::Result<RecordBuf>
+    where
+        R: Record + ?Sized,
+    {
+        let mut record_buf = RecordBuf::try_from_alignment_record(header, record)?;
+
+        if !self.options(&record_buf).is_lossless() {
+            self.transform(header, &mut record_buf)?;
+        }
+
+        Ok(record_buf)
+    }
+}
+
+fn replace_matching_base_quality_scores(
+    header: &sam::Header,
+    reference_sequence_repository: &fasta::Repository,
+    record: &mut RecordBuf,
+    score: u8,
+) -> io::Result<()> {
+    if record.flags().is_unmapped() || record.quality_scores().is_empty() {
+        return Ok(());
+    }
+
+    let (Some(reference_sequence_id), Some(alignment_start)) =
+        (record.reference_sequence_id(), record.alignment_start())
+    else {
+        return Ok(());
+    };
+
+    let (reference_sequence_name, _) = header
+        .reference_sequences()
+        .get_index(reference_sequence_id)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+        })?;
+
+    let reference_sequence = reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })?;
+
+    let positions = matching_base_positions(
+        reference_sequence.as_ref(),
+        usize::from(alignment_start) - 1,
+        record.cigar().as_ref(),
+        record.sequence().as_ref(),
+    );
+
+    let quality_scores = record.quality_scores_mut().as_mut();
+
+    for i in positions {
+        if let Some(s) = quality_scores.get_mut(i) {
+            *s = score;
+        }
+    }
+
+    Ok(())
+}
+
+fn matching_base_positions(
+    reference_bases: &[u8],
+    mut reference_position: usize,
+    ops: &[sam::alignment::record::cigar::Op],
+    read_bases: &[u8],
+) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut read_position = 0;
+
+    for op in ops {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for j in 0..len {
+                    let is_match = match op.kind() {
+                        Kind::SequenceMatch => true,
+                        Kind::SequenceMismatch => false,
+                        _ => match (
+                            read_bases.get(read_position + j),
+                            reference_bases.get(reference_position + j),
+                        ) {
+                            (Some(b'='), Some(_)) => true,
+                            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                            _ => false,
+                        },
+                    };
+
+                    if is_match {
+                        positions.push(read_position + j);
+                    }
+                }
+
+                read_position += len;
+                reference_position += len;
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::Deletion | Kind::Skip => reference_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::record::{Flags, cigar::Op},
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::alignment::transform::QualityScoreBinner;
+
+    #[test]
+    fn test_transform() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTAC".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(const { NonZeroUsize::new(10).unwrap() }),
+            )
+            .build();
+
+        let transformer = Transformer::builder()
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .set_read_group_options(
+                "rg0",
+                Options::default()
+                    .set_quality_score_binner(QualityScoreBinner::illumina_8_level())
+                    .set_matching_base_quality_score(1)
+                    .preserve_read_names(false),
+            )
+            .build();
+
+        let build_record = |read_group_id: Option<&str>| {
+            let mut builder = RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACTT".into())
+                .set_quality_scores(vec![45, 12, 21, 3].into());
+
+            if let Some(id) = read_group_id {
+                builder =
+                    builder.set_data([(Tag::READ_GROUP, Value::from(id))].into_iter().collect());
+            }
+
+            builder.build()
+        };
+
+        let mut record = build_record(None);
+        transformer.transform(&header, &mut record)?;
+        assert_eq!(record, build_record(None));
+
+        let mut record = build_record(Some("rg0"));
+        transformer.transform(&header, &mut record)?;
+        assert!(record.name().is_none());
+        assert_eq!(record.quality_scores().as_ref(), [1, 1, 22, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matching_base_positions() {
+        let reference_bases = b"ACGTACGTAC";
+
+        let ops = [
+            Op::new(Kind::SoftClip, 1),
+            Op::new(Kind::Match, 3),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Deletion, 2),
+            Op::new(Kind::SequenceMatch, 1),
+            Op::new(Kind::SequenceMismatch, 1),
+        ];
+
+        let read_bases = b"NA=AGTA";
+        let positions = matching_base_positions(reference_bases, 0, &ops, read_bases);
+        assert_eq!(positions, [1, 2, 5]);
+    }
+}
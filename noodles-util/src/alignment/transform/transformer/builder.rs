@@ -0,0 +1,92 @@
+//! Alignment record transformer builder.
+
+use std::collections::HashMap;
+
+use bstr::BString;
+use noodles_fasta as fasta;
+
+use super::Transformer;
+use crate::alignment::transform::Options;
+
+/// An alignment record transformer builder.
+#[derive(Default)]
+pub struct Builder {
+    reference_sequence_repository: fasta::Repository,
+    default_options: Options,
+    read_group_options: HashMap<BString, Options>,
+}
+
+impl Builder {
+    /// Sets the reference sequence repository.
+    ///
+    /// This is only used when replacing quality scores of bases that match the reference
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::alignment::transform::Transformer;
+    ///
+    /// let repository = fasta::Repository::default();
+    /// let builder = Transformer::builder().set_reference_sequence_repository(repository);
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = reference_sequence_repository;
+        self
+    }
+
+    /// Sets the default options.
+    ///
+    /// These are used for records that do not have read group specific options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::{Options, Transformer};
+    /// let options = Options::default().preserve_read_names(false);
+    /// let builder = Transformer::builder().set_default_options(options);
+    /// ```
+    pub fn set_default_options(mut self, options: Options) -> Self {
+        self.default_options = options;
+        self
+    }
+
+    /// Sets the options for a read group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::{Options, QualityScoreBinner, Transformer};
+    /// let options = Options::default()
+    ///     .set_quality_score_binner(QualityScoreBinner::illumina_8_level());
+    /// let builder = Transformer::builder().set_read_group_options("rg0", options);
+    /// ```
+    pub fn set_read_group_options<I>(mut self, read_group_id: I, options: Options) -> Self
+    where
+        I: Into<BString>,
+    {
+        self.read_group_options
+            .insert(read_group_id.into(), options);
+        self
+    }
+
+    /// Builds an alignment record transformer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transform::Transformer;
+    /// let transformer = Transformer::builder().build();
+    /// ```
+    pub fn build(self) -> Transformer {
+        Transformer {
+            reference_sequence_repository: self.reference_sequence_repository,
+            default_options: self.default_options,
+            read_group_options: self.read_group_options,
+        }
+    }
+}
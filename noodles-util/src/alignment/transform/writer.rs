@@ -0,0 +1,107 @@
+//! Alignment record transform writer.
+
+use std::io;
+
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::Transformer;
+
+/// An alignment writer that transforms records before writing them to an inner alignment writer.
+///
+/// If the transformer is lossless, records are written to the inner writer unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam::{self as sam, alignment::{io::Write, RecordBuf}};
+/// use noodles_util::alignment::transform::{self, Options, QualityScoreBinner, Transformer};
+///
+/// let transformer = Transformer::builder()
+///     .set_default_options(
+///         Options::default().set_quality_score_binner(QualityScoreBinner::illumina_8_level()),
+///     )
+///     .build();
+///
+/// let mut writer = transform::Writer::new(bam::io::Writer::new(io::sink()), transformer);
+///
+/// let header = sam::Header::default();
+/// writer.write_alignment_header(&header)?;
+///
+/// let record = RecordBuf::default();
+/// writer.write_alignment_record(&header, &record)?;
+///
+/// writer.finish(&header)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Writer<W> {
+    inner: W,
+    transformer: Transformer,
+}
+
+impl<W> Writer<W> {
+    /// Creates a transform writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_util::alignment::transform::{self, Transformer};
+    ///
+    /// let transformer = Transformer::default();
+    /// let writer = transform::Writer::new(bam::io::Writer::new(io::sink()), transformer);
+    /// ```
+    pub fn new(inner: W, transformer: Transformer) -> Self {
+        Self { inner, transformer }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Returns the transformer.
+    pub fn transformer(&self) -> &Transformer {
+        &self.transformer
+    }
+}
+
+impl<W> sam::alignment::io::Write for Writer<W>
+where
+    W: sam::alignment::io::Write,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_alignment_header(header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn Record,
+    ) -> io::Result<()> {
+        if self.transformer.is_lossless() {
+            return self.inner.write_alignment_record(header, record);
+        }
+
+        let record = self
+            .transformer
+            .transform_alignment_record(header, record)?;
+        self.inner.write_alignment_record(header, &record)
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.finish(header)
+    }
+}
@@ -1,8 +1,18 @@
 //! **noodles-util** are utilities for working with noodles. Currently, this consists of a unified
-//! interface for reading and writing alignment (BAM/CRAM/SAM) and variant (VCF/BCF) data.
+//! interface for reading and writing alignment (BAM/CRAM/SAM) and variant (VCF/BCF) data,
+//! reference sequence dictionary utilities, reference sequence renaming, and read trimming.
 
 #[cfg(feature = "alignment")]
 pub mod alignment;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
+#[cfg(feature = "rename")]
+pub mod rename;
+
+#[cfg(feature = "trim")]
+pub mod trim;
+
 #[cfg(feature = "variant")]
 pub mod variant;
@@ -0,0 +1,9 @@
+//! Reference sequence dictionary utilities.
+//!
+//! This builds reference sequence dictionaries (`.dict`) from FASTA files and verifies the
+//! reference sequences of alignment and variant headers against them.
+
+pub mod dictionary;
+pub mod verifier;
+
+pub use self::verifier::Verifier;
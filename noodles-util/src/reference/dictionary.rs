@@ -0,0 +1,239 @@
+//! Reference sequence dictionary.
+
+use std::{
+    io::{self, BufRead},
+    num::NonZeroUsize,
+    path::Path,
+};
+
+use md5::{Digest, Md5};
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{
+        Map,
+        map::{self, ReferenceSequence, reference_sequence::tag},
+    },
+};
+
+/// A reference sequence dictionary builder.
+///
+/// A reference sequence dictionary is a SAM header with a reference sequence (`@SQ`) record for
+/// each sequence in a FASTA, similar to the output of Picard `CreateSequenceDictionary`. Each
+/// record has the sequence length (`LN`) and MD5 checksum (`M5`) and, if set, a URI (`UR`),
+/// genome assembly ID (`AS`), and species (`SP`).
+#[derive(Debug, Default)]
+pub struct Builder {
+    uri: Option<String>,
+    assembly_id: Option<String>,
+    species: Option<String>,
+}
+
+impl Builder {
+    /// Sets the URI of the reference sequences (`UR`).
+    ///
+    /// When building from a path, this defaults to a `file:` URI of the absolute path of the
+    /// source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::reference::dictionary::Builder;
+    /// let builder = Builder::default().set_uri("https://example.com/reference.fa");
+    /// ```
+    pub fn set_uri<S>(mut self, uri: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Sets the genome assembly ID (`AS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::reference::dictionary::Builder;
+    /// let builder = Builder::default().set_assembly_id("GRCh38");
+    /// ```
+    pub fn set_assembly_id<S>(mut self, assembly_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.assembly_id = Some(assembly_id.into());
+        self
+    }
+
+    /// Sets the species (`SP`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::reference::dictionary::Builder;
+    /// let builder = Builder::default().set_species("Homo sapiens");
+    /// ```
+    pub fn set_species<S>(mut self, species: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.species = Some(species.into());
+        self
+    }
+
+    /// Builds a reference sequence dictionary from a FASTA file.
+    ///
+    /// The FASTA can be bgzip-compressed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::reference::dictionary::Builder;
+    /// let dictionary = Builder::default().build_from_path("reference.fa")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(mut self, src: P) -> io::Result<sam::Header>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        if self.uri.is_none() {
+            let path = src.canonicalize()?;
+            self.uri = Some(format!("file:{}", path.display()));
+        }
+
+        let mut reader = fasta::io::reader::Builder.build_from_path(src)?;
+        self.build_from_reader(&mut reader)
+    }
+
+    /// Builds a reference sequence dictionary from a FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::reference::dictionary::Builder;
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nacgtacgt\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    ///
+    /// let dictionary = Builder::default().build_from_reader(&mut reader)?;
+    ///
+    /// let reference_sequences = dictionary.reference_sequences();
+    /// assert_eq!(reference_sequences.len(), 2);
+    /// assert_eq!(reference_sequences[0].length().get(), 4);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(self, reader: &mut fasta::io::Reader<R>) -> io::Result<sam::Header>
+    where
+        R: BufRead,
+    {
+        let mut builder = sam::Header::builder().set_header(Map::<map::Header>::default());
+
+        for result in reader.records() {
+            let record = result?;
+            let sequence = record.sequence().as_ref();
+
+            let length = NonZeroUsize::new(sequence.len()).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("empty sequence: {}", String::from_utf8_lossy(record.name())),
+                )
+            })?;
+
+            let mut reference_sequence = Map::<ReferenceSequence>::new(length);
+            let other_fields = reference_sequence.other_fields_mut();
+
+            other_fields.insert(tag::MD5_CHECKSUM, md5_checksum(sequence).into());
+
+            if let Some(uri) = &self.uri {
+                other_fields.insert(tag::URI, uri.as_str().into());
+            }
+
+            if let Some(assembly_id) = &self.assembly_id {
+                other_fields.insert(tag::ASSEMBLY_ID, assembly_id.as_str().into());
+            }
+
+            if let Some(species) = &self.species {
+                other_fields.insert(tag::SPECIES, species.as_str().into());
+            }
+
+            builder = builder.add_reference_sequence(record.name().to_vec(), reference_sequence);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+/// Calculates the MD5 checksum of a reference sequence.
+///
+/// This is the hex-encoded MD5 checksum of the sequence with all characters outside the range
+/// `!`..=`~` removed and all lowercase characters converted to uppercase, i.e., the value of an
+/// `@SQ M5` field. Gaps (`-`) and pads (`*`) are kept.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::reference::dictionary::md5_checksum;
+/// assert_eq!(md5_checksum(b"acgt\n"), "f1f8f4bf413b16ad135722aa4591043e");
+/// ```
+pub fn md5_checksum(sequence: &[u8]) -> String {
+    let mut hasher = Md5::new();
+
+    for chunk in sequence.split(|b| !b.is_ascii_graphic()) {
+        hasher.update(chunk.to_ascii_uppercase());
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_from_reader() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nacgtacgt\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+
+        let dictionary = Builder::default()
+            .set_uri("file:/tmp/reference.fa")
+            .set_assembly_id("ndls")
+            .build_from_reader(&mut reader)?;
+
+        let mut writer = sam::io::Writer::new(Vec::new());
+        writer.write_header(&dictionary)?;
+
+        let expected = b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:4\tM5:f1f8f4bf413b16ad135722aa4591043e\tUR:file:/tmp/reference.fa\tAS:ndls
+@SQ\tSN:sq1\tLN:8\tM5:cc0af3a4fedb18378b4b57b98068e69f\tUR:file:/tmp/reference.fa\tAS:ndls
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_empty_sequence() {
+        let data = b">sq0\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+
+        assert!(matches!(
+            Builder::default().build_from_reader(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_md5_checksum() {
+        assert_eq!(md5_checksum(b"ACGT"), "f1f8f4bf413b16ad135722aa4591043e");
+        assert_eq!(md5_checksum(b"ac g\tt"), "f1f8f4bf413b16ad135722aa4591043e");
+        assert_eq!(md5_checksum(b"ac-g*t"), "769a626472b1ab5bcdfd681151a9b32b");
+    }
+}
@@ -0,0 +1,439 @@
+//! Reference sequence verifier.
+
+use std::{collections::HashMap, fmt, iter, str};
+
+use bstr::ByteSlice;
+use noodles_core::region::Aliases;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{
+        Map,
+        map::{ReferenceSequence, reference_sequence::tag},
+    },
+};
+use noodles_vcf as vcf;
+
+const CHR_PREFIX: &str = "chr";
+
+/// A reference sequence verifier.
+///
+/// This compares the reference sequences listed in a header, e.g., the `@SQ` records of a
+/// BAM/CRAM or the `##contig` records of a VCF, against a reference sequence dictionary, e.g.,
+/// one built from a FASTA (see [`super::dictionary::Builder`]).
+///
+/// Names that are not in the dictionary are resolved using aliases. A name matches a dictionary
+/// name when they are in the same alias group or when the name or one of its aliases only differs
+/// from it by a `chr` prefix (e.g., `chr1` and `1`).
+#[derive(Debug, Default)]
+pub struct Verifier {
+    aliases: Aliases,
+}
+
+/// A reference sequence mismatch.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mismatch {
+    /// The reference sequence is missing from the dictionary.
+    MissingSequence {
+        /// The reference sequence name.
+        name: String,
+    },
+    /// The reference sequence length differs from the dictionary.
+    Length {
+        /// The reference sequence name.
+        name: String,
+        /// The dictionary reference sequence name.
+        dictionary_name: String,
+        /// The reference sequence length.
+        length: usize,
+        /// The dictionary reference sequence length.
+        dictionary_length: usize,
+    },
+    /// The reference sequence MD5 checksum differs from the dictionary.
+    Md5Checksum {
+        /// The reference sequence name.
+        name: String,
+        /// The dictionary reference sequence name.
+        dictionary_name: String,
+        /// The reference sequence MD5 checksum.
+        md5_checksum: String,
+        /// The dictionary reference sequence MD5 checksum.
+        dictionary_md5_checksum: String,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSequence { name } => write!(f, "{name}: missing from dictionary"),
+            Self::Length {
+                name,
+                dictionary_name,
+                length,
+                dictionary_length,
+            } => write!(
+                f,
+                "{name}: length mismatch: expected {dictionary_length} ({dictionary_name}), got {length}"
+            ),
+            Self::Md5Checksum {
+                name,
+                dictionary_name,
+                md5_checksum,
+                dictionary_md5_checksum,
+            } => write!(
+                f,
+                "{name}: MD5 checksum mismatch: expected {dictionary_md5_checksum} ({dictionary_name}), got {md5_checksum}"
+            ),
+        }
+    }
+}
+
+/// A reference sequence verification report.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    mismatches: Vec<Mismatch>,
+    aliases: Vec<(String, String)>,
+}
+
+impl Report {
+    /// Returns whether all reference sequences match the dictionary.
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns the mismatches.
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+
+    /// Returns the reference sequences that were matched to a dictionary sequence with a different
+    /// name.
+    ///
+    /// Each pair is a reference sequence name and its matching dictionary name.
+    pub fn aliases(&self) -> &[(String, String)] {
+        &self.aliases
+    }
+}
+
+struct Entry {
+    name: String,
+    length: Option<usize>,
+    md5_checksum: Option<String>,
+}
+
+impl Verifier {
+    /// Creates a reference sequence verifier with the given aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_util::reference::Verifier;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["NC_000001.11", "chr1", "1"]);
+    ///
+    /// let verifier = Verifier::new(aliases);
+    /// ```
+    pub fn new(aliases: Aliases) -> Self {
+        Self { aliases }
+    }
+
+    /// Adds an alias group.
+    ///
+    /// All names in the group refer to the same reference sequence. If any of the names are
+    /// already in a group, the groups are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::reference::Verifier;
+    /// let mut verifier = Verifier::default();
+    /// verifier.add_aliases(["NC_000001.11", "chr1", "1"]);
+    /// ```
+    pub fn add_aliases<I, N>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<[u8]>,
+    {
+        self.aliases.insert(names);
+    }
+
+    /// Verifies the reference sequences (`@SQ`) of a SAM header against a dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam as sam;
+    /// use noodles_util::reference::{dictionary, Verifier};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    /// let dictionary = dictionary::Builder::default().build_from_reader(&mut reader)?;
+    ///
+    /// let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n".parse()?;
+    ///
+    /// let report = Verifier::default().verify(&header, &dictionary);
+    /// assert!(!report.is_ok());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn verify(&self, header: &sam::Header, dictionary: &sam::Header) -> Report {
+        let entries = header
+            .reference_sequences()
+            .iter()
+            .map(|(name, reference_sequence)| Entry {
+                name: name.to_string(),
+                length: Some(reference_sequence.length().get()),
+                md5_checksum: md5_checksum(reference_sequence),
+            });
+
+        self.verify_entries(entries, dictionary)
+    }
+
+    /// Verifies the contigs (`##contig`) of a VCF header against a dictionary.
+    ///
+    /// Contigs without a length or MD5 checksum are only verified by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::reference::{dictionary, Verifier};
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let data = b">chr1\nACGT\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    /// let dictionary = dictionary::Builder::default().build_from_reader(&mut reader)?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig("1", Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// let report = Verifier::default().verify_vcf_header(&header, &dictionary);
+    /// assert!(report.is_ok());
+    /// assert_eq!(report.aliases(), [(String::from("1"), String::from("chr1"))]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn verify_vcf_header(&self, header: &vcf::Header, dictionary: &sam::Header) -> Report {
+        let entries = header.contigs().iter().map(|(name, contig)| Entry {
+            name: name.clone(),
+            length: contig.length(),
+            md5_checksum: contig.md5().map(String::from),
+        });
+
+        self.verify_entries(entries, dictionary)
+    }
+
+    fn verify_entries<I>(&self, entries: I, dictionary: &sam::Header) -> Report
+    where
+        I: IntoIterator<Item = Entry>,
+    {
+        let dictionary: HashMap<String, Entry> = dictionary
+            .reference_sequences()
+            .iter()
+            .map(|(name, reference_sequence)| {
+                let name = name.to_string();
+
+                let entry = Entry {
+                    name: name.clone(),
+                    length: Some(reference_sequence.length().get()),
+                    md5_checksum: md5_checksum(reference_sequence),
+                };
+
+                (name, entry)
+            })
+            .collect();
+
+        let mut report = Report::default();
+
+        for entry in entries {
+            let Some(dictionary_entry) = self.resolve(&entry.name, &dictionary) else {
+                report
+                    .mismatches
+                    .push(Mismatch::MissingSequence { name: entry.name });
+                continue;
+            };
+
+            if let (Some(length), Some(dictionary_length)) = (entry.length, dictionary_entry.length)
+            {
+                if length != dictionary_length {
+                    report.mismatches.push(Mismatch::Length {
+                        name: entry.name.clone(),
+                        dictionary_name: dictionary_entry.name.clone(),
+                        length,
+                        dictionary_length,
+                    });
+                }
+            }
+
+            if let (Some(md5_checksum), Some(dictionary_md5_checksum)) =
+                (&entry.md5_checksum, &dictionary_entry.md5_checksum)
+            {
+                if !md5_checksum.eq_ignore_ascii_case(dictionary_md5_checksum) {
+                    report.mismatches.push(Mismatch::Md5Checksum {
+                        name: entry.name.clone(),
+                        dictionary_name: dictionary_entry.name.clone(),
+                        md5_checksum: md5_checksum.clone(),
+                        dictionary_md5_checksum: dictionary_md5_checksum.clone(),
+                    });
+                }
+            }
+
+            if entry.name != dictionary_entry.name {
+                report
+                    .aliases
+                    .push((entry.name, dictionary_entry.name.clone()));
+            }
+        }
+
+        report
+    }
+
+    fn resolve<'d>(&self, name: &str, dictionary: &'d HashMap<String, Entry>) -> Option<&'d Entry> {
+        let get = |name: &[u8]| {
+            str::from_utf8(name)
+                .ok()
+                .and_then(|name| dictionary.get(name))
+        };
+
+        if let Some(entry) = self
+            .aliases
+            .resolve(name.as_bytes(), |alias| get(alias).is_some())
+            .and_then(get)
+        {
+            return Some(entry);
+        }
+
+        let aliases = self.aliases.get(name.as_bytes()).unwrap_or_default();
+
+        iter::once(name)
+            .chain(aliases.iter().filter_map(|alias| alias.to_str().ok()))
+            .filter_map(chr_prefix_alias)
+            .find_map(|alias| dictionary.get(&alias))
+    }
+}
+
+fn md5_checksum(reference_sequence: &Map<ReferenceSequence>) -> Option<String> {
+    reference_sequence
+        .other_fields()
+        .get(&tag::MD5_CHECKSUM)
+        .map(|value| value.to_string())
+}
+
+// Returns the name that only differs from the given name by a `chr` prefix.
+fn chr_prefix_alias(name: &str) -> Option<String> {
+    match name.strip_prefix(CHR_PREFIX) {
+        Some("") => None,
+        Some(s) => Some(s.into()),
+        None => Some(format!("{CHR_PREFIX}{name}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_fasta as fasta;
+
+    use super::*;
+    use crate::reference::dictionary;
+
+    fn build_dictionary() -> std::io::Result<sam::Header> {
+        let data = b">chr1\nACGT\n>chr2\nACGTACGT\n>chrM\nAC\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+        dictionary::Builder::default().build_from_reader(&mut reader)
+    }
+
+    #[test]
+    fn test_verify() -> Result<(), Box<dyn std::error::Error>> {
+        let dictionary = build_dictionary()?;
+
+        let report = Verifier::default().verify(&dictionary, &dictionary);
+        assert!(report.is_ok());
+        assert!(report.aliases().is_empty());
+
+        let header: sam::Header = "@SQ\tSN:1\tLN:4\tM5:f1f8f4bf413b16ad135722aa4591043e
+@SQ\tSN:2\tLN:13
+@SQ\tSN:M\tLN:2\tM5:d7eba311421bbc9d3ada44709dd61534
+@SQ\tSN:sq3\tLN:8
+"
+        .parse()?;
+
+        let report = Verifier::default().verify(&header, &dictionary);
+
+        assert_eq!(
+            report.mismatches(),
+            [
+                Mismatch::Length {
+                    name: String::from("2"),
+                    dictionary_name: String::from("chr2"),
+                    length: 13,
+                    dictionary_length: 8,
+                },
+                Mismatch::Md5Checksum {
+                    name: String::from("M"),
+                    dictionary_name: String::from("chrM"),
+                    md5_checksum: String::from("d7eba311421bbc9d3ada44709dd61534"),
+                    dictionary_md5_checksum: String::from("4144e097d2fa7a491cec2a7a4322f2bc"),
+                },
+                Mismatch::MissingSequence {
+                    name: String::from("sq3"),
+                },
+            ]
+        );
+
+        assert_eq!(
+            report.aliases(),
+            [
+                (String::from("1"), String::from("chr1")),
+                (String::from("2"), String::from("chr2")),
+                (String::from("M"), String::from("chrM")),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_aliases() -> Result<(), Box<dyn std::error::Error>> {
+        let dictionary = build_dictionary()?;
+        let header: sam::Header = "@SQ\tSN:NC_000001.11\tLN:4\n".parse()?;
+
+        let report = Verifier::default().verify(&header, &dictionary);
+        assert!(!report.is_ok());
+
+        let mut verifier = Verifier::default();
+        verifier.add_aliases(["NC_000001.11", "CM000663.2"]);
+        verifier.add_aliases(["CM000663.2", "chr1"]);
+
+        let report = verifier.verify(&header, &dictionary);
+        assert!(report.is_ok());
+        assert_eq!(
+            report.aliases(),
+            [(String::from("NC_000001.11"), String::from("chr1"))]
+        );
+
+        let header: sam::Header = "@SQ\tSN:MT\tLN:2\n".parse()?;
+
+        let report = Verifier::default().verify(&header, &dictionary);
+        assert!(!report.is_ok());
+
+        let mut aliases = Aliases::default();
+        aliases.insert(["MT", "M"]);
+
+        let report = Verifier::new(aliases).verify(&header, &dictionary);
+        assert!(report.is_ok());
+        assert_eq!(
+            report.aliases(),
+            [(String::from("MT"), String::from("chrM"))]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chr_prefix_alias() {
+        assert_eq!(chr_prefix_alias("chr1"), Some(String::from("1")));
+        assert_eq!(chr_prefix_alias("1"), Some(String::from("chr1")));
+        assert_eq!(chr_prefix_alias("M"), Some(String::from("chrM")));
+        assert!(chr_prefix_alias("chr").is_none());
+    }
+}
@@ -0,0 +1,539 @@
+//! Reference sequence renaming.
+//!
+//! A [`Renamer`] maps reference sequence names to new names, e.g., UCSC-style names (`chr1`) to
+//! Ensembl-style names (`1`). It rewrites SAM headers, VCF headers and records, and BED and GFF
+//! streams.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, BufRead, Write},
+    mem,
+};
+
+use bstr::{BStr, BString, ByteSlice};
+use noodles_core::region::Aliases;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{
+        Map,
+        map::{ReferenceSequence, reference_sequence::tag},
+    },
+};
+use noodles_vcf::{self as vcf, header::StringMaps};
+
+/// A reference sequence renamer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Renamer {
+    names: HashMap<BString, BString>,
+}
+
+impl Renamer {
+    /// Creates a renamer from pairs of source and destination names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::rename::Renamer;
+    /// let renamer = Renamer::new([("chr1", "1"), ("chrM", "MT")]);
+    /// assert_eq!(renamer.get(b"chr1"), Some(b"1".into()));
+    /// ```
+    pub fn new<I, S, D>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (S, D)>,
+        S: Into<BString>,
+        D: Into<BString>,
+    {
+        let names = pairs
+            .into_iter()
+            .map(|(src, dst)| (src.into(), dst.into()))
+            .collect();
+
+        Self { names }
+    }
+
+    /// Creates a renamer that maps aliases to the given target names.
+    ///
+    /// Each alias of a target name is renamed to the target name. Names that are not an alias of
+    /// any target name are not renamed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1", "NC_000001.11"]);
+    ///
+    /// let renamer = Renamer::from_aliases(&aliases, ["1"]);
+    /// assert_eq!(renamer.get(b"chr1"), Some(b"1".into()));
+    /// assert_eq!(renamer.get(b"NC_000001.11"), Some(b"1".into()));
+    /// assert!(renamer.get(b"1").is_none());
+    /// ```
+    pub fn from_aliases<I, N>(aliases: &Aliases, target_names: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<[u8]>,
+    {
+        let mut names = HashMap::new();
+
+        for target_name in target_names {
+            let target_name = target_name.as_ref();
+
+            let Some(group) = aliases.get(target_name) else {
+                continue;
+            };
+
+            for alias in group {
+                if alias != target_name {
+                    names.insert(alias.clone(), target_name.into());
+                }
+            }
+        }
+
+        Self { names }
+    }
+
+    /// Returns the new name of the given name, if it is renamed.
+    pub fn get(&self, name: &[u8]) -> Option<&BStr> {
+        self.names.get(name.as_bstr()).map(|name| name.as_bstr())
+    }
+
+    /// Returns whether there are any names to rename.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Renames the reference sequences in a SAM header.
+    ///
+    /// Alignment records refer to reference sequences by index, so records read with the original
+    /// header are written with the new names when using the renamed header.
+    ///
+    /// Alternative names (`AN`) that become the name of a reference sequence are removed, and an
+    /// `AN` field is removed when none of its names remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use noodles_sam::{self as sam, header::record::value::{map::ReferenceSequence, Map}};
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let mut header = sam::Header::builder()
+    ///     .add_reference_sequence("chr1", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+    ///     .build();
+    ///
+    /// let renamer = Renamer::new([("chr1", "1")]);
+    /// renamer.rename_sam_header(&mut header)?;
+    ///
+    /// assert!(header.reference_sequences().contains_key(&b"1"[..]));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rename_sam_header(&self, header: &mut sam::Header) -> io::Result<()> {
+        let mut renamed = sam::header::ReferenceSequences::default();
+
+        for (name, reference_sequence) in header.reference_sequences() {
+            let name = self.get(name).unwrap_or(name.as_ref());
+
+            if renamed
+                .insert(name.into(), reference_sequence.clone())
+                .is_some()
+            {
+                return Err(duplicate_name_error(name));
+            }
+        }
+
+        let names: HashSet<BString> = renamed.keys().cloned().collect();
+
+        for reference_sequence in renamed.values_mut() {
+            remove_conflicting_alternative_names(&names, reference_sequence);
+        }
+
+        *header.reference_sequences_mut() = renamed;
+
+        Ok(())
+    }
+
+    /// Renames the contigs in a VCF header.
+    ///
+    /// This also rebuilds the header string maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_contig("chr1", Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// let renamer = Renamer::new([("chr1", "1")]);
+    /// renamer.rename_vcf_header(&mut header)?;
+    ///
+    /// assert!(header.contigs().contains_key("1"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rename_vcf_header(&self, header: &mut vcf::Header) -> io::Result<()> {
+        let mut renamed = vcf::header::Contigs::default();
+
+        for (name, contig) in header.contigs() {
+            let name = match self.get(name.as_bytes()) {
+                Some(new_name) => new_name
+                    .to_str()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+                None => name,
+            };
+
+            if renamed.insert(name.into(), contig.clone()).is_some() {
+                return Err(duplicate_name_error(name.as_bytes()));
+            }
+        }
+
+        // The string maps are built from the header, so the original contigs are restored if
+        // building them fails.
+        let contigs = mem::replace(header.contigs_mut(), renamed);
+
+        match StringMaps::try_from(&*header) {
+            Ok(string_maps) => {
+                *header.string_maps_mut() = string_maps;
+                Ok(())
+            }
+            Err(e) => {
+                *header.contigs_mut() = contigs;
+                Err(io::Error::new(io::ErrorKind::InvalidInput, e))
+            }
+        }
+    }
+
+    /// Renames the reference sequence name of a VCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_sequence_name("chr1")
+    ///     .build();
+    ///
+    /// let renamer = Renamer::new([("chr1", "1")]);
+    /// renamer.rename_vcf_record_buf(&mut record)?;
+    ///
+    /// assert_eq!(record.reference_sequence_name(), "1");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rename_vcf_record_buf(&self, record: &mut vcf::variant::RecordBuf) -> io::Result<()> {
+        let reference_sequence_name = record.reference_sequence_name_mut();
+
+        if let Some(name) = self.get(reference_sequence_name.as_bytes()) {
+            let name = name
+                .to_str()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            reference_sequence_name.replace_range(.., name);
+        }
+
+        Ok(())
+    }
+
+    /// Renames the reference sequence names of a BED stream.
+    ///
+    /// The first field of each record is renamed. Comments and `browser` and `track` lines are
+    /// copied as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let src = b"track name=sample\nchr1\t7\t13\n";
+    /// let mut dst = Vec::new();
+    ///
+    /// let renamer = Renamer::new([("chr1", "1")]);
+    /// renamer.rename_bed(&src[..], &mut dst)?;
+    ///
+    /// assert_eq!(dst, b"track name=sample\n1\t7\t13\n");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rename_bed<R, W>(&self, mut reader: R, mut writer: W) -> io::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let mut line = Vec::new();
+
+        while read_line(&mut reader, &mut line)? != 0 {
+            if is_bed_metadata(&line) {
+                writer.write_all(&line)?;
+            } else {
+                self.write_renamed_field(&mut writer, &line, b'\t')?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renames the reference sequence names of a GFF stream.
+    ///
+    /// The first field of each record and the sequence ID of `##sequence-region` directives are
+    /// renamed. If the stream has a FASTA section, the names of its sequences are also renamed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::rename::Renamer;
+    ///
+    /// let src = b"##gff-version 3\n##sequence-region chr1 1 8\nchr1\t.\tgene\t1\t8\t.\t+\t.\tID=g0\n";
+    /// let mut dst = Vec::new();
+    ///
+    /// let renamer = Renamer::new([("chr1", "1")]);
+    /// renamer.rename_gff(&src[..], &mut dst)?;
+    ///
+    /// assert_eq!(
+    ///     dst,
+    ///     b"##gff-version 3\n##sequence-region 1 1 8\n1\t.\tgene\t1\t8\t.\t+\t.\tID=g0\n"
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn rename_gff<R, W>(&self, mut reader: R, mut writer: W) -> io::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        const SEQUENCE_REGION_PREFIX: &[u8] = b"##sequence-region ";
+        const FASTA_DIRECTIVE: &[u8] = b"##FASTA";
+
+        let mut line = Vec::new();
+        let mut in_fasta = false;
+
+        while read_line(&mut reader, &mut line)? != 0 {
+            if in_fasta {
+                if let Some(definition) = line.strip_prefix(b">") {
+                    writer.write_all(b">")?;
+                    self.write_renamed_field(&mut writer, definition, b' ')?;
+                } else {
+                    writer.write_all(&line)?;
+                }
+            } else if let Some(rest) = line.strip_prefix(SEQUENCE_REGION_PREFIX) {
+                writer.write_all(SEQUENCE_REGION_PREFIX)?;
+                self.write_renamed_field(&mut writer, rest, b' ')?;
+            } else if line.starts_with(b"#") {
+                in_fasta = line.trim_ascii_end() == FASTA_DIRECTIVE;
+                writer.write_all(&line)?;
+            } else {
+                self.write_renamed_field(&mut writer, &line, b'\t')?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_renamed_field<W>(&self, writer: &mut W, line: &[u8], delimiter: u8) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let end = line
+            .iter()
+            .position(|&b| b == delimiter || b == b'\n' || b == b'\r')
+            .unwrap_or(line.len());
+
+        let (name, rest) = line.split_at(end);
+
+        match self.get(name) {
+            Some(new_name) => writer.write_all(new_name)?,
+            None => writer.write_all(name)?,
+        }
+
+        writer.write_all(rest)
+    }
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    buf.clear();
+    reader.read_until(b'\n', buf)
+}
+
+fn is_bed_metadata(line: &[u8]) -> bool {
+    line.starts_with(b"#") || line.starts_with(b"browser") || line.starts_with(b"track")
+}
+
+fn remove_conflicting_alternative_names(
+    names: &HashSet<BString>,
+    reference_sequence: &mut Map<ReferenceSequence>,
+) {
+    let other_fields = reference_sequence.other_fields_mut();
+
+    let Some(value) = other_fields.get(&tag::ALTERNATIVE_NAMES) else {
+        return;
+    };
+
+    let alternative_names: Vec<_> = value
+        .split(|&b| b == b',')
+        .filter(|name| !names.contains(name.as_bstr()))
+        .collect();
+
+    if alternative_names.is_empty() {
+        other_fields.shift_remove(&tag::ALTERNATIVE_NAMES);
+    } else {
+        let value = bstr::join(",", alternative_names);
+        other_fields.insert(tag::ALTERNATIVE_NAMES, value.into());
+    }
+}
+
+fn duplicate_name_error(name: &[u8]) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!(
+            "duplicate reference sequence name after renaming: {}",
+            name.as_bstr()
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use vcf::header::record::value::{Map as VcfMap, map::Contig};
+
+    use super::*;
+
+    #[test]
+    fn test_rename_sam_header() -> io::Result<()> {
+        let mut header = sam::Header::builder()
+            .add_reference_sequence("chr1", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+            .add_reference_sequence("chr2", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+            .build();
+
+        Renamer::new([("chr1", "1")]).rename_sam_header(&mut header)?;
+
+        let names: Vec<_> = header.reference_sequences().keys().cloned().collect();
+        assert_eq!(names, [BString::from("1"), BString::from("chr2")]);
+
+        let result = Renamer::new([("1", "chr2")]).rename_sam_header(&mut header);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let names: Vec<_> = header.reference_sequences().keys().cloned().collect();
+        assert_eq!(names, [BString::from("1"), BString::from("chr2")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_sam_header_with_alternative_names() -> io::Result<()> {
+        let mut header: sam::Header = "@SQ\tSN:chr1\tLN:8\tAN:1,NC_000001.11
+@SQ\tSN:chr2\tLN:8\tAN:2
+@SQ\tSN:chr3\tLN:8\tAN:NC_000003.12
+"
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Renamer::new([("chr1", "1"), ("chr2", "2")]).rename_sam_header(&mut header)?;
+
+        let alternative_names: Vec<_> = header
+            .reference_sequences()
+            .values()
+            .map(|reference_sequence| {
+                reference_sequence
+                    .other_fields()
+                    .get(&tag::ALTERNATIVE_NAMES)
+                    .cloned()
+            })
+            .collect();
+
+        assert_eq!(
+            alternative_names,
+            [
+                Some(BString::from("NC_000001.11")),
+                None,
+                Some(BString::from("NC_000003.12")),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_vcf_header() -> io::Result<()> {
+        let mut header = vcf::Header::builder()
+            .add_contig("chr1", VcfMap::<Contig>::new())
+            .add_contig("chr2", VcfMap::<Contig>::new())
+            .build();
+
+        Renamer::new([("chr2", "2")]).rename_vcf_header(&mut header)?;
+
+        let names: Vec<_> = header.contigs().keys().map(String::as_str).collect();
+        assert_eq!(names, ["chr1", "2"]);
+
+        let contigs = header.string_maps().contigs();
+        assert_eq!(contigs.get_index_of("2"), Some(1));
+        assert!(contigs.get_index_of("chr2").is_none());
+
+        let result = Renamer::new([("2", "chr1")]).rename_vcf_header(&mut header);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let names: Vec<_> = header.contigs().keys().map(String::as_str).collect();
+        assert_eq!(names, ["chr1", "2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_bed() -> io::Result<()> {
+        let src = b"# comment\nchr1\t7\t13\r\nchr2\t21\t34\nchr3\n";
+        let mut dst = Vec::new();
+
+        Renamer::new([("chr1", "1"), ("chr3", "3")]).rename_bed(&src[..], &mut dst)?;
+
+        assert_eq!(dst, b"# comment\n1\t7\t13\r\nchr2\t21\t34\n3\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_gff() -> io::Result<()> {
+        let src = b"\
+##gff-version 3
+##sequence-region chr1 1 8
+chr1\t.\tgene\t1\t8\t.\t+\t.\tID=g0
+##FASTA
+>chr1 description
+ACGTACGT
+";
+
+        let mut dst = Vec::new();
+        Renamer::new([("chr1", "1")]).rename_gff(&src[..], &mut dst)?;
+
+        let expected = b"\
+##gff-version 3
+##sequence-region 1 1 8
+1\t.\tgene\t1\t8\t.\t+\t.\tID=g0
+##FASTA
+>1 description
+ACGTACGT
+";
+
+        assert_eq!(dst, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_aliases() {
+        let mut aliases = Aliases::default();
+        aliases.insert(["chr1", "1"]);
+        aliases.insert(["chr2", "2"]);
+
+        let renamer = Renamer::from_aliases(&aliases, ["1", "chr2", "3"]);
+
+        assert_eq!(renamer.get(b"chr1"), Some(b"1".as_bstr()));
+        assert_eq!(renamer.get(b"2"), Some(b"chr2".as_bstr()));
+        assert!(renamer.get(b"1").is_none());
+        assert!(renamer.get(b"chr2").is_none());
+    }
+}
@@ -0,0 +1,18 @@
+//! Read trimming and filtering.
+//!
+//! This includes primitives to find 3' adapters ([`adapter`]), low quality 3' ends ([`quality`]),
+//! homopolymer tails ([`poly_x`]), and read pair insert sizes ([`overlap`]). A [`Trimmer`]
+//! combines them with length and `N` filters and applies them to FASTQ records and unaligned SAM
+//! records ([`Record`]).
+
+pub mod adapter;
+pub mod overlap;
+pub mod poly_x;
+pub mod quality;
+mod record;
+pub mod trimmer;
+
+pub use self::{
+    record::Record,
+    trimmer::{QualityTrimming, Trimmer},
+};
@@ -0,0 +1,147 @@
+//! 3' adapter detection.
+
+/// Finds the start position of a 3' adapter in a sequence.
+///
+/// This uses a semi-global alignment of the adapter to the sequence, where the adapter can start
+/// anywhere in the sequence and can be partially overlapping the 3' end of the sequence, i.e.,
+/// only a prefix of the adapter is present. The number of edits (mismatches, insertions, and
+/// deletions) of an alignment must be at most `max_error_rate` times the length of the aligned
+/// adapter, and the aligned adapter must be at least `min_overlap` bases long.
+///
+/// Bases are compared case-insensitively, and `N` in the adapter matches any base.
+///
+/// This returns the leftmost start position of an acceptable alignment, i.e., the position to
+/// trim the sequence at.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::trim::adapter;
+///
+/// let adapter = b"AGATCGGAAGAG";
+///
+/// // full match
+/// assert_eq!(adapter::find(b"ACGTACGTAGATCGGAAGAGCA", adapter, 0.1, 3), Some(8));
+///
+/// // partial overlap at the 3' end
+/// assert_eq!(adapter::find(b"ACGTACGTACGTAGATC", adapter, 0.1, 3), Some(12));
+///
+/// // overlap too short
+/// assert_eq!(adapter::find(b"ACGTACGTACGTAG", adapter, 0.1, 3), None);
+/// ```
+pub fn find(
+    sequence: &[u8],
+    adapter: &[u8],
+    max_error_rate: f64,
+    min_overlap: usize,
+) -> Option<usize> {
+    let m = adapter.len();
+    let n = sequence.len();
+
+    if m == 0 || n == 0 {
+        return None;
+    }
+
+    let max_errors = |len: usize| (max_error_rate * len as f64).floor() as usize;
+
+    // Each cell is (edit distance, sequence start position) of the best alignment of
+    // `adapter[..j]` ending at `sequence[..i]`, stored by column.
+    let mut prev: Vec<(usize, usize)> = (0..=m).map(|j| (j, 0)).collect();
+    let mut curr = prev.clone();
+
+    let mut best: Option<usize> = None;
+
+    let mut update_best = |start: usize| {
+        if best.is_none_or(|b| start < b) {
+            best = Some(start);
+        }
+    };
+
+    for i in 1..=n {
+        curr[0] = (0, i);
+
+        for j in 1..=m {
+            let cost = usize::from(!bases_eq(adapter[j - 1], sequence[i - 1]));
+
+            let (d, s) = prev[j - 1];
+            let mut cell = (d + cost, s);
+
+            let (d, s) = curr[j - 1];
+            if d + 1 < cell.0 {
+                cell = (d + 1, s);
+            }
+
+            let (d, s) = prev[j];
+            if d + 1 < cell.0 {
+                cell = (d + 1, s);
+            }
+
+            curr[j] = cell;
+        }
+
+        let (d, start) = curr[m];
+
+        if m >= min_overlap && d <= max_errors(m) && start < n {
+            update_best(start);
+        }
+
+        if i == n {
+            for (j, &(d, start)) in curr.iter().enumerate().take(m).skip(min_overlap.max(1)) {
+                if d <= max_errors(j) && start < n {
+                    update_best(start);
+                }
+            }
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    best
+}
+
+fn bases_eq(adapter_base: u8, base: u8) -> bool {
+    adapter_base.eq_ignore_ascii_case(&b'N') || adapter_base.eq_ignore_ascii_case(&base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADAPTER: &[u8] = b"AGATCGGAAGAG";
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(b"AGATCGGAAGAG", ADAPTER, 0.1, 3), Some(0));
+        assert_eq!(find(b"ACGTAGATCGGAAGAG", ADAPTER, 0.1, 3), Some(4));
+        assert_eq!(find(b"acgtagatcggaagag", ADAPTER, 0.1, 3), Some(4));
+        assert_eq!(find(b"ACGTACGT", ADAPTER, 0.1, 3), None);
+        assert_eq!(find(b"", ADAPTER, 0.1, 3), None);
+        assert_eq!(find(b"ACGT", b"", 0.1, 3), None);
+    }
+
+    #[test]
+    fn test_find_with_errors() {
+        // mismatch
+        assert_eq!(find(b"CCCCAGATCGCAAGAGTT", ADAPTER, 0.1, 3), Some(4));
+        // deletion
+        assert_eq!(find(b"CCCCAGATCGAAGAGTT", ADAPTER, 0.1, 3), Some(4));
+        // insertion
+        assert_eq!(find(b"CCCCAGATCGTGAAGAGTT", ADAPTER, 0.1, 3), Some(4));
+        // too many errors
+        assert_eq!(find(b"CCCCAGTTCGCAAGAGTT", ADAPTER, 0.1, 3), None);
+    }
+
+    #[test]
+    fn test_find_with_partial_overlap() {
+        assert_eq!(find(b"CCCCCCAGA", ADAPTER, 0.1, 3), Some(6));
+        assert_eq!(find(b"CCCCCCAG", ADAPTER, 0.1, 3), None);
+        assert_eq!(find(b"CCCCCCAG", ADAPTER, 0.1, 2), Some(6));
+        assert_eq!(find(b"CCCCCCAGATCGCAA", ADAPTER, 0.1, 3), None);
+        assert_eq!(find(b"CCCCCCAGATCGCAAG", ADAPTER, 0.1, 3), Some(6));
+    }
+
+    #[test]
+    fn test_find_with_wildcard_adapter() {
+        assert_eq!(find(b"CCCCAGTTCG", b"AGNNCG", 0.0, 3), Some(4));
+    }
+}
@@ -0,0 +1,84 @@
+//! Paired read overlap detection.
+
+/// Finds the insert size of a read pair by overlapping the first read with the reverse complement
+/// of the second read.
+///
+/// When the insert is shorter than the reads, each read runs through the insert into the adapter
+/// on the other end, and the start of the first read aligns with the end of the reverse
+/// complement of the second read. Such an insert size can be used to trim adapters from both
+/// reads without knowing the adapter sequences.
+///
+/// Insert sizes are tested from the length of the shorter read down to `min_overlap`. The first
+/// overlap with a mismatch count of at most `max_mismatch_rate` times the overlap length is
+/// returned. Bases are compared case-insensitively, and `N` is a mismatch.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::trim::overlap;
+///
+/// // insert: ACGTTGCAACGGTTCA
+/// let r1 = b"ACGTTGCAACGGTTCAAGATCGGAAG";
+/// let r2 = b"TGAACCGTTGCAACGTAGATCGGAAG";
+/// assert_eq!(overlap::find_insert_size(r1, r2, 10, 0.1), Some(16));
+/// ```
+pub fn find_insert_size(
+    sequence_1: &[u8],
+    sequence_2: &[u8],
+    min_overlap: usize,
+    max_mismatch_rate: f64,
+) -> Option<usize> {
+    let max_len = sequence_1.len().min(sequence_2.len());
+
+    (min_overlap.max(1)..=max_len).rev().find(|&len| {
+        let max_mismatch_count = (max_mismatch_rate * len as f64).floor() as usize;
+
+        let mut mismatch_count = 0;
+
+        for (&a, &b) in sequence_1[..len].iter().zip(sequence_2[..len].iter().rev()) {
+            if !is_complement(a, b) {
+                mismatch_count += 1;
+
+                if mismatch_count > max_mismatch_count {
+                    return false;
+                }
+            }
+        }
+
+        true
+    })
+}
+
+fn is_complement(a: u8, b: u8) -> bool {
+    matches!(
+        (a.to_ascii_uppercase(), b.to_ascii_uppercase()),
+        (b'A', b'T') | (b'C', b'G') | (b'G', b'C') | (b'T', b'A')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_insert_size() {
+        let r1 = b"ACGTTGCAACGGTTCAAGATCGGAAG";
+        let r2 = b"TGAACCGTTGCAACGTCTGTCTCTTA";
+        assert_eq!(find_insert_size(r1, r2, 10, 0.1), Some(16));
+
+        // mismatch
+        let r2 = b"TGAACCGTAGCAACGTCTGTCTCTTA";
+        assert_eq!(find_insert_size(r1, r2, 10, 0.1), Some(16));
+        assert_eq!(find_insert_size(r1, r2, 10, 0.0), None);
+
+        // insert shorter than the minimum overlap
+        assert_eq!(find_insert_size(r1, r2, 20, 0.1), None);
+
+        // insert longer than the reads
+        let r1 = b"ACGTTGCAACGG";
+        let r2 = b"CCCCCCCCCCCC";
+        assert_eq!(find_insert_size(r1, r2, 6, 0.1), None);
+
+        assert_eq!(find_insert_size(b"", b"", 0, 0.1), None);
+    }
+}
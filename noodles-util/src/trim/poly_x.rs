@@ -0,0 +1,61 @@
+//! 3' homopolymer (poly-X) tail detection.
+
+// One mismatch is allowed for every 8 bases, as in fastp.
+const BASES_PER_MISMATCH: usize = 8;
+
+/// Finds the start position of a homopolymer tail of `base` at the 3' end of a sequence.
+///
+/// This is typically used to remove poly-G tails, which are called when there is no signal on
+/// 2-color sequencing platforms, and poly-A tails. Mismatches are allowed at a rate of 1 per 8
+/// bases. Bases are compared case-insensitively.
+///
+/// This returns `None` if the tail is shorter than `min_length`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::trim::poly_x;
+/// assert_eq!(poly_x::find(b"CCCAGGGGGGGGGGGG", b'G', 10), Some(4));
+/// assert_eq!(poly_x::find(b"CCCCGGGAGGGGGGGGGGG", b'G', 10), Some(4));
+/// assert_eq!(poly_x::find(b"ACGTGGGG", b'G', 10), None);
+/// ```
+pub fn find(sequence: &[u8], base: u8, min_length: usize) -> Option<usize> {
+    let len = sequence.len();
+
+    let mut mismatch_count = 0;
+    let mut start = len;
+
+    for (i, b) in sequence.iter().enumerate().rev() {
+        if b.eq_ignore_ascii_case(&base) {
+            start = i;
+        } else {
+            mismatch_count += 1;
+
+            let tail_length = len - i;
+
+            if mismatch_count > tail_length / BASES_PER_MISMATCH {
+                break;
+            }
+        }
+    }
+
+    let tail_length = len - start;
+    (tail_length > 0 && tail_length >= min_length).then_some(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(b"", b'G', 10), None);
+        assert_eq!(find(b"GGGGGGGGGG", b'G', 10), Some(0));
+        assert_eq!(find(b"ACGTAAAAAAAAAA", b'A', 10), Some(4));
+        assert_eq!(find(b"ACGTaaaaaaaaaa", b'A', 10), Some(4));
+        assert_eq!(find(b"ACGTGGGGGGGGGGT", b'G', 10), None);
+        assert_eq!(find(b"ACGTGGGGGGGGGGGGGGTG", b'G', 10), None);
+        assert_eq!(find(b"ACGTGGGGTGGGGGGGGG", b'G', 10), Some(4));
+        assert_eq!(find(b"ACGTGGGGTTGGGGGGGG", b'G', 10), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! 3' quality trimming.
+
+/// Finds the end position of a sequence after trimming low quality bases from the 3' end using
+/// the BWA algorithm.
+///
+/// This is the algorithm used by `bwa aln -q`. It finds the position that maximizes the sum of
+/// `threshold - q` over the bases after it, stopping when the sum becomes negative.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::trim::quality;
+/// assert_eq!(quality::bwa(&[40, 40, 40, 40, 2, 30, 2], 20), 4);
+/// assert_eq!(quality::bwa(&[40, 40, 40, 40], 20), 4);
+/// ```
+pub fn bwa(quality_scores: &[u8], threshold: u8) -> usize {
+    let threshold = i32::from(threshold);
+
+    let mut sum = 0;
+    let mut max = 0;
+    let mut end = quality_scores.len();
+
+    for (i, &score) in quality_scores.iter().enumerate().rev() {
+        sum += threshold - i32::from(score);
+
+        if sum < 0 {
+            break;
+        }
+
+        if sum > max {
+            max = sum;
+            end = i;
+        }
+    }
+
+    end
+}
+
+/// Finds the end position of a sequence after trimming at the first window with a low mean
+/// quality score.
+///
+/// Windows of `window_size` bases are scanned from the 5' end. The sequence is trimmed at the
+/// start of the first window with a mean quality score less than `threshold`. If the sequence is
+/// shorter than the window size, the whole sequence is a single window.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::trim::quality;
+/// assert_eq!(quality::sliding_window(&[40, 40, 40, 40, 2, 30, 2], 2, 20), 4);
+/// assert_eq!(quality::sliding_window(&[40, 40, 40, 40], 2, 20), 4);
+/// ```
+pub fn sliding_window(quality_scores: &[u8], window_size: usize, threshold: u8) -> usize {
+    let len = quality_scores.len();
+    let window_size = window_size.clamp(1, len.max(1));
+    let min_sum = u32::from(threshold) * window_size as u32;
+
+    if len == 0 {
+        return 0;
+    }
+
+    let mut sum: u32 = quality_scores[..window_size]
+        .iter()
+        .copied()
+        .map(u32::from)
+        .sum();
+
+    for start in 0..=(len - window_size) {
+        if start > 0 {
+            sum -= u32::from(quality_scores[start - 1]);
+            sum += u32::from(quality_scores[start + window_size - 1]);
+        }
+
+        if sum < min_sum {
+            return start;
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bwa() {
+        assert_eq!(bwa(&[], 20), 0);
+        assert_eq!(bwa(&[2, 2, 2], 20), 0);
+        assert_eq!(bwa(&[40, 40, 10, 25, 10], 20), 2);
+        assert_eq!(bwa(&[40, 40, 10, 40, 10], 20), 4);
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        assert_eq!(sliding_window(&[], 4, 20), 0);
+        assert_eq!(sliding_window(&[2, 2, 2], 4, 20), 0);
+        assert_eq!(sliding_window(&[30, 30, 30], 4, 20), 3);
+        assert_eq!(sliding_window(&[40, 40, 40, 10, 10, 10], 3, 20), 3);
+        assert_eq!(sliding_window(&[40, 40, 40, 5, 10, 10], 3, 20), 2);
+        assert_eq!(sliding_window(&[40, 40, 40, 10, 10, 10], 0, 20), 3);
+    }
+}
@@ -0,0 +1,180 @@
+use std::{borrow::Cow, io, ops::Range};
+
+use noodles_fastq::{self as fastq, qc::QualityEncoding};
+use noodles_sam as sam;
+
+/// A read that can be trimmed.
+pub trait Record {
+    /// Returns the bases.
+    fn sequence(&self) -> &[u8];
+
+    /// Returns the Phred quality scores.
+    ///
+    /// Encoded quality scores are decoded using the given quality encoding. This is empty if the
+    /// quality scores are missing.
+    fn quality_scores(&self, quality_encoding: QualityEncoding) -> io::Result<Cow<'_, [u8]>>;
+
+    /// Keeps only the bases and quality scores in the given range.
+    fn retain(&mut self, range: Range<usize>) -> io::Result<()>;
+}
+
+impl Record for fastq::Record {
+    fn sequence(&self) -> &[u8] {
+        self.sequence()
+    }
+
+    fn quality_scores(&self, quality_encoding: QualityEncoding) -> io::Result<Cow<'_, [u8]>> {
+        self.quality_scores()
+            .iter()
+            .map(|&c| {
+                quality_encoding.decode(c).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "invalid quality score for {quality_encoding:?}: {:?}",
+                            char::from(c)
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn retain(&mut self, range: Range<usize>) -> io::Result<()> {
+        retain(self.sequence_mut(), range.clone())?;
+
+        if !self.quality_scores().is_empty() {
+            retain(self.quality_scores_mut(), range)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Only unaligned records can be trimmed. Quality scores are already decoded, so the quality
+/// encoding is ignored.
+impl Record for sam::alignment::RecordBuf {
+    fn sequence(&self) -> &[u8] {
+        self.sequence().as_ref()
+    }
+
+    fn quality_scores(&self, _: QualityEncoding) -> io::Result<Cow<'_, [u8]>> {
+        Ok(Cow::Borrowed(self.quality_scores().as_ref()))
+    }
+
+    fn retain(&mut self, range: Range<usize>) -> io::Result<()> {
+        if !self.cigar().as_ref().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot trim an aligned record",
+            ));
+        }
+
+        retain(self.sequence_mut().as_mut(), range.clone())?;
+
+        if !self.quality_scores().is_empty() {
+            retain(self.quality_scores_mut().as_mut(), range)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn retain(buf: &mut Vec<u8>, range: Range<usize>) -> io::Result<()> {
+    if range.start > range.end || range.end > buf.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid trim range",
+        ));
+    }
+
+    buf.truncate(range.end);
+    buf.drain(..range.start);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::alignment::record::cigar::{Op, op::Kind};
+
+    use super::*;
+
+    #[test]
+    fn test_fastq_record_quality_scores() -> io::Result<()> {
+        let record = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "hJ@#");
+
+        assert_eq!(
+            Record::quality_scores(&record, QualityEncoding::Phred33)?.as_ref(),
+            [71, 41, 31, 2]
+        );
+
+        assert!(matches!(
+            Record::quality_scores(&record, QualityEncoding::Phred64),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "hJ@B");
+
+        assert_eq!(
+            Record::quality_scores(&record, QualityEncoding::Phred64)?.as_ref(),
+            [40, 10, 0, 2]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_fastq_record() -> io::Result<()> {
+        let mut record =
+            fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGTAC", "NDLS#!");
+
+        assert_eq!(
+            Record::quality_scores(&record, QualityEncoding::Phred33)?.as_ref(),
+            [45, 35, 43, 50, 2, 0]
+        );
+
+        Record::retain(&mut record, 1..4)?;
+        assert_eq!(record.sequence(), b"CGT");
+        assert_eq!(record.quality_scores(), b"DLS");
+
+        assert!(matches!(
+            Record::retain(&mut record, 2..4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_record_buf() -> io::Result<()> {
+        let mut record = sam::alignment::RecordBuf::builder()
+            .set_sequence(b"ACGTAC".into())
+            .set_quality_scores(vec![45, 35, 43, 50, 2, 0].into())
+            .build();
+
+        Record::retain(&mut record, 1..4)?;
+        assert_eq!(record.sequence().as_ref(), b"CGT");
+        assert_eq!(record.quality_scores().as_ref(), [35, 43, 50]);
+
+        let mut record = sam::alignment::RecordBuf::builder()
+            .set_sequence(b"ACGT".into())
+            .build();
+
+        Record::retain(&mut record, 0..2)?;
+        assert_eq!(record.sequence().as_ref(), b"AC");
+        assert!(record.quality_scores().is_empty());
+
+        let mut record = sam::alignment::RecordBuf::builder()
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".into())
+            .build();
+
+        assert!(matches!(
+            Record::retain(&mut record, 0..2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
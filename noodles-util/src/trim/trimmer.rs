@@ -0,0 +1,368 @@
+//! Read trimmer.
+
+mod builder;
+
+use std::io;
+
+use noodles_fastq::qc::QualityEncoding;
+
+pub use self::builder::Builder;
+use super::{Record, adapter, overlap, poly_x, quality};
+
+/// A 3' quality trimming method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QualityTrimming {
+    /// BWA-style trimming ([`quality::bwa`]).
+    Bwa {
+        /// The quality score threshold.
+        threshold: u8,
+    },
+    /// Sliding window trimming ([`quality::sliding_window`]).
+    SlidingWindow {
+        /// The window size.
+        window_size: usize,
+        /// The minimum mean quality score of a window.
+        threshold: u8,
+    },
+}
+
+/// A read trimmer and filter.
+///
+/// A trimmer removes, in order, 3' adapters, poly-X tails, and low quality bases from the 3' end
+/// of reads and then checks whether the trimmed reads pass the length and `N` filters.
+#[derive(Clone, Debug)]
+pub struct Trimmer {
+    adapters: Vec<Vec<u8>>,
+    max_adapter_error_rate: f64,
+    min_adapter_overlap: usize,
+    poly_x_bases: Vec<u8>,
+    min_poly_x_length: usize,
+    quality_encoding: QualityEncoding,
+    quality_trimming: Option<QualityTrimming>,
+    min_pair_overlap: Option<usize>,
+    max_pair_mismatch_rate: f64,
+    min_length: usize,
+    max_length: Option<usize>,
+    max_n_count: Option<usize>,
+}
+
+impl Trimmer {
+    /// Returns a builder to create a trimmer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Trims a read and returns whether it passes the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use noodles_util::trim::{QualityTrimming, Trimmer};
+    ///
+    /// let trimmer = Trimmer::builder()
+    ///     .set_adapters(vec![b"AGATCGGAAGAG".to_vec()])
+    ///     .set_quality_trimming(QualityTrimming::Bwa { threshold: 20 })
+    ///     .set_min_length(4)
+    ///     .build();
+    ///
+    /// let mut record = fastq::Record::new(
+    ///     fastq::record::Definition::new("r0", ""),
+    ///     "ACGTACGTAGATCGGAAGAG",
+    ///     "IIIIII##IIIIIIIIIIII",
+    /// );
+    ///
+    /// assert!(trimmer.trim(&mut record)?);
+    /// assert_eq!(record.sequence(), b"ACGTAC");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn trim<R>(&self, record: &mut R) -> io::Result<bool>
+    where
+        R: Record,
+    {
+        let end = self.find_end(record)?;
+        record.retain(0..end)?;
+        Ok(self.passes(record))
+    }
+
+    /// Trims a read pair and returns whether both reads pass the filters.
+    ///
+    /// If pair overlap detection is enabled ([`Builder::set_min_pair_overlap`]), both reads are
+    /// also trimmed to the insert size found by overlapping the reads
+    /// ([`overlap::find_insert_size`]) with at most [`Builder::set_max_pair_mismatch_rate`]
+    /// mismatches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use noodles_util::trim::Trimmer;
+    ///
+    /// let trimmer = Trimmer::builder().set_min_pair_overlap(10).build();
+    ///
+    /// let mut r1 = fastq::Record::new(
+    ///     fastq::record::Definition::new("r0/1", ""),
+    ///     "ACGTTGCAACGGTTCAAGATCGGAAG",
+    ///     "IIIIIIIIIIIIIIIIIIIIIIIIII",
+    /// );
+    ///
+    /// let mut r2 = fastq::Record::new(
+    ///     fastq::record::Definition::new("r0/2", ""),
+    ///     "TGAACCGTTGCAACGTCTGTCTCTTA",
+    ///     "IIIIIIIIIIIIIIIIIIIIIIIIII",
+    /// );
+    ///
+    /// assert!(trimmer.trim_pair(&mut r1, &mut r2)?);
+    /// assert_eq!(r1.sequence(), b"ACGTTGCAACGGTTCA");
+    /// assert_eq!(r2.sequence(), b"TGAACCGTTGCAACGT");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn trim_pair<R>(&self, record_1: &mut R, record_2: &mut R) -> io::Result<bool>
+    where
+        R: Record,
+    {
+        let mut end_1 = self.find_end(record_1)?;
+        let mut end_2 = self.find_end(record_2)?;
+
+        if let Some(min_overlap) = self.min_pair_overlap {
+            if let Some(insert_size) = overlap::find_insert_size(
+                record_1.sequence(),
+                record_2.sequence(),
+                min_overlap,
+                self.max_pair_mismatch_rate,
+            ) {
+                end_1 = end_1.min(insert_size);
+                end_2 = end_2.min(insert_size);
+            }
+        }
+
+        record_1.retain(0..end_1)?;
+        record_2.retain(0..end_2)?;
+
+        Ok(self.passes(record_1) && self.passes(record_2))
+    }
+
+    fn find_end<R>(&self, record: &R) -> io::Result<usize>
+    where
+        R: Record,
+    {
+        let sequence = record.sequence();
+
+        let mut end = self
+            .adapters
+            .iter()
+            .filter_map(|adapter| {
+                adapter::find(
+                    sequence,
+                    adapter,
+                    self.max_adapter_error_rate,
+                    self.min_adapter_overlap,
+                )
+            })
+            .min()
+            .unwrap_or(sequence.len());
+
+        for &base in &self.poly_x_bases {
+            if let Some(start) = poly_x::find(&sequence[..end], base, self.min_poly_x_length) {
+                end = start;
+            }
+        }
+
+        if let Some(quality_trimming) = self.quality_trimming {
+            let quality_scores = record.quality_scores(self.quality_encoding)?;
+
+            if quality_scores.len() == sequence.len() {
+                let quality_scores = &quality_scores[..end];
+
+                end = match quality_trimming {
+                    QualityTrimming::Bwa { threshold } => quality::bwa(quality_scores, threshold),
+                    QualityTrimming::SlidingWindow {
+                        window_size,
+                        threshold,
+                    } => quality::sliding_window(quality_scores, window_size, threshold),
+                };
+            }
+        }
+
+        Ok(end)
+    }
+
+    fn passes<R>(&self, record: &R) -> bool
+    where
+        R: Record,
+    {
+        let sequence = record.sequence();
+        let len = sequence.len();
+
+        if len < self.min_length || self.max_length.is_some_and(|max| len > max) {
+            return false;
+        }
+
+        if let Some(max_n_count) = self.max_n_count {
+            let n_count = sequence
+                .iter()
+                .filter(|b| b.eq_ignore_ascii_case(&b'N'))
+                .count();
+
+            if n_count > max_n_count {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for Trimmer {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_fastq as fastq;
+    use noodles_sam as sam;
+
+    use super::*;
+
+    fn build_record(sequence: &str, quality_scores: &str) -> fastq::Record {
+        fastq::Record::new(
+            fastq::record::Definition::new("r0", ""),
+            sequence,
+            quality_scores,
+        )
+    }
+
+    #[test]
+    fn test_trim() -> io::Result<()> {
+        let trimmer = Trimmer::builder()
+            .set_adapters(vec![b"AGATCGGAAGAG".to_vec()])
+            .set_poly_x_bases(b"G".to_vec())
+            .set_quality_trimming(QualityTrimming::SlidingWindow {
+                window_size: 2,
+                threshold: 20,
+            })
+            .build();
+
+        let mut record = build_record("ACGTACGTAGATCGGA", "IIIIIIIIIIIIIIII");
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence(), b"ACGTACGT");
+        assert_eq!(record.quality_scores(), b"IIIIIIII");
+
+        let mut record = build_record("ACGTACCAGGGGGGGGGGGG", "IIIIIIIIIIIIIIIIIIII");
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence(), b"ACGTACCA");
+
+        let mut record = build_record("ACGTACGT", "IIIIII##");
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence(), b"ACGTAC");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_with_quality_encoding() -> io::Result<()> {
+        let trimmer = Trimmer::builder()
+            .set_quality_encoding(QualityEncoding::Phred64)
+            .set_quality_trimming(QualityTrimming::Bwa { threshold: 20 })
+            .build();
+
+        let mut record = build_record("ACGTACGT", "hhhhhhBB");
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence(), b"ACGTAC");
+
+        let mut record = build_record("ACGT", "hh##");
+        assert!(matches!(
+            trimmer.trim(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_with_filters() -> io::Result<()> {
+        let trimmer = Trimmer::builder()
+            .set_min_length(4)
+            .set_max_length(6)
+            .set_max_n_count(1)
+            .build();
+
+        let mut record = build_record("ACG", "III");
+        assert!(!trimmer.trim(&mut record)?);
+
+        let mut record = build_record("ACGTACG", "IIIIIII");
+        assert!(!trimmer.trim(&mut record)?);
+
+        let mut record = build_record("ACNN", "IIII");
+        assert!(!trimmer.trim(&mut record)?);
+
+        let mut record = build_record("ACGN", "IIII");
+        assert!(trimmer.trim(&mut record)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_record_buf() -> io::Result<()> {
+        let trimmer = Trimmer::builder()
+            .set_quality_trimming(QualityTrimming::Bwa { threshold: 20 })
+            .build();
+
+        let mut record = sam::alignment::RecordBuf::builder()
+            .set_sequence(b"ACGTACGT".into())
+            .set_quality_scores(vec![40, 40, 40, 40, 40, 40, 2, 2].into())
+            .build();
+
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence().as_ref(), b"ACGTAC");
+        assert_eq!(record.quality_scores().as_ref(), [40, 40, 40, 40, 40, 40]);
+
+        let mut record = sam::alignment::RecordBuf::builder()
+            .set_sequence(b"ACGTACGT".into())
+            .build();
+
+        assert!(trimmer.trim(&mut record)?);
+        assert_eq!(record.sequence().as_ref(), b"ACGTACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_pair() -> io::Result<()> {
+        let trimmer = Trimmer::builder()
+            .set_min_pair_overlap(10)
+            .set_min_length(20)
+            .build();
+
+        let mut r1 = build_record("ACGTTGCAACGGTTCAAGATCGGAAG", "IIIIIIIIIIIIIIIIIIIIIIIIII");
+        let mut r2 = build_record("TGAACCGTTGCAACGTCTGTCTCTTA", "IIIIIIIIIIIIIIIIIIIIIIIIII");
+
+        assert!(!trimmer.trim_pair(&mut r1, &mut r2)?);
+        assert_eq!(r1.sequence(), b"ACGTTGCAACGGTTCA");
+        assert_eq!(r2.sequence(), b"TGAACCGTTGCAACGT");
+
+        let trimmer = Trimmer::builder()
+            .set_max_adapter_error_rate(0.0)
+            .set_min_pair_overlap(10)
+            .build();
+
+        // r2 has a mismatch in the overlap.
+        let mut r1 = build_record("ACGTTGCAACGGTTCAAGATCGGAAG", "IIIIIIIIIIIIIIIIIIIIIIIIII");
+        let mut r2 = build_record("TGAACCGTTGCATCGTCTGTCTCTTA", "IIIIIIIIIIIIIIIIIIIIIIIIII");
+
+        assert!(trimmer.trim_pair(&mut r1, &mut r2)?);
+        assert_eq!(r1.sequence(), b"ACGTTGCAACGGTTCA");
+        assert_eq!(r2.sequence(), b"TGAACCGTTGCATCGT");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use noodles_fastq::qc::QualityEncoding;
+
+use super::{QualityTrimming, Trimmer};
+
+const DEFAULT_MAX_ADAPTER_ERROR_RATE: f64 = 0.1;
+const DEFAULT_MAX_PAIR_MISMATCH_RATE: f64 = 0.1;
+const DEFAULT_MIN_ADAPTER_OVERLAP: usize = 3;
+const DEFAULT_MIN_POLY_X_LENGTH: usize = 10;
+
+/// A read trimmer builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    adapters: Vec<Vec<u8>>,
+    max_adapter_error_rate: f64,
+    min_adapter_overlap: usize,
+    poly_x_bases: Vec<u8>,
+    min_poly_x_length: usize,
+    quality_encoding: QualityEncoding,
+    quality_trimming: Option<QualityTrimming>,
+    min_pair_overlap: Option<usize>,
+    max_pair_mismatch_rate: f64,
+    min_length: usize,
+    max_length: Option<usize>,
+    max_n_count: Option<usize>,
+}
+
+impl Builder {
+    /// Sets the 3' adapter sequences to trim.
+    ///
+    /// By default, no adapters are trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_adapters(vec![b"AGATCGGAAGAG".to_vec()]);
+    /// ```
+    pub fn set_adapters(mut self, adapters: Vec<Vec<u8>>) -> Self {
+        self.adapters = adapters;
+        self
+    }
+
+    /// Sets the maximum error rate of adapter alignments.
+    ///
+    /// By default, this is 0.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_max_adapter_error_rate(0.2);
+    /// ```
+    pub fn set_max_adapter_error_rate(mut self, max_adapter_error_rate: f64) -> Self {
+        self.max_adapter_error_rate = max_adapter_error_rate;
+        self
+    }
+
+    /// Sets the minimum length of an adapter partially overlapping the 3' end of a read.
+    ///
+    /// By default, this is 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_min_adapter_overlap(5);
+    /// ```
+    pub fn set_min_adapter_overlap(mut self, min_adapter_overlap: usize) -> Self {
+        self.min_adapter_overlap = min_adapter_overlap;
+        self
+    }
+
+    /// Sets the bases of 3' homopolymer tails to trim.
+    ///
+    /// By default, no homopolymer tails are trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_poly_x_bases(b"GA".to_vec());
+    /// ```
+    pub fn set_poly_x_bases(mut self, poly_x_bases: Vec<u8>) -> Self {
+        self.poly_x_bases = poly_x_bases;
+        self
+    }
+
+    /// Sets the minimum length of a 3' homopolymer tail to trim.
+    ///
+    /// By default, this is 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_min_poly_x_length(8);
+    /// ```
+    pub fn set_min_poly_x_length(mut self, min_poly_x_length: usize) -> Self {
+        self.min_poly_x_length = min_poly_x_length;
+        self
+    }
+
+    /// Sets the quality score encoding of FASTQ records.
+    ///
+    /// By default, this is Phred+33. This is not used for SAM records, which have decoded quality
+    /// scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::qc::QualityEncoding;
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_quality_encoding(QualityEncoding::Phred64);
+    /// ```
+    pub fn set_quality_encoding(mut self, quality_encoding: QualityEncoding) -> Self {
+        self.quality_encoding = quality_encoding;
+        self
+    }
+
+    /// Sets the 3' quality trimming method.
+    ///
+    /// By default, reads are not quality trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::{QualityTrimming, Trimmer};
+    ///
+    /// let builder =
+    ///     Trimmer::builder().set_quality_trimming(QualityTrimming::Bwa { threshold: 20 });
+    /// ```
+    pub fn set_quality_trimming(mut self, quality_trimming: QualityTrimming) -> Self {
+        self.quality_trimming = Some(quality_trimming);
+        self
+    }
+
+    /// Enables read pair overlap detection with the given minimum overlap.
+    ///
+    /// By default, read pair overlap detection is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_min_pair_overlap(30);
+    /// ```
+    pub fn set_min_pair_overlap(mut self, min_pair_overlap: usize) -> Self {
+        self.min_pair_overlap = Some(min_pair_overlap);
+        self
+    }
+
+    /// Sets the maximum mismatch rate of read pair overlaps.
+    ///
+    /// By default, this is 0.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_max_pair_mismatch_rate(0.05);
+    /// ```
+    pub fn set_max_pair_mismatch_rate(mut self, max_pair_mismatch_rate: f64) -> Self {
+        self.max_pair_mismatch_rate = max_pair_mismatch_rate;
+        self
+    }
+
+    /// Sets the minimum length of a trimmed read to pass the filters.
+    ///
+    /// By default, this is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_min_length(20);
+    /// ```
+    pub fn set_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Sets the maximum length of a trimmed read to pass the filters.
+    ///
+    /// By default, there is no maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_max_length(150);
+    /// ```
+    pub fn set_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the maximum number of `N` bases in a trimmed read to pass the filters.
+    ///
+    /// By default, there is no maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let builder = Trimmer::builder().set_max_n_count(5);
+    /// ```
+    pub fn set_max_n_count(mut self, max_n_count: usize) -> Self {
+        self.max_n_count = Some(max_n_count);
+        self
+    }
+
+    /// Builds a read trimmer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::trim::Trimmer;
+    /// let trimmer = Trimmer::builder().build();
+    /// ```
+    pub fn build(self) -> Trimmer {
+        Trimmer {
+            adapters: self.adapters,
+            max_adapter_error_rate: self.max_adapter_error_rate,
+            min_adapter_overlap: self.min_adapter_overlap,
+            poly_x_bases: self.poly_x_bases,
+            min_poly_x_length: self.min_poly_x_length,
+            quality_encoding: self.quality_encoding,
+            quality_trimming: self.quality_trimming,
+            min_pair_overlap: self.min_pair_overlap,
+            max_pair_mismatch_rate: self.max_pair_mismatch_rate,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            max_n_count: self.max_n_count,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            adapters: Vec::new(),
+            max_adapter_error_rate: DEFAULT_MAX_ADAPTER_ERROR_RATE,
+            min_adapter_overlap: DEFAULT_MIN_ADAPTER_OVERLAP,
+            poly_x_bases: Vec::new(),
+            min_poly_x_length: DEFAULT_MIN_POLY_X_LENGTH,
+            quality_encoding: QualityEncoding::Phred33,
+            quality_trimming: None,
+            min_pair_overlap: None,
+            max_pair_mismatch_rate: DEFAULT_MAX_PAIR_MISMATCH_RATE,
+            min_length: 0,
+            max_length: None,
+            max_n_count: None,
+        }
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 
 use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
+use noodles_core::region::Aliases;
 use noodles_csi::BinningIndex;
 use noodles_vcf as vcf;
 
@@ -21,6 +22,7 @@ pub struct Builder {
     compression_method: Option<Option<CompressionMethod>>,
     format: Option<Format>,
     index: Option<Box<dyn BinningIndex>>,
+    aliases: Aliases,
 }
 
 impl Builder {
@@ -78,6 +80,26 @@ impl Builder {
         self
     }
 
+    /// Sets reference sequence name aliases.
+    ///
+    /// Query regions with names that are not in the input are resolved using these aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Aliases;
+    /// use noodles_util::variant;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert(["chr1", "1"]);
+    ///
+    /// let builder = variant::io::indexed_reader::Builder::default().set_aliases(aliases);
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     /// Builds an indexed variant reader from a path.
     ///
     /// The compression method and format will be autodetected, if not overridden. If no index is
@@ -110,7 +132,8 @@ impl Builder {
 
         match (format, compression_method) {
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = vcf::io::indexed_reader::Builder::default();
+                let mut builder =
+                    vcf::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(index) = self.index {
                     builder = builder.set_index(index);
@@ -119,7 +142,8 @@ impl Builder {
                 builder.build_from_path(src).map(IndexedReader::Vcf)
             }
             (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = bcf::io::indexed_reader::Builder::default();
+                let mut builder =
+                    bcf::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(index) = self.index {
                     builder = builder.set_index(index);
@@ -178,7 +202,8 @@ impl Builder {
 
         match (format, compression) {
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = vcf::io::indexed_reader::Builder::default();
+                let mut builder =
+                    vcf::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(index) = self.index {
                     builder = builder.set_index(index);
@@ -187,7 +212,8 @@ impl Builder {
                 builder.build_from_reader(reader).map(IndexedReader::Vcf)
             }
             (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
-                let mut builder = bcf::io::indexed_reader::Builder::default();
+                let mut builder =
+                    bcf::io::indexed_reader::Builder::default().set_aliases(self.aliases);
 
                 if let Some(index) = self.index {
                     builder = builder.set_index(index);
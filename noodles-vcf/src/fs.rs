@@ -1,5 +1,5 @@
 //! VCF filesystem operations.
 
-mod index;
+pub(crate) mod index;
 
-pub use self::index::index;
+pub use self::index::{index, index_with_worker_count, validate as validate_index};
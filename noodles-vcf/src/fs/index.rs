@@ -1,10 +1,15 @@
+mod multithreaded;
+mod validate;
+
 use std::{fs::File, io, path::Path};
 
 use noodles_bgzf as bgzf;
+use noodles_core::Position;
 use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
 use noodles_tabix as tabix;
 
-use crate::{Record, io::Reader, variant::Record as _};
+pub use self::{multithreaded::index_with_worker_count, validate::validate};
+use crate::{Header, Record, io::Reader, variant::Record as _};
 
 /// Indexes a bgzipped-compressed VCF file.
 ///
@@ -26,7 +31,7 @@ where
     index_inner(&mut reader)
 }
 
-fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<tabix::Index>
+pub(crate) fn index_inner<R>(reader: &mut Reader<R>) -> io::Result<tabix::Index>
 where
     R: bgzf::io::BufRead,
 {
@@ -35,10 +40,57 @@ where
     let mut indexer = tabix::index::Indexer::default();
     indexer.set_header(csi::binning_index::index::header::Builder::vcf().build());
 
+    index_records(reader, &header, &mut indexer, None)?;
+
+    Ok(indexer.build())
+}
+
+// Indexes records until the end of the stream or until a record starts at or after `limit`. This
+// returns the position of the stream after the last indexed record.
+fn index_records<R>(
+    reader: &mut Reader<R>,
+    header: &Header,
+    indexer: &mut tabix::index::Indexer,
+    limit: Option<bgzf::VirtualPosition>,
+) -> io::Result<bgzf::VirtualPosition>
+where
+    R: bgzf::io::BufRead,
+{
+    read_records(
+        reader,
+        header,
+        limit,
+        |reference_sequence_name, start, end, chunk| {
+            indexer.add_record(reference_sequence_name, start, end, chunk)
+        },
+    )
+}
+
+// Reads records until the end of the stream or until a record starts at or after `limit`, calling
+// `f` with the reference sequence name, start and end positions, and chunk of each record. This
+// returns the position of the stream after the last read record.
+fn read_records<R, F>(
+    reader: &mut Reader<R>,
+    header: &Header,
+    limit: Option<bgzf::VirtualPosition>,
+    mut f: F,
+) -> io::Result<bgzf::VirtualPosition>
+where
+    R: bgzf::io::BufRead,
+    F: FnMut(&str, Position, Position, Chunk) -> io::Result<()>,
+{
     let mut record = Record::default();
     let mut start_position = reader.get_ref().virtual_position();
 
-    while reader.read_record(&mut record)? != 0 {
+    loop {
+        if limit.is_some_and(|limit| start_position >= limit) {
+            break;
+        }
+
+        if reader.read_record(&mut record)? == 0 {
+            break;
+        }
+
         let end_position = reader.get_ref().virtual_position();
         let chunk = Chunk::new(start_position, end_position);
 
@@ -49,12 +101,12 @@ where
             .transpose()?
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
 
-        let end = record.variant_end(&header)?;
+        let end = record.variant_end(header)?;
 
-        indexer.add_record(reference_sequence_name, start, end, chunk)?;
+        f(reference_sequence_name, start, end, chunk)?;
 
         start_position = end_position;
     }
 
-    Ok(indexer.build())
+    Ok(start_position)
 }
@@ -0,0 +1,224 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, Read, Seek},
+    num::NonZeroUsize,
+    panic,
+    path::Path,
+    thread,
+};
+
+use noodles_bgzf::{self as bgzf, io::Seek as _};
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+use super::index_records;
+use crate::{Header, io::Reader};
+
+type PartialIndex = (tabix::index::Indexer, bgzf::VirtualPosition);
+
+/// Indexes a bgzip-compressed VCF file using multiple threads.
+///
+/// The file is split into `worker_count` partitions at BGZF block boundaries, and each partition
+/// is indexed on its own thread. The partial indexes are then merged in order. The resulting
+/// index is the same as the one built by [`super::index`].
+///
+/// The first record in a partition starts after the first newline in the partition. If the start
+/// of a partition does not match the end of the previous partition, e.g., when a block ends with
+/// a newline, the partition is reindexed from the end of the previous partition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{num::NonZeroUsize, thread};
+/// use noodles_vcf as vcf;
+///
+/// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+/// let _index = vcf::fs::index_with_worker_count("sample.vcf.gz", worker_count)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_with_worker_count<P>(src: P, worker_count: NonZeroUsize) -> io::Result<tabix::Index>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+    index_inner(|| File::open(src), worker_count)
+}
+
+pub(super) fn index_inner<F, R>(open: F, worker_count: NonZeroUsize) -> io::Result<tabix::Index>
+where
+    F: Fn() -> io::Result<R> + Sync,
+    R: Read + Seek,
+{
+    let mut reader = open().map(bgzf::io::Reader::new).map(Reader::new)?;
+    let header = reader.read_header()?;
+    let header_end = reader.get_ref().virtual_position();
+
+    let positions: Vec<_> = bgzf::io::partition(&mut open()?, worker_count)?
+        .into_iter()
+        .filter(|&position| position > header_end.compressed())
+        .collect();
+
+    let mut ends = Vec::with_capacity(positions.len() + 1);
+
+    for &position in &positions {
+        let end = bgzf::VirtualPosition::try_from((position, 0))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        ends.push(Some(end));
+    }
+
+    ends.push(None);
+
+    thread::scope(|scope| {
+        let open = &open;
+        let header = &header;
+
+        let handles: Vec<_> = ends
+            .iter()
+            .zip(&ends[1..])
+            .map(|(&start, &end)| {
+                scope.spawn(move || -> io::Result<Option<_>> {
+                    let mut reader = open().map(bgzf::io::Reader::new)?;
+
+                    // SAFETY: Only the last end is `None`.
+                    let start = start.unwrap();
+
+                    let Some(start) = find_record_start(&mut reader, start)? else {
+                        return Ok(None);
+                    };
+
+                    let partial_index = index_partition(reader, header, start, end)?;
+
+                    Ok(Some((start, partial_index)))
+                })
+            })
+            .collect();
+
+        let (mut indexer, mut position) =
+            index_partition(reader.into_inner(), header, header_end, ends[0])?;
+
+        indexer.set_header(csi::binning_index::index::header::Builder::vcf().build());
+
+        for (handle, &end) in handles.into_iter().zip(&ends[1..]) {
+            let result = handle
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+            // A partition that failed or started at an unexpected position is reindexed from
+            // the end of the previous partition.
+            let (partial_indexer, end_position) = match result {
+                Ok(Some((start, partial_index))) if start == position => partial_index,
+                _ => {
+                    let reader = open().map(bgzf::io::Reader::new)?;
+                    index_partition(reader, header, position, end)?
+                }
+            };
+
+            indexer.merge(partial_indexer)?;
+            position = end_position;
+        }
+
+        Ok(indexer.build())
+    })
+}
+
+fn index_partition<R>(
+    mut reader: bgzf::io::Reader<R>,
+    header: &Header,
+    start: bgzf::VirtualPosition,
+    end: Option<bgzf::VirtualPosition>,
+) -> io::Result<PartialIndex>
+where
+    R: Read + Seek,
+{
+    reader.seek_to_virtual_position(start)?;
+
+    let mut reader = Reader::new(reader);
+    let mut indexer = tabix::index::Indexer::default();
+    let position = index_records(&mut reader, header, &mut indexer, end)?;
+
+    Ok((indexer, position))
+}
+
+fn find_record_start<R>(
+    reader: &mut bgzf::io::Reader<R>,
+    start: bgzf::VirtualPosition,
+) -> io::Result<Option<bgzf::VirtualPosition>>
+where
+    R: Read + Seek,
+{
+    const LINE_FEED: u8 = b'\n';
+
+    reader.seek_to_virtual_position(start)?;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(i) = src.iter().position(|&b| b == LINE_FEED) {
+            reader.consume(i + 1);
+            return Ok(Some(reader.virtual_position()));
+        }
+
+        let len = src.len();
+        reader.consume(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::variant::{RecordBuf, io::Write};
+
+    #[test]
+    fn test_index_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Default::default())
+            .add_contig("sq1", Default::default())
+            .add_contig("sq2", Default::default())
+            .build();
+
+        let mut writer = crate::io::Writer::new(bgzf::io::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, step) in [("sq0", 31), ("sq1", 47), ("sq2", 5)] {
+            for i in 0..8192 {
+                let record = RecordBuf::builder()
+                    .set_reference_sequence_name(reference_sequence_name)
+                    .set_variant_start(Position::try_from(1 + i * step)?)
+                    .set_reference_bases("ACGT".repeat(1 + i % 7))
+                    .build();
+
+                writer.write_variant_record(&header, &record)?;
+            }
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(bgzf::io::Reader::new(&data[..]));
+        let expected = super::super::index_inner(&mut reader)?;
+
+        for worker_count in [1, 3, 8, 64] {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+            let actual = index_inner(|| Ok(Cursor::new(&data)), worker_count)?;
+            assert_eq!(actual, expected);
+
+            let mut actual_buf = Vec::new();
+            tabix::io::Writer::new(&mut actual_buf).write_index(&actual)?;
+
+            let mut expected_buf = Vec::new();
+            tabix::io::Writer::new(&mut expected_buf).write_index(&expected)?;
+
+            assert_eq!(actual_buf, expected_buf);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use std::{fs::File, io, path::Path};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{
+    self as csi, BinningIndex,
+    binning_index::{Validator, index::reference_sequence::index::LinearIndex, validator::Report},
+};
+use noodles_tabix as tabix;
+
+use super::read_records;
+use crate::io::Reader;
+
+/// Validates a tabix index against its bgzipped-compressed VCF file.
+///
+/// The returned report lists the issues of the index, e.g., when the index is stale because the
+/// VCF file was regenerated. If there are any issues, the report includes a repaired index built
+/// from the VCF file.
+///
+/// Reference sequences are matched by name. This returns an error if the records are not in the
+/// order of the reference sequences of the index. Such an index cannot be repaired and must be
+/// rebuilt, e.g., using [`super::index`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_tabix as tabix;
+/// use noodles_vcf as vcf;
+///
+/// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+/// let report = vcf::fs::validate_index("sample.vcf.gz", &index)?;
+///
+/// for issue in report.issues() {
+///     eprintln!("{issue}");
+/// }
+///
+/// if let Some(repaired_index) = report.repaired_index() {
+///     tabix::fs::write("sample.vcf.gz.tbi", repaired_index)?;
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn validate<P>(src: P, index: &tabix::Index) -> io::Result<Report<LinearIndex>>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src)
+        .map(bgzf::io::Reader::new)
+        .map(Reader::new)?;
+
+    validate_inner(&mut reader, index)
+}
+
+fn validate_inner<R>(
+    reader: &mut Reader<R>,
+    index: &tabix::Index,
+) -> io::Result<Report<LinearIndex>>
+where
+    R: bgzf::io::BufRead,
+{
+    let header = reader.read_header()?;
+
+    let mut index_header = index
+        .header()
+        .cloned()
+        .unwrap_or_else(|| csi::binning_index::index::header::Builder::vcf().build());
+
+    let mut reference_sequence_names = index_header.reference_sequence_names().clone();
+    let mut validator = Validator::new(index);
+
+    read_records(
+        reader,
+        &header,
+        None,
+        |reference_sequence_name, start, end, chunk| {
+            let (reference_sequence_id, _) =
+                reference_sequence_names.insert_full(reference_sequence_name.into());
+
+            validator
+            .add_record(Some((reference_sequence_id, start, end, true)), chunk)
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "reference sequence {reference_sequence_name} is out of order of the index"
+                    ),
+                )
+            })
+        },
+    )?;
+
+    *index_header.reference_sequence_names_mut() = reference_sequence_names;
+    validator.set_header(index_header);
+
+    Ok(validator.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_csi::binning_index::validator::Issue;
+
+    use super::*;
+    use crate::{
+        Header,
+        fs::index::index_inner,
+        variant::{RecordBuf, io::Write},
+    };
+
+    fn build_data(records: &[(&str, usize)]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Default::default())
+            .add_contig("sq1", Default::default())
+            .build();
+
+        let mut writer = crate::io::Writer::new(bgzf::io::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        for &(reference_sequence_name, variant_start) in records {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(variant_start)?)
+                .set_reference_bases("A")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        Ok(writer.into_inner().finish()?)
+    }
+
+    #[test]
+    fn test_validate_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data(&[("sq0", 8), ("sq0", 13)])?;
+        let index = index_inner(&mut Reader::new(bgzf::io::Reader::new(&data[..])))?;
+
+        let report = validate_inner(&mut Reader::new(bgzf::io::Reader::new(&data[..])), &index)?;
+        assert!(report.is_ok());
+
+        let stale_data = build_data(&[("sq0", 8), ("sq0", 13), ("sq1", 21)])?;
+        let report = validate_inner(
+            &mut Reader::new(bgzf::io::Reader::new(&stale_data[..])),
+            &index,
+        )?;
+
+        assert!(report.issues().contains(&Issue::MissingReferenceSequence {
+            reference_sequence_id: 1
+        }));
+
+        let repaired_index = report.repaired_index().unwrap();
+        let expected = index_inner(&mut Reader::new(bgzf::io::Reader::new(&stale_data[..])))?;
+        assert_eq!(repaired_index, &expected);
+
+        let unordered_data = build_data(&[("sq1", 21), ("sq0", 8)])?;
+        assert!(matches!(
+            validate_inner(
+                &mut Reader::new(bgzf::io::Reader::new(&unordered_data[..])),
+                &index,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -3,16 +3,20 @@
 mod builder;
 
 pub use self::builder::Builder;
+pub(crate) use self::builder::build_index_src;
 
-use std::io::{self, BufRead, Read};
+use std::{
+    borrow::Cow,
+    io::{self, BufRead, Read},
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::Aliases};
 use noodles_csi::BinningIndex;
 
 use super::{
     Reader,
-    reader::{Query, RecordBufs},
+    reader::{Query, QueryRegions, RecordBufs},
 };
 use crate::{Header, Record, variant::RecordBuf};
 
@@ -20,6 +24,7 @@ use crate::{Header, Record, variant::RecordBuf};
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
+    aliases: Aliases,
 }
 
 impl<R> IndexedReader<R> {
@@ -76,6 +81,16 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Returns the reference sequence name aliases used to resolve query regions.
+    pub fn aliases(&self) -> &Aliases {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the reference sequence name aliases.
+    pub fn aliases_mut(&mut self) -> &mut Aliases {
+        &mut self.aliases
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -88,7 +103,24 @@ where
         header: &'h Header,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = resolve_region(&self.aliases, &self.index, region);
+        self.inner.query(header, &self.index, &region)
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_regions`].
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| resolve_region(&self.aliases, &self.index, region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
     }
 }
 
@@ -104,6 +136,21 @@ where
         Self {
             inner: Reader::new(bgzf::io::Reader::new(inner)),
             index: Box::new(index),
+            aliases: Aliases::default(),
+        }
+    }
+}
+
+fn resolve_region<'r>(
+    aliases: &Aliases,
+    index: &dyn BinningIndex,
+    region: &'r Region,
+) -> Cow<'r, Region> {
+    match index.header() {
+        Some(header) => {
+            let names = header.reference_sequence_names();
+            aliases.resolve_region(region, |name| names.contains(name))
         }
+        None => Cow::Borrowed(region),
     }
 }
@@ -0,0 +1,258 @@
+//! Indexed VCF writer.
+
+mod builder;
+
+use std::{
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::binning_index::{PendingRecord, index::reference_sequence::bin::Chunk};
+use noodles_tabix as tabix;
+
+pub use self::builder::Builder;
+use super::Writer;
+use crate::{Header, Record};
+
+/// An indexed VCF writer.
+///
+/// An indexed VCF writer writes bgzip-compressed VCF and builds a tabix index while writing
+/// records, which avoids reading the file back to index it (cf. [`crate::fs::index`]).
+///
+/// The records must be coordinate-sorted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{self as vcf, variant::{io::Write, RecordBuf}};
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Default::default())
+///     .build();
+///
+/// let mut writer = vcf::io::IndexedWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// writer.write_variant_record(&header, &record)?;
+///
+/// let index = writer.finish()?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::io::Writer<W>>,
+    indexer: tabix::index::Indexer,
+    pending_record: PendingRecord<(String, Position, Position)>,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer with a default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::io::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Builder::default().build_from_writer(inner)
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &bgzf::io::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a VCF header.
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a VCF record.
+    pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
+        use crate::variant::io::Write;
+        self.write_variant_record(header, record)
+    }
+
+    /// Shuts down the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.tbi`.
+    pub fn finish(&mut self) -> io::Result<tabix::Index> {
+        self.inner.get_mut().flush()?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        if let Some((context, chunk)) = self.pending_record.finish(end_position) {
+            self.index_record(context, chunk)?;
+        }
+
+        self.inner.get_mut().try_finish()?;
+
+        let indexer = mem::replace(&mut self.indexer, new_indexer());
+        let index = indexer.build();
+
+        if let Some(dst) = self.index_dst.take() {
+            tabix::fs::write(dst, &index)?;
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::io::IndexedWriter::new(Vec::new());
+    /// assert!(writer.into_inner().get_ref().is_empty());
+    /// ```
+    pub fn into_inner(self) -> bgzf::io::Writer<W> {
+        self.inner.into_inner()
+    }
+
+    fn index_record(
+        &mut self,
+        (reference_sequence_name, start, end): (String, Position, Position),
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        self.indexer
+            .add_record(&reference_sequence_name, start, end, chunk)
+    }
+}
+
+impl<W> crate::variant::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_variant_header(&mut self, header: &Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &Header,
+        record: &dyn crate::variant::Record,
+    ) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_variant_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+
+        let end = record.variant_end(header)?;
+
+        let context = (reference_sequence_name.into(), start, end);
+
+        if let Some((context, chunk)) = self.pending_record.replace(context, chunk) {
+            self.index_record(context, chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(super) fn new_indexer() -> tabix::index::Indexer {
+    use noodles_csi::binning_index::index::header::Builder;
+
+    let mut indexer = tabix::index::Indexer::default();
+    indexer.set_header(Builder::vcf().build());
+    indexer
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+    use crate::{
+        io::Reader,
+        variant::{RecordBuf, io::Write as _},
+    };
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let header = Header::builder()
+            .add_contig("sq0", Default::default())
+            .add_contig("sq1", Default::default())
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, start) in
+            [("sq0", 1), ("sq0", 20000), ("sq1", 8), ("sq1", 70000)]
+        {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(start).unwrap())
+                .set_reference_bases("ACGT")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let actual = writer.finish()?;
+
+        let data = writer.into_inner().into_inner();
+        let mut reader = Reader::new(bgzf::io::Reader::new(&data[..]));
+        let expected = crate::fs::index::index_inner(&mut reader)?;
+
+        // The serial indexer reads past the BGZF EOF block after the last record, so only the
+        // end position of the last record differs.
+        let actual_reference_sequences = actual.reference_sequences();
+        let expected_reference_sequences = expected.reference_sequences();
+
+        assert_eq!(actual.header(), expected.header());
+        assert_eq!(
+            actual_reference_sequences[0],
+            expected_reference_sequences[0]
+        );
+        assert_eq!(
+            actual_reference_sequences[1].index(),
+            expected_reference_sequences[1].index()
+        );
+        assert_eq!(
+            actual_reference_sequences[1]
+                .bins()
+                .keys()
+                .collect::<Vec<_>>(),
+            expected_reference_sequences[1]
+                .bins()
+                .keys()
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
@@ -3,21 +3,26 @@
 mod builder;
 pub mod header;
 pub(crate) mod query;
+mod query_regions;
 pub(crate) mod record;
 pub mod record_buf;
 mod record_bufs;
 
 use self::record::read_record;
 pub(crate) use self::record_buf::parse_record_buf;
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{
+    builder::Builder, query::Query, query_regions::QueryRegions, record_bufs::RecordBufs,
+};
 
 use std::{
-    io::{self, BufRead},
-    iter,
+    collections::HashMap,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    iter, mem,
+    num::NonZeroUsize,
 };
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::RegionSet};
 use noodles_csi::BinningIndex;
 
 use self::header::read_header;
@@ -322,6 +327,148 @@ where
     }
 }
 
+impl<R> Reader<bgzf::io::Reader<R>>
+where
+    R: Read,
+{
+    /// Returns an iterator over records that skips corrupt data.
+    ///
+    /// This is meant to be used with a BGZF reader in salvage mode (see
+    /// [`bgzf::io::reader::Builder::set_salvage`]). When the BGZF reader skips invalid blocks, the
+    /// line that overlaps the skipped data is discarded, along with the remainder of the partial
+    /// line at the start of the following block. If the skipped data is known to end with a line
+    /// feed (see [`bgzf::io::Reader::last_skipped_byte`]), the following block starts with a
+    /// complete line, which is kept unless it fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let decoder = bgzf::io::reader::Builder::default()
+    ///     .set_salvage(true)
+    ///     .build_from_path("sample.vcf.gz")?;
+    /// let mut reader = vcf::io::Reader::new(decoder);
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.salvaged_records() {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    ///
+    /// for range in reader.get_ref().skipped_ranges() {
+    ///     eprintln!("skipped compressed range: {range:?}");
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn salvaged_records(&mut self) -> impl Iterator<Item = io::Result<Record>> {
+        let mut record = Record::default();
+        let mut is_after_skip = false;
+
+        iter::from_fn(move || {
+            loop {
+                let skip_count = self.inner.skipped_ranges().len();
+                let result = self.read_record(&mut record);
+                let is_first_line_after_skip = mem::take(&mut is_after_skip);
+
+                match result {
+                    Ok(0) => return None,
+                    Ok(_) => return Some(Ok(record.clone())),
+                    Err(_) if self.inner.skipped_ranges().len() > skip_count => {
+                        if ends_with_line_feed(&self.inner) {
+                            is_after_skip = true;
+                        } else {
+                            match discard_partial_line(&mut self.inner) {
+                                Ok(is_line_start) => is_after_skip = is_line_start,
+                                Err(e) => return Some(Err(e)),
+                            }
+                        }
+                    }
+                    // The last byte of the skipped data is unverified, so the first line after it
+                    // may still be partial.
+                    Err(_) if is_first_line_after_skip => {}
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+        })
+    }
+}
+
+impl<R> Reader<bgzf::io::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Verifies the integrity of all BGZF blocks in the underlying stream.
+    ///
+    /// This reads the entire stream from the start and then restores the current position. See
+    /// [`bgzf::io::verify`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io, num::NonZeroUsize, thread};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    /// let report = reader.verify(worker_count)?;
+    ///
+    /// if !report.is_ok() {
+    ///     eprintln!("invalid blocks: {:?}", report.invalid_blocks());
+    ///     eprintln!("has EOF block: {}", report.has_eof_block());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn verify(&mut self, worker_count: NonZeroUsize) -> io::Result<bgzf::io::verify::Report> {
+        let position = self.inner.virtual_position();
+
+        let inner = self.inner.get_mut();
+        inner.seek(SeekFrom::Start(0))?;
+        let report = bgzf::io::verify(inner, worker_count)?;
+
+        self.inner.seek(position)?;
+
+        Ok(report)
+    }
+}
+
+// Returns whether the most recently skipped BGZF data is known to end with a line feed.
+fn ends_with_line_feed<R>(reader: &bgzf::io::Reader<R>) -> bool
+where
+    R: Read,
+{
+    reader.last_skipped_byte() == Some(b'\n')
+}
+
+// Discards the remainder of a line that was cut off by skipped BGZF data.
+//
+// This returns whether the reader stopped after more skipped data that ends with a line feed
+// rather than at the end of the line.
+fn discard_partial_line<R>(reader: &mut bgzf::io::Reader<R>) -> io::Result<bool>
+where
+    R: Read,
+{
+    loop {
+        let skip_count = reader.skipped_ranges().len();
+
+        match reader.skip_until(b'\n') {
+            Ok(_) => return Ok(false),
+            Err(_) if reader.skipped_ranges().len() > skip_count => {
+                if ends_with_line_feed(reader) {
+                    return Ok(true);
+                }
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -373,6 +520,66 @@ where
             header,
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The regions are sorted and merged before querying the index, and the chunks of all
+    /// regions are merged. This reads each block and record at most once, and each record is
+    /// paired with the indices of the regions it intersects, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let mut reference_sequence_ids = HashMap::new();
+        let mut intervals = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+            reference_sequence_ids.insert(reference_sequence_name, reference_sequence_id);
+            intervals.push((reference_sequence_id, region.interval()));
+        }
+
+        let region_set = RegionSet::new(intervals);
+        let chunks = noodles_csi::binning_index::query_regions(index, &region_set)?;
+
+        Ok(QueryRegions::new(
+            self.get_mut(),
+            chunks,
+            header,
+            reference_sequence_ids,
+            region_set,
+        ))
+    }
 }
 
 impl<R> crate::variant::io::Read<R> for Reader<R>
@@ -472,6 +679,81 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_salvaged_records() -> io::Result<()> {
+        use std::io::Write;
+
+        use noodles_core::Position;
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t2\t.\tA\t.\t.\tPASS\t.
+sq0\t3\t.\tA\t.\t.\tPASS\t.
+sq0\t4\t.\tA\t.\t.\tPASS\t.
+";
+
+        const HEADER_LEN: usize = 60;
+        const LINE_LEN: usize = 21;
+
+        fn read_positions(ends: [usize; 4]) -> io::Result<Vec<Option<Position>>> {
+            let mut writer = bgzf::io::Writer::new(Vec::new());
+            let mut block_positions = Vec::new();
+            let mut start = 0;
+
+            for end in ends {
+                writer.flush()?;
+                block_positions.push(writer.get_ref().len());
+                writer.write_all(&DATA[start..end])?;
+                start = end;
+            }
+
+            let mut data = writer.finish()?;
+
+            // Corrupt the CRC32 of block 2.
+            data[block_positions[3] - 8] ^= 0xff;
+
+            let mut reader = Reader::new(
+                bgzf::io::reader::Builder::default()
+                    .set_salvage(true)
+                    .build_from_reader(&data[..]),
+            );
+
+            reader.read_header()?;
+
+            reader
+                .salvaged_records()
+                .map(|result| result.and_then(|record| record.variant_start().transpose()))
+                .collect()
+        }
+
+        // Block 2 starts and ends within lines 1 and 2, respectively.
+        let positions = read_positions([
+            HEADER_LEN,
+            HEADER_LEN + LINE_LEN + 3,
+            HEADER_LEN + 2 * LINE_LEN + 5,
+            DATA.len(),
+        ])?;
+
+        assert_eq!(positions, [Position::new(1), Position::new(4)]);
+
+        // Block 2 starts within line 1 and ends at the end of line 1.
+        let positions = read_positions([
+            HEADER_LEN,
+            HEADER_LEN + LINE_LEN + 3,
+            HEADER_LEN + 2 * LINE_LEN,
+            DATA.len(),
+        ])?;
+
+        assert_eq!(
+            positions,
+            [Position::new(1), Position::new(3), Position::new(4)]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -0,0 +1,158 @@
+use std::{collections::HashMap, io};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::{Interval, RegionSet};
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use crate::{Header, Record, variant::Record as _};
+
+/// An iterator over records of a VCF reader that intersect any of a list of regions.
+///
+/// Each record is read at most once and is paired with the indices of the regions it intersects,
+/// in ascending order.
+///
+/// This is created by calling [`super::Reader::query_regions`].
+pub struct QueryRegions<'r, 'h, R> {
+    reader: super::Reader<csi::io::Query<'r, R>>,
+    header: &'h Header,
+    reference_sequence_ids: HashMap<Vec<u8>, usize>,
+    regions: RegionSet,
+    record: Record,
+}
+
+impl<'r, 'h, R> QueryRegions<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        header: &'h Header,
+        reference_sequence_ids: HashMap<Vec<u8>, usize>,
+        regions: RegionSet,
+    ) -> Self {
+        Self {
+            reader: super::Reader::new(csi::io::Query::new(reader, chunks)),
+            header,
+            reference_sequence_ids,
+            regions,
+            record: Record::default(),
+        }
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<Vec<usize>>> {
+        loop {
+            if self.reader.read_record(&mut self.record)? == 0 {
+                return Ok(None);
+            }
+
+            let indices = self.overlapping_regions()?;
+
+            if !indices.is_empty() {
+                return Ok(Some(indices));
+            }
+        }
+    }
+
+    fn overlapping_regions(&self) -> io::Result<Vec<usize>> {
+        let reference_sequence_name = self.record.reference_sequence_name().as_bytes();
+
+        let Some(&reference_sequence_id) = self.reference_sequence_ids.get(reference_sequence_name)
+        else {
+            return Ok(Vec::new());
+        };
+
+        let Some(start) = self.record.variant_start().transpose()? else {
+            return Ok(Vec::new());
+        };
+
+        let end = self.record.variant_end(self.header)?;
+
+        Ok(self
+            .regions
+            .overlapping(reference_sequence_id, Interval::from(start..=end)))
+    }
+}
+
+impl<R> Iterator for QueryRegions<'_, '_, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Record, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_record() {
+            Ok(Some(indices)) => Some(Ok((self.record.clone(), indices))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::variant::{RecordBuf, io::Write};
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Default::default())
+            .add_contig("sq1", Default::default())
+            .build();
+
+        let mut writer = crate::io::Writer::new(bgzf::io::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, variant_start, reference_bases) in [
+            ("sq0", 8, "A"),
+            ("sq0", 13, "ACGT"),
+            ("sq0", 21, "A"),
+            ("sq1", 5, "A"),
+        ] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(variant_start)?)
+                .set_reference_bases(reference_bases)
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let index = crate::fs::index::index_inner(&mut crate::io::Reader::new(
+            bgzf::io::Reader::new(&data[..]),
+        ))?;
+
+        let mut reader = crate::io::Reader::new(bgzf::io::Reader::new(Cursor::new(data)));
+        reader.read_header()?;
+
+        let regions = ["sq0:15-21".parse()?, "sq1".parse()?, "sq0:1-13".parse()?];
+
+        let actual = reader
+            .query_regions(&header, &index, &regions)?
+            .map(|result| {
+                result.and_then(|(record, indices)| {
+                    let start = record.variant_start().transpose()?;
+                    Ok((record.reference_sequence_name().to_string(), start, indices))
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let expected = [
+            (String::from("sq0"), Position::new(8), vec![2]),
+            (String::from("sq0"), Position::new(13), vec![0, 2]),
+            (String::from("sq0"), Position::new(21), vec![0]),
+            (String::from("sq1"), Position::new(5), vec![1]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -51,6 +51,10 @@ pub use noodles_gtf as gtf;
 #[doc(inline)]
 pub use noodles_htsget as htsget;
 
+#[cfg(feature = "htsget-server")]
+#[doc(inline)]
+pub use noodles_htsget_server as htsget_server;
+
 #[cfg(feature = "refget")]
 #[doc(inline)]
 pub use noodles_refget as refget;